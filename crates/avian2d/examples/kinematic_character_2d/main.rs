@@ -5,13 +5,14 @@
 //! - Basic directional movement and jumping
 //! - Support for both keyboard and gamepad input
 //! - A configurable maximum slope angle
+//! - Stepping up stairs and snapping to the ground
 //! - Collision response for kinematic bodies
 //!
-//! The character controller logic is contained within the `plugin` module.
+//! The character is moved by Avian's built-in `CharacterController`.
+//! Input, gravity, and movement are handled in the `plugin` module.
 //!
 //! For a dynamic character controller, see the `dynamic_character_2d` example.
 
-#[expect(clippy::type_complexity)]
 mod plugin;
 
 use avian2d::prelude::*;
@@ -27,7 +28,8 @@ fn main() {
             // Add physics plugins and specify a units-per-meter scaling factor, 1 meter = 20 pixels.
             // The unit allows the engine to tune its parameters for the scale of the world, improving stability.
             PhysicsPlugins::default().with_length_unit(20.0),
-            CharacterControllerPlugin,
+            CharacterControllerPlugin::default(),
+            CharacterMovementPlugin,
         ))
         .insert_resource(ClearColor(Color::srgb(0.05, 0.05, 0.1)))
         .insert_resource(Gravity(Vec2::NEG_Y * 1000.0))
@@ -42,14 +44,8 @@ fn setup(
 ) {
     // Player
     commands.spawn((
-        CharacterController,
+        CharacterController::default(),
         CharacterMovementSettings::default(),
-        CharacterCollisions::default(),
-        GroundDetection {
-            // Use a slightly smaller capsule for shape casts used for ground detection
-            cast_shape: Some(Collider::capsule(12.49, 20.0)),
-            ..default()
-        },
         Collider::capsule(12.5, 20.0),
         Mesh2d(meshes.add(Capsule2d::new(12.5, 20.0))),
         MeshMaterial2d(materials.add(Color::srgb(0.2, 0.7, 0.9))),
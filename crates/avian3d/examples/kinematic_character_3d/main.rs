@@ -5,15 +5,15 @@
 //! - Basic directional movement and jumping
 //! - Support for both keyboard and gamepad input
 //! - A configurable maximum slope angle
+//! - Stepping up stairs and snapping to the ground
 //! - Collision response for kinematic bodies
 //! - Loading a platformer environment from a glTF
 //!
-//! The character controller logic is contained within the `plugin` module.
+//! The character is moved by Avian's built-in `CharacterController`.
+//! Input, gravity, and movement are handled in the `plugin` module.
 //!
 //! For a dynamic character controller, see the `dynamic_character_3d` example.
 
-#![expect(clippy::type_complexity)]
-
 mod plugin;
 
 use avian3d::prelude::*;
@@ -27,7 +27,8 @@ fn main() {
             DefaultPlugins,
             ExampleCommonPlugin,
             PhysicsPlugins::default(),
-            CharacterControllerPlugin,
+            CharacterControllerPlugin::default(),
+            CharacterMovementPlugin,
         ))
         .add_systems(Startup, setup)
         .run();
@@ -41,14 +42,8 @@ fn setup(
 ) {
     // Player
     commands.spawn((
-        CharacterController,
+        CharacterController::default(),
         CharacterMovementSettings::default(),
-        CharacterCollisions::default(),
-        GroundDetection {
-            // Use a slightly smaller capsule for shape casts used for ground detection
-            cast_shape: Some(Collider::capsule(0.399, 1.0)),
-            ..default()
-        },
         Collider::capsule(0.4, 1.0),
         Mesh3d(meshes.add(Capsule3d::new(0.4, 1.0))),
         MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.6))),
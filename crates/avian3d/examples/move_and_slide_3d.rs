@@ -231,6 +231,7 @@ fn run_move_and_slide(
         let MoveAndSlideOutput {
             position,
             projected_velocity,
+            ..
         } = move_and_slide.move_and_slide(
             collider,
             transform.translation.real(),
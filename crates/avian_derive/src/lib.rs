@@ -3,9 +3,18 @@
 use proc_macro::TokenStream;
 
 use proc_macro_error3::{abort, emit_error, proc_macro_error};
+use proc_macro2::Literal;
 use quote::quote;
 use syn::{Data, DeriveInput, parse_macro_input, spanned::Spanned};
 
+/// The maximum number of physics layers, matching the width of `LayerBits` in Avian.
+#[cfg(not(any(feature = "layers-64", feature = "layers-128")))]
+const MAX_LAYERS: usize = 32;
+#[cfg(all(feature = "layers-64", not(feature = "layers-128")))]
+const MAX_LAYERS: usize = 64;
+#[cfg(feature = "layers-128")]
+const MAX_LAYERS: usize = 128;
+
 // Modified macro from the discontinued Heron
 // https://github.com/jcornaz/heron/blob/main/macros/src/lib.rs
 /// A derive macro for defining physics layers using an enum.
@@ -15,11 +24,13 @@ use syn::{Data, DeriveInput, parse_macro_input, spanned::Spanned};
 ///
 /// # Requirements
 ///
-/// - The enum must have at most 32 variants.
+/// - The enum must have at most 32 variants, or 64 and 128 variants
+///   with the `layers-64` and `layers-128` features.
 /// - The enum variants must not have any fields.
 /// - The enum must have a default variant with the `#[default]` attribute.
 ///   - The first bit `1 << 0` will *always* be reserved for the default layer.
 ///     The bit values of the other layers are determined by their order in the enum, starting from `1 << 1`.
+/// - The `PhysicsLayer` trait and the `LayerBits` type must be in scope, for example by importing the prelude.
 ///
 /// # Example
 ///
@@ -58,10 +69,11 @@ pub fn derive_physics_layer(input: TokenStream) -> TokenStream {
         }
     };
 
-    if variants.len() > 32 {
+    if variants.len() > MAX_LAYERS {
         emit_error!(
             enum_ident,
-            "`PhysicsLayer` only supports a maximum of 32 layers"
+            "`PhysicsLayer` only supports a maximum of {} layers", MAX_LAYERS;
+            help = "enable the `layers-64` or `layers-128` feature to support more layers"
         );
     }
 
@@ -99,7 +111,7 @@ pub fn derive_physics_layer(input: TokenStream) -> TokenStream {
             if !variant.fields.is_empty() {
                 return Err(variant.fields.span());
             }
-            let bits: u32 = 1 << index;
+            let bits = Literal::u128_unsuffixed(1u128.checked_shl(index as u32).unwrap_or(0));
             let ident = &variant.ident;
 
             Ok(quote! { #enum_ident::#ident => #bits, })
@@ -116,19 +128,21 @@ pub fn derive_physics_layer(input: TokenStream) -> TokenStream {
         }
     };
 
-    let all_bits: u32 = if variants.len() == 32 {
-        0xffffffff
+    let all_bits = Literal::u128_unsuffixed(if variants.len() >= MAX_LAYERS {
+        u128::MAX >> (128 - MAX_LAYERS)
     } else {
         (1 << variants.len()) - 1
-    };
+    });
 
+    // Like `PhysicsLayer`, `LayerBits` is expected to be in scope, for example through the prelude.
+    // This keeps the return types in sync with the width of `LayerBits` in Avian.
     let expanded = quote! {
         impl PhysicsLayer for #enum_ident {
-            fn all_bits() -> u32 {
+            fn all_bits() -> LayerBits {
                 #all_bits
             }
 
-            fn to_bits(&self) -> u32 {
+            fn to_bits(&self) -> LayerBits {
                 match self {
                     #(#to_bits)*
                 }
@@ -0,0 +1,682 @@
+//! A kinematic [`CharacterController`] built on top of [`MoveAndSlide`].
+//!
+//! See the documentation of [`CharacterController`] for more information.
+
+use super::move_and_slide::accumulate_pushes;
+use crate::prelude::*;
+use bevy::{
+    ecs::{intern::Interned, schedule::ScheduleLabel},
+    prelude::*,
+};
+use core::time::Duration;
+
+/// A plugin for moving [`CharacterController`]s with [`MoveAndSlide`]
+/// and updating their [`CharacterGroundState`].
+pub struct CharacterControllerPlugin {
+    schedule: Interned<dyn ScheduleLabel>,
+}
+
+impl CharacterControllerPlugin {
+    /// Creates a [`CharacterControllerPlugin`] with the schedule that is used for running the [`PhysicsSchedule`].
+    ///
+    /// The default schedule is `FixedPostUpdate`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Default for CharacterControllerPlugin {
+    fn default() -> Self {
+        Self::new(FixedPostUpdate)
+    }
+}
+
+impl Plugin for CharacterControllerPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            self.schedule,
+            CharacterControllerSystems
+                .after(PhysicsSystems::First)
+                .before(PhysicsSystems::Prepare),
+        );
+
+        app.init_resource::<CharacterPushes>();
+
+        app.add_systems(
+            self.schedule,
+            (move_character_controllers, apply_character_pushes)
+                .chain()
+                .in_set(CharacterControllerSystems),
+        );
+
+        app.add_systems(
+            PhysicsSchedule,
+            accumulate_pushes
+                .after(PhysicsStepSystems::NarrowPhase)
+                .before(PhysicsStepSystems::Solver),
+        );
+    }
+}
+
+/// A system set for moving [`CharacterController`]s. Runs before [`PhysicsSystems::Prepare`]
+/// in the schedule that runs the [`PhysicsSchedule`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterControllerSystems;
+
+/// A kinematic character controller that is moved with [`MoveAndSlide`].
+///
+/// Each physics step, the character is moved according to its [`LinearVelocity`],
+/// sliding along any colliders that are hit on the way. On top of the *move and slide* algorithm,
+/// the controller handles the following behaviors:
+///
+/// - **Grounding**: Surfaces within the [`max_slope_angle`](Self::max_slope_angle) are considered ground.
+///   The ground that the character is standing on is written to the [`CharacterGroundState`].
+/// - **Slope limits**: Grounded characters don't slide down walkable slopes,
+///   and can't walk up slopes that are too steep.
+/// - **Step-up**: Grounded characters automatically step up obstacles that are at most
+///   [`step_height`](Self::step_height) tall, such as stairs.
+/// - **Ground snapping**: Grounded characters stay on the ground when walking down slopes and stairs,
+///   as long as the ground is within [`ground_snap_distance`](Self::ground_snap_distance).
+/// - **Moving platforms**: Grounded characters are carried along by the ground they are standing on,
+///   including the motion caused by the rotation of the ground. In 3D, the character is also rotated
+///   along with the ground around the [`up`](Self::up) direction.
+///   The velocity of the ground is added to the velocity of the character when leaving the ground,
+///   and subtracted from it when landing, so the character keeps its momentum.
+/// - **Pushing**: The character pushes dynamic rigid bodies that it moves into, and is pushed by
+///   dynamic rigid bodies that move into it. See [Pushing](#pushing) for more information.
+///
+/// Characters are moved by the [`CharacterControllerPlugin`], which is not included in [`PhysicsPlugins`]
+/// and must be added separately.
+///
+/// Gravity, movement input, and jumping are left to the user. They can be implemented by modifying
+/// the [`LinearVelocity`] of the character before [`CharacterControllerSystems`], for example in `FixedUpdate`.
+/// While the character is grounded, its [`LinearVelocity`] is relative to the ground.
+///
+/// # Jumping
+///
+/// [`CharacterGroundState::can_jump`] returns `true` if the character is grounded, or if it has walked off
+/// of the ground within the [`coyote_time`](Self::coyote_time). To jump, add a velocity along the
+/// [`up`](Self::up) direction. Moving away from the ground detaches the character from it,
+/// and ends the coyote time.
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         CharacterController::default().with_step_height(0.3),
+///         Collider::capsule(0.4, 1.0),
+///     ));
+/// }
+///
+/// fn jump(mut characters: Query<(&CharacterGroundState, &mut LinearVelocity)>) {
+///     for (ground_state, mut linear_velocity) in &mut characters {
+///         // Jump whenever possible.
+///         if ground_state.can_jump() {
+///             linear_velocity.y = 7.0;
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Pushing
+///
+/// When the character hits a dynamic rigid body, an impulse is applied to the body using the
+/// [`PushConfig`] of the [`config`](Self::config). The impulses are applied after all characters have been moved. The [`ComputedMass`] of the character is used as the
+/// [`PushConfig::mass`], so setting a [`Mass`] on the character controls how strongly it pushes.
+/// The character itself is still blocked by the body, so it only keeps moving as the body moves away.
+///
+/// Dynamic bodies that move into the character add to its [`AccumulatedPush`], based on the ratio
+/// of their masses. The push is added to the [`LinearVelocity`] of the character before its next move.
+/// To disable pushing, set the [`push`](MoveAndSlideConfig::push) configuration to `None`.
+///
+/// # Limitations
+///
+/// The character must be a root entity, and its [`Collider`] must be on the same entity.
+/// The position of the character is read from and written to its [`Transform`].
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[require(
+    RigidBody::Kinematic,
+    CustomPositionIntegration,
+    CharacterGroundState,
+    AccumulatedPush
+)]
+pub struct CharacterController {
+    /// The up direction of the character.
+    ///
+    /// Default: `Dir::Y`
+    pub up: Dir,
+
+    /// The maximum angle (in radians) between the [`up`](Self::up) direction and the normal
+    /// of a surface for it to be considered walkable ground. Steeper surfaces are considered walls.
+    ///
+    /// Default: 45 degrees (π / 4 radians)
+    pub max_slope_angle: f32,
+
+    /// The maximum height of obstacles that the character can step up while grounded.
+    /// Set this to `0.0` to disable stepping.
+    ///
+    /// This is implicitly scaled by the [`PhysicsLengthUnit`].
+    ///
+    /// Default: `0.25`
+    pub step_height: f32,
+
+    /// The maximum distance that a grounded character is moved down to stay on the ground,
+    /// for example when walking down slopes or stairs. Set this to `0.0` to disable snapping.
+    ///
+    /// This is implicitly scaled by the [`PhysicsLengthUnit`].
+    ///
+    /// Default: `0.2`
+    pub ground_snap_distance: f32,
+
+    /// The time (in seconds) after walking off of the ground during which
+    /// [`CharacterGroundState::can_jump`] still returns `true`.
+    ///
+    /// Default: `0.1`
+    pub coyote_time: f32,
+
+    /// The configuration used for [`MoveAndSlide::move_and_slide`].
+    pub config: MoveAndSlideConfig,
+}
+
+impl Default for CharacterController {
+    fn default() -> Self {
+        Self {
+            up: Dir::Y,
+            max_slope_angle: core::f32::consts::FRAC_PI_4,
+            step_height: 0.25,
+            ground_snap_distance: 0.2,
+            coyote_time: 0.1,
+            config: MoveAndSlideConfig {
+                push: Some(PushConfig::default()),
+                ..default()
+            },
+        }
+    }
+}
+
+impl CharacterController {
+    /// Sets the up direction of the character.
+    pub fn with_up(mut self, up: Dir) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Sets the maximum angle (in radians) between the up direction and the normal
+    /// of a surface for it to be considered walkable ground.
+    pub fn with_max_slope_angle(mut self, max_slope_angle: f32) -> Self {
+        self.max_slope_angle = max_slope_angle;
+        self
+    }
+
+    /// Sets the maximum height of obstacles that the character can step up while grounded.
+    pub fn with_step_height(mut self, step_height: f32) -> Self {
+        self.step_height = step_height;
+        self
+    }
+
+    /// Sets the maximum distance that a grounded character is moved down to stay on the ground.
+    pub fn with_ground_snap_distance(mut self, ground_snap_distance: f32) -> Self {
+        self.ground_snap_distance = ground_snap_distance;
+        self
+    }
+
+    /// Sets the time (in seconds) after walking off of the ground during which the character can still jump.
+    pub fn with_coyote_time(mut self, coyote_time: f32) -> Self {
+        self.coyote_time = coyote_time;
+        self
+    }
+
+    /// Sets the configuration used for [`MoveAndSlide::move_and_slide`].
+    pub fn with_config(mut self, config: MoveAndSlideConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns `true` if a surface with the given normal is walkable ground.
+    pub fn is_walkable(&self, normal: Vector) -> bool {
+        self.up.dot(normal) >= self.max_slope_angle.cos()
+    }
+}
+
+/// The ground state of a [`CharacterController`], updated each physics step.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct CharacterGroundState {
+    /// The collider entity that the character is standing on, or `None` if the character is airborne.
+    pub entity: Option<Entity>,
+
+    /// The surface normal of the ground, or zero if the character is airborne.
+    pub normal: Vector,
+
+    /// The velocity of the ground at the point below the character, or zero if the character is airborne.
+    ///
+    /// This includes the velocity caused by the rotation of the ground.
+    pub velocity: Vector,
+
+    /// The angular velocity of the ground, or zero if the character is airborne.
+    pub angular_velocity: AngularVector,
+
+    /// The remaining time (in seconds) during which the character can jump after walking off of the ground.
+    ///
+    /// This is reset to [`CharacterController::coyote_time`] while the character is grounded,
+    /// and set to zero when the character moves away from the ground, for example by jumping.
+    pub coyote_time_left: f32,
+}
+
+impl CharacterGroundState {
+    /// Returns `true` if the character is standing on the ground.
+    pub fn is_grounded(&self) -> bool {
+        self.entity.is_some()
+    }
+
+    /// Returns `true` if the character is grounded, or if it walked off of the ground
+    /// within the [`CharacterController::coyote_time`].
+    pub fn can_jump(&self) -> bool {
+        self.is_grounded() || self.coyote_time_left > 0.0
+    }
+}
+
+/// The kinds of surfaces hit by a character during a single move.
+#[derive(Default)]
+struct SurfaceHits {
+    wall: bool,
+    ceiling: bool,
+}
+
+/// The [`BodyPush`]es of [`CharacterController`]s, applied after all characters have been moved.
+#[derive(Resource, Default)]
+struct CharacterPushes(Vec<BodyPush>);
+
+/// Moves [`CharacterController`]s with [`MoveAndSlide`] and updates their [`CharacterGroundState`].
+fn move_character_controllers(
+    mut characters: Query<(
+        Entity,
+        &CharacterController,
+        &mut CharacterGroundState,
+        &mut Transform,
+        &mut LinearVelocity,
+        &mut AccumulatedPush,
+        &ComputedMass,
+        &Collider,
+        Option<&CollisionLayers>,
+    )>,
+    bodies: Query<ForcesReadOnly, Without<CharacterController>>,
+    move_and_slide: MoveAndSlide,
+    mut pushes: ResMut<CharacterPushes>,
+    time: Res<Time<Physics>>,
+) {
+    let delta = time.delta();
+
+    if delta.is_zero() {
+        return;
+    }
+
+    let delta_secs = delta.as_secs_f32();
+    let length_unit = move_and_slide.length_unit.0;
+
+    for (
+        entity,
+        controller,
+        mut ground_state,
+        mut transform,
+        mut linear_velocity,
+        mut push,
+        mass,
+        shape,
+        layers,
+    ) in &mut characters
+    {
+        let up = controller.up;
+
+        // Apply pushes from dynamic bodies.
+        linear_velocity.0 += push.take();
+
+        // Only the character's own movement pushes bodies, using the mass of the character.
+        let mut config = controller.config.clone();
+        if let Some(push_config) = &mut config.push {
+            push_config.mass = mass.value();
+        }
+        let passive_config = MoveAndSlideConfig {
+            push: None,
+            ..controller.config.clone()
+        };
+        let was_grounded = ground_state.is_grounded();
+
+        // Rotate the character along with the ground it is standing on.
+        #[cfg(feature = "3d")]
+        if was_grounded {
+            let yaw_velocity = ground_state.angular_velocity.dot(*up);
+            transform.rotation =
+                Quat::from_axis_angle(*up, yaw_velocity * delta_secs) * transform.rotation;
+        }
+
+        let rotation = Rotation::from(transform.rotation);
+        let skin_width = length_unit * controller.config.skin_width;
+        let mut filter = SpatialQueryFilter::from_excluded_entities([entity]);
+        if let Some(layers) = layers {
+            filter.mask = layers.filters;
+        }
+
+        #[cfg(feature = "2d")]
+        let mut position = transform.translation.xy().real();
+        #[cfg(feature = "3d")]
+        let mut position = transform.translation.real();
+
+        // Carry the character along with the ground it is standing on.
+        if was_grounded && ground_state.velocity != Vector::ZERO {
+            let carry_velocity = arc_velocity(
+                ground_state.velocity,
+                ground_state.angular_velocity,
+                delta_secs,
+            );
+            position = move_and_slide
+                .move_and_slide(
+                    shape,
+                    position,
+                    rotation,
+                    carry_velocity,
+                    delta,
+                    &passive_config,
+                    &filter,
+                    |_| MoveAndSlideHitResponse::Accept,
+                )
+                .position;
+        }
+
+        // Move the character according to its own velocity.
+        let velocity = linear_velocity.0;
+        let mut hits = SurfaceHits::default();
+        let start = position;
+        let output = move_and_slide.move_and_slide(
+            shape,
+            position,
+            rotation,
+            velocity,
+            delta,
+            &config,
+            &filter,
+            |hit| {
+                limit_slopes(controller, velocity, hit, &mut hits);
+                MoveAndSlideHitResponse::Accept
+            },
+        );
+        position = output.position;
+        pushes.0.extend(output.pushes);
+
+        // Try to step up obstacles that blocked horizontal movement.
+        if was_grounded && hits.wall && controller.step_height > 0.0 {
+            let step_height = length_unit * controller.step_height;
+            if let Some(stepped) = step_up(
+                &move_and_slide,
+                controller,
+                &passive_config,
+                shape,
+                start,
+                rotation,
+                velocity,
+                delta,
+                step_height,
+                skin_width,
+                &filter,
+            ) && horizontal_distance(stepped - start, up)
+                > horizontal_distance(position - start, up) + skin_width
+            {
+                position = stepped;
+            }
+        }
+
+        // Detect the ground below the character. Grounded characters that are not moving away
+        // from the ground are snapped down to it.
+        let moving_away = velocity.dot(*up) > 0.0;
+        let snap = was_grounded && !moving_away;
+        let ground_config = GroundDetectionConfig {
+            up,
+            max_slope_angle: controller.max_slope_angle,
+            max_distance: if snap {
+                controller.ground_snap_distance
+            } else {
+                controller.config.skin_width
+            },
+        };
+        let ground = move_and_slide
+            .detect_ground(
+                shape,
+                position,
+                rotation,
+                &ground_config,
+                skin_width,
+                &filter,
+            )
+            .filter(|_| !moving_away);
+
+        if let Some(ground) = ground {
+            if snap {
+                position -= (ground.distance * *up).real();
+            }
+
+            let body = bodies.get(ground.body).ok();
+            let ground_velocity = body
+                .as_ref()
+                .map_or(Vector::ZERO, |body| ground.point_velocity(body));
+
+            // The velocity of a grounded character is relative to the ground.
+            if !was_grounded {
+                linear_velocity.0 -= ground_velocity;
+            }
+
+            // Don't accumulate velocity into the ground.
+            let velocity_along_up = linear_velocity.dot(*up);
+            if velocity_along_up < 0.0 {
+                linear_velocity.0 -= velocity_along_up * *up;
+            }
+
+            *ground_state = CharacterGroundState {
+                entity: Some(ground.entity),
+                normal: *ground.normal,
+                velocity: ground_velocity,
+                angular_velocity: body
+                    .as_ref()
+                    .map(|body| ground.angular_velocity(body))
+                    .unwrap_or_default(),
+                coyote_time_left: controller.coyote_time,
+            };
+        } else {
+            if was_grounded {
+                // Keep the momentum of the ground when leaving it.
+                linear_velocity.0 += ground_state.velocity;
+            }
+
+            let coyote_time_left = if moving_away {
+                0.0
+            } else {
+                (ground_state.coyote_time_left - delta_secs).max(0.0)
+            };
+
+            *ground_state = CharacterGroundState {
+                coyote_time_left,
+                ..default()
+            };
+        }
+
+        // Stop moving up when hitting a ceiling.
+        if hits.ceiling {
+            let velocity_along_up = linear_velocity.dot(*up);
+            if velocity_along_up > 0.0 {
+                linear_velocity.0 -= velocity_along_up * *up;
+            }
+        }
+
+        #[cfg(feature = "2d")]
+        {
+            transform.translation = position.f32().extend(transform.translation.z);
+        }
+        #[cfg(feature = "3d")]
+        {
+            transform.translation = position.f32();
+        }
+    }
+}
+
+/// Applies the [`BodyPush`]es of [`CharacterController`]s to the pushed dynamic rigid bodies.
+fn apply_character_pushes(
+    mut pushes: ResMut<CharacterPushes>,
+    mut bodies: Query<(&RigidBody, &ComputedMass, Forces)>,
+) {
+    for push in pushes.0.drain(..) {
+        if let Ok((rb, mass, mut forces)) = bodies.get_mut(push.body)
+            && rb.is_dynamic()
+        {
+            push.apply(mass, &mut forces);
+        }
+    }
+}
+
+/// Prevents characters from sliding down walkable slopes and from climbing slopes that are too steep.
+///
+/// Records the kind of the hit surface in `hits`.
+fn limit_slopes(
+    controller: &CharacterController,
+    input_velocity: Vector,
+    hit: MoveAndSlideHitData,
+    hits: &mut SurfaceHits,
+) {
+    let up = *controller.up;
+    let normal = **hit.normal;
+
+    let is_ground = controller.is_walkable(normal);
+    let is_ceiling = controller.is_walkable(-normal);
+    hits.ceiling |= is_ceiling;
+    hits.wall |= !is_ground && !is_ceiling;
+
+    // Decompose the input velocity and the current sliding velocity relative to the surface
+    // to determine whether the character is trying to climb or slip, and whether it actually is.
+    let vertical_input = up * input_velocity.dot(up);
+    let intended = decompose_velocity(input_velocity - vertical_input, normal, up);
+    let current = decompose_velocity(*hit.velocity, normal, up);
+
+    let slipping_intent = up.dot(intended.vertical_tangent) < -0.001;
+    let slipping = up.dot(current.vertical_tangent) < -0.001;
+    let climbing_intent = up.dot(vertical_input) > 0.0;
+    let climbing = up.dot(current.vertical_tangent) > 0.0;
+
+    let cant_climb = !is_ground && climbing && !climbing_intent;
+    let shouldnt_slip = is_ground && slipping && !slipping_intent;
+
+    if cant_climb || shouldnt_slip {
+        // Remove the vertical motion along the surface.
+        *hit.velocity = current.horizontal_tangent + current.normal_part;
+    }
+}
+
+/// Tries to step up an obstacle by moving up by `step_height`, then forward, and then back down.
+///
+/// Returns the position after stepping, or `None` if the character would not land on anything.
+#[expect(clippy::too_many_arguments)]
+fn step_up(
+    move_and_slide: &MoveAndSlide,
+    controller: &CharacterController,
+    config: &MoveAndSlideConfig,
+    shape: &Collider,
+    start: RVector,
+    rotation: Rotation,
+    velocity: Vector,
+    delta: Duration,
+    step_height: f32,
+    skin_width: f32,
+    filter: &SpatialQueryFilter,
+) -> Option<RVector> {
+    let up = *controller.up;
+
+    // Move up as far as possible.
+    let raise = move_and_slide
+        .cast_move(shape, start, rotation, step_height * up, skin_width, filter)
+        .map_or(step_height, |hit| hit.distance);
+    if raise <= skin_width {
+        return None;
+    }
+    let raised = start + (raise * up).real();
+
+    // Move forward horizontally.
+    let horizontal_velocity = velocity - up * velocity.dot(up);
+    let forward = move_and_slide
+        .move_and_slide(
+            shape,
+            raised,
+            rotation,
+            horizontal_velocity,
+            delta,
+            config,
+            filter,
+            |_| MoveAndSlideHitResponse::Accept,
+        )
+        .position;
+
+    // Move back down, landing on top of the obstacle.
+    let hit =
+        move_and_slide.cast_move(shape, forward, rotation, -raise * up, skin_width, filter)?;
+    (up.dot(hit.normal1) > 0.0).then(|| forward - (hit.distance * up).real())
+}
+
+/// Returns the velocity that moves a point on a body rotating with `angular_velocity`
+/// along the chord of its arc of rotation over `delta_secs`.
+///
+/// Rotating the point velocity by half of the rotation gives the direction of the chord.
+/// Moving along the point velocity itself would make characters drift outward on rotating platforms.
+fn arc_velocity(velocity: Vector, angular_velocity: AngularVector, delta_secs: f32) -> Vector {
+    #[cfg(feature = "2d")]
+    {
+        Rot2::radians(0.5 * angular_velocity * delta_secs) * velocity
+    }
+    #[cfg(feature = "3d")]
+    {
+        Quat::from_scaled_axis(0.5 * angular_velocity * delta_secs) * velocity
+    }
+}
+
+/// Returns the length of the part of `offset` that is perpendicular to `up`.
+fn horizontal_distance(offset: RVector, up: Dir) -> f32 {
+    let offset = offset.f32();
+    (offset - up * offset.dot(*up)).length()
+}
+
+/// The decomposition of a velocity vector into parts relative to a surface normal and an up direction.
+struct VelocityDecomposition {
+    /// The part of the velocity that is directly against the surface normal.
+    normal_part: Vector,
+    /// The part of the velocity that is tangent to the surface and perpendicular to the up direction.
+    horizontal_tangent: Vector,
+    /// The part of the velocity that is tangent to the surface and along the up direction.
+    vertical_tangent: Vector,
+}
+
+/// Decomposes a velocity vector into parts relative to a surface `normal` and an `up` direction.
+#[cfg_attr(feature = "2d", expect(unused_variables))]
+fn decompose_velocity(velocity: Vector, normal: Vector, up: Vector) -> VelocityDecomposition {
+    let normal_part = normal * normal.dot(velocity);
+    let tangent_part = velocity - normal_part;
+
+    // In 2D, the surface has no horizontal tangent.
+    #[cfg(feature = "2d")]
+    let horizontal_tangent = Vector::ZERO;
+    #[cfg(feature = "3d")]
+    let horizontal_tangent = {
+        let direction = normal.cross(up).normalize_or_zero();
+        tangent_part.dot(direction) * direction
+    };
+
+    VelocityDecomposition {
+        normal_part,
+        horizontal_tangent,
+        vertical_tangent: tangent_part - horizontal_tangent,
+    }
+}
@@ -1,12 +1,21 @@
 //! Utilities for implementing character controllers.
 
+pub mod controller;
 pub mod move_and_slide;
 mod velocity_project;
 
+#[cfg(test)]
+mod tests;
+
 /// Re-exports common types related to character controller functionality.
 pub mod prelude {
+    pub use super::controller::{
+        CharacterController, CharacterControllerPlugin, CharacterControllerSystems,
+        CharacterGroundState,
+    };
     pub use super::move_and_slide::{
-        MoveAndSlide, MoveAndSlideConfig, MoveAndSlideHitData, MoveAndSlideHitResponse,
-        MoveAndSlideOutput,
+        AccumulatedPush, BodyPush, GroundDetectionConfig, MoveAndSlide, MoveAndSlideConfig,
+        MoveAndSlideGround, MoveAndSlideHitData, MoveAndSlideHitResponse, MoveAndSlideOutput,
+        PushConfig,
     };
 }
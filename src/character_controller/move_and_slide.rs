@@ -4,7 +4,7 @@
 
 pub use super::velocity_project::*;
 
-use crate::{collision::collider::contact_query::contact_manifolds, prelude::*};
+use crate::{collision::collider::contact_query::contact_manifolds, math::RecipOrZero, prelude::*};
 use bevy::{ecs::system::SystemParam, prelude::*};
 use core::time::Duration;
 
@@ -49,6 +49,8 @@ pub const COS_5_DEGREES: f32 = 0.99619469;
 /// - Performing shape casts optimized for movement via [`cast_move`](MoveAndSlide::cast_move).
 /// - Depenetrating shapes that are intersecting colliders via [`depenetrate`](MoveAndSlide::depenetrate).
 /// - Performing intersection tests via [`intersections`](MoveAndSlide::intersections).
+/// - Checking whether a new shape fits at a given pose via [`can_resize`](MoveAndSlide::can_resize) and [`try_resize`](MoveAndSlide::try_resize).
+/// - Detecting the ground below a shape via [`detect_ground`](MoveAndSlide::detect_ground).
 /// - Projecting velocities to slide along contact planes via [`project_velocity`](MoveAndSlide::project_velocity).
 ///
 /// These methods are used internally by the move and slide algorithm, but can also be used independently
@@ -77,6 +79,7 @@ pub struct MoveAndSlide<'w, 's> {
             &'static Position,
             &'static Rotation,
             Option<&'static CollisionLayers>,
+            &'static ColliderOf,
         ),
         (With<ColliderOf>, Without<Sensor>),
     >,
@@ -163,6 +166,22 @@ pub struct MoveAndSlideConfig {
     /// If the number of planes exceeds this value, the algorithm will stop collecting new planes.
     /// This is a safety measure to prevent excessive computation time for dense geometry.
     pub max_planes: usize,
+
+    /// Configuration for detecting the ground below the shape after moving it.
+    ///
+    /// If set, the ground is reported in [`MoveAndSlideOutput::ground`].
+    /// This is useful for inheriting the velocity of moving platforms.
+    ///
+    /// Default: `None`
+    pub ground_detection: Option<GroundDetectionConfig>,
+
+    /// Configuration for pushing dynamic rigid bodies that are hit by the shape.
+    ///
+    /// If set, a [`BodyPush`] is reported in [`MoveAndSlideOutput::pushes`] for each accepted hit
+    /// against a collider attached to a rigid body.
+    ///
+    /// Default: `None`
+    pub push: Option<PushConfig>,
 }
 
 impl Default for MoveAndSlideConfig {
@@ -177,6 +196,8 @@ impl Default for MoveAndSlideConfig {
             planes: Vec::new(),
             plane_similarity_dot_threshold: COS_5_DEGREES,
             max_planes: 20,
+            ground_detection: None,
+            push: None,
         }
     }
 }
@@ -248,11 +269,159 @@ impl From<&MoveAndSlideConfig> for DepenetrationConfig {
     }
 }
 
-/// Output from [`MoveAndSlide::move_and_slide`].
+/// Configuration for [`MoveAndSlide::detect_ground`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct GroundDetectionConfig {
+    /// The up direction of the shape. The ground is searched for in the opposite direction.
+    ///
+    /// Default: `Dir::Y`
+    pub up: Dir,
+
+    /// The maximum angle (in radians) between the [`up`](Self::up) direction and the normal
+    /// of a surface for it to be considered ground.
+    ///
+    /// Default: 45 degrees (π / 4 radians)
+    pub max_slope_angle: f32,
+
+    /// The maximum distance below the shape to search for ground.
+    ///
+    /// This is implicitly scaled by the [`PhysicsLengthUnit`].
+    ///
+    /// Default: `0.05`
+    pub max_distance: f32,
+}
+
+impl Default for GroundDetectionConfig {
+    fn default() -> Self {
+        Self {
+            up: Dir::Y,
+            max_slope_angle: core::f32::consts::FRAC_PI_4,
+            max_distance: 0.05,
+        }
+    }
+}
+
+/// Configuration for pushing dynamic rigid bodies with [`MoveAndSlide::move_and_slide`].
+///
+/// See [`BodyPush`] for more information.
 #[derive(Clone, Copy, Debug, PartialEq, Reflect)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 #[reflect(Debug, PartialEq)]
+pub struct PushConfig {
+    /// The mass of the character pushing bodies. Zero mass is treated as infinite mass.
+    ///
+    /// The impulse applied to a pushed body depends on the ratio of its mass to the mass of the character,
+    /// so heavy bodies are harder to push than light ones.
+    ///
+    /// Default: `1.0`
+    pub mass: f32,
+
+    /// A multiplier for the impulse applied to pushed bodies.
+    ///
+    /// With a strength of `1.0`, bodies are pushed like in a perfectly inelastic collision
+    /// between the character and the body.
+    ///
+    /// Default: `1.0`
+    pub strength: f32,
+}
+
+impl Default for PushConfig {
+    fn default() -> Self {
+        Self {
+            mass: 1.0,
+            strength: 1.0,
+        }
+    }
+}
+
+/// A push of a rigid body that was hit by a shape moved with [`MoveAndSlide::move_and_slide`].
+///
+/// Pushes are reported in [`MoveAndSlideOutput::pushes`] if [`MoveAndSlideConfig::push`] is set.
+/// They are not applied automatically, but can be applied to the pushed bodies with [`BodyPush::apply`].
+/// The [`CharacterController`] applies the pushes of all characters after they have been moved.
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn apply_pushes(pushes: &[BodyPush], mut bodies: Query<(&RigidBody, &ComputedMass, Forces)>) {
+///     for push in pushes {
+///         if let Ok((rb, mass, mut forces)) = bodies.get_mut(push.body)
+///             && rb.is_dynamic()
+///         {
+///             push.apply(mass, &mut forces);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct BodyPush {
+    /// The rigid body that was hit.
+    pub body: Entity,
+
+    /// The world-space point where the body was hit.
+    pub point: RVector,
+
+    /// The direction in which the body is pushed.
+    pub direction: Dir,
+
+    /// The velocity of the shape when it hit the body.
+    pub velocity: Vector,
+
+    /// The configuration used for computing the impulse applied to the body.
+    pub config: PushConfig,
+}
+
+impl BodyPush {
+    /// Applies the push to the pushed body using [`Forces`].
+    ///
+    /// An impulse is applied along the [`direction`](Self::direction) at the [`point`](Self::point).
+    /// The impulse is based on the speed at which the shape is moving toward the body
+    /// and the ratio of their masses, as configured by the [`PushConfig`].
+    /// Bodies that are already moving away from the shape are not pushed.
+    ///
+    /// Only dynamic rigid bodies should be pushed.
+    pub fn apply(&self, mass: &ComputedMass, forces: &mut impl WriteRigidBodyForces) {
+        if let Some(impulse) = self.impulse(mass, forces) {
+            forces.apply_linear_impulse_at_point(impulse, self.point);
+        }
+    }
+
+    /// Computes the impulse applied to a body by [`BodyPush::apply`],
+    /// or `None` if the body is not pushed.
+    pub fn impulse(&self, mass: &ComputedMass, body: &impl ReadRigidBodyForces) -> Option<Vector> {
+        let relative_speed = self
+            .direction
+            .dot(self.velocity - body.velocity_at_point(self.point));
+        if relative_speed <= 0.0 {
+            return None;
+        }
+
+        // Apply the impulse of an inelastic collision between the shape and the body,
+        // using the reduced mass of the two.
+        let inverse_mass_sum = self.config.mass.recip_or_zero() + mass.inverse();
+        if inverse_mass_sum <= 0.0 {
+            return None;
+        }
+        let impulse = self.config.strength * relative_speed / inverse_mass_sum;
+
+        Some(impulse * self.direction)
+    }
+}
+
+/// Output from [`MoveAndSlide::move_and_slide`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
 pub struct MoveAndSlideOutput {
     /// The final position of the character after move and slide.
     ///
@@ -272,6 +441,88 @@ pub struct MoveAndSlideOutput {
     /// This ways, the character's position is only updated via the move and slide algorithm,
     /// and not also by the physics integrator.
     pub projected_velocity: Vector,
+
+    /// The ground below the character after move and slide.
+    ///
+    /// This is only detected if [`MoveAndSlideConfig::ground_detection`] is set,
+    /// and is `None` if no ground was found.
+    pub ground: Option<MoveAndSlideGround>,
+
+    /// The rigid bodies pushed by the character during move and slide.
+    ///
+    /// This is only filled if [`MoveAndSlideConfig::push`] is set.
+    /// The pushes must be applied with [`BodyPush::apply`].
+    pub pushes: Vec<BodyPush>,
+}
+
+/// The ground below a shape, detected by [`MoveAndSlide::detect_ground`].
+///
+/// The velocity of the ground can be computed with [`MoveAndSlideGround::point_velocity`]
+/// and applied to the character so that it is carried along by moving platforms.
+#[cfg_attr(
+    feature = "3d",
+    doc = "Similarly, [`MoveAndSlideGround::yaw_velocity`] can be used to rotate the character along with rotating platforms."
+)]
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+///
+/// fn ground_velocity(
+///     ground: MoveAndSlideGround,
+///     bodies: Query<ForcesReadOnly, Without<Player>>,
+#[cfg_attr(feature = "2d", doc = ") -> Vec2 {")]
+#[cfg_attr(feature = "3d", doc = ") -> Vec3 {")]
+///     bodies
+///         .get(ground.body)
+///         .map_or(Default::default(), |body| ground.point_velocity(&body))
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct MoveAndSlideGround {
+    /// The entity of the ground collider.
+    pub entity: Entity,
+
+    /// The entity of the rigid body that the ground collider is attached to.
+    pub body: Entity,
+
+    /// The point on the ground below the shape, expressed in world space.
+    pub point: RVector,
+
+    /// The surface normal of the ground, expressed in world space.
+    pub normal: Dir,
+
+    /// The distance from the shape to the ground, respecting the skin width.
+    pub distance: f32,
+}
+
+impl MoveAndSlideGround {
+    /// Computes the velocity of the ground at [`point`](Self::point), given the ground [`body`](Self::body).
+    ///
+    /// This includes both the linear velocity of the body and the velocity caused by its rotation
+    /// around its center of mass.
+    pub fn point_velocity(&self, body: &impl ReadRigidBodyForces) -> Vector {
+        body.velocity_at_point(self.point)
+    }
+
+    /// Returns the angular velocity of the ground, given the ground [`body`](Self::body).
+    pub fn angular_velocity(&self, body: &impl ReadRigidBodyForces) -> AngularVector {
+        body.angular_velocity()
+    }
+
+    /// Computes the angular velocity of the ground [`body`](Self::body) around the `up` direction.
+    ///
+    /// Rotating the character by this angular velocity keeps it facing the same way relative to a rotating platform.
+    #[cfg(feature = "3d")]
+    pub fn yaw_velocity(&self, body: &impl ReadRigidBodyForces, up: Dir) -> f32 {
+        body.angular_velocity().dot(*up)
+    }
 }
 
 /// Data related to a hit during [`MoveAndSlide::move_and_slide`].
@@ -393,6 +644,32 @@ impl MoveHitData {
     }
 }
 
+/// The velocity that dynamic rigid bodies have pushed a kinematic character with.
+///
+/// Contacts can't move kinematic bodies, so dynamic bodies can't push characters moved with
+/// [`MoveAndSlide`] on their own. Instead, when a dynamic body is about to collide with a kinematic body
+/// that has this component, the velocity that the collision would give to the kinematic body is added here.
+/// It is based on the approach speed of the bodies and the ratio of their [`ComputedMass`].
+///
+/// Pushes are accumulated during the physics step, after the narrow phase.
+/// The push should be added to the velocity used for the next move of the character,
+/// and then reset, for example with [`AccumulatedPush::take`].
+/// [`CharacterController`]s do this automatically.
+///
+/// This requires the [`CharacterControllerPlugin`].
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct AccumulatedPush(pub Vector);
+
+impl AccumulatedPush {
+    /// Returns the accumulated push velocity, and resets it to zero.
+    pub fn take(&mut self) -> Vector {
+        core::mem::take(&mut self.0)
+    }
+}
+
 impl<'w, 's> MoveAndSlide<'w, 's> {
     /// Moves a shape along a given velocity vector, sliding along any colliders that are hit on the way.
     ///
@@ -494,6 +771,7 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
         let shape_rotation = shape_rotation.into();
 
         let mut position = shape_position;
+        let mut pushes = Vec::new();
         let mut time_left = delta_time.as_secs_f32();
         let skin_width = self.length_unit.0 * config.skin_width;
 
@@ -542,6 +820,7 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
             // due to a Parry bug. Otherwise, `contact_manifolds` would pick up this normal anyways.
             // TODO: Remove this once the collision bug is fixed.
             let mut first_normal = Dir::new_unchecked(sweep_hit.normal1);
+            let hit_velocity = velocity;
             let hit_response = on_hit(MoveAndSlideHitData {
                 entity: sweep_hit.entity,
                 point,
@@ -554,6 +833,19 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
 
             if hit_response == MoveAndSlideHitResponse::Accept {
                 planes.push(first_normal);
+
+                // Push the hit body.
+                if let Some(push_config) = config.push
+                    && let Ok((.., collider_of)) = self.colliders.get(sweep_hit.entity)
+                {
+                    pushes.push(BodyPush {
+                        body: collider_of.body,
+                        point: sweep_hit.point1,
+                        direction: -first_normal,
+                        velocity: hit_velocity,
+                        config: push_config,
+                    });
+                }
             } else if hit_response == MoveAndSlideHitResponse::Abort {
                 break;
             }
@@ -627,9 +919,22 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
             self.depenetrate(shape, position, shape_rotation, &config.into(), filter);
         position += depenetration_offset.real();
 
+        let ground = config.ground_detection.as_ref().and_then(|ground_config| {
+            self.detect_ground(
+                shape,
+                position,
+                shape_rotation,
+                ground_config,
+                skin_width,
+                filter,
+            )
+        });
+
         MoveAndSlideOutput {
             position,
             projected_velocity: velocity,
+            ground,
+            pushes,
         }
     }
 
@@ -804,6 +1109,82 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
         (hit.distance - skin_distance).max(0.0)
     }
 
+    /// Detects the ground below a shape by casting it opposite to the [`up`](GroundDetectionConfig::up) direction.
+    ///
+    /// The normal of a shape cast is tilted at edges and corners, for example when standing at the edge of a step.
+    /// In that case, the surface just beyond the contact point is checked with a ray cast instead.
+    ///
+    /// # Arguments
+    ///
+    /// - `shape`: The shape being cast represented as a [`Collider`].
+    /// - `shape_position`: Where the shape is cast from.
+    /// - `shape_rotation`: The rotation of the shape being cast.
+    /// - `config`: A [`GroundDetectionConfig`] that determines what is considered ground.
+    /// - `skin_width`: The distance to keep between the shape and the ground, as in [`Self::cast_move`].
+    /// - `filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query. It is highly recommended to exclude the entity holding the collider itself,
+    ///   otherwise the character will collide with itself.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(MoveAndSlideGround)` if a surface that is not too steep was found within [`GroundDetectionConfig::max_distance`].
+    /// - `None` if there is no ground below the shape.
+    #[must_use]
+    pub fn detect_ground(
+        &self,
+        shape: &Collider,
+        shape_position: RVector,
+        shape_rotation: impl Into<Rot>,
+        config: &GroundDetectionConfig,
+        skin_width: f32,
+        filter: &SpatialQueryFilter,
+    ) -> Option<MoveAndSlideGround> {
+        let up = *config.up;
+        let min_up_dot = config.max_slope_angle.cos();
+        let max_distance = self.length_unit.0 * config.max_distance;
+
+        let hit = self.cast_move(
+            shape,
+            shape_position,
+            shape_rotation,
+            -max_distance * up,
+            skin_width,
+            filter,
+        )?;
+
+        let (entity, point, normal) = if up.dot(hit.normal1) >= min_up_dot {
+            (hit.entity, hit.point1, hit.normal1)
+        } else {
+            // Check the surface just beyond the contact point.
+            let offset = (hit.point1 - shape_position).f32();
+            let outward = (offset - up * offset.dot(up)).normalize_or_zero();
+            let probe_distance = 2.0 * skin_width;
+            let origin = hit.point1 + (probe_distance * (up + outward)).real();
+            let ray_hit = self.spatial_query.cast_ray_predicate(
+                origin,
+                -config.up,
+                2.0 * probe_distance,
+                true,
+                filter,
+                &|entity| self.colliders.contains(entity),
+            )?;
+            if up.dot(ray_hit.normal) < min_up_dot {
+                return None;
+            }
+            let point = origin - (ray_hit.distance * up).real();
+            (ray_hit.entity, point, ray_hit.normal)
+        };
+
+        let (.., collider_of) = self.colliders.get(entity).ok()?;
+
+        Some(MoveAndSlideGround {
+            entity,
+            body: collider_of.body,
+            point,
+            normal: Dir::new_unchecked(normal),
+            distance: hit.distance,
+        })
+    }
+
     /// Moves a collider so that it no longer intersects any other collider and keeps a minimum distance
     /// of [`DepenetrationConfig::skin_width`] scaled by the [`PhysicsLengthUnit`].
     ///
@@ -1001,6 +1382,157 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
         fixup
     }
 
+    /// Returns `true` if the given `shape` fits at the given position and rotation without intersecting
+    /// any other collider, while keeping a minimum distance of [`DepenetrationConfig::skin_width`]
+    /// scaled by the [`PhysicsLengthUnit`].
+    ///
+    /// This can be used to check whether a character can change its [`Collider`],
+    /// for example to stand back up after crouching. Intersections within the
+    /// [`DepenetrationConfig::max_depenetration_error`] are allowed.
+    ///
+    /// # Arguments
+    ///
+    /// - `shape`: The new shape represented as a [`Collider`].
+    /// - `shape_position`: The position of the shape.
+    /// - `shape_rotation`: The rotation of the shape.
+    /// - `config`: A [`DepenetrationConfig`] that determines the skin width and the allowed error.
+    /// - `filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// # Related methods
+    ///
+    /// - [`MoveAndSlide::try_resize`]
+    /// - [`MoveAndSlide::intersections`]
+    pub fn can_resize(
+        &self,
+        shape: &Collider,
+        shape_position: RVector,
+        shape_rotation: impl Into<Rot>,
+        config: &DepenetrationConfig,
+        filter: &SpatialQueryFilter,
+    ) -> bool {
+        let skin_width = self.length_unit.0 * config.skin_width;
+        let max_error = self.length_unit.0 * config.max_depenetration_error;
+        let mut total_error = 0.0;
+
+        self.intersections(
+            shape,
+            shape_position,
+            shape_rotation,
+            skin_width,
+            filter,
+            |_, contact_point, _| {
+                total_error += (contact_point.penetration + skin_width).max(0.0);
+                total_error < max_error
+            },
+        );
+
+        total_error < max_error
+    }
+
+    /// Checks whether the given `shape` fits at the given position and rotation like [`MoveAndSlide::can_resize`],
+    /// and if it doesn't, tries to find an offset that makes it fit using [`MoveAndSlide::depenetrate`].
+    ///
+    /// This is useful for changing the [`Collider`] of a character, for example to stand back up after crouching.
+    /// If the character is near the ground or already slightly penetrating it, the new shape can be moved
+    /// out of the ground instead of being rejected.
+    ///
+    /// # Arguments
+    ///
+    /// - `shape`: The new shape represented as a [`Collider`].
+    /// - `shape_position`: The position of the shape.
+    /// - `shape_rotation`: The rotation of the shape.
+    /// - `config`: A [`DepenetrationConfig`] that determines the behavior of the depenetration.
+    /// - `filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// # Returns
+    ///
+    /// The offset that should be added to `shape_position` for the shape to fit, which is zero if the shape
+    /// already fits. Returns `None` if the shape doesn't fit even after depenetration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    #[cfg_attr(
+        feature = "2d",
+        doc = "use avian2d::{prelude::*, character_controller::move_and_slide::DepenetrationConfig, math::ToRealPrecision};"
+    )]
+    #[cfg_attr(
+        feature = "3d",
+        doc = "use avian3d::{prelude::*, character_controller::move_and_slide::DepenetrationConfig, math::ToRealPrecision};"
+    )]
+    /// #[derive(Component)]
+    /// struct Crouching {
+    ///     standing_collider: Collider,
+    /// }
+    ///
+    /// fn stand_up(
+    ///     mut commands: Commands,
+    ///     player: Single<(Entity, &Crouching, &mut Collider, &mut Transform)>,
+    ///     move_and_slide: MoveAndSlide,
+    /// ) {
+    ///     let (entity, crouching, mut collider, mut transform) = player.into_inner();
+    ///     let filter = SpatialQueryFilter::from_excluded_entities([entity]);
+    ///
+    ///     // Stand up only if there is room for the standing collider.
+    ///     if let Some(offset) = move_and_slide.try_resize(
+    ///         &crouching.standing_collider,
+    #[cfg_attr(feature = "2d", doc = "        transform.translation.xy().real(),")]
+    #[cfg_attr(feature = "3d", doc = "        transform.translation.real(),")]
+    #[cfg_attr(
+        feature = "2d",
+        doc = "        transform.rotation.to_euler(EulerRot::XYZ).2,"
+    )]
+    #[cfg_attr(feature = "3d", doc = "        transform.rotation,")]
+    ///         &DepenetrationConfig::default(),
+    ///         &filter,
+    ///     ) {
+    ///         *collider = crouching.standing_collider.clone();
+    #[cfg_attr(
+        feature = "2d",
+        doc = "        transform.translation += offset.extend(0.0);"
+    )]
+    #[cfg_attr(feature = "3d", doc = "        transform.translation += offset;")]
+    ///         commands.entity(entity).remove::<Crouching>();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Related methods
+    ///
+    /// - [`MoveAndSlide::can_resize`]
+    /// - [`MoveAndSlide::depenetrate`]
+    #[must_use]
+    pub fn try_resize(
+        &self,
+        shape: &Collider,
+        shape_position: RVector,
+        shape_rotation: impl Into<Rot>,
+        config: &DepenetrationConfig,
+        filter: &SpatialQueryFilter,
+    ) -> Option<Vector> {
+        let shape_rotation = shape_rotation.into();
+
+        if self.can_resize(shape, shape_position, shape_rotation, config, filter) {
+            return Some(Vector::ZERO);
+        }
+
+        // Try to move the shape out of the colliders it intersects.
+        let offset = self.depenetrate(shape, shape_position, shape_rotation, config, filter);
+        if offset == Vector::ZERO {
+            return None;
+        }
+
+        self.can_resize(
+            shape,
+            shape_position + offset.real(),
+            shape_rotation,
+            config,
+            filter,
+        )
+        .then_some(offset)
+    }
+
     /// An [intersection test](spatial_query#intersection-tests) that calls a callback for each [`Collider`] found
     /// that is closer to the given `shape` with a given position and rotation than `prediction_distance`.
     ///
@@ -1038,13 +1570,16 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
             .aabb_intersections_with_aabb(expanded_aabb);
 
         'outer: for intersection_entity in aabb_intersections {
-            let Ok((intersection_collider, intersection_pos, intersection_rot, layers)) =
+            let Ok((intersection_collider, intersection_pos, intersection_rot, layers, _)) =
                 self.colliders.get(intersection_entity)
             else {
                 continue;
             };
             let layers = layers.copied().unwrap_or_default();
-            if !filter.test(intersection_entity, layers) {
+            if !self
+                .spatial_query
+                .test_filter(filter, intersection_entity, layers)
+            {
                 continue;
             }
             let mut manifolds = Vec::new();
@@ -1083,3 +1618,70 @@ impl<'w, 's> MoveAndSlide<'w, 's> {
         project_velocity(v, normals)
     }
 }
+
+/// Accumulates pushes from dynamic rigid bodies that are about to collide with bodies that have an [`AccumulatedPush`].
+pub(super) fn accumulate_pushes(
+    mut characters: Query<(
+        Entity,
+        &mut AccumulatedPush,
+        &ComputedMass,
+        &LinearVelocity,
+        &RigidBodyColliders,
+    )>,
+    bodies: Query<(&RigidBody, &ComputedMass, ForcesReadOnly)>,
+    collisions: Collisions,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (entity, mut push, mass, linear_velocity, colliders) in &mut characters {
+        for collider in colliders.iter() {
+            for contact_pair in collisions.graph().contact_pairs_with(collider) {
+                // The contact normal points from the first collider to the second.
+                let (other_body, sign) = if contact_pair.collider1 == collider {
+                    (contact_pair.body2, -1.0)
+                } else {
+                    (contact_pair.body1, 1.0)
+                };
+                let Some(other_body) = other_body.filter(|&body| body != entity) else {
+                    continue;
+                };
+                let Ok((rb, other_mass, other_forces)) = bodies.get(other_body) else {
+                    continue;
+                };
+                if !rb.is_dynamic() {
+                    continue;
+                }
+
+                // The velocity change of the character in an inelastic collision with the body.
+                let inverse_mass_sum = mass.inverse() + other_mass.inverse();
+                if inverse_mass_sum <= 0.0 {
+                    continue;
+                }
+                let mass_ratio = mass.inverse() / inverse_mass_sum;
+
+                for manifold in contact_pair.manifolds.iter() {
+                    let Some(deepest) = manifold
+                        .points
+                        .iter()
+                        .max_by(|a, b| a.penetration.total_cmp(&b.penetration))
+                    else {
+                        continue;
+                    };
+
+                    // Push the character if the body is approaching fast enough
+                    // to collide with it during this time step.
+                    let direction = sign * manifold.normal;
+                    let relative_velocity =
+                        other_forces.velocity_at_point(deepest.point) - linear_velocity.0;
+                    let approach_speed = direction.dot(relative_velocity);
+                    if approach_speed > 0.0
+                        && deepest.penetration + approach_speed * delta_secs >= 0.0
+                    {
+                        push.0 += mass_ratio * approach_speed * direction;
+                    }
+                }
+            }
+        }
+    }
+}
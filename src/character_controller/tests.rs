@@ -0,0 +1,395 @@
+use core::time::Duration;
+
+use approx::assert_relative_eq;
+use bevy::{ecs::system::SystemState, prelude::*};
+
+use super::move_and_slide::DepenetrationConfig;
+use crate::{
+    prelude::*,
+    tests::utils::{TIMESTEP, create_app},
+};
+
+/// Tests that a [`CharacterController`] walking into a low step climbs onto it.
+#[test]
+fn character_controller_walks_up_steps() {
+    let mut app = create_app();
+    app.add_plugins(CharacterControllerPlugin::default());
+
+    // Apply gravity and walk to the right.
+    app.add_systems(
+        FixedUpdate,
+        |mut characters: Query<&mut LinearVelocity, With<CharacterController>>, time: Res<Time>| {
+            for mut linear_velocity in &mut characters {
+                linear_velocity.x = 3.0;
+                linear_velocity.y -= 20.0 * time.delta_secs();
+            }
+        },
+    );
+    app.finish();
+
+    app.world_mut().spawn((
+        RigidBody::Static,
+        #[cfg(feature = "2d")]
+        Collider::rectangle(40.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(40.0, 1.0, 40.0),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+    ));
+    let step = app
+        .world_mut()
+        .spawn((
+            RigidBody::Static,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(10.0, 0.2),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(10.0, 0.2, 10.0),
+            Transform::from_xyz(7.0, 0.1, 0.0),
+        ))
+        .id();
+    let character = app
+        .world_mut()
+        .spawn((
+            CharacterController::default(),
+            Collider::capsule(0.4, 1.0),
+            Transform::from_xyz(0.0, 1.0, 0.0),
+        ))
+        .id();
+
+    // Run simulation for 2 seconds.
+    for _ in 0..128 {
+        app.update();
+    }
+
+    // The character should have stepped up onto the step, and be standing on it.
+    let translation = app.world().get::<Transform>(character).unwrap().translation;
+    let ground_state = app.world().get::<CharacterGroundState>(character).unwrap();
+    assert!(translation.x > 3.0);
+    assert_relative_eq!(translation.y, 1.1, epsilon = 0.05);
+    assert_eq!(ground_state.entity, Some(step));
+    assert!(ground_state.can_jump());
+}
+
+/// Tests that a [`CharacterController`] standing on a rotating platform is carried and rotated with it.
+#[test]
+#[cfg(feature = "3d")]
+fn character_controller_rides_rotating_platform() {
+    let mut app = create_app();
+    app.add_plugins(CharacterControllerPlugin::default());
+
+    // Apply gravity and slow down horizontal movement relative to the ground.
+    app.add_systems(
+        FixedUpdate,
+        |mut characters: Query<&mut LinearVelocity, With<CharacterController>>, time: Res<Time>| {
+            for mut linear_velocity in &mut characters {
+                linear_velocity.x *= 0.8;
+                linear_velocity.y -= 20.0 * time.delta_secs();
+                linear_velocity.z *= 0.8;
+            }
+        },
+    );
+    app.finish();
+
+    let platform = app
+        .world_mut()
+        .spawn((
+            RigidBody::Kinematic,
+            Collider::cylinder(5.0, 1.0),
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            AngularVelocity(Vector::Y),
+        ))
+        .id();
+    let character = app
+        .world_mut()
+        .spawn((
+            CharacterController::default(),
+            Collider::capsule(0.4, 1.0),
+            Transform::from_xyz(3.0, 0.92, 0.0),
+        ))
+        .id();
+
+    // Run simulation for 5 seconds.
+    for _ in 0..320 {
+        app.update();
+    }
+
+    // The character should be carried around the rotation axis without drifting outward,
+    // and rotated along with the platform.
+    let platform_yaw = app.world().get::<Transform>(platform).unwrap().rotation;
+    let transform = app.world().get::<Transform>(character).unwrap();
+    let ground_state = app.world().get::<CharacterGroundState>(character).unwrap();
+    assert_eq!(ground_state.entity, Some(platform));
+    assert_relative_eq!(transform.translation.xz().length(), 3.0, epsilon = 0.05);
+    assert!(transform.rotation.angle_between(platform_yaw) < 0.1);
+    assert!(
+        (platform_yaw * Vec3::X)
+            .normalize()
+            .dot(transform.translation.with_y(0.0).normalize())
+            > 0.99
+    );
+}
+
+/// Tests that a [`CharacterController`] pushes dynamic bodies it walks into,
+/// and is pushed by dynamic bodies that hit it.
+#[test]
+fn character_controller_pushes_dynamic_bodies() {
+    let mut app = create_app();
+    app.add_plugins(CharacterControllerPlugin::default());
+
+    // Apply gravity, walk to the right until the character has walked a distance, and then slow down.
+    app.add_systems(
+        FixedUpdate,
+        |mut characters: Query<(&mut LinearVelocity, &Transform), With<CharacterController>>,
+         time: Res<Time>| {
+            for (mut linear_velocity, transform) in &mut characters {
+                if transform.translation.x < 3.0 {
+                    linear_velocity.x = 3.0;
+                } else {
+                    linear_velocity.x *= 0.95;
+                }
+                linear_velocity.y -= 20.0 * time.delta_secs();
+            }
+        },
+    );
+    app.finish();
+
+    app.world_mut().spawn((
+        RigidBody::Static,
+        #[cfg(feature = "2d")]
+        Collider::rectangle(40.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(40.0, 1.0, 40.0),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+    ));
+    let pushed_box = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 1.0, 1.0),
+            Transform::from_xyz(2.0, 0.5, 0.0),
+        ))
+        .id();
+    let character = app
+        .world_mut()
+        .spawn((
+            CharacterController::default(),
+            Collider::capsule(0.4, 1.0),
+            Transform::from_xyz(0.0, 1.0, 0.0),
+        ))
+        .id();
+
+    // Run simulation for 2 seconds.
+    for _ in 0..128 {
+        app.update();
+    }
+
+    // The character should have pushed the box ahead of it.
+    let character_x = app
+        .world()
+        .get::<Transform>(character)
+        .unwrap()
+        .translation
+        .x;
+    let box_x = app
+        .world()
+        .get::<Transform>(pushed_box)
+        .unwrap()
+        .translation
+        .x;
+    assert!(character_x > 2.5);
+    assert!(box_x > character_x + 0.8);
+
+    // Throw a heavy box at the character from the left.
+    app.world_mut().spawn((
+        RigidBody::Dynamic,
+        #[cfg(feature = "2d")]
+        Collider::rectangle(1.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(1.0, 1.0, 1.0),
+        Mass(50.0),
+        Transform::from_xyz(character_x - 3.0, 1.0, 0.0),
+        LinearVelocity(Vector::X * 8.0),
+    ));
+
+    // Run simulation for 1 second.
+    let mut max_x = character_x;
+    for _ in 0..64 {
+        app.update();
+        max_x = max_x.max(
+            app.world()
+                .get::<Transform>(character)
+                .unwrap()
+                .translation
+                .x,
+        );
+    }
+
+    // The character should have been pushed to the right by the box.
+    assert!(max_x > character_x + 0.5);
+}
+
+/// Tests that [`MoveAndSlide::can_resize`] and [`MoveAndSlide::try_resize`] only allow
+/// resizing a shape if there is enough room for it.
+#[test]
+fn move_and_slide_resize_requires_room() {
+    let mut app = create_app();
+    app.finish();
+
+    // A room that is 2 units tall.
+    app.world_mut().spawn((
+        RigidBody::Static,
+        #[cfg(feature = "2d")]
+        Collider::rectangle(40.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(40.0, 1.0, 40.0),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+    ));
+    app.world_mut().spawn((
+        RigidBody::Static,
+        #[cfg(feature = "2d")]
+        Collider::rectangle(40.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(40.0, 1.0, 40.0),
+        Transform::from_xyz(0.0, 2.5, 0.0),
+    ));
+
+    app.update();
+
+    let mut state = SystemState::<MoveAndSlide>::new(app.world_mut());
+    let move_and_slide = state.get_mut(app.world_mut()).unwrap();
+    let config = DepenetrationConfig::default();
+    let filter = SpatialQueryFilter::default();
+
+    // A 1.8 units tall capsule fits in the room.
+    let standing = Collider::capsule(0.4, 1.0);
+    let position = RVector::Y * 0.91;
+    assert!(move_and_slide.can_resize(&standing, position, Rotation::IDENTITY, &config, &filter));
+    assert_eq!(
+        move_and_slide.try_resize(&standing, position, Rotation::IDENTITY, &config, &filter),
+        Some(Vector::ZERO)
+    );
+
+    // If the capsule is slightly inside of the floor, it is moved up to fit.
+    let position = RVector::Y * 0.88;
+    assert!(!move_and_slide.can_resize(&standing, position, Rotation::IDENTITY, &config, &filter));
+    let offset = move_and_slide
+        .try_resize(&standing, position, Rotation::IDENTITY, &config, &filter)
+        .unwrap();
+    assert_relative_eq!(offset.y, 0.03, epsilon = 0.005);
+
+    // A 2.4 units tall capsule doesn't fit anywhere in the room.
+    let tall = Collider::capsule(0.4, 1.6);
+    let position = RVector::Y;
+    assert!(!move_and_slide.can_resize(&tall, position, Rotation::IDENTITY, &config, &filter));
+    assert_eq!(
+        move_and_slide.try_resize(&tall, position, Rotation::IDENTITY, &config, &filter),
+        None
+    );
+}
+
+/// Tests that [`MoveAndSlide::move_and_slide`] reports the ground below the shape
+/// if [`MoveAndSlideConfig::ground_detection`] is set, including the velocity of moving
+/// and rotating ground.
+#[test]
+fn move_and_slide_detects_moving_and_rotating_ground() {
+    let mut app = create_app();
+    app.insert_resource(Gravity::ZERO);
+    app.finish();
+
+    // A platform moving along the X axis, and a platform rotating around the Y axis.
+    let moving_platform = app
+        .world_mut()
+        .spawn((
+            RigidBody::Kinematic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(4.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(4.0, 1.0, 4.0),
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            LinearVelocity(Vector::X * 2.0),
+        ))
+        .id();
+    let rotating_platform = app
+        .world_mut()
+        .spawn((
+            RigidBody::Kinematic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(4.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(4.0, 1.0, 4.0),
+            Transform::from_xyz(10.0, -0.5, 0.0),
+            #[cfg(feature = "2d")]
+            AngularVelocity(1.0),
+            #[cfg(feature = "3d")]
+            AngularVelocity(Vector::Y),
+        ))
+        .id();
+
+    app.update();
+
+    let mut state = SystemState::<(MoveAndSlide, Query<ForcesReadOnly>)>::new(app.world_mut());
+    let (move_and_slide, bodies) = state.get(app.world()).unwrap();
+
+    let shape = Collider::capsule(0.4, 1.0);
+    let config = MoveAndSlideConfig {
+        ground_detection: Some(GroundDetectionConfig {
+            max_distance: 0.1,
+            ..default()
+        }),
+        ..default()
+    };
+    let filter = SpatialQueryFilter::default();
+
+    let detect_ground = |position: RVector| {
+        move_and_slide
+            .move_and_slide(
+                &shape,
+                position,
+                Rotation::IDENTITY,
+                Vector::NEG_Y,
+                Duration::from_secs_f32(TIMESTEP),
+                &config,
+                &filter,
+                |_| MoveAndSlideHitResponse::Accept,
+            )
+            .ground
+    };
+
+    // The velocity of the moving platform is the same everywhere on it.
+    let moving_position = app.world().get::<Position>(moving_platform).unwrap().0;
+    let ground = detect_ground(moving_position + RVector::X + RVector::Y * 1.4)
+        .expect("The shape should be on the moving platform");
+    let body = bodies.get(ground.body).unwrap();
+    assert_eq!(ground.body, moving_platform);
+    assert_relative_eq!(
+        ground.point_velocity(&body),
+        Vector::X * 2.0,
+        epsilon = 1e-4
+    );
+    #[cfg(feature = "2d")]
+    assert_relative_eq!(ground.angular_velocity(&body), 0.0);
+    #[cfg(feature = "3d")]
+    assert_relative_eq!(ground.yaw_velocity(&body, Dir::Y), 0.0);
+
+    // One unit away from the rotation axis, the rotating platform moves at one unit per second.
+    let rotating_position = app.world().get::<Position>(rotating_platform).unwrap().0;
+    let ground = detect_ground(rotating_position + RVector::X + RVector::Y * 1.4)
+        .expect("The shape should be on the rotating platform");
+    let body = bodies.get(ground.body).unwrap();
+    let point_velocity = ground.point_velocity(&body);
+    assert_eq!(ground.body, rotating_platform);
+    #[cfg(feature = "2d")]
+    {
+        // The point is also half a unit above the center of the platform.
+        assert_relative_eq!(point_velocity, Vector::new(-0.5, 1.0), epsilon = 0.05);
+        assert_relative_eq!(ground.angular_velocity(&body), 1.0);
+    }
+    #[cfg(feature = "3d")]
+    {
+        assert_relative_eq!(point_velocity, Vector::NEG_Z, epsilon = 0.05);
+        assert_relative_eq!(ground.yaw_velocity(&body, Dir::Y), 1.0);
+        assert_relative_eq!(ground.angular_velocity(&body), Vector::Y);
+    }
+}
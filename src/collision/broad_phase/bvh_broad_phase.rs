@@ -43,14 +43,35 @@ where
     fn build(&self, app: &mut App) {
         app.add_systems(
             PhysicsSchedule,
-            collect_collision_pairs::<H>.in_set(BroadPhaseSystems::CollectCollisions),
+            (
+                mark_proxies_moved_on_matrix_change
+                    .run_if(resource_changed::<CollisionMatrix>)
+                    .in_set(BroadPhaseSystems::First),
+                collect_collision_pairs::<H>.in_set(BroadPhaseSystems::CollectCollisions),
+            ),
         );
     }
 }
 
+/// Marks all proxies as moved when the [`CollisionMatrix`] is changed,
+/// so that pairs of colliders that can now interact are found even if they are not moving.
+///
+/// Pairs that can no longer interact are removed by the narrow phase.
+fn mark_proxies_moved_on_matrix_change(
+    proxy_keys: Query<&ColliderTreeProxyKey, Without<ColliderDisabled>>,
+    mut moved_proxies: ResMut<MovedProxies>,
+) {
+    for proxy_key in &proxy_keys {
+        if *proxy_key != ColliderTreeProxyKey::PLACEHOLDER {
+            moved_proxies.insert(*proxy_key);
+        }
+    }
+}
+
 fn collect_collision_pairs<H: CollisionHooks>(
     trees: ResMut<ColliderTrees>,
     moved_proxies: Res<MovedProxies>,
+    collision_matrix: Res<CollisionMatrix>,
     hooks: StaticSystemParam<H>,
     par_commands: ParallelCommands,
     mut contact_graph: ResMut<ContactGraph>,
@@ -97,6 +118,7 @@ fn collect_collision_pairs<H: CollisionHooks>(
                         proxy_aabb1,
                         proxy1,
                         &moved_proxies,
+                        &collision_matrix,
                         &hooks,
                         &mut commands,
                         &contact_graph,
@@ -114,6 +136,7 @@ fn collect_collision_pairs<H: CollisionHooks>(
                         proxy_aabb1,
                         proxy1,
                         &moved_proxies,
+                        &collision_matrix,
                         &hooks,
                         &mut commands,
                         &contact_graph,
@@ -133,6 +156,7 @@ fn collect_collision_pairs<H: CollisionHooks>(
                             proxy_aabb1,
                             proxy1,
                             &moved_proxies,
+                            &collision_matrix,
                             &hooks,
                             &mut commands,
                             &contact_graph,
@@ -151,6 +175,7 @@ fn collect_collision_pairs<H: CollisionHooks>(
                         proxy_aabb1,
                         proxy1,
                         &moved_proxies,
+                        &collision_matrix,
                         &hooks,
                         &mut commands,
                         &contact_graph,
@@ -216,6 +241,7 @@ fn query_tree(
     proxy_aabb1: Aabb,
     proxy1: &ColliderTreeProxy,
     moved_proxies: &MovedProxies,
+    collision_matrix: &CollisionMatrix,
     hooks: &impl CollisionHooks,
     commands: &mut Commands,
     contact_graph: &ContactGraph,
@@ -254,7 +280,7 @@ fn query_tree(
             }
 
             // Check if the layers interact.
-            if !proxy1.layers.interacts_with(proxy2.layers) {
+            if !collision_matrix.layers_interact(proxy1.layers, proxy2.layers) {
                 continue;
             }
 
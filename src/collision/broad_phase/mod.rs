@@ -176,7 +176,8 @@ pub struct BroadPhaseCorePlugin;
 impl Plugin for BroadPhaseCorePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ContactGraph>()
-            .init_resource::<JointGraph>();
+            .init_resource::<JointGraph>()
+            .init_resource::<CollisionMatrix>();
 
         app.configure_sets(
             PhysicsSchedule,
@@ -4,13 +4,20 @@
 
 use core::marker::PhantomData;
 
-#[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
+#[cfg(all(
+    any(feature = "collider-from-mesh", feature = "collider-from-image"),
+    feature = "default-collider"
+))]
 use crate::collision::collider::cache::ColliderCache;
+#[cfg(feature = "persistent-collider-cache")]
+use crate::collision::collider::cache::PersistentColliderCache;
 use crate::{
     collision::collider::{ColliderAabbMargin, EnlargedAabb},
     physics_transform::{PhysicsTransformConfig, PhysicsTransformSystems, init_physics_transform},
     prelude::*,
 };
+#[cfg(feature = "default-collider")]
+use bevy::tasks::{AsyncComputeTaskPool, Task, futures::check_ready};
 #[cfg(all(feature = "bevy_scene", feature = "default-collider"))]
 use bevy::world_serialization::{
     WorldAssetRoot, WorldInstance as SceneInstance, WorldInstanceSpawner,
@@ -235,14 +242,24 @@ impl<C: ScalableCollider> Plugin for ColliderBackendPlugin<C> {
                 .chain(),
         );
 
+        #[cfg(feature = "default-collider")]
+        app.init_resource::<ColliderConstructorTasks>();
+
         #[cfg(feature = "default-collider")]
         app.add_systems(
             Update,
             (
-                init_collider_constructors,
-                init_collider_constructor_hierarchies,
-            ),
+                (
+                    init_collider_constructors,
+                    init_collider_constructor_hierarchies,
+                ),
+                poll_collider_constructor_tasks,
+            )
+                .chain(),
         );
+
+        #[cfg(feature = "default-collider")]
+        app.add_observer(on_remove_collider_constructor_task);
     }
 }
 
@@ -253,28 +270,330 @@ impl<C: ScalableCollider> Plugin for ColliderBackendPlugin<C> {
 #[reflect(Component, Debug, Default)]
 pub struct ColliderMarker;
 
+/// A component for an entity with [`AsyncColliderConstruction`] that is waiting for its [`Collider`]
+/// to be computed by a task in [`ColliderConstructorTasks`].
+#[cfg(feature = "default-collider")]
+#[derive(Component)]
+struct ColliderConstructorTask {
+    /// The ID of the task in [`ColliderConstructorTasks`].
+    task_id: u64,
+    /// The entity with the [`ColliderConstructor`] or [`ColliderConstructorHierarchy`].
+    owner: Entity,
+    /// The constructor used for computing the collider.
+    constructor: ColliderConstructor,
+    /// The collision layers and density for colliders generated by a [`ColliderConstructorHierarchy`].
+    hierarchy_components: Option<(CollisionLayers, ColliderDensity)>,
+}
+
+/// The assets that a collider is computed from, used for caching the collider and sharing tasks.
+#[cfg(feature = "default-collider")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct ColliderSource {
+    /// The mesh that the collider is computed from.
+    #[cfg(feature = "collider-from-mesh")]
+    mesh_id: Option<AssetId<Mesh>>,
+    /// The image that the collider is computed from.
+    #[cfg(feature = "collider-from-image")]
+    image_id: Option<AssetId<Image>>,
+}
+
+#[cfg(any(feature = "collider-from-mesh", feature = "collider-from-image"))]
+impl ColliderSource {
+    /// Creates a [`ColliderSource`] for a collider computed from the given mesh.
+    #[cfg(feature = "collider-from-mesh")]
+    fn from_mesh(mesh_id: AssetId<Mesh>) -> Self {
+        Self {
+            mesh_id: Some(mesh_id),
+            #[cfg(feature = "collider-from-image")]
+            image_id: None,
+        }
+    }
+
+    /// Creates a [`ColliderSource`] for a collider computed from the given image.
+    #[cfg(feature = "collider-from-image")]
+    fn from_image(image_id: AssetId<Image>) -> Self {
+        Self {
+            #[cfg(feature = "collider-from-mesh")]
+            mesh_id: None,
+            image_id: Some(image_id),
+        }
+    }
+
+    /// Returns `true` if the collider is computed from an asset.
+    fn is_asset(&self) -> bool {
+        #[cfg(feature = "collider-from-mesh")]
+        if self.mesh_id.is_some() {
+            return true;
+        }
+        #[cfg(feature = "collider-from-image")]
+        if self.image_id.is_some() {
+            return true;
+        }
+        false
+    }
+}
+
+/// The tasks computing [`Collider`]s for entities with [`AsyncColliderConstruction`].
+///
+/// Entities that request a collider with the same [`ColliderConstructor`] from the same asset
+/// share a single task, so that expensive colliders are only computed once.
+#[cfg(feature = "default-collider")]
+#[derive(Resource, Default)]
+struct ColliderConstructorTasks {
+    tasks: Vec<SharedColliderConstructorTask>,
+    next_id: u64,
+}
+
+/// A task computing a [`Collider`] for one or more entities with [`AsyncColliderConstruction`].
+#[cfg(feature = "default-collider")]
+struct SharedColliderConstructorTask {
+    id: u64,
+    task: Task<Option<Collider>>,
+    /// The constructor used for computing the collider.
+    #[cfg_attr(
+        not(any(feature = "collider-from-mesh", feature = "collider-from-image")),
+        expect(dead_code)
+    )]
+    constructor: ColliderConstructor,
+    /// The assets that the collider is computed from.
+    #[cfg_attr(
+        not(any(feature = "collider-from-mesh", feature = "collider-from-image")),
+        expect(dead_code)
+    )]
+    source: ColliderSource,
+    /// The entities waiting for the collider.
+    entities: Vec<Entity>,
+}
+
+#[cfg(feature = "default-collider")]
+impl ColliderConstructorTasks {
+    /// Returns the ID of a running task computing a collider with the given constructor
+    /// from the given assets, if there is one.
+    ///
+    /// Colliders that are not computed from an asset are cheap to compute, so their tasks are not shared.
+    #[cfg(any(feature = "collider-from-mesh", feature = "collider-from-image"))]
+    fn find(&self, constructor: &ColliderConstructor, source: ColliderSource) -> Option<u64> {
+        if !source.is_asset() {
+            return None;
+        }
+        self.tasks
+            .iter()
+            .find(|task| task.source == source && &task.constructor == constructor)
+            .map(|task| task.id)
+    }
+
+    /// Adds a new task and returns its ID.
+    fn add(
+        &mut self,
+        task: Task<Option<Collider>>,
+        constructor: ColliderConstructor,
+        source: ColliderSource,
+    ) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.push(SharedColliderConstructorTask {
+            id,
+            task,
+            constructor,
+            source,
+            entities: Vec::new(),
+        });
+        id
+    }
+
+    /// Adds an entity that is waiting for the collider computed by the task with the given ID.
+    fn add_entity(&mut self, id: u64, entity: Entity) {
+        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
+            task.entities.push(entity);
+        }
+    }
+
+    /// Removes an entity that is waiting for the collider computed by the task with the given ID.
+    /// If no entities are waiting for the task anymore, it is cancelled.
+    fn remove_entity(&mut self, id: u64, entity: Entity) {
+        let Some(index) = self.tasks.iter().position(|task| task.id == id) else {
+            return;
+        };
+        let task = &mut self.tasks[index];
+        task.entities.retain(|&e| e != entity);
+        if task.entities.is_empty() {
+            // Dropping the task cancels it.
+            self.tasks.swap_remove(index);
+        }
+    }
+}
+
+/// Tracks the [`ColliderConstructorTask`]s of an entity with [`AsyncColliderConstruction`].
+#[cfg(feature = "default-collider")]
+#[derive(Component)]
+struct PendingColliderConstructorTasks {
+    /// The number of tasks that have not finished yet.
+    remaining: usize,
+    /// Whether [`RigidBodyDisabled`] was inserted while the tasks are running.
+    disabled_body: bool,
+    /// Whether the tasks belong to a [`ColliderConstructorHierarchy`].
+    hierarchy: bool,
+}
+
+/// The result of constructing a collider from a [`ColliderConstructor`].
+#[cfg(feature = "default-collider")]
+enum ColliderConstruction {
+    /// The collider was computed immediately, or `None` if it could not be computed.
+    Ready(Option<Collider>),
+    /// The collider is being computed asynchronously by the task with the given ID in [`ColliderConstructorTasks`].
+    Pending(u64),
+}
+
+/// Computes a collider from the given constructor, or spawns a task for computing it
+/// on the [`AsyncComputeTaskPool`] if `is_async` is `true` and the collider is not cached.
+///
+/// If a task is already computing the same collider from the same asset, it is shared instead.
+#[cfg(feature = "default-collider")]
+fn construct_collider(
+    constructor: &ColliderConstructor,
+    is_async: bool,
+    tasks: &mut ColliderConstructorTasks,
+    #[cfg(feature = "collider-from-mesh")] mesh: Option<(&Handle<Mesh>, &Mesh)>,
+    #[cfg(feature = "collider-from-mesh")] collider_cache: Option<&mut ColliderCache<Mesh>>,
+    #[cfg(feature = "persistent-collider-cache")] persistent_collider_cache: Option<
+        &PersistentColliderCache,
+    >,
+    #[cfg(feature = "collider-from-image")] image: Option<(&Handle<Image>, &Image)>,
+    #[cfg(feature = "collider-from-image")] image_collider_cache: Option<&mut ColliderCache<Image>>,
+) -> ColliderConstruction {
+    #[cfg(feature = "collider-from-mesh")]
+    if let Some((mesh_handle, mesh)) = mesh {
+        if !is_async {
+            return ColliderConstruction::Ready(match collider_cache {
+                Some(cache) => cache.get_or_insert(
+                    mesh_handle,
+                    mesh,
+                    constructor.clone(),
+                    #[cfg(feature = "persistent-collider-cache")]
+                    persistent_collider_cache,
+                ),
+                None => Collider::try_from_constructor(constructor.clone(), Some(mesh)),
+            });
+        }
+
+        if let Some(collider) =
+            collider_cache.and_then(|cache| cache.get(mesh_handle.id(), constructor))
+        {
+            return ColliderConstruction::Ready(Some(collider));
+        }
+
+        let source = ColliderSource::from_mesh(mesh_handle.id());
+        if let Some(id) = tasks.find(constructor, source) {
+            return ColliderConstruction::Pending(id);
+        }
+
+        let task_constructor = constructor.clone();
+        let mesh = mesh.clone();
+        #[cfg(feature = "persistent-collider-cache")]
+        let persistent_collider_cache = persistent_collider_cache.cloned();
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            #[cfg(feature = "persistent-collider-cache")]
+            if let Some(persistent_collider_cache) = persistent_collider_cache {
+                return persistent_collider_cache.get_or_insert(&mesh, &task_constructor);
+            }
+            Collider::try_from_constructor(task_constructor, Some(&mesh))
+        });
+        return ColliderConstruction::Pending(tasks.add(task, constructor.clone(), source));
+    }
+
+    #[cfg(feature = "collider-from-image")]
+    if let Some((image_handle, image)) = image {
+        let try_from_image = |constructor: &ColliderConstructor| {
+            Collider::try_from_constructor(constructor.clone(), Some(image))
+        };
+
+        if !is_async {
+            return ColliderConstruction::Ready(match image_collider_cache {
+                Some(cache) => {
+                    cache.get_or_insert_with(image_handle.id(), constructor.clone(), try_from_image)
+                }
+                None => try_from_image(constructor),
+            });
+        }
+
+        if let Some(collider) =
+            image_collider_cache.and_then(|cache| cache.get(image_handle.id(), constructor))
+        {
+            return ColliderConstruction::Ready(Some(collider));
+        }
+
+        let source = ColliderSource::from_image(image_handle.id());
+        if let Some(id) = tasks.find(constructor, source) {
+            return ColliderConstruction::Pending(id);
+        }
+
+        let task_constructor = constructor.clone();
+        let image = image.clone();
+        let task = AsyncComputeTaskPool::get()
+            .spawn(async move { Collider::try_from_constructor(task_constructor, Some(&image)) });
+        return ColliderConstruction::Pending(tasks.add(task, constructor.clone(), source));
+    }
+
+    #[cfg(any(feature = "collider-from-mesh", feature = "collider-from-image"))]
+    let try_from_constructor =
+        |constructor: ColliderConstructor| Collider::try_from_constructor(constructor, None);
+    #[cfg(not(any(feature = "collider-from-mesh", feature = "collider-from-image")))]
+    let try_from_constructor = Collider::try_from_constructor;
+
+    if is_async {
+        let task_constructor = constructor.clone();
+        let task = AsyncComputeTaskPool::get()
+            .spawn(async move { try_from_constructor(task_constructor) });
+        ColliderConstruction::Pending(tasks.add(
+            task,
+            constructor.clone(),
+            ColliderSource::default(),
+        ))
+    } else {
+        ColliderConstruction::Ready(try_from_constructor(constructor.clone()))
+    }
+}
+
 /// Generates [`Collider`]s based on [`ColliderConstructor`]s.
 ///
-/// If a [`ColliderConstructor`] requires a mesh, the system keeps running
-/// until the mesh associated with the mesh handle is available.
+/// If a [`ColliderConstructor`] requires a mesh or an image, the system keeps running
+/// until the mesh or image associated with the handle is available.
+///
+/// If the entity has [`AsyncColliderConstruction`], the collider is computed asynchronously,
+/// and inserted by [`poll_collider_constructor_tasks`] once it is ready.
 ///
 /// # Panics
 ///
-/// Panics if the [`ColliderConstructor`] requires a mesh but no mesh handle is found.
+/// Panics if the [`ColliderConstructor`] requires a mesh but no mesh handle is found,
+/// or if it requires an image but no sprite is found.
 #[cfg(feature = "default-collider")]
 fn init_collider_constructors(
     mut commands: Commands,
+    mut tasks: ResMut<ColliderConstructorTasks>,
     #[cfg(feature = "collider-from-mesh")] meshes: Res<Assets<Mesh>>,
     #[cfg(feature = "collider-from-mesh")] mesh_handles: Query<&Mesh3d>,
-    #[cfg(feature = "collider-from-mesh")] mut collider_cache: Option<ResMut<ColliderCache>>,
+    #[cfg(feature = "collider-from-mesh")] mut collider_cache: Option<ResMut<ColliderCache<Mesh>>>,
+    #[cfg(feature = "persistent-collider-cache")] persistent_collider_cache: Option<
+        Res<PersistentColliderCache>,
+    >,
+    #[cfg(feature = "collider-from-image")] images: Res<Assets<Image>>,
+    #[cfg(feature = "collider-from-image")] sprites: Query<&Sprite>,
+    #[cfg(feature = "collider-from-image")] mut image_collider_cache: Option<
+        ResMut<ColliderCache<Image>>,
+    >,
     constructors: Query<(
         Entity,
         Option<&Collider>,
         Option<&Name>,
         &ColliderConstructor,
+        Has<AsyncColliderConstruction>,
+        Has<RigidBody>,
+        Has<RigidBodyDisabled>,
     )>,
 ) {
-    for (entity, existing_collider, name, constructor) in constructors.iter() {
+    for (entity, existing_collider, name, constructor, is_async, is_body, is_disabled) in
+        constructors.iter()
+    {
         let name = pretty_name(name, entity);
         if existing_collider.is_some() {
             warn!(
@@ -285,7 +604,7 @@ fn init_collider_constructors(
             continue;
         }
         #[cfg(feature = "collider-from-mesh")]
-        let collider = if constructor.requires_mesh() {
+        let mesh = if constructor.requires_mesh() {
             let mesh_handle = mesh_handles.get(entity).unwrap_or_else(|_| panic!(
                 "Tried to add a collider to entity {name} via {constructor:#?} that requires a mesh, \
                 but no mesh handle was found"));
@@ -293,25 +612,73 @@ fn init_collider_constructors(
                 // Mesh required, but not loaded yet
                 continue;
             };
-            collider_cache
-                .as_mut()
-                .map(|cache| cache.get_or_insert(mesh_handle, mesh, constructor.clone()))
-                .unwrap_or_else(|| Collider::try_from_constructor(constructor.clone(), Some(mesh)))
+            Some((&mesh_handle.0, mesh))
         } else {
-            Collider::try_from_constructor(constructor.clone(), None)
+            None
         };
+        #[cfg(feature = "collider-from-image")]
+        let image = if constructor.requires_image() {
+            let sprite = sprites.get(entity).unwrap_or_else(|_| panic!(
+                "Tried to add a collider to entity {name} via {constructor:#?} that requires an image, \
+                but no sprite was found"));
+            let Some(image) = images.get(&sprite.image) else {
+                // Image required, but not loaded yet
+                continue;
+            };
+            Some((&sprite.image, image))
+        } else {
+            None
+        };
+
+        let construction = construct_collider(
+            constructor,
+            is_async,
+            &mut tasks,
+            #[cfg(feature = "collider-from-mesh")]
+            mesh,
+            #[cfg(feature = "collider-from-mesh")]
+            collider_cache.as_deref_mut(),
+            #[cfg(feature = "persistent-collider-cache")]
+            persistent_collider_cache.as_deref(),
+            #[cfg(feature = "collider-from-image")]
+            image,
+            #[cfg(feature = "collider-from-image")]
+            image_collider_cache.as_deref_mut(),
+        );
 
-        #[cfg(not(feature = "collider-from-mesh"))]
-        let collider = Collider::try_from_constructor(constructor.clone());
+        match construction {
+            ColliderConstruction::Ready(Some(collider)) => {
+                commands.entity(entity).insert(collider);
+                commands.trigger(ColliderConstructorReady { entity })
+            }
+            ColliderConstruction::Ready(None) => {
+                error!(
+                    "Tried to add a collider to entity {name} via {constructor:#?}, \
+                    but the collider could not be generated. Skipping.",
+                );
+            }
+            ColliderConstruction::Pending(task_id) => {
+                tasks.add_entity(task_id, entity);
 
-        if let Some(collider) = collider {
-            commands.entity(entity).insert(collider);
-            commands.trigger(ColliderConstructorReady { entity })
-        } else {
-            error!(
-                "Tried to add a collider to entity {name} via {constructor:#?}, \
-                but the collider could not be generated. Skipping.",
-            );
+                // Keep the body inactive until the collider is ready.
+                let disabled_body = is_body && !is_disabled;
+                if disabled_body {
+                    commands.entity(entity).insert(RigidBodyDisabled);
+                }
+                commands.entity(entity).insert((
+                    PendingColliderConstructorTasks {
+                        remaining: 1,
+                        disabled_body,
+                        hierarchy: false,
+                    },
+                    ColliderConstructorTask {
+                        task_id,
+                        owner: entity,
+                        constructor: constructor.clone(),
+                        hierarchy_components: None,
+                    },
+                ));
+            }
         }
         commands.entity(entity).remove::<ColliderConstructor>();
     }
@@ -320,22 +687,42 @@ fn init_collider_constructors(
 /// Generates [`Collider`]s for descendants of entities with the [`ColliderConstructorHierarchy`] component.
 ///
 /// If an entity has a `SceneInstance`, its collider hierarchy is only generated once the scene is ready.
+///
+/// If the entity has [`AsyncColliderConstruction`], the colliders are computed asynchronously,
+/// and inserted by [`poll_collider_constructor_tasks`] once they are ready.
 #[cfg(feature = "default-collider")]
 fn init_collider_constructor_hierarchies(
     mut commands: Commands,
+    mut tasks: ResMut<ColliderConstructorTasks>,
     #[cfg(feature = "collider-from-mesh")] meshes: Res<Assets<Mesh>>,
     #[cfg(feature = "collider-from-mesh")] mesh_handles: Query<&Mesh3d>,
-    #[cfg(feature = "collider-from-mesh")] mut collider_cache: Option<ResMut<ColliderCache>>,
+    #[cfg(feature = "collider-from-mesh")] mut collider_cache: Option<ResMut<ColliderCache<Mesh>>>,
+    #[cfg(feature = "persistent-collider-cache")] persistent_collider_cache: Option<
+        Res<PersistentColliderCache>,
+    >,
+    #[cfg(feature = "collider-from-image")] images: Res<Assets<Image>>,
+    #[cfg(feature = "collider-from-image")] sprites: Query<&Sprite>,
+    #[cfg(feature = "collider-from-image")] mut image_collider_cache: Option<
+        ResMut<ColliderCache<Image>>,
+    >,
     #[cfg(feature = "bevy_scene")] scene_spawner: If<Res<WorldInstanceSpawner>>,
     #[cfg(feature = "bevy_scene")] scenes: Query<&WorldAssetRoot>,
     #[cfg(feature = "bevy_scene")] scene_instances: Query<&SceneInstance>,
-    collider_constructors: Query<(Entity, &ColliderConstructorHierarchy)>,
+    collider_constructors: Query<(
+        Entity,
+        &ColliderConstructorHierarchy,
+        Has<AsyncColliderConstruction>,
+        Has<RigidBody>,
+        Has<RigidBodyDisabled>,
+    )>,
     children: Query<&Children>,
     child_query: Query<(Option<&Name>, Option<&Collider>)>,
 ) {
     use super::ColliderConstructorHierarchyConfig;
 
-    for (scene_entity, collider_constructor_hierarchy) in collider_constructors.iter() {
+    for (scene_entity, collider_constructor_hierarchy, is_async, is_body, is_disabled) in
+        collider_constructors.iter()
+    {
         #[cfg(feature = "bevy_scene")]
         {
             if scenes.contains(scene_entity) {
@@ -351,6 +738,8 @@ fn init_collider_constructor_hierarchies(
             }
         }
 
+        let mut pending_tasks = 0;
+
         for child_entity in children.iter_descendants(scene_entity) {
             let Ok((name, existing_collider)) = child_query.get(child_entity) else {
                 continue;
@@ -397,7 +786,7 @@ fn init_collider_constructor_hierarchies(
             };
 
             #[cfg(feature = "collider-from-mesh")]
-            let collider = if constructor.requires_mesh() {
+            let mesh = if constructor.requires_mesh() {
                 let Ok(mesh_handle) = mesh_handles.get(child_entity) else {
                     // This child entity does not have a mesh, so we skip it.
                     continue;
@@ -406,34 +795,74 @@ fn init_collider_constructor_hierarchies(
                     // Mesh required, but not loaded yet
                     continue;
                 };
-                collider_cache
-                    .as_mut()
-                    .map(|cache| cache.get_or_insert(mesh_handle, mesh, constructor.clone()))
-                    .unwrap_or_else(|| {
-                        Collider::try_from_constructor(constructor.clone(), Some(mesh))
-                    })
+                Some((&mesh_handle.0, mesh))
             } else {
-                Collider::try_from_constructor(constructor.clone(), None)
+                None
             };
-
-            #[cfg(not(feature = "collider-from-mesh"))]
-            let collider = Collider::try_from_constructor(constructor);
-
-            if let Some(collider) = collider {
-                commands.entity(child_entity).insert((
-                    collider,
-                    collider_data
-                        .layers
-                        .unwrap_or(collider_constructor_hierarchy.default_layers),
-                    collider_data
-                        .density
-                        .unwrap_or(collider_constructor_hierarchy.default_density),
-                ));
+            #[cfg(feature = "collider-from-image")]
+            let image = if constructor.requires_image() {
+                let Ok(sprite) = sprites.get(child_entity) else {
+                    // This child entity does not have a sprite, so we skip it.
+                    continue;
+                };
+                let Some(image) = images.get(&sprite.image) else {
+                    // Image required, but not loaded yet
+                    continue;
+                };
+                Some((&sprite.image, image))
             } else {
-                error!(
-                    "Tried to add a collider to entity {pretty_name} via {collider_constructor_hierarchy:#?}, \
-                        but the collider could not be generated. Skipping.",
-                );
+                None
+            };
+
+            let construction = construct_collider(
+                &constructor,
+                is_async,
+                &mut tasks,
+                #[cfg(feature = "collider-from-mesh")]
+                mesh,
+                #[cfg(feature = "collider-from-mesh")]
+                collider_cache.as_deref_mut(),
+                #[cfg(feature = "persistent-collider-cache")]
+                persistent_collider_cache.as_deref(),
+                #[cfg(feature = "collider-from-image")]
+                image,
+                #[cfg(feature = "collider-from-image")]
+                image_collider_cache.as_deref_mut(),
+            );
+
+            let hierarchy_components = (
+                collider_data
+                    .layers
+                    .unwrap_or(collider_constructor_hierarchy.default_layers),
+                collider_data
+                    .density
+                    .unwrap_or(collider_constructor_hierarchy.default_density),
+            );
+
+            match construction {
+                ColliderConstruction::Ready(Some(collider)) => {
+                    commands
+                        .entity(child_entity)
+                        .insert((collider, hierarchy_components));
+                }
+                ColliderConstruction::Ready(None) => {
+                    error!(
+                        "Tried to add a collider to entity {pretty_name} via {collider_constructor_hierarchy:#?}, \
+                            but the collider could not be generated. Skipping.",
+                    );
+                }
+                ColliderConstruction::Pending(task_id) => {
+                    tasks.add_entity(task_id, child_entity);
+                    pending_tasks += 1;
+                    commands
+                        .entity(child_entity)
+                        .insert(ColliderConstructorTask {
+                            task_id,
+                            owner: scene_entity,
+                            constructor,
+                            hierarchy_components: Some(hierarchy_components),
+                        });
+                }
             }
         }
 
@@ -441,9 +870,122 @@ fn init_collider_constructor_hierarchies(
             .entity(scene_entity)
             .remove::<ColliderConstructorHierarchy>();
 
-        commands.trigger(ColliderConstructorHierarchyReady {
-            entity: scene_entity,
-        })
+        if pending_tasks > 0 {
+            // Keep the body inactive until all colliders are ready.
+            let disabled_body = is_body && !is_disabled;
+            if disabled_body {
+                commands.entity(scene_entity).insert(RigidBodyDisabled);
+            }
+            commands
+                .entity(scene_entity)
+                .insert(PendingColliderConstructorTasks {
+                    remaining: pending_tasks,
+                    disabled_body,
+                    hierarchy: true,
+                });
+        } else {
+            commands.trigger(ColliderConstructorHierarchyReady {
+                entity: scene_entity,
+            })
+        }
+    }
+}
+
+/// Inserts the [`Collider`]s computed by finished tasks in [`ColliderConstructorTasks`]
+/// for the entities waiting for them.
+#[cfg(feature = "default-collider")]
+fn poll_collider_constructor_tasks(
+    mut commands: Commands,
+    mut tasks: ResMut<ColliderConstructorTasks>,
+    waiting: Query<(Option<&Name>, &ColliderConstructorTask)>,
+    #[cfg(feature = "collider-from-mesh")] mut collider_cache: Option<ResMut<ColliderCache<Mesh>>>,
+    #[cfg(feature = "collider-from-image")] mut image_collider_cache: Option<
+        ResMut<ColliderCache<Image>>,
+    >,
+) {
+    tasks.tasks.retain_mut(|shared_task| {
+        let Some(collider) = check_ready(&mut shared_task.task) else {
+            return true;
+        };
+
+        #[cfg(any(feature = "collider-from-mesh", feature = "collider-from-image"))]
+        if let Some(collider) = &collider {
+            #[cfg(feature = "collider-from-mesh")]
+            if let (Some(cache), Some(mesh_id)) =
+                (collider_cache.as_mut(), shared_task.source.mesh_id)
+            {
+                cache.insert(mesh_id, shared_task.constructor.clone(), collider.clone());
+            }
+            #[cfg(feature = "collider-from-image")]
+            if let (Some(cache), Some(image_id)) =
+                (image_collider_cache.as_mut(), shared_task.source.image_id)
+            {
+                cache.insert(image_id, shared_task.constructor.clone(), collider.clone());
+            }
+        }
+
+        for &entity in &shared_task.entities {
+            let Ok((name, task)) = waiting.get(entity) else {
+                continue;
+            };
+
+            if let Some(collider) = &collider {
+                if let Some(hierarchy_components) = task.hierarchy_components {
+                    commands
+                        .entity(entity)
+                        .insert((collider.clone(), hierarchy_components));
+                } else {
+                    commands.entity(entity).insert(collider.clone());
+                    commands.trigger(ColliderConstructorReady { entity });
+                }
+            } else {
+                let name = pretty_name(name, entity);
+                error!(
+                    "Tried to add a collider to entity {name} via {:#?}, \
+                    but the collider could not be generated. Skipping.",
+                    task.constructor
+                );
+            }
+
+            commands.entity(entity).remove::<ColliderConstructorTask>();
+        }
+
+        false
+    });
+}
+
+/// Re-enables the owner of a [`ColliderConstructorTask`] and triggers
+/// [`ColliderConstructorHierarchyReady`] when its last task is finished or removed.
+#[cfg(feature = "default-collider")]
+fn on_remove_collider_constructor_task(
+    trigger: On<Remove, ColliderConstructorTask>,
+    tasks: Query<&ColliderConstructorTask>,
+    mut shared_tasks: ResMut<ColliderConstructorTasks>,
+    mut pending: Query<&mut PendingColliderConstructorTasks>,
+    mut commands: Commands,
+) {
+    let Ok(task) = tasks.get(trigger.entity) else {
+        return;
+    };
+
+    // Stop waiting for the collider, cancelling the task if no other entity is waiting for it.
+    shared_tasks.remove_entity(task.task_id, trigger.entity);
+    let Ok(mut pending_tasks) = pending.get_mut(task.owner) else {
+        return;
+    };
+
+    pending_tasks.remaining = pending_tasks.remaining.saturating_sub(1);
+    if pending_tasks.remaining > 0 {
+        return;
+    }
+
+    let mut owner_commands = commands.entity(task.owner);
+    owner_commands.try_remove::<PendingColliderConstructorTasks>();
+    if pending_tasks.disabled_body {
+        owner_commands.try_remove::<RigidBodyDisabled>();
+    }
+    if pending_tasks.hierarchy {
+        commands.trigger(ColliderConstructorHierarchyReady { entity: task.owner });
     }
 }
 
@@ -1,3 +1,6 @@
+#[cfg(feature = "persistent-collider-cache")]
+use core::hash::Hasher;
+
 use bevy::{platform::collections::HashMap, prelude::*};
 
 use super::{Collider, ColliderConstructor};
@@ -5,45 +8,110 @@ use super::{Collider, ColliderConstructor};
 /// A plugin for caching colliders created from meshes via [`ColliderConstructor`] or [`ColliderConstructorHierarchy`](super::ColliderConstructorHierarchy).
 /// With this plugin enabled, colliders created from meshes through such constructors will be created only once and reused.
 /// This is especially useful when performing convex decomposition, as this is a very expensive operation.
+///
+/// In 2D, colliders created from the images of sprites with the `collider-from-image` feature
+/// are cached per image in the same way.
+///
+/// The cache is only kept in memory by default. With the `persistent-collider-cache` feature,
+/// the cache can also be persisted to disk across runs by inserting the `PersistentColliderCache` resource.
 pub struct ColliderCachePlugin;
 
 impl Plugin for ColliderCachePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<ColliderCache>();
-        app.add_systems(PreUpdate, clear_unused_colliders);
+        #[cfg(feature = "collider-from-mesh")]
+        app.init_resource::<ColliderCache<Mesh>>()
+            .add_systems(PreUpdate, clear_unused_colliders::<Mesh>);
+        #[cfg(feature = "collider-from-image")]
+        app.init_resource::<ColliderCache<Image>>()
+            .add_systems(PreUpdate, clear_unused_colliders::<Image>);
+    }
+}
+
+/// Caches the colliders created from assets of type `A`, keyed by the asset and the [`ColliderConstructor`].
+#[derive(Debug, Resource)]
+pub(crate) struct ColliderCache<A: Asset>(
+    HashMap<AssetId<A>, Vec<(ColliderConstructor, Collider)>>,
+);
+
+impl<A: Asset> Default for ColliderCache<A> {
+    fn default() -> Self {
+        Self(HashMap::default())
     }
 }
 
-#[derive(Debug, Resource, Default)]
-pub(crate) struct ColliderCache(HashMap<AssetId<Mesh>, Vec<(ColliderConstructor, Collider)>>);
+impl<A: Asset> ColliderCache<A> {
+    /// Returns the cached collider for the given asset and constructor, if it exists.
+    pub(crate) fn get(
+        &self,
+        asset_id: AssetId<A>,
+        constructor: &ColliderConstructor,
+    ) -> Option<Collider> {
+        self.0
+            .get(&asset_id)?
+            .iter()
+            .find(|(c, _)| c == constructor)
+            .map(|(_, collider)| collider.clone())
+    }
 
-impl ColliderCache {
+    /// Inserts a collider computed for the given asset and constructor into the cache,
+    /// replacing the existing entry for the same asset and constructor.
+    pub(crate) fn insert(
+        &mut self,
+        asset_id: AssetId<A>,
+        constructor: ColliderConstructor,
+        collider: Collider,
+    ) {
+        let entries = self.0.entry(asset_id).or_default();
+        if let Some((_, cached)) = entries.iter_mut().find(|(c, _)| c == &constructor) {
+            *cached = collider;
+        } else {
+            entries.push((constructor, collider));
+        }
+    }
+
+    /// Returns the cached collider for the given asset and constructor,
+    /// or computes it with `f` and inserts it into the cache if it is not cached yet.
+    pub(crate) fn get_or_insert_with(
+        &mut self,
+        asset_id: AssetId<A>,
+        constructor: ColliderConstructor,
+        f: impl FnOnce(&ColliderConstructor) -> Option<Collider>,
+    ) -> Option<Collider> {
+        let entries = self.0.entry(asset_id).or_default();
+        if let Some((_ctor, collider)) = entries.iter().find(|(c, _)| c == &constructor) {
+            return Some(collider.clone());
+        }
+
+        let collider = f(&constructor)?;
+        entries.push((constructor, collider.clone()));
+        Some(collider)
+    }
+}
+
+#[cfg(feature = "collider-from-mesh")]
+impl ColliderCache<Mesh> {
     pub(crate) fn get_or_insert(
         &mut self,
         mesh_handle: &Handle<Mesh>,
         mesh: &Mesh,
         constructor: ColliderConstructor,
+        #[cfg(feature = "persistent-collider-cache")] persistent_cache: Option<
+            &PersistentColliderCache,
+        >,
     ) -> Option<Collider> {
-        let id = mesh_handle.id();
-        let Some(entries) = self.0.get_mut(&id) else {
-            let collider = Collider::try_from_constructor(constructor.clone(), Some(mesh))?;
-            self.0
-                .insert(id, vec![(constructor.clone(), collider.clone())]);
-            return Some(collider);
-        };
-        if let Some((_ctor, collider)) = entries.iter().find(|(c, _)| c == &constructor) {
-            Some(collider.clone())
-        } else {
-            let collider = Collider::try_from_constructor(constructor.clone(), Some(mesh))?;
-            entries.push((constructor.clone(), collider.clone()));
-            Some(collider)
-        }
+        self.get_or_insert_with(mesh_handle.id(), constructor, |constructor| {
+            #[cfg(feature = "persistent-collider-cache")]
+            if let Some(persistent_cache) = persistent_cache {
+                return persistent_cache.get_or_insert(mesh, constructor);
+            }
+            Collider::try_from_constructor(constructor.clone(), Some(mesh))
+        })
     }
 }
 
-fn clear_unused_colliders(
-    mut asset_events: MessageReader<AssetEvent<Mesh>>,
-    mut collider_cache: ResMut<ColliderCache>,
+fn clear_unused_colliders<A: Asset>(
+    mut asset_events: MessageReader<AssetEvent<A>>,
+    mut collider_cache: ResMut<ColliderCache<A>>,
 ) {
     for event in asset_events.read() {
         if let AssetEvent::Removed { id } | AssetEvent::Unused { id } = event
@@ -53,3 +121,256 @@ fn clear_unused_colliders(
         }
     }
 }
+
+/// A resource for persisting colliders created from meshes to disk across runs,
+/// used by the [`ColliderCachePlugin`].
+///
+/// When a collider is not found in the in-memory cache, the persistent cache is checked
+/// before the collider is computed, and newly computed colliders are written to disk.
+/// This avoids recomputing expensive colliders like convex decompositions on every launch.
+///
+/// Each collider is stored in its own file in the [`directory`](Self::directory), keyed by a hash
+/// of the mesh vertex positions and indices, the [`ColliderConstructor`] and its parameters,
+/// and the version of Avian. If any of these change, the key changes, and the collider is recomputed.
+/// Entries with outdated keys are not removed automatically, but the whole cache can be cleared
+/// with [`PersistentColliderCache::clear`].
+///
+/// Colliders are serialized using [RON](https://github.com/ron-rs/ron).
+/// Entries that fail to load, for example because they are corrupted, are recomputed and overwritten.
+///
+/// # Example
+///
+/// ```no_run
+/// use avian3d::prelude::*;
+/// use bevy::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .insert_resource(PersistentColliderCache::new("cache/colliders"))
+///         .run();
+/// }
+/// ```
+#[cfg(feature = "persistent-collider-cache")]
+#[derive(Resource, Clone, Debug, PartialEq, Eq)]
+pub struct PersistentColliderCache {
+    /// The directory where the cached colliders are stored.
+    pub directory: std::path::PathBuf,
+}
+
+#[cfg(feature = "persistent-collider-cache")]
+impl PersistentColliderCache {
+    /// The file extension of cached colliders.
+    const EXTENSION: &str = "collider.ron";
+
+    /// Creates a new [`PersistentColliderCache`] that stores colliders in the given directory.
+    ///
+    /// The directory is created when the first collider is stored.
+    pub fn new(directory: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Returns the cached collider for the given mesh and constructor, or computes and stores it
+    /// if it is not cached yet.
+    ///
+    /// Returns `None` if the collider could not be computed.
+    pub fn get_or_insert(
+        &self,
+        mesh: &Mesh,
+        constructor: &ColliderConstructor,
+    ) -> Option<Collider> {
+        if let Some(collider) = self.get(mesh, constructor) {
+            return Some(collider);
+        }
+
+        let collider = Collider::try_from_constructor(constructor.clone(), Some(mesh))?;
+
+        if let Err(error) = self.insert(mesh, constructor, &collider) {
+            warn!(
+                "Failed to write collider to the persistent collider cache at {}: {error}",
+                self.directory.display()
+            );
+        }
+
+        Some(collider)
+    }
+
+    /// Returns the cached collider for the given mesh and constructor, if it exists and can be loaded.
+    pub fn get(&self, mesh: &Mesh, constructor: &ColliderConstructor) -> Option<Collider> {
+        let path = self.path(mesh, constructor);
+        let bytes = std::fs::read(&path).ok()?;
+
+        match ron::de::from_bytes(&bytes) {
+            Ok(collider) => Some(collider),
+            Err(error) => {
+                warn!(
+                    "Failed to load cached collider from {}, recomputing it: {error}",
+                    path.display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Stores the given collider in the cache for the given mesh and constructor.
+    pub fn insert(
+        &self,
+        mesh: &Mesh,
+        constructor: &ColliderConstructor,
+        collider: &Collider,
+    ) -> std::io::Result<()> {
+        let serialized = ron::ser::to_string(collider).map_err(std::io::Error::other)?;
+
+        std::fs::create_dir_all(&self.directory)?;
+
+        // Write to a temporary file first so that a partially written entry is never loaded.
+        let path = self.path(mesh, constructor);
+        let temporary_path = path.with_extension("tmp");
+        std::fs::write(&temporary_path, serialized)?;
+        std::fs::rename(temporary_path, path)
+    }
+
+    /// Removes all cached colliders from the [`directory`](Self::directory).
+    pub fn clear(&self) -> std::io::Result<()> {
+        let entries = match std::fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(Self::EXTENSION))
+            {
+                std::fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the path of the cache entry for the given mesh and constructor.
+    fn path(&self, mesh: &Mesh, constructor: &ColliderConstructor) -> std::path::PathBuf {
+        let key = Self::key(mesh, constructor);
+        self.directory
+            .join(format!("{key:016x}.{}", Self::EXTENSION))
+    }
+
+    /// Computes a key from the contents of the mesh used for collider generation,
+    /// the constructor and its parameters, and the version and precision of Avian.
+    fn key(mesh: &Mesh, constructor: &ColliderConstructor) -> u64 {
+        use bevy::mesh::{Indices, VertexAttributeValues};
+
+        let mut hasher = StableHasher::default();
+
+        hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.write(core::any::type_name::<crate::math::Real>().as_bytes());
+
+        // The `Debug` representation includes all parameters of the constructor.
+        hasher.write(format!("{constructor:?}").as_bytes());
+
+        if let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            match positions {
+                VertexAttributeValues::Float32(values) => {
+                    values.iter().for_each(|v| hasher.write(&v.to_le_bytes()));
+                }
+                VertexAttributeValues::Float32x3(values) => values
+                    .iter()
+                    .flatten()
+                    .for_each(|v| hasher.write(&v.to_le_bytes())),
+                other => hasher.write(other.get_bytes()),
+            }
+        }
+
+        // Separate the positions from the indices.
+        hasher.write(&[0xff]);
+
+        match mesh.indices() {
+            Some(Indices::U16(indices)) => {
+                indices.iter().for_each(|i| hasher.write(&i.to_le_bytes()));
+            }
+            Some(Indices::U32(indices)) => {
+                indices.iter().for_each(|i| hasher.write(&i.to_le_bytes()));
+            }
+            None => {}
+        }
+
+        hasher.finish()
+    }
+}
+
+/// A 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hasher.
+///
+/// Unlike the hashers in the standard library, the output is stable across runs and Rust versions,
+/// which is required for the keys of the [`PersistentColliderCache`].
+#[cfg(feature = "persistent-collider-cache")]
+struct StableHasher(u64);
+
+#[cfg(feature = "persistent-collider-cache")]
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+#[cfg(feature = "persistent-collider-cache")]
+impl core::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "persistent-collider-cache"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persistent_cache_invalidates_on_changes() {
+        let directory = std::env::temp_dir().join(format!(
+            "avian_persistent_collider_cache_test_{}",
+            std::process::id()
+        ));
+        let cache = PersistentColliderCache::new(&directory);
+
+        let mesh = Mesh::from(Cuboid::new(1.0, 2.0, 3.0));
+        let constructor = ColliderConstructor::ConvexHullFromMesh;
+
+        assert!(cache.get(&mesh, &constructor).is_none());
+
+        let collider = cache.get_or_insert(&mesh, &constructor).unwrap();
+        let cached = cache.get(&mesh, &constructor).unwrap();
+        assert_eq!(
+            cached.shape_scaled().compute_local_aabb(),
+            collider.shape_scaled().compute_local_aabb()
+        );
+
+        // Changing either the constructor or the mesh should invalidate the entry.
+        assert!(
+            cache
+                .get(&mesh, &ColliderConstructor::TrimeshFromMesh)
+                .is_none()
+        );
+        assert!(
+            cache
+                .get(&Mesh::from(Cuboid::new(1.0, 2.0, 4.0)), &constructor)
+                .is_none()
+        );
+
+        cache.clear().unwrap();
+        assert!(cache.get(&mesh, &constructor).is_none());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
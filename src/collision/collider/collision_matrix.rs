@@ -0,0 +1,406 @@
+use bevy::prelude::*;
+
+use super::{CollisionLayers, LayerBits, LayerMask};
+
+/// The number of layers in a [`CollisionMatrix`], matching the number of bits in [`LayerBits`].
+const LAYER_COUNT: usize = LayerBits::BITS as usize;
+
+/// A global layer-vs-layer matrix that determines which [collision layers](CollisionLayers)
+/// can interact with each other, similar to the physics layer collision matrix in Unity.
+///
+/// The matrix is applied on top of the [`CollisionLayers`] of each collider:
+/// two colliders can interact only if their [`CollisionLayers`] interact *and* the matrix
+/// allows interaction between one of the memberships of the first collider and one of the
+/// memberships of the second collider. In other words, the filters of each collider are derived
+/// from its memberships using the matrix, and further restricted by its own [`CollisionLayers::filters`].
+///
+/// This means that it is typically enough to only set the memberships of colliders,
+/// and to leave the filters at [`LayerMask::ALL`]. By default, every layer interacts with every layer.
+///
+/// The matrix is honored by the broad phase and narrow phase. Changing the resource re-evaluates
+/// existing contact pairs, removing pairs that can no longer interact and finding new pairs
+/// that can now interact. Custom broad phase implementations should also check
+/// [`CollisionMatrix::interacts`] when creating contact pairs.
+///
+/// [Spatial queries](crate::spatial_query) use the [`SpatialQueryFilter`] given to them,
+/// which can be derived from the matrix using [`SpatialQueryFilter::from_collision_matrix`].
+///
+/// [`SpatialQueryFilter`]: crate::spatial_query::SpatialQueryFilter
+/// [`SpatialQueryFilter::from_collision_matrix`]: crate::spatial_query::SpatialQueryFilter::from_collision_matrix
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// #[derive(PhysicsLayer, Clone, Copy, Debug, Default)]
+/// enum GameLayer {
+///     #[default]
+///     Default,
+///     Player,
+///     Enemy,
+///     Projectile,
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Players don't collide with each other, and projectiles don't collide with each other.
+///     commands.insert_resource(
+///         CollisionMatrix::default()
+///             .with_interaction(GameLayer::Player, GameLayer::Player, false)
+///             .with_interaction(GameLayer::Projectile, GameLayer::Projectile, false),
+///     );
+///
+///     // Only the memberships need to be set for colliders.
+///     commands.spawn((
+///         RigidBody::Dynamic,
+#[cfg_attr(feature = "2d", doc = "        Collider::circle(0.5),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::sphere(0.5),")]
+///         CollisionLayers::new(GameLayer::Player, LayerMask::ALL),
+///     ));
+/// }
+/// ```
+///
+/// # Loading From an Asset
+///
+/// With the `collision-matrix-asset` feature, the matrix can also be loaded
+/// from a RON asset with the `.collision_matrix.ron` extension using the `CollisionMatrixAssetPlugin`.
+/// The asset lists the pairs of layer indices that should *not* interact:
+///
+/// ```ron
+/// (
+///     ignored_pairs: [
+///         (1, 1), // Player vs. Player
+///         (3, 3), // Projectile vs. Projectile
+///     ],
+/// )
+/// ```
+#[derive(Resource, Reflect, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "collision-matrix-asset", derive(Asset))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct CollisionMatrix {
+    /// For each layer, the mask of layers that it can interact with.
+    rows: [LayerMask; LAYER_COUNT],
+    /// Whether every layer interacts with every layer, allowing interaction checks to skip the rows.
+    interacts_all: bool,
+}
+
+impl Default for CollisionMatrix {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl CollisionMatrix {
+    /// A matrix in which every layer interacts with every layer.
+    pub const ALL: Self = Self {
+        rows: [LayerMask::ALL; LAYER_COUNT],
+        interacts_all: true,
+    };
+
+    /// A matrix in which no layers interact with each other.
+    pub const NONE: Self = Self {
+        rows: [LayerMask::NONE; LAYER_COUNT],
+        interacts_all: false,
+    };
+
+    /// Sets whether the given layers can interact with each other.
+    ///
+    /// Interaction is symmetric: every layer in `layers1` is set to interact
+    /// (or not interact) with every layer in `layers2`, and vice versa.
+    pub fn set_interaction(
+        &mut self,
+        layers1: impl Into<LayerMask>,
+        layers2: impl Into<LayerMask>,
+        interacts: bool,
+    ) {
+        let layers1: LayerMask = layers1.into();
+        let layers2: LayerMask = layers2.into();
+
+        for (layers, other) in [(layers1, layers2), (layers2, layers1)] {
+            for index in layer_indices(layers) {
+                if interacts {
+                    self.rows[index] |= other;
+                } else {
+                    self.rows[index] &= !other;
+                }
+            }
+        }
+
+        self.interacts_all = self.rows.iter().all(|row| *row == LayerMask::ALL);
+    }
+
+    /// Sets whether the given layers can interact with each other, and returns the matrix.
+    ///
+    /// See [`CollisionMatrix::set_interaction`].
+    pub fn with_interaction(
+        mut self,
+        layers1: impl Into<LayerMask>,
+        layers2: impl Into<LayerMask>,
+        interacts: bool,
+    ) -> Self {
+        self.set_interaction(layers1, layers2, interacts);
+        self
+    }
+
+    /// Returns the layers that colliders with the given `memberships` can interact with.
+    pub fn filters(&self, memberships: impl Into<LayerMask>) -> LayerMask {
+        layer_indices(memberships.into())
+            .fold(LayerMask::NONE, |filters, index| filters | self.rows[index])
+    }
+
+    /// Returns [`CollisionLayers`] with the given `memberships`,
+    /// and filters derived from the matrix.
+    pub fn layers(&self, memberships: impl Into<LayerMask>) -> CollisionLayers {
+        let memberships: LayerMask = memberships.into();
+        CollisionLayers::new(memberships, self.filters(memberships))
+    }
+
+    /// Returns `true` if colliders with the memberships `memberships1`
+    /// can interact with colliders with the memberships `memberships2`.
+    #[inline]
+    pub fn interacts(&self, memberships1: LayerMask, memberships2: LayerMask) -> bool {
+        if self.interacts_all {
+            return memberships1 != LayerMask::NONE && memberships2 != LayerMask::NONE;
+        }
+        layer_indices(memberships1).any(|index| (self.rows[index].0 & memberships2.0) != 0)
+    }
+
+    /// Returns `true` if colliders with the given [`CollisionLayers`] can interact,
+    /// taking both the matrix and the filters of the colliders into account.
+    #[inline]
+    pub fn layers_interact(&self, layers1: CollisionLayers, layers2: CollisionLayers) -> bool {
+        // If the layers interact, both memberships are non-empty, so the default matrix can be skipped.
+        layers1.interacts_with(layers2)
+            && (self.interacts_all || self.interacts(layers1.memberships, layers2.memberships))
+    }
+
+    /// Returns the pairs of layer indices that do not interact with each other,
+    /// with the first index of each pair being less than or equal to the second.
+    pub fn ignored_pairs(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (0..LAYER_COUNT).flat_map(move |i| {
+            let ignored = !self.rows[i] & LayerMask(LayerBits::MAX << i);
+            layer_indices(ignored).map(move |j| (i as u32, j as u32))
+        })
+    }
+}
+
+/// Returns an iterator over the indices of the set bits in the given mask.
+#[inline]
+fn layer_indices(mask: LayerMask) -> impl Iterator<Item = usize> {
+    let mut bits = mask.0;
+    core::iter::from_fn(move || {
+        if bits == 0 {
+            return None;
+        }
+        let index = bits.trailing_zeros() as usize;
+        bits &= bits - 1;
+        Some(index)
+    })
+}
+
+/// The serialized representation of a [`CollisionMatrix`], listing the pairs of layers that do not interact.
+#[cfg(feature = "serialize")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "CollisionMatrix")]
+struct CollisionMatrixRepr {
+    #[serde(default)]
+    ignored_pairs: Vec<(u32, u32)>,
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for CollisionMatrix {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CollisionMatrixRepr {
+            ignored_pairs: self.ignored_pairs().collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for CollisionMatrix {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CollisionMatrixRepr::deserialize(deserializer)?;
+        let mut matrix = CollisionMatrix::ALL;
+
+        for (layer1, layer2) in repr.ignored_pairs {
+            if layer1 >= LayerBits::BITS || layer2 >= LayerBits::BITS {
+                return Err(serde::de::Error::custom(format!(
+                    "layer pair ({layer1}, {layer2}) is out of range, there are only {} layers",
+                    LayerBits::BITS
+                )));
+            }
+            matrix.set_interaction(LayerMask(1 << layer1), LayerMask(1 << layer2), false);
+        }
+
+        Ok(matrix)
+    }
+}
+
+/// A plugin for loading a [`CollisionMatrix`] from a RON asset.
+///
+/// Assets with the `.collision_matrix.ron` extension are loaded as [`CollisionMatrix`] assets.
+/// The asset referenced by the [`CollisionMatrixHandle`] resource is copied to the [`CollisionMatrix`]
+/// resource whenever it is loaded or modified, so the matrix also supports hot reloading.
+///
+/// # Example
+///
+/// ```no_run
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default(),
+///             CollisionMatrixAssetPlugin,
+///         ))
+///         .add_systems(Startup, load_collision_matrix)
+///         .run();
+/// }
+///
+/// fn load_collision_matrix(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let handle = asset_server.load("physics/layers.collision_matrix.ron");
+///     commands.insert_resource(CollisionMatrixHandle(handle));
+/// }
+/// ```
+#[cfg(feature = "collision-matrix-asset")]
+pub struct CollisionMatrixAssetPlugin;
+
+#[cfg(feature = "collision-matrix-asset")]
+impl Plugin for CollisionMatrixAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollisionMatrix>()
+            .init_asset::<CollisionMatrix>()
+            .init_asset_loader::<CollisionMatrixLoader>()
+            .add_systems(
+                PreUpdate,
+                update_collision_matrix_from_asset.run_if(resource_exists::<CollisionMatrixHandle>),
+            );
+    }
+}
+
+/// A resource holding the handle of the [`CollisionMatrix`] asset
+/// that is used for the [`CollisionMatrix`] resource.
+///
+/// See [`CollisionMatrixAssetPlugin`].
+#[cfg(feature = "collision-matrix-asset")]
+#[derive(Resource, Clone, Debug, Default, Deref, DerefMut, PartialEq, Eq)]
+pub struct CollisionMatrixHandle(pub Handle<CollisionMatrix>);
+
+/// An [`AssetLoader`](bevy::asset::AssetLoader) for loading a [`CollisionMatrix`] from RON.
+#[cfg(feature = "collision-matrix-asset")]
+#[derive(Default, TypePath)]
+pub struct CollisionMatrixLoader;
+
+/// An error that can occur when loading a [`CollisionMatrix`] asset.
+#[cfg(feature = "collision-matrix-asset")]
+#[derive(Debug, thiserror::Error)]
+pub enum CollisionMatrixLoaderError {
+    /// The asset could not be read.
+    #[error("could not read collision matrix: {0}")]
+    Io(#[from] std::io::Error),
+    /// The asset is not a valid RON collision matrix.
+    #[error("could not parse collision matrix: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[cfg(feature = "collision-matrix-asset")]
+impl bevy::asset::AssetLoader for CollisionMatrixLoader {
+    type Asset = CollisionMatrix;
+    type Settings = ();
+    type Error = CollisionMatrixLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn bevy::asset::io::Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut bevy::asset::LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["collision_matrix.ron"]
+    }
+}
+
+/// Copies the [`CollisionMatrix`] asset referenced by the [`CollisionMatrixHandle`]
+/// to the [`CollisionMatrix`] resource when it is loaded or modified.
+#[cfg(feature = "collision-matrix-asset")]
+fn update_collision_matrix_from_asset(
+    mut asset_events: MessageReader<AssetEvent<CollisionMatrix>>,
+    handle: Res<CollisionMatrixHandle>,
+    assets: Res<Assets<CollisionMatrix>>,
+    mut collision_matrix: ResMut<CollisionMatrix>,
+) {
+    let handle_changed = handle.is_changed();
+    let asset_changed = asset_events.read().any(|event| match event {
+        AssetEvent::Added { id } | AssetEvent::Modified { id } => *id == handle.id(),
+        _ => false,
+    });
+
+    if (handle_changed || asset_changed)
+        && let Some(matrix) = assets.get(&handle.0)
+    {
+        collision_matrix.set_if_neq(matrix.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(PhysicsLayer, Clone, Copy, Default)]
+    enum GameLayer {
+        #[default]
+        Default,
+        Player,
+        Enemy,
+        Projectile,
+    }
+
+    #[test]
+    fn interactions() {
+        let matrix = CollisionMatrix::default()
+            .with_interaction(GameLayer::Player, GameLayer::Player, false)
+            .with_interaction(
+                GameLayer::Projectile,
+                [GameLayer::Projectile, GameLayer::Default],
+                false,
+            );
+
+        let player = LayerMask::from(GameLayer::Player);
+        let enemy = LayerMask::from(GameLayer::Enemy);
+        let projectile = LayerMask::from(GameLayer::Projectile);
+
+        assert!(!matrix.interacts(player, player));
+        assert!(matrix.interacts(player, enemy));
+        assert!(matrix.interacts(enemy, player));
+        assert!(!matrix.interacts(projectile, LayerMask::DEFAULT));
+        assert!(!matrix.interacts(LayerMask::DEFAULT, projectile));
+        assert!(matrix.interacts(projectile | player, projectile));
+
+        assert_eq!(
+            matrix.layers(GameLayer::Player).filters,
+            !LayerMask::from(GameLayer::Player)
+        );
+        assert_eq!(
+            matrix.ignored_pairs().collect::<Vec<_>>(),
+            vec![(0, 3), (1, 1), (3, 3)]
+        );
+
+        // Re-enabling every interaction restores the default matrix.
+        let matrix = matrix.with_interaction(LayerMask::ALL, LayerMask::ALL, true);
+        assert_eq!(matrix, CollisionMatrix::default());
+        assert!(matrix.interacts(player, projectile));
+        assert!(!matrix.interacts(LayerMask::NONE, player));
+    }
+}
@@ -242,6 +242,56 @@ impl ColliderConstructorHierarchy {
     }
 }
 
+/// A marker component that makes the [`ColliderConstructor`] or [`ColliderConstructorHierarchy`]
+/// on the same entity compute its colliders asynchronously on the [`AsyncComputeTaskPool`].
+///
+/// This is useful for expensive colliders like convex decompositions or voxelized meshes,
+/// which could otherwise stall frames when levels are streamed in.
+///
+/// Each [`Collider`] is inserted once it has been computed. The [`ColliderConstructorReady`] event
+/// is triggered when the collider of a [`ColliderConstructor`] is inserted, and the
+/// [`ColliderConstructorHierarchyReady`] event is triggered when all colliders
+/// of a [`ColliderConstructorHierarchy`] have been inserted.
+///
+/// If the entity is a [`RigidBody`], it is kept inactive using [`RigidBodyDisabled`]
+/// until all of its colliders have been inserted, so that it does not fall through the world
+/// in the meantime. If the entity already had [`RigidBodyDisabled`], it is left disabled.
+///
+/// Colliders found in the cache of the `ColliderCachePlugin` are inserted immediately.
+/// Entities that use the same constructor for the same mesh or image share a single task,
+/// so the collider is only computed once.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         WorldAssetRoot(asset_server.load("my_model.gltf#Scene0")),
+#[cfg_attr(
+    feature = "2d",
+    doc = "        ColliderConstructorHierarchy::new(ColliderConstructor::Circle { radius: 2.0 }),"
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "        ColliderConstructorHierarchy::new(ColliderConstructor::ConvexDecompositionFromMesh),"
+)]
+///         AsyncColliderConstruction,
+///         RigidBody::Dynamic,
+///     ));
+/// }
+/// ```
+///
+/// [`AsyncComputeTaskPool`]: bevy::tasks::AsyncComputeTaskPool
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct AsyncColliderConstruction;
+
 /// Configuration for a specific collider generated from a scene using [`ColliderConstructorHierarchy`].
 #[derive(Clone, Debug, Default, PartialEq, Reflect)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -264,6 +314,7 @@ pub struct ColliderConstructorHierarchyConfig {
 
 /// A component that will automatically generate a [`Collider`] at runtime using [`Collider::try_from_constructor`].
 /// Enabling the `collider-from-mesh` feature activates support for computing the shape dynamically from the mesh attached to the same entity.
+/// Similarly, in 2D, enabling the `collider-from-image` feature activates support for computing the shape from the image of the `Sprite` attached to the same entity.
 ///
 /// Since [`Collider`] is not [`Reflect`], you can use this type to statically specify a collider's shape instead.
 ///
@@ -276,7 +327,8 @@ pub struct ColliderConstructorHierarchyConfig {
 /// # Panics
 ///
 /// The system handling the generation of colliders will panic if the specified [`ColliderConstructor`]
-/// requires a mesh, but the entity does not have a `Handle<Mesh>` component.
+/// requires a mesh, but the entity does not have a `Handle<Mesh>` component, or if it requires an image,
+/// but the entity does not have a `Sprite` component.
 ///
 /// # Example
 ///
@@ -478,6 +530,21 @@ pub enum ColliderConstructor {
         voxel_size: f32,
         fill_mode: FillMode,
     },
+    /// Constructs a collider with [`Collider::polyline_from_image`] using the image of the entity's `Sprite`.
+    #[cfg(feature = "collider-from-image")]
+    PolylineFromImage {
+        alpha_threshold: f32,
+        tolerance: f32,
+    },
+    /// Constructs a collider with [`Collider::convex_decomposition_from_image`] using the image of the entity's `Sprite`.
+    #[cfg(feature = "collider-from-image")]
+    ConvexDecompositionFromImage {
+        alpha_threshold: f32,
+        tolerance: f32,
+    },
+    /// Constructs a collider with [`Collider::voxels_from_image`] using the image of the entity's `Sprite`.
+    #[cfg(feature = "collider-from-image")]
+    VoxelsFromImage { alpha_threshold: f32 },
     /// Constructs a collider with [`Collider::compound`].
     Compound(Vec<(Position, Rotation, ColliderConstructor)>),
 }
@@ -497,6 +564,17 @@ impl ColliderConstructor {
         )
     }
 
+    /// Returns `true` if the collider type requires an image to be generated.
+    #[cfg(feature = "collider-from-image")]
+    pub fn requires_image(&self) -> bool {
+        matches!(
+            self,
+            Self::PolylineFromImage { .. }
+                | Self::ConvexDecompositionFromImage { .. }
+                | Self::VoxelsFromImage { .. }
+        )
+    }
+
     /// Construct a [`ColliderConstructor::Compound`] from arbitrary [`Position`] and [`Rotation`] representations.
     pub fn compound<P, R>(shapes: Vec<(P, R, ColliderConstructor)>) -> Self
     where
@@ -582,6 +660,35 @@ mod tests {
         assert!(app.query_err::<&ColliderConstructor>(entity));
     }
 
+    #[cfg(feature = "collider-from-image")]
+    #[test]
+    #[should_panic]
+    fn collider_constructor_requires_sprite_on_image() {
+        let mut app = create_test_app();
+
+        app.world_mut().spawn(IMAGE_COLLIDER.clone());
+
+        app.update();
+    }
+
+    #[cfg(feature = "collider-from-image")]
+    #[test]
+    fn collider_constructor_converts_image_on_sprite() {
+        let mut app = create_test_app();
+
+        let image = app.add_image();
+        let entity = app
+            .world_mut()
+            .spawn((IMAGE_COLLIDER.clone(), Sprite::from_image(image)))
+            .id();
+
+        app.update();
+
+        assert!(app.query_ok::<&Collider>(entity));
+        assert!(app.query_ok::<&Sprite>(entity));
+        assert!(app.query_err::<&ColliderConstructor>(entity));
+    }
+
     #[test]
     fn collider_constructor_hierarchy_does_nothing_on_self_with_primitive() {
         let mut app = create_test_app();
@@ -832,6 +939,12 @@ mod tests {
     #[cfg(feature = "collider-from-mesh")]
     const COMPUTED_COLLIDER: ColliderConstructor = ColliderConstructor::TrimeshFromMesh;
 
+    #[cfg(feature = "collider-from-image")]
+    const IMAGE_COLLIDER: ColliderConstructor = ColliderConstructor::PolylineFromImage {
+        alpha_threshold: 0.5,
+        tolerance: 1.0,
+    };
+
     fn create_test_app() -> App {
         let mut app = App::new();
         app.add_plugins((
@@ -840,6 +953,8 @@ mod tests {
             #[cfg(feature = "bevy_scene")]
             WorldSerializationPlugin,
             MeshPlugin,
+            #[cfg(feature = "collider-from-image")]
+            ImagePlugin::default(),
             PhysicsPlugins::default(),
         ));
 
@@ -874,6 +989,9 @@ mod tests {
 
         #[cfg(feature = "collider-from-mesh")]
         fn add_mesh(&mut self) -> Handle<Mesh>;
+
+        #[cfg(feature = "collider-from-image")]
+        fn add_image(&mut self) -> Handle<Image>;
     }
 
     impl AppExt for App {
@@ -890,5 +1008,13 @@ mod tests {
                 .unwrap()
                 .add(Mesh::from(Cuboid::default()))
         }
+
+        #[cfg(feature = "collider-from-image")]
+        fn add_image(&mut self) -> Handle<Image> {
+            self.world_mut()
+                .get_resource_mut::<Assets<Image>>()
+                .unwrap()
+                .add(Image::default())
+        }
     }
 }
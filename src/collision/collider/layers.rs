@@ -2,26 +2,47 @@ use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign,
 
 use bevy::prelude::*;
 
+/// The integer type used for the bits of a [`LayerMask`], determining the maximum number of layers.
+///
+/// By default, this is `u32`, supporting 32 layers. The `layers-64` and `layers-128` features
+/// change it to `u64` and `u128`, supporting 64 and 128 layers respectively.
+#[cfg(not(any(feature = "layers-64", feature = "layers-128")))]
+pub type LayerBits = u32;
+
+/// The integer type used for the bits of a [`LayerMask`], determining the maximum number of layers.
+///
+/// By default, this is `u32`, supporting 32 layers. The `layers-64` and `layers-128` features
+/// change it to `u64` and `u128`, supporting 64 and 128 layers respectively.
+#[cfg(all(feature = "layers-64", not(feature = "layers-128")))]
+pub type LayerBits = u64;
+
+/// The integer type used for the bits of a [`LayerMask`], determining the maximum number of layers.
+///
+/// By default, this is `u32`, supporting 32 layers. The `layers-64` and `layers-128` features
+/// change it to `u64` and `u128`, supporting 64 and 128 layers respectively.
+#[cfg(feature = "layers-128")]
+pub type LayerBits = u128;
+
 /// A layer used for determining which entities should interact with each other.
 /// Physics layers are used heavily by [`CollisionLayers`].
 ///
 /// This trait can be derived for enums with `#[derive(PhysicsLayer)]`.
 pub trait PhysicsLayer: Sized + Default {
     /// Converts the layer to a bitmask.
-    fn to_bits(&self) -> u32;
+    fn to_bits(&self) -> LayerBits;
     /// Creates a layer bitmask with all bits set to 1.
-    fn all_bits() -> u32;
+    fn all_bits() -> LayerBits;
 }
 
 impl<'a, L: PhysicsLayer> PhysicsLayer for &'a L
 where
     &'a L: Default,
 {
-    fn to_bits(&self) -> u32 {
+    fn to_bits(&self) -> LayerBits {
         L::to_bits(self)
     }
 
-    fn all_bits() -> u32 {
+    fn all_bits() -> LayerBits {
         L::all_bits()
     }
 }
@@ -31,6 +52,9 @@ where
 /// A [`LayerMask`] can be constructed from bits directly, or from types implementing [`PhysicsLayer`].
 /// The first bit `0b0001` is reserved for the default layer, which all entities belong to by default.
 ///
+/// The bits are stored as [`LayerBits`], which supports 32 layers by default.
+/// The `layers-64` and `layers-128` features can be used to increase the number of layers to 64 or 128.
+///
 /// ```
 #[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
 #[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
@@ -63,7 +87,7 @@ where
 /// let mask2 = LayerMask(0b0010);
 /// assert_eq!(mask1 | mask2, LayerMask(0b0011));
 ///
-/// // You can also add layers from `u32` bitmasks and compare against them directly.
+/// // You can also add layers from `LayerBits` bitmasks and compare against them directly.
 /// assert_eq!(mask1 | 0b0010, 0b0011);
 /// ```
 ///
@@ -76,17 +100,17 @@ where
 /// pub const FIRST_LAYER: LayerMask = LayerMask(1 << 0);
 /// pub const LAST_LAYER: LayerMask = LayerMask(1 << 31);
 ///
-/// // Bitwise operations for `LayerMask` unfortunately can't be const, so we need to access the `u32` values.
+/// // Bitwise operations for `LayerMask` unfortunately can't be const, so we need to access the `LayerBits` values.
 /// pub const COMBINED: LayerMask = LayerMask(FIRST_LAYER.0 | LAST_LAYER.0);
 /// ```
 #[derive(Reflect, Clone, Copy, Debug, Deref, DerefMut, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 #[reflect(Debug, PartialEq)]
-pub struct LayerMask(pub u32);
+pub struct LayerMask(pub LayerBits);
 
-impl From<u32> for LayerMask {
-    fn from(layer: u32) -> Self {
+impl From<LayerBits> for LayerMask {
+    fn from(layer: LayerBits) -> Self {
         Self(layer)
     }
 }
@@ -114,7 +138,7 @@ impl<L: Into<LayerMask>, const N: usize> From<[L; N]> for LayerMask {
 
 impl LayerMask {
     /// Contains all layers.
-    pub const ALL: Self = Self(0xffff_ffff);
+    pub const ALL: Self = Self(LayerBits::MAX);
     /// Contains no layers.
     pub const NONE: Self = Self(0);
     /// Contains the default layer.
@@ -314,9 +338,9 @@ impl Not for LayerMask {
 /// # use bevy::prelude::Commands;
 /// #
 /// // `1 << n` is bitshifting: the first layer shifted by `n` layers.
-/// pub const FIRST_LAYER: u32 = 1 << 0; // Note: this is the default layer.
-/// pub const SECOND_LAYER: u32 = 1 << 1;
-/// pub const LAST_LAYER: u32 = 1 << 31;
+/// pub const FIRST_LAYER: LayerBits = 1 << 0; // Note: this is the default layer.
+/// pub const SECOND_LAYER: LayerBits = 1 << 1;
+/// pub const LAST_LAYER: LayerBits = 1 << 31;
 ///
 /// fn spawn(mut commands: Commands) {
 ///     // This collider belongs to the first two layers and can interact with the last layer.
@@ -409,10 +433,11 @@ impl CollisionLayers {
 
     /// Creates a new [`CollisionLayers`] configuration using bits.
     ///
-    /// There is one bit per group and mask, so there are a total of 32 layers.
+    /// There is one bit per group and mask, so there are a total of 32 layers by default,
+    /// or 64 and 128 layers with the `layers-64` and `layers-128` features.
     /// For example, if an entity is a part of the layers `[0, 1, 3]` and can interact with the layers `[1, 2]`,
     /// the memberships in bits would be `0b01011` while the filters would be `0b00110`.
-    pub const fn from_bits(memberships: u32, filters: u32) -> Self {
+    pub const fn from_bits(memberships: LayerBits, filters: LayerBits) -> Self {
         Self {
             memberships: LayerMask(memberships),
             filters: LayerMask(filters),
@@ -421,9 +446,9 @@ impl CollisionLayers {
 
     /// Returns true if an entity with this [`CollisionLayers`] configuration
     /// can interact with an entity with the `other` [`CollisionLayers`] configuration.
-    pub fn interacts_with(self, other: Self) -> bool {
-        (self.memberships & other.filters) != LayerMask::NONE
-            && (other.memberships & self.filters) != LayerMask::NONE
+    #[inline]
+    pub const fn interacts_with(self, other: Self) -> bool {
+        (self.memberships.0 & other.filters.0) != 0 && (other.memberships.0 & self.filters.0) != 0
     }
 }
 
@@ -469,4 +494,71 @@ mod tests {
         );
         assert!(!with_bitmask.filters.has_all(GameLayer::Enemy));
     }
+
+    #[test]
+    fn last_layer() {
+        let last_layer = LayerMask(1 << (LayerBits::BITS - 1));
+        let layers = CollisionLayers::new(last_layer, last_layer);
+
+        assert!(layers.interacts_with(layers));
+        assert!(!layers.interacts_with(CollisionLayers::DEFAULT));
+        assert!(LayerMask::ALL.has_all(last_layer));
+        assert_eq!(!LayerMask::NONE, LayerMask::ALL);
+    }
+
+    #[cfg(any(feature = "layers-64", feature = "layers-128"))]
+    #[test]
+    fn more_than_32_layers() {
+        #[derive(PhysicsLayer, Default)]
+        enum ManyLayers {
+            #[default]
+            L0,
+            L1,
+            L2,
+            L3,
+            L4,
+            L5,
+            L6,
+            L7,
+            L8,
+            L9,
+            L10,
+            L11,
+            L12,
+            L13,
+            L14,
+            L15,
+            L16,
+            L17,
+            L18,
+            L19,
+            L20,
+            L21,
+            L22,
+            L23,
+            L24,
+            L25,
+            L26,
+            L27,
+            L28,
+            L29,
+            L30,
+            L31,
+            L32,
+            L33,
+            L34,
+            L35,
+            L36,
+            L37,
+            L38,
+            L39,
+        }
+
+        assert_eq!(ManyLayers::L39.to_bits(), 1 << 39);
+        assert_eq!(ManyLayers::all_bits(), (1 << 40) - 1);
+
+        let layers = CollisionLayers::new(ManyLayers::L39, [ManyLayers::L0, ManyLayers::L39]);
+        assert!(layers.interacts_with(layers));
+        assert!(!layers.interacts_with(CollisionLayers::new(ManyLayers::L38, LayerMask::ALL)));
+    }
 }
@@ -15,18 +15,31 @@ mod backend;
 
 pub use backend::{ColliderBackendPlugin, ColliderMarker};
 
-#[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
+#[cfg(all(
+    any(feature = "collider-from-mesh", feature = "collider-from-image"),
+    feature = "default-collider"
+))]
 mod cache;
-#[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
+#[cfg(all(
+    any(feature = "collider-from-mesh", feature = "collider-from-image"),
+    feature = "default-collider"
+))]
 pub use cache::ColliderCachePlugin;
+#[cfg(feature = "persistent-collider-cache")]
+pub use cache::PersistentColliderCache;
 pub mod collider_hierarchy;
 pub mod collider_transform;
 #[cfg(all(feature = "3d", any(feature = "parry-f32", feature = "parry-f64")))]
 pub mod trimesh_builder;
 
+mod collision_matrix;
 mod layers;
+pub use collision_matrix::*;
 pub use layers::*;
 
+#[cfg(all(test, any(feature = "parry-f32", feature = "parry-f64")))]
+mod tests;
+
 /// The default [`Collider`] that uses Parry.
 #[cfg(all(
     feature = "default-collider",
@@ -43,8 +56,9 @@ pub use parry::*;
 mod constructor;
 #[cfg(feature = "default-collider")]
 pub use constructor::{
-    ColliderConstructor, ColliderConstructorHierarchy, ColliderConstructorHierarchyConfig,
-    ColliderConstructorHierarchyReady, ColliderConstructorReady,
+    AsyncColliderConstruction, ColliderConstructor, ColliderConstructorHierarchy,
+    ColliderConstructorHierarchyConfig, ColliderConstructorHierarchyReady,
+    ColliderConstructorReady,
 };
 
 /// A trait for creating colliders from other types.
@@ -0,0 +1,306 @@
+//! Traces the outlines of the opaque parts of images for creating 2D colliders.
+
+use crate::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// A grid storing which pixels of an image are opaque, with the y-axis pointing up.
+pub(super) struct OpacityMask {
+    width: u32,
+    height: u32,
+    /// Whether each pixel is opaque, stored row by row starting from the bottom row.
+    opaque: Vec<bool>,
+}
+
+impl OpacityMask {
+    /// Computes which pixels of the image have an alpha value of at least `alpha_threshold`.
+    ///
+    /// Returns `None` if the pixel data of the image can't be read.
+    pub fn from_image(image: &Image, alpha_threshold: f32) -> Option<Self> {
+        let (width, height) = (image.width(), image.height());
+        let mut opaque = Vec::with_capacity(width as usize * height as usize);
+
+        // Image rows start from the top, so iterate them in reverse.
+        for y in (0..height).rev() {
+            for x in 0..width {
+                let color = image.get_color_at(x, y).ok()?;
+                opaque.push(color.alpha() >= alpha_threshold);
+            }
+        }
+
+        Some(Self {
+            width,
+            height,
+            opaque,
+        })
+    }
+
+    /// Returns `true` if the pixel at the given coordinates is opaque.
+    /// Pixels outside of the image are considered transparent.
+    fn is_opaque(&self, x: i32, y: i32) -> bool {
+        x >= 0
+            && y >= 0
+            && (x as u32) < self.width
+            && (y as u32) < self.height
+            && self.opaque[y as usize * self.width as usize + x as usize]
+    }
+
+    /// Returns the offset from pixel coordinates to local coordinates,
+    /// centering the image at the origin.
+    pub fn offset(&self) -> Vector {
+        -0.5 * Vector::new(self.width as f32, self.height as f32)
+    }
+
+    /// Returns the coordinates of all opaque pixels.
+    pub fn opaque_pixels(&self) -> Vec<IVec2> {
+        (0..self.height as i32)
+            .flat_map(|y| (0..self.width as i32).map(move |x| IVec2::new(x, y)))
+            .filter(|pixel| self.is_opaque(pixel.x, pixel.y))
+            .collect()
+    }
+
+    /// Traces the outlines of the opaque regions using marching squares, and simplifies them
+    /// using the Douglas-Peucker algorithm with the given `tolerance`.
+    ///
+    /// Returns the closed loops of the outlines. Outer boundaries are counterclockwise,
+    /// and the boundaries of holes are clockwise.
+    pub fn outline_loops(&self, tolerance: f32) -> Vec<Vec<Vector>> {
+        // The samples of the marching squares are at pixel centers. Outline vertices
+        // lie at the midpoints of cell edges, so they are stored in doubled coordinates.
+        let mut segments = Vec::new();
+        for y in -1..self.height as i32 {
+            for x in -1..self.width as i32 {
+                let case = self.is_opaque(x, y) as u8
+                    | (self.is_opaque(x + 1, y) as u8) << 1
+                    | (self.is_opaque(x + 1, y + 1) as u8) << 2
+                    | (self.is_opaque(x, y + 1) as u8) << 3;
+
+                let bottom = IVec2::new(2 * x + 1, 2 * y);
+                let right = IVec2::new(2 * x + 2, 2 * y + 1);
+                let top = IVec2::new(2 * x + 1, 2 * y + 2);
+                let left = IVec2::new(2 * x, 2 * y + 1);
+
+                // Segments are directed so that the opaque side is on the left.
+                // Diagonal corners of saddle cases are treated as disconnected.
+                match case {
+                    1 => segments.push((bottom, left)),
+                    2 => segments.push((right, bottom)),
+                    3 => segments.push((right, left)),
+                    4 => segments.push((top, right)),
+                    5 => segments.extend([(bottom, left), (top, right)]),
+                    6 => segments.push((top, bottom)),
+                    7 => segments.push((top, left)),
+                    8 => segments.push((left, top)),
+                    9 => segments.push((bottom, top)),
+                    10 => segments.extend([(right, bottom), (left, top)]),
+                    11 => segments.push((right, top)),
+                    12 => segments.push((left, right)),
+                    13 => segments.push((bottom, right)),
+                    14 => segments.push((left, bottom)),
+                    _ => {}
+                }
+            }
+        }
+
+        // Every vertex has exactly one outgoing segment, so the segments can be chained into loops.
+        let mut next: HashMap<IVec2, IVec2> = segments.iter().copied().collect();
+        let offset = self.offset() + Vector::splat(0.5);
+
+        // Start loops in the order the segments were found to keep the output deterministic.
+        let mut loops = Vec::new();
+        for (start, _) in segments {
+            let mut outline = Vec::new();
+            let mut current = start;
+            while let Some(end) = next.remove(&current) {
+                outline.push(current.as_vec2() * 0.5 + offset);
+                current = end;
+            }
+
+            let outline = simplify_loop(&outline, tolerance);
+            if outline.len() >= 3 {
+                loops.push(outline);
+            }
+        }
+
+        loops
+    }
+
+    /// Traces the outlines of the opaque regions like [`OpacityMask::outline_loops`].
+    ///
+    /// Returns the vertices and indices of a polyline made of the closed loops.
+    pub fn outlines(&self, tolerance: f32) -> (Vec<RVector>, Vec<[u32; 2]>) {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for outline in self.outline_loops(tolerance) {
+            let base = vertices.len() as u32;
+            let count = outline.len() as u32;
+            vertices.extend(outline.into_iter().map(|v| v.real()));
+            indices.extend((0..count).map(|i| [base + i, base + (i + 1) % count]));
+        }
+
+        (vertices, indices)
+    }
+}
+
+/// Simplifies a closed loop using the Douglas-Peucker algorithm.
+fn simplify_loop(points: &[Vector], tolerance: f32) -> Vec<Vector> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let farthest_from = |points: &[Vector], origin: Vector| {
+        (0..points.len())
+            .max_by(|&a, &b| {
+                origin
+                    .distance_squared(points[a])
+                    .total_cmp(&origin.distance_squared(points[b]))
+            })
+            .unwrap()
+    };
+
+    // Split the loop at two points that are far apart, and simplify both halves separately.
+    // The farthest points are always corners, so no unnecessary vertices are kept.
+    let start = farthest_from(points, points[0]);
+    let points: Vec<Vector> = points[start..]
+        .iter()
+        .chain(&points[..start])
+        .copied()
+        .collect();
+    let farthest = farthest_from(&points, points[0]);
+
+    let mut first_half = douglas_peucker(&points[..=farthest], tolerance);
+    let mut second_half = points[farthest..].to_vec();
+    second_half.push(points[0]);
+    let second_half = douglas_peucker(&second_half, tolerance);
+
+    // Both halves contain their endpoints, which are shared with the other half.
+    first_half.pop();
+    first_half.extend_from_slice(&second_half[..second_half.len() - 1]);
+    first_half
+}
+
+/// Simplifies an open polyline using the Douglas-Peucker algorithm, keeping its endpoints.
+fn douglas_peucker(points: &[Vector], tolerance: f32) -> Vec<Vector> {
+    let last = points.len() - 1;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut stack = vec![(0, last)];
+    while let Some((start, end)) = stack.pop() {
+        let (a, b) = (points[start], points[end]);
+        let mut max_distance = 0.0;
+        let mut max_index = start;
+
+        for (i, &point) in points.iter().enumerate().take(end).skip(start + 1) {
+            let distance = distance_to_segment(point, a, b);
+            if distance > max_distance {
+                max_distance = distance;
+                max_index = i;
+            }
+        }
+
+        if max_distance > tolerance {
+            keep[max_index] = true;
+            stack.push((start, max_index));
+            stack.push((max_index, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&point, keep)| keep.then_some(point))
+        .collect()
+}
+
+/// Computes the distance from a point to the line segment between `a` and `b`.
+fn distance_to_segment(point: Vector, a: Vector, b: Vector) -> f32 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared <= f32::EPSILON {
+        return point.distance(a);
+    }
+    let t = ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    point.distance(a + t * ab)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Real;
+
+    /// Creates an image from rows of `#` (opaque) and `.` (transparent) pixels, starting from the top row.
+    fn image_from_rows(rows: &[&str]) -> Image {
+        let width = rows[0].len() as u32;
+        let height = rows.len() as u32;
+        let data = rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .flat_map(|c| [255, 255, 255, if c == '#' { 255 } else { 0 }])
+            .collect();
+
+        // The default image is a 1x1 RGBA image.
+        let mut image = Image::default();
+        image.texture_descriptor.size.width = width;
+        image.texture_descriptor.size.height = height;
+        image.data = Some(data);
+        image
+    }
+
+    #[test]
+    fn outlines_with_holes() {
+        let image = image_from_rows(&[
+            "......", //
+            ".####.", //
+            ".#..#.", //
+            ".#..#.", //
+            ".####.", //
+            "......", //
+        ]);
+        let mask = OpacityMask::from_image(&image, 0.5).unwrap();
+        let (vertices, indices) = mask.outlines(0.1);
+
+        // The outer boundary and the hole are simplified to octagons with cut corners.
+        assert_eq!(vertices.len(), 16);
+        assert_eq!(indices.len(), 16);
+
+        // The outer boundary is counterclockwise and the hole is clockwise.
+        let signed_area = |range: core::ops::Range<usize>| {
+            let points = &vertices[range];
+            (0..points.len())
+                .map(|i| points[i].perp_dot(points[(i + 1) % points.len()]))
+                .sum::<Real>()
+                / 2.0
+        };
+        let areas = [signed_area(0..8), signed_area(8..16)];
+        assert!(areas.iter().any(|&area| area > 0.0));
+        assert!(areas.iter().any(|&area| area < 0.0));
+
+        // Straight edges lie on pixel boundaries, and the image is centered at the origin.
+        let max_x = vertices.iter().map(|v| v.x).fold(Real::MIN, Real::max);
+        assert_eq!(max_x, 2.0);
+        let min_y = vertices.iter().map(|v| v.y).fold(Real::MAX, Real::min);
+        assert_eq!(min_y, -2.0);
+    }
+
+    #[test]
+    fn simplification_removes_small_details() {
+        // A rectangle with a one-pixel bump on the top edge.
+        let image = image_from_rows(&[
+            "...#......", //
+            "##########", //
+            "##########", //
+        ]);
+        let mask = OpacityMask::from_image(&image, 0.5).unwrap();
+
+        let (detailed, _) = mask.outlines(0.0);
+        let (simplified, _) = mask.outlines(1.0);
+        assert!(simplified.len() < detailed.len());
+        assert!(simplified.len() >= 3);
+
+        // Images without opaque pixels have no outlines.
+        let empty = OpacityMask::from_image(&image_from_rows(&["..", ".."]), 0.5).unwrap();
+        assert!(empty.outlines(0.0).0.is_empty());
+    }
+}
@@ -2,6 +2,10 @@
 
 pub mod contact_query;
 
+#[cfg(feature = "collider-from-image")]
+mod image_outline;
+#[cfg(feature = "collider-from-image")]
+mod polygon_decomposition;
 #[cfg(feature = "2d")]
 mod primitives2d;
 #[cfg(feature = "3d")]
@@ -1331,6 +1335,127 @@ impl Collider {
         })
     }
 
+    /// Creates a collider with a polyline shape outlining the opaque parts of an `Image`.
+    ///
+    /// Pixels with an alpha value of at least `alpha_threshold` are considered opaque.
+    /// The outlines are traced using marching squares, including the outlines of holes,
+    /// and simplified using the Douglas-Peucker algorithm, removing details smaller than
+    /// `tolerance` pixels.
+    ///
+    /// The collider is centered at the origin like a `Sprite` using the image,
+    /// with one pixel corresponding to one unit.
+    ///
+    /// Returns `None` if the image has no opaque pixels or its pixel data can't be read,
+    /// for example because it is compressed or not kept in the main world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use avian2d::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    ///     let image = Image::default();
+    ///     commands.spawn((
+    ///         Collider::polyline_from_image(&image, 0.5, 1.0).unwrap(),
+    ///         Sprite::from_image(images.add(image)),
+    ///     ));
+    /// }
+    /// ```
+    #[cfg(feature = "collider-from-image")]
+    pub fn polyline_from_image(
+        image: &Image,
+        alpha_threshold: f32,
+        tolerance: f32,
+    ) -> Option<Self> {
+        let (vertices, indices) =
+            image_outline::OpacityMask::from_image(image, alpha_threshold)?.outlines(tolerance);
+        (!vertices.is_empty()).then(|| Self::polyline(vertices, Some(indices)))
+    }
+
+    /// Creates a compound shape obtained from the convex decomposition of the opaque parts of an `Image`.
+    ///
+    /// The outlines of the opaque parts are computed like in [`Collider::polyline_from_image`],
+    /// and then triangulated and merged into convex polygons. Unlike [`Collider::convex_decomposition`],
+    /// the decomposition is exact, and holes in the opaque parts are preserved.
+    ///
+    /// Returns `None` if the image has no opaque pixels or its pixel data can't be read,
+    /// for example because it is compressed or not kept in the main world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use avian2d::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    ///     let image = Image::default();
+    ///     commands.spawn((
+    ///         Collider::convex_decomposition_from_image(&image, 0.5, 1.0).unwrap(),
+    ///         Sprite::from_image(images.add(image)),
+    ///     ));
+    /// }
+    /// ```
+    #[cfg(feature = "collider-from-image")]
+    pub fn convex_decomposition_from_image(
+        image: &Image,
+        alpha_threshold: f32,
+        tolerance: f32,
+    ) -> Option<Self> {
+        let loops = image_outline::OpacityMask::from_image(image, alpha_threshold)?
+            .outline_loops(tolerance);
+        let parts: Vec<_> = polygon_decomposition::convex_decomposition(&loops)
+            .into_iter()
+            .filter_map(|points| {
+                let points = points.into_iter().map(|point| point.real()).collect();
+                Self::convex_polyline(points)
+            })
+            .map(|part| (Position::default(), Rotation::default(), part))
+            .collect();
+        (!parts.is_empty()).then(|| Self::compound(parts))
+    }
+
+    /// Creates a voxel collider with one voxel for each opaque pixel of an `Image`.
+    ///
+    /// Pixels with an alpha value of at least `alpha_threshold` are considered opaque.
+    ///
+    /// The collider is centered at the origin like a `Sprite` using the image,
+    /// with one pixel corresponding to one unit.
+    ///
+    /// Returns `None` if the image has no opaque pixels or its pixel data can't be read,
+    /// for example because it is compressed or not kept in the main world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use avian2d::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    ///     let image = Image::default();
+    ///     commands.spawn((
+    ///         Collider::voxels_from_image(&image, 0.5).unwrap(),
+    ///         Sprite::from_image(images.add(image)),
+    ///     ));
+    /// }
+    /// ```
+    #[cfg(feature = "collider-from-image")]
+    pub fn voxels_from_image(image: &Image, alpha_threshold: f32) -> Option<Self> {
+        let mask = image_outline::OpacityMask::from_image(image, alpha_threshold)?;
+        let pixels = mask.opaque_pixels();
+        if pixels.is_empty() {
+            return None;
+        }
+
+        // Voxels start at the origin, so offset them to center the image.
+        let voxels = Self::voxels(Vector::ONE, &pixels);
+        Some(Self::compound(vec![(
+            Position(mask.offset().real()),
+            Rotation::default(),
+            voxels,
+        )]))
+    }
+
     /// Attempts to create a collider with the given [`ColliderConstructor`].
     /// By using this, you can serialize and deserialize the collider's creation method
     /// separately from the collider itself via the [`ColliderConstructor`] enum.
@@ -1342,12 +1467,19 @@ impl Collider {
 - Creating the collider from the given [`ColliderConstructor`] failed."
     )]
     #[cfg_attr(
-        not(feature = "collider-from-mesh"),
+        feature = "collider-from-image",
+        doc = "Returns `None` in the following cases:
+- The given [`ColliderConstructor`] requires an image, but none was provided.
+- Creating the collider from the given [`ColliderConstructor`] failed."
+    )]
+    #[cfg_attr(
+        not(any(feature = "collider-from-mesh", feature = "collider-from-image")),
         doc = "Returns `None` if creating the collider from the given [`ColliderConstructor`] failed."
     )]
     pub fn try_from_constructor(
         collider_constructor: ColliderConstructor,
         #[cfg(feature = "collider-from-mesh")] mesh: Option<&Mesh>,
+        #[cfg(feature = "collider-from-image")] image: Option<&Image>,
     ) -> Option<Self> {
         match collider_constructor {
             #[cfg(feature = "2d")]
@@ -1499,6 +1631,20 @@ impl Collider {
                 voxel_size,
                 fill_mode,
             } => Self::voxelized_trimesh_from_mesh(mesh?, voxel_size, fill_mode),
+            #[cfg(feature = "collider-from-image")]
+            ColliderConstructor::PolylineFromImage {
+                alpha_threshold,
+                tolerance,
+            } => Self::polyline_from_image(image?, alpha_threshold, tolerance),
+            #[cfg(feature = "collider-from-image")]
+            ColliderConstructor::ConvexDecompositionFromImage {
+                alpha_threshold,
+                tolerance,
+            } => Self::convex_decomposition_from_image(image?, alpha_threshold, tolerance),
+            #[cfg(feature = "collider-from-image")]
+            ColliderConstructor::VoxelsFromImage { alpha_threshold } => {
+                Self::voxels_from_image(image?, alpha_threshold)
+            }
             ColliderConstructor::Compound(compound_constructors) => {
                 let shapes: Vec<_> =
                     ColliderConstructor::flatten_compound_constructors(compound_constructors)
@@ -1508,6 +1654,8 @@ impl Collider {
                                 collider_constructor,
                                 #[cfg(feature = "collider-from-mesh")]
                                 mesh,
+                                #[cfg(feature = "collider-from-image")]
+                                image,
                             )
                             .map(|collider| (position, rotation, collider))
                         })
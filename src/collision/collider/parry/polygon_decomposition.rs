@@ -0,0 +1,385 @@
+//! Decomposes 2D polygons with holes into convex polygons.
+
+use crate::prelude::*;
+use bevy::platform::collections::HashMap;
+
+/// Decomposes the area bounded by the given closed loops into convex polygons.
+///
+/// Outer boundaries must be counterclockwise, and the boundaries of holes clockwise.
+/// Each outer boundary is triangulated together with its holes using ear clipping,
+/// and the triangles are then merged into convex polygons using the Hertel-Mehlhorn algorithm.
+///
+/// The returned polygons are counterclockwise.
+pub(super) fn convex_decomposition(loops: &[Vec<Vector>]) -> Vec<Vec<Vector>> {
+    let (outers, holes): (Vec<&Vec<Vector>>, Vec<&Vec<Vector>>) =
+        loops.iter().partition(|points| signed_area(points) > 0.0);
+
+    // Assign each hole to the smallest outer boundary that contains it.
+    let mut holes_by_outer = vec![Vec::new(); outers.len()];
+    for hole in holes {
+        let parent = outers
+            .iter()
+            .enumerate()
+            .filter(|(_, outer)| contains_point(outer, hole[0]))
+            .min_by(|(_, a), (_, b)| signed_area(a).total_cmp(&signed_area(b)))
+            .map(|(i, _)| i);
+        if let Some(parent) = parent {
+            holes_by_outer[parent].push(hole);
+        }
+    }
+
+    outers
+        .into_iter()
+        .zip(holes_by_outer)
+        .flat_map(|(outer, holes)| {
+            let polygon = bridge_holes(outer, holes);
+            let triangles = triangulate(&polygon);
+            merge_convex(&polygon, triangles)
+        })
+        .collect()
+}
+
+/// Connects the holes to the outer boundary with bridge edges,
+/// producing a single loop that can be triangulated.
+fn bridge_holes(outer: &[Vector], mut holes: Vec<&Vec<Vector>>) -> Vec<Vector> {
+    let max_x = |points: &[Vector]| points.iter().map(|p| p.x).fold(f32::MIN, f32::max);
+
+    // Bridge the holes from right to left, so that the ray cast from each hole
+    // can only hit the outer boundary or holes that have already been bridged.
+    holes.sort_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+
+    let mut polygon = outer.to_vec();
+    for hole in holes {
+        let hole_index = (0..hole.len())
+            .max_by(|&a, &b| hole[a].x.total_cmp(&hole[b].x))
+            .unwrap();
+        let Some(bridge_index) = find_bridge_vertex(&polygon, hole[hole_index]) else {
+            continue;
+        };
+
+        // Walk around the hole starting and ending at the bridged vertex,
+        // and return along the bridge to the outer vertex.
+        let mut bridged = Vec::with_capacity(polygon.len() + hole.len() + 2);
+        bridged.extend_from_slice(&polygon[..=bridge_index]);
+        bridged.extend(hole[hole_index..].iter().chain(&hole[..=hole_index]));
+        bridged.extend_from_slice(&polygon[bridge_index..]);
+        polygon = bridged;
+    }
+
+    polygon
+}
+
+/// Finds a vertex of the polygon that is visible from the given point inside of it.
+fn find_bridge_vertex(polygon: &[Vector], point: Vector) -> Option<usize> {
+    let n = polygon.len();
+
+    // Cast a ray in the positive x direction and find the closest edge that it hits.
+    let mut hit_x = f32::INFINITY;
+    let mut candidate = None;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        if (a.y > point.y) == (b.y > point.y) {
+            continue;
+        }
+        let x = a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y);
+        if x >= point.x && x < hit_x {
+            hit_x = x;
+            candidate = Some(if a.x > b.x { i } else { (i + 1) % n });
+        }
+    }
+    let candidate = candidate?;
+
+    // The endpoint of the hit edge may be occluded by reflex vertices inside the triangle
+    // between the point, the hit point, and the endpoint. In that case, the reflex vertex
+    // with the smallest angle to the ray is visible instead.
+    let hit = Vector::new(hit_x, point.y);
+    let mut best = candidate;
+    let mut best_key = (f32::INFINITY, f32::INFINITY);
+    for i in 0..n {
+        let vertex = polygon[i];
+        if vertex == polygon[candidate]
+            || !is_reflex(polygon, i)
+            || !triangle_contains(vertex, point, hit, polygon[candidate])
+        {
+            continue;
+        }
+        let delta = vertex - point;
+        let key = (delta.y.abs().atan2(delta.x), delta.length_squared());
+        if key < best_key {
+            best = i;
+            best_key = key;
+        }
+    }
+
+    Some(best)
+}
+
+/// Triangulates a counterclockwise polygon using ear clipping.
+/// Returns the vertex indices of the triangles.
+fn triangulate(polygon: &[Vector]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+
+    let corner = |remaining: &[usize], i: usize| {
+        let n = remaining.len();
+        [
+            remaining[(i + n - 1) % n],
+            remaining[i],
+            remaining[(i + 1) % n],
+        ]
+    };
+
+    let mut i = 0;
+    let mut attempts = 0;
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let [prev, current, next] = corner(&remaining, i);
+
+        if is_ear(polygon, &remaining, prev, current, next) {
+            triangles.push([prev, current, next]);
+            remaining.remove(i);
+            i %= remaining.len();
+            attempts = 0;
+        } else if attempts < n {
+            i = (i + 1) % n;
+            attempts += 1;
+        } else {
+            // No ears were found, which can only happen for degenerate input,
+            // such as simplified outlines that intersect themselves.
+            // Clip the flattest corner to guarantee progress.
+            let flattest = (0..n)
+                .min_by(|&a, &b| {
+                    let area = |i| triangle_area(polygon, corner(&remaining, i)).abs();
+                    area(a).total_cmp(&area(b))
+                })
+                .unwrap();
+            let triangle = corner(&remaining, flattest);
+            if triangle_area(polygon, triangle) > 0.0 {
+                triangles.push(triangle);
+            }
+            remaining.remove(flattest);
+            i = flattest % remaining.len();
+            attempts = 0;
+        }
+    }
+
+    if let [a, b, c] = remaining[..]
+        && triangle_area(polygon, [a, b, c]) > 0.0
+    {
+        triangles.push([a, b, c]);
+    }
+
+    triangles
+}
+
+/// Returns `true` if the corner at `current` can be clipped off as a triangle.
+fn is_ear(
+    polygon: &[Vector],
+    remaining: &[usize],
+    prev: usize,
+    current: usize,
+    next: usize,
+) -> bool {
+    if triangle_area(polygon, [prev, current, next]) <= 0.0 {
+        return false;
+    }
+
+    // Bridges duplicate vertices, so compare positions instead of indices.
+    let (a, b, c) = (polygon[prev], polygon[current], polygon[next]);
+    !remaining.iter().any(|&i| {
+        let point = polygon[i];
+        point != a && point != b && point != c && triangle_contains(point, a, b, c)
+    })
+}
+
+/// Merges triangles into convex polygons using the Hertel-Mehlhorn algorithm,
+/// removing diagonals for as long as the resulting polygons stay convex.
+fn merge_convex(polygon: &[Vector], triangles: Vec<[usize; 3]>) -> Vec<Vec<Vector>> {
+    let mut parts: Vec<Option<Vec<usize>>> = triangles
+        .into_iter()
+        .map(|triangle| Some(triangle.to_vec()))
+        .collect();
+
+    // Map each directed edge to the part that it belongs to.
+    let mut edge_owners = HashMap::<(usize, usize), usize>::default();
+    for (id, part) in parts.iter().enumerate() {
+        for edge in edges(part.as_ref().unwrap()) {
+            edge_owners.insert(edge, id);
+        }
+    }
+
+    // Diagonals are edges shared by two parts. Sort them to keep the output deterministic.
+    let mut diagonals: Vec<(usize, usize)> = edge_owners
+        .keys()
+        .filter(|&&(a, b)| a < b && edge_owners.contains_key(&(b, a)))
+        .copied()
+        .collect();
+    diagonals.sort_unstable();
+
+    for (a, b) in diagonals {
+        let (Some(&first), Some(&second)) = (edge_owners.get(&(a, b)), edge_owners.get(&(b, a)))
+        else {
+            continue;
+        };
+        if first == second {
+            continue;
+        }
+
+        let merged = merge_parts(
+            parts[first].as_ref().unwrap(),
+            parts[second].as_ref().unwrap(),
+            a,
+            b,
+        );
+        if !is_convex(polygon, &merged) {
+            continue;
+        }
+
+        edge_owners.remove(&(a, b));
+        edge_owners.remove(&(b, a));
+        for edge in edges(&merged) {
+            edge_owners.insert(edge, first);
+        }
+        parts[first] = Some(merged);
+        parts[second] = None;
+    }
+
+    parts
+        .into_iter()
+        .flatten()
+        .map(|part| part.into_iter().map(|i| polygon[i]).collect())
+        .collect()
+}
+
+/// Merges two parts along the edge from `a` to `b` of the `first` part,
+/// which is the edge from `b` to `a` of the `second` part.
+fn merge_parts(first: &[usize], second: &[usize], a: usize, b: usize) -> Vec<usize> {
+    let start_first = first.iter().position(|&i| i == b).unwrap();
+    let start_second = second.iter().position(|&i| i == a).unwrap();
+
+    // The first part goes from `b` around to `a`, and the second part from `a` around to `b`.
+    let second_len = second.len();
+    let first = first[start_first..].iter().chain(&first[..start_first]);
+    let second = second[start_second..].iter().chain(&second[..start_second]);
+    first
+        .chain(second.skip(1).take(second_len - 2))
+        .copied()
+        .collect()
+}
+
+/// Returns the directed edges of a loop of vertex indices.
+fn edges(indices: &[usize]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    (0..indices.len()).map(|i| (indices[i], indices[(i + 1) % indices.len()]))
+}
+
+/// Returns `true` if the counterclockwise loop of vertex indices is convex.
+fn is_convex(polygon: &[Vector], indices: &[usize]) -> bool {
+    let n = indices.len();
+    (0..n).all(|i| {
+        let triangle = [indices[(i + n - 1) % n], indices[i], indices[(i + 1) % n]];
+        triangle_area(polygon, triangle) >= -f32::EPSILON
+    })
+}
+
+/// Returns `true` if the vertex at index `i` of the counterclockwise polygon is reflex.
+fn is_reflex(polygon: &[Vector], i: usize) -> bool {
+    let n = polygon.len();
+    triangle_area(polygon, [(i + n - 1) % n, i, (i + 1) % n]) < 0.0
+}
+
+/// Computes twice the signed area of the triangle with the given vertex indices.
+/// The area is positive for counterclockwise triangles.
+fn triangle_area(polygon: &[Vector], [a, b, c]: [usize; 3]) -> f32 {
+    (polygon[b] - polygon[a]).perp_dot(polygon[c] - polygon[a])
+}
+
+/// Returns `true` if the point is inside of or on the boundary of the triangle.
+fn triangle_contains(point: Vector, a: Vector, b: Vector, c: Vector) -> bool {
+    let d1 = (b - a).perp_dot(point - a);
+    let d2 = (c - b).perp_dot(point - b);
+    let d3 = (a - c).perp_dot(point - c);
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+/// Returns `true` if the point is inside of the closed loop, using the even-odd rule.
+fn contains_point(points: &[Vector], point: Vector) -> bool {
+    let n = points.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Computes the signed area of a closed loop. The area is positive for counterclockwise loops.
+fn signed_area(points: &[Vector]) -> f32 {
+    (0..points.len())
+        .map(|i| points[i].perp_dot(points[(i + 1) % points.len()]))
+        .sum::<f32>()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(center: Vector, half_size: f32) -> Vec<Vector> {
+        vec![
+            center + Vector::new(-half_size, -half_size),
+            center + Vector::new(half_size, -half_size),
+            center + Vector::new(half_size, half_size),
+            center + Vector::new(-half_size, half_size),
+        ]
+    }
+
+    #[test]
+    fn convex_decomposition_with_hole() {
+        let outer = square(Vector::ZERO, 2.0);
+        let mut hole = square(Vector::new(0.5, 0.0), 1.0);
+        hole.reverse();
+
+        let parts = convex_decomposition(&[outer, hole]);
+
+        // The parts are convex and cover the area of the outer boundary minus the hole.
+        let area: f32 = parts.iter().map(|part| signed_area(part)).sum();
+        assert!((area - 12.0).abs() < 1e-4);
+        for part in &parts {
+            let indices: Vec<usize> = (0..part.len()).collect();
+            assert!(is_convex(part, &indices));
+        }
+
+        // The hole is not covered by any part.
+        let in_hole = Vector::new(0.5, 0.0);
+        let in_solid = Vector::new(-1.5, 0.0);
+        assert!(!parts.iter().any(|part| contains_point(part, in_hole)));
+        assert!(parts.iter().any(|part| contains_point(part, in_solid)));
+
+        // Triangles are merged into larger convex parts.
+        assert!(parts.len() < 8);
+    }
+
+    #[test]
+    fn convex_decomposition_of_concave_polygon() {
+        // An L-shape needs at least two convex parts.
+        let l_shape = vec![
+            Vector::new(0.0, 0.0),
+            Vector::new(2.0, 0.0),
+            Vector::new(2.0, 1.0),
+            Vector::new(1.0, 1.0),
+            Vector::new(1.0, 2.0),
+            Vector::new(0.0, 2.0),
+        ];
+
+        let parts = convex_decomposition(&[l_shape]);
+        let area: f32 = parts.iter().map(|part| signed_area(part)).sum();
+        assert!((area - 3.0).abs() < 1e-4);
+        assert_eq!(parts.len(), 2);
+    }
+}
@@ -0,0 +1,161 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+use crate::{prelude::*, tests::utils::create_app};
+
+/// Tests that changing the [`CollisionMatrix`] at runtime adds and removes contact pairs,
+/// and is taken into account by [spatial queries](crate::spatial_query).
+#[test]
+fn collision_matrix_changes_reevaluate_contact_pairs() {
+    #[derive(PhysicsLayer, Clone, Copy, Default)]
+    enum GameLayer {
+        #[default]
+        Default,
+        Player,
+        Trigger,
+    }
+
+    let mut app = create_app();
+    app.insert_resource(Gravity::ZERO);
+    app.insert_resource(CollisionMatrix::default().with_interaction(
+        GameLayer::Player,
+        GameLayer::Trigger,
+        false,
+    ));
+    app.finish();
+
+    // Only memberships are set, filters are derived from the matrix.
+    let trigger = app
+        .world_mut()
+        .spawn((
+            RigidBody::Static,
+            #[cfg(feature = "2d")]
+            Collider::circle(0.5),
+            #[cfg(feature = "3d")]
+            Collider::sphere(0.5),
+            Sensor,
+            CollisionLayers::new(GameLayer::Trigger, LayerMask::ALL),
+        ))
+        .id();
+    let player = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::circle(0.5),
+            #[cfg(feature = "3d")]
+            Collider::sphere(0.5),
+            CollisionLayers::new(GameLayer::Player, LayerMask::ALL),
+        ))
+        .id();
+
+    // Queries on behalf of the player should only see the trigger if the layers can interact.
+    let query_finds_trigger = |app: &mut App| {
+        let filter = SpatialQueryFilter::default()
+            .with_memberships(GameLayer::Player)
+            .with_excluded_entities([player]);
+        app.world_mut()
+            .run_system_once(move |spatial_query: SpatialQuery| {
+                spatial_query
+                    .point_intersections(RVector::ZERO, &filter)
+                    .contains(&trigger)
+            })
+            .unwrap()
+    };
+
+    app.update();
+    assert!(
+        !app.world()
+            .resource::<ContactGraph>()
+            .contains(trigger, player)
+    );
+    assert!(!query_finds_trigger(&mut app));
+
+    // Allowing the interaction at runtime should find the pair even though nothing moves.
+    app.world_mut()
+        .resource_mut::<CollisionMatrix>()
+        .set_interaction(GameLayer::Player, GameLayer::Trigger, true);
+    app.update();
+    assert!(
+        app.world()
+            .resource::<ContactGraph>()
+            .contains(trigger, player)
+    );
+    assert!(query_finds_trigger(&mut app));
+
+    // Disallowing it again should remove the existing pair.
+    app.world_mut()
+        .resource_mut::<CollisionMatrix>()
+        .set_interaction(GameLayer::Player, GameLayer::Trigger, false);
+    app.update();
+    assert!(
+        !app.world()
+            .resource::<ContactGraph>()
+            .contains(trigger, player)
+    );
+    assert!(!query_finds_trigger(&mut app));
+}
+
+/// Tests that [`AsyncColliderConstruction`] keeps the body disabled until the collider is ready,
+/// without re-enabling bodies that were disabled by the user.
+#[test]
+fn async_collider_constructor_inserts_collider_when_ready() {
+    #[derive(Resource, Default)]
+    struct ReadyEntities(Vec<Entity>);
+
+    let mut app = create_app();
+    app.init_resource::<ReadyEntities>();
+    app.add_observer(
+        |event: On<ColliderConstructorReady>, mut ready: ResMut<ReadyEntities>| {
+            ready.0.push(event.entity);
+        },
+    );
+    app.finish();
+
+    let body = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            ColliderConstructor::Circle { radius: 0.5 },
+            #[cfg(feature = "3d")]
+            ColliderConstructor::Sphere { radius: 0.5 },
+            AsyncColliderConstruction,
+        ))
+        .id();
+    let disabled_body = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            ColliderConstructor::Circle { radius: 0.5 },
+            #[cfg(feature = "3d")]
+            ColliderConstructor::Sphere { radius: 0.5 },
+            AsyncColliderConstruction,
+            RigidBodyDisabled,
+        ))
+        .id();
+
+    for _ in 0..100 {
+        app.update();
+        if app.world().resource::<ReadyEntities>().0.len() == 2 {
+            break;
+        }
+
+        // The body is kept inactive while the collider is being computed.
+        if !app.world().entity(body).contains::<Collider>() {
+            assert!(app.world().entity(body).contains::<RigidBodyDisabled>());
+        }
+    }
+
+    let ready = &app.world().resource::<ReadyEntities>().0;
+    assert!(ready.contains(&body) && ready.contains(&disabled_body));
+
+    let body_ref = app.world().entity(body);
+    assert!(body_ref.contains::<Collider>());
+    assert!(!body_ref.contains::<ColliderConstructor>());
+    assert!(!body_ref.contains::<RigidBodyDisabled>());
+
+    let disabled_body_ref = app.world().entity(disabled_body);
+    assert!(disabled_body_ref.contains::<Collider>());
+    assert!(disabled_body_ref.contains::<RigidBodyDisabled>());
+}
@@ -1,9 +1,11 @@
 //! Collision events for detecting when colliders start or stop touching.
 //!
-//! Avian provides two collision event types:
+//! Avian provides three collision event types:
 //!
 //! - [`CollisionStart`]: Triggered when two colliders start touching.
 //! - [`CollisionEnd`]: Triggered when two colliders stop touching.
+//! - [`ContactForce`]: Triggered when the contact force between two colliders exceeds
+//!   the [`ContactForceEventThreshold`] of one of the colliders.
 //!
 //! Depending on your use case, you may want to read them as [`Message`]s with a [`MessageReader`],
 //! or observe them as [`Event`]s with an [observer](Observer). Avian supports both options.
@@ -98,7 +100,38 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Contact Force Events
+//!
+//! [`CollisionStart`] and [`CollisionEnd`] only tell when contact begins and ends.
+//! For use cases like impact sounds, damage, or breakable objects, it can be useful
+//! to know how strong the contact is on every time step instead.
+//!
+//! To receive [`ContactForce`] events for a collider, add the [`ContactForceEventThreshold`] component.
+//! The event is written as a [`Message`] and triggered as an [`Event`] whenever the total contact force
+//! between the collider and another collider exceeds the threshold.
+//!
+//! ```
+#![cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#![cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+//! use bevy::prelude::*;
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands
+//!         .spawn((
+//!             RigidBody::Dynamic,
+#![cfg_attr(feature = "2d", doc = "            Collider::rectangle(1.0, 1.0),")]
+#![cfg_attr(feature = "3d", doc = "            Collider::cuboid(1.0, 1.0, 1.0),")]
+//!             // Trigger `ContactForce` events for forces larger than 500 N.
+//!             ContactForceEventThreshold(500.0),
+//!         ))
+//!         .observe(|event: On<ContactForce>| {
+//!             println!("Impact with a force of {} N", event.total_force_magnitude);
+//!         });
+//! }
+//! ```
 
+use crate::{collision::contact_types::ContactPoint, math::Vector};
 use bevy::prelude::*;
 
 /// A [collision event](self) that is triggered when two colliders start touching.
@@ -295,3 +328,101 @@ pub type OnCollisionEnd = CollisionEnd;
 #[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 #[reflect(Component, Debug)]
 pub struct CollisionEventsEnabled;
+
+/// A [collision event](self) that is triggered when the contact force between two colliders
+/// exceeds the [`ContactForceEventThreshold`] of one of the colliders.
+///
+/// The event can be read using a [`MessageReader`] or observed using an [observer](Observer).
+/// The message is written once per contact pair if the threshold of either collider is exceeded,
+/// while the observer event is only triggered for colliders whose own threshold is exceeded.
+///
+/// The contact force is computed by dividing the [normal impulses](ContactPoint::normal_impulse)
+/// of the contact pair by the length of the time step. Contacts between sleeping bodies
+/// are not solved, and do not trigger the event.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Breakable;
+///
+/// fn setup_breakables(mut commands: Commands) {
+///     commands
+///         .spawn((
+///             Breakable,
+///             RigidBody::Dynamic,
+#[cfg_attr(feature = "2d", doc = "            Collider::rectangle(1.0, 1.0),")]
+#[cfg_attr(feature = "3d", doc = "            Collider::cuboid(1.0, 1.0, 1.0),")]
+///             // Trigger `ContactForce` events for forces larger than 1000 N.
+///             ContactForceEventThreshold(1000.0),
+///         ))
+///         .observe(break_on_impact);
+/// }
+///
+/// fn break_on_impact(event: On<ContactForce>, mut commands: Commands) {
+///     // `collider1` is the event target, and `collider2` is the other collider.
+///     println!(
+///         "{} was hit by {} with a force of {} N",
+///         event.collider1, event.collider2, event.total_force_magnitude,
+///     );
+///     commands.entity(event.collider1).despawn();
+/// }
+/// ```
+///
+/// # Scheduling
+///
+/// The [`ContactForce`] event is triggered after the physics step in the [`CollisionEventSystems`]
+/// system set. At this point, the solver has already run and contact impulses have been updated.
+///
+/// [`CollisionEventSystems`]: super::narrow_phase::CollisionEventSystems
+#[derive(EntityEvent, Message, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactForce {
+    /// The entity of the first collider in the contact.
+    ///
+    /// For observers watching this event as an [`EntityEvent`], this is the target entity.
+    #[event_target]
+    pub collider1: Entity,
+    /// The entity of the second collider in the contact.
+    pub collider2: Entity,
+    /// The rigid body that [`collider1`](Self::collider1) is attached to.
+    ///
+    /// If the collider is not attached to a rigid body, this will be `None`.
+    pub body1: Option<Entity>,
+    /// The rigid body that [`collider2`](Self::collider2) is attached to.
+    ///
+    /// If the collider is not attached to a rigid body, this will be `None`.
+    pub body2: Option<Entity>,
+    /// The sum of all contact forces applied along contact normals, pointing
+    /// from [`collider1`](Self::collider1) to [`collider2`](Self::collider2).
+    pub total_force: Vector,
+    /// The sum of the magnitudes of all contact forces applied along contact normals.
+    ///
+    /// This is the value compared against the [`ContactForceEventThreshold`].
+    /// Note that it is *not* the magnitude of the [`total_force`](Self::total_force).
+    pub total_force_magnitude: f32,
+    /// The world-space direction of the largest contact force, pointing
+    /// from [`collider1`](Self::collider1) to [`collider2`](Self::collider2).
+    pub max_force_direction: Vector,
+    /// The magnitude of the largest contact force.
+    pub max_force_magnitude: f32,
+    /// The contact point with the largest contact force.
+    pub max_force_contact: ContactPoint,
+}
+
+/// A component that enables [`ContactForce`] events for a collider entity,
+/// triggered when the total contact force exceeds the given threshold.
+///
+/// The threshold is in Newtons (N), or more generally in kg⋅m/s².
+/// A threshold of `0.0` triggers events for any contact with a non-zero contact force.
+///
+/// See the [`ContactForce`] documentation for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct ContactForceEventThreshold(pub f32);
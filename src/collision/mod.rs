@@ -44,6 +44,10 @@
 //!
 //! Collision events are only sent or triggered for entities that have the [`CollisionEventsEnabled`] component.
 //!
+//! For measuring how strong contacts are, the [`ContactForce`] event can be enabled
+//! for colliders with the [`ContactForceEventThreshold`] component. It is triggered
+//! whenever the contact force between two colliders exceeds the threshold.
+//!
 //! See the documentation of the event types and the [`collision_events`] module
 //! for more information and usage examples.
 //!
@@ -76,16 +80,24 @@ pub mod narrow_phase;
 mod diagnostics;
 pub use diagnostics::CollisionDiagnostics;
 
+#[cfg(all(test, any(feature = "parry-f32", feature = "parry-f64")))]
+mod tests;
+
 /// Re-exports common types related to collision detection functionality.
 pub mod prelude {
     pub use super::broad_phase::{BroadPhaseCorePlugin, BroadPhaseSystems, BvhBroadPhasePlugin};
-    #[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
+    #[cfg(all(
+        any(feature = "collider-from-mesh", feature = "collider-from-image"),
+        feature = "default-collider"
+    ))]
     pub use super::collider::ColliderCachePlugin;
+    #[cfg(feature = "persistent-collider-cache")]
+    pub use super::collider::PersistentColliderCache;
     pub use super::collider::{
         AnyCollider, ColliderAabb, ColliderAabbMargin, ColliderBackendPlugin, ColliderContext,
         ColliderDisabled, ColliderMarker, ColliderPairContext, CollidingEntities, CollisionLayers,
-        CollisionMargin, IntoCollider, LayerMask, PhysicsLayer, ScalableCollider, Sensor,
-        SimpleCollider,
+        CollisionMargin, CollisionMatrix, IntoCollider, LayerBits, LayerMask, PhysicsLayer,
+        ScalableCollider, Sensor, SimpleCollider,
         collider_hierarchy::{ColliderHierarchyPlugin, ColliderOf, RigidBodyColliders},
         collider_transform::{ColliderTransform, ColliderTransformPlugin},
     };
@@ -94,13 +106,16 @@ pub mod prelude {
         any(feature = "parry-f32", feature = "parry-f64")
     ))]
     pub use super::collider::{
-        Collider, ColliderConstructor, ColliderConstructorHierarchy,
+        AsyncColliderConstruction, Collider, ColliderConstructor, ColliderConstructorHierarchy,
         ColliderConstructorHierarchyReady, ColliderConstructorReady, FillMode, TrimeshFlags,
         VhacdParameters,
     };
+    #[cfg(feature = "collision-matrix-asset")]
+    pub use super::collider::{CollisionMatrixAssetPlugin, CollisionMatrixHandle};
     #[expect(deprecated)]
     pub use super::collision_events::{
-        CollisionEnd, CollisionEventsEnabled, CollisionStart, OnCollisionEnd, OnCollisionStart,
+        CollisionEnd, CollisionEventsEnabled, CollisionStart, ContactForce,
+        ContactForceEventThreshold, OnCollisionEnd, OnCollisionStart,
     };
     pub use super::contact_types::{
         Collisions, ContactEdge, ContactGraph, ContactManifold, ContactPair, ContactPairFlags,
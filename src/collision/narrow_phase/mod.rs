@@ -88,6 +88,7 @@ where
         app.init_resource::<NarrowPhaseConfig>()
             .init_resource::<ContactGraph>()
             .init_resource::<JointGraph>()
+            .init_resource::<CollisionMatrix>()
             .init_resource::<ContactStatusBits>()
             .init_resource::<ContactStatusChangeQueue>()
             .init_resource::<DefaultFriction>()
@@ -97,7 +98,8 @@ where
         app.init_resource::<NarrowPhaseThreadLocals>();
 
         app.add_message::<CollisionStart>()
-            .add_message::<CollisionEnd>();
+            .add_message::<CollisionEnd>()
+            .add_message::<ContactForce>();
 
         // Set up system set scheduling.
         app.configure_sets(
@@ -145,13 +147,15 @@ where
             app.add_observer(remove_body_on::<Insert, RigidBody>);
             app.add_observer(remove_body_on::<Remove, RigidBody>);
 
-            // Trigger collision events for colliders that started or stopped touching.
+            // Trigger collision events for colliders that started or stopped touching,
+            // and contact force events for contacts exceeding their force threshold.
             app.add_systems(
                 self.schedule,
-                trigger_collision_events
+                (trigger_collision_events, trigger_contact_force_events)
+                    .chain()
                     .in_set(CollisionEventSystems)
                     // TODO: Ideally we don't need to make this ambiguous, but currently it is
-                    //       to avoid conflicts since the system has exclusive world access.
+                    //       to avoid conflicts since the systems have exclusive world access.
                     .ambiguous_with(PhysicsStepSystems::Finalize),
             );
         }
@@ -165,7 +169,7 @@ where
     }
 }
 
-/// A system set for triggering the [`CollisionStart`] and [`CollisionEnd`] events.
+/// A system set for triggering the [`CollisionStart`], [`CollisionEnd`], and [`ContactForce`] events.
 ///
 /// Runs in [`PhysicsStepSystems::Finalize`], after the solver has run and contact impulses
 /// have been computed and applied.
@@ -354,6 +358,98 @@ fn trigger_collision_events(
     });
 }
 
+#[derive(SystemParam)]
+struct TriggerContactForceEventsContext<'w, 's> {
+    query: Query<'w, 's, &'static ContactForceEventThreshold>,
+    contact_graph: Res<'w, ContactGraph>,
+    time: Res<'w, Time>,
+    writer: MessageWriter<'w, ContactForce>,
+}
+
+/// Writes and triggers [`ContactForce`] events for touching contact pairs whose
+/// total contact force exceeds the [`ContactForceEventThreshold`] of one of the colliders.
+fn trigger_contact_force_events(
+    // We use exclusive access here to avoid queuing a new command for each event.
+    world: &mut World,
+    state: &mut SystemState<TriggerContactForceEventsContext>,
+    // Cache events in a buffer to avoid reallocating every time.
+    mut events: Local<Vec<ContactForce>>,
+) {
+    let mut state = state.get_mut(world).unwrap();
+
+    let delta_secs = state.time.delta_secs();
+
+    if state.query.is_empty() || delta_secs == 0.0 {
+        return;
+    }
+
+    for contact_pair in state.contact_graph.iter_active_touching() {
+        let threshold1 = state.query.get(contact_pair.collider1).ok();
+        let threshold2 = state.query.get(contact_pair.collider2).ok();
+
+        if threshold1.is_none() && threshold2.is_none() {
+            continue;
+        }
+
+        let total_force_magnitude = contact_pair.total_normal_impulse_magnitude() / delta_secs;
+        let exceeds1 = threshold1.is_some_and(|t| total_force_magnitude > t.0);
+        let exceeds2 = threshold2.is_some_and(|t| total_force_magnitude > t.0);
+
+        if !exceeds1 && !exceeds2 {
+            continue;
+        }
+
+        // Find the contact point with the largest normal impulse.
+        let Some((max_force_direction, max_force_contact)) = contact_pair
+            .manifolds
+            .iter()
+            .flat_map(|manifold| manifold.points.iter().map(|point| (manifold.normal, point)))
+            .max_by(|(_, a), (_, b)| {
+                a.normal_impulse
+                    .partial_cmp(&b.normal_impulse)
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            })
+        else {
+            continue;
+        };
+
+        let event = ContactForce {
+            collider1: contact_pair.collider1,
+            collider2: contact_pair.collider2,
+            body1: contact_pair.body1,
+            body2: contact_pair.body2,
+            total_force: contact_pair.total_normal_impulse() / delta_secs,
+            total_force_magnitude,
+            max_force_direction,
+            max_force_magnitude: max_force_contact.normal_impulse / delta_secs,
+            max_force_contact: *max_force_contact,
+        };
+
+        state.writer.write(event);
+
+        if exceeds1 {
+            events.push(event);
+        }
+        if exceeds2 {
+            events.push(ContactForce {
+                collider1: event.collider2,
+                collider2: event.collider1,
+                body1: event.body2,
+                body2: event.body1,
+                total_force: -event.total_force,
+                max_force_direction: -event.max_force_direction,
+                max_force_contact: event.max_force_contact.flipped(),
+                ..event
+            });
+        }
+    }
+
+    // Trigger the events, draining the buffer in the process.
+    events.drain(..).for_each(|event| {
+        world.trigger(event);
+    });
+}
+
 // ===============================================================
 // The rest of this module contains observers and helper functions
 // for updating the contact graph when bodies or colliders are
@@ -81,6 +81,7 @@ pub struct NarrowPhase<'w, 's, C: AnyCollider> {
     pub config: Res<'w, NarrowPhaseConfig>,
     default_friction: Res<'w, DefaultFriction>,
     default_restitution: Res<'w, DefaultRestitution>,
+    collision_matrix: Res<'w, CollisionMatrix>,
     length_unit: Res<'w, PhysicsLengthUnit>,
 }
 
@@ -490,7 +491,11 @@ impl<C: AnyCollider> NarrowPhase<'_, '_, C> {
             // Also check if the collision layers are still compatible and the contact pair is valid.
             // TODO: Ideally, we would have fine-grained change detection for `CollisionLayers`
             //       rather than checking it for every pair here.
-            if !overlap || !collider1.layers.interacts_with(*collider2.layers) {
+            if !overlap
+                || !self
+                    .collision_matrix
+                    .layers_interact(*collider1.layers, *collider2.layers)
+            {
                 // The AABBs no longer overlap. The contact pair should be removed.
                 contacts.flags.set(ContactPairFlags::DISJOINT_AABB, true);
                 contact_status_bits.set(contact_id);
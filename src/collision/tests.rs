@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::{prelude::*, tests::utils::create_app};
+
+/// Tests that [`ContactForce`] events are only triggered for colliders whose contact force
+/// exceeds their [`ContactForceEventThreshold`].
+#[test]
+fn contact_force_events_respect_threshold() {
+    #[derive(Resource, Default)]
+    struct TriggeredForces(Vec<(Entity, f32)>);
+
+    let mut app = create_app();
+    app.init_resource::<TriggeredForces>();
+    app.finish();
+
+    app.world_mut().spawn((
+        RigidBody::Static,
+        #[cfg(feature = "2d")]
+        Collider::rectangle(10.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(10.0, 1.0, 10.0),
+    ));
+
+    let record_force = |event: On<ContactForce>, mut forces: ResMut<TriggeredForces>| {
+        forces
+            .0
+            .push((event.collider1, event.total_force_magnitude));
+    };
+
+    // The weight of a 1 kg box is roughly 9.81 N, which exceeds
+    // the threshold of the first box but not the second one.
+    let light_threshold = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 1.0, 1.0),
+            Mass(1.0),
+            // Sleeping contacts are not solved, and don't trigger contact force events.
+            SleepingDisabled,
+            Position(RVector::X * -2.0 + RVector::Y),
+            ContactForceEventThreshold(5.0),
+        ))
+        .observe(record_force)
+        .id();
+    app.world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 1.0, 1.0),
+            Mass(1.0),
+            SleepingDisabled,
+            Position(RVector::X * 2.0 + RVector::Y),
+            ContactForceEventThreshold(100.0),
+        ))
+        .observe(record_force);
+
+    for _ in 0..120 {
+        app.update();
+    }
+
+    // Only consider the last step, once the boxes have come to rest.
+    app.world_mut().resource_mut::<TriggeredForces>().0.clear();
+    app.update();
+
+    let forces = &app.world().resource::<TriggeredForces>().0;
+    assert!(!forces.is_empty());
+    assert!(
+        forces
+            .iter()
+            .all(|(entity, force)| *entity == light_threshold && *force > 5.0)
+    );
+
+    // The message is written for the pair exceeding the threshold.
+    let messages = app.world().resource::<Messages<ContactForce>>();
+    assert!(
+        messages.iter_current_update_messages().any(|event| {
+            event.collider1 == light_threshold || event.collider2 == light_threshold
+        })
+    );
+}
@@ -126,6 +126,13 @@ impl Plugin for PhysicsDebugPlugin {
                 debug_render_constraint::<RevoluteJoint, 2>,
                 #[cfg(feature = "3d")]
                 debug_render_constraint::<SphericalJoint, 2>,
+                debug_render_constraint::<WheelJoint, 2>,
+                debug_render_constraint::<GenericJoint, 2>,
+                debug_render_constraint::<GearJoint, 2>,
+                debug_render_constraint::<RackAndPinionJoint, 2>,
+                debug_render_constraint::<PulleyJoint, 2>,
+                debug_render_constraint::<ProjectedJoint, 2>,
+                debug_render_constraint::<TargetJoint, 1>,
                 debug_render_raycasts,
                 #[cfg(all(
                     feature = "default-collider",
@@ -66,6 +66,11 @@ fn diagnostic_entity_counts(
     distance_joint_query: Query<&DistanceJoint>,
     revolute_joint_query: Query<&RevoluteJoint>,
     #[cfg(feature = "3d")] spherical_joint_query: Query<&SphericalJoint>,
+    wheel_joint_query: Query<&WheelJoint>,
+    generic_joint_query: Query<&GenericJoint>,
+    gear_joint_query: Query<&GearJoint>,
+    rack_and_pinion_joint_query: Query<&RackAndPinionJoint>,
+    pulley_joint_query: Query<&PulleyJoint>,
     mut diagnostics: ResMut<PhysicsEntityDiagnostics>,
 ) {
     // Count the body types in a single pass.
@@ -86,7 +91,12 @@ fn diagnostic_entity_counts(
     diagnostics.joint_count = fixed_joint_query.count() as u32
         + prismatic_joint_query.count() as u32
         + distance_joint_query.count() as u32
-        + revolute_joint_query.count() as u32;
+        + revolute_joint_query.count() as u32
+        + wheel_joint_query.count() as u32
+        + generic_joint_query.count() as u32
+        + gear_joint_query.count() as u32
+        + rack_and_pinion_joint_query.count() as u32
+        + pulley_joint_query.count() as u32;
     #[cfg(feature = "3d")]
     {
         diagnostics.joint_count += spherical_joint_query.count() as u32;
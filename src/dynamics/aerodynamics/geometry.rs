@@ -0,0 +1,242 @@
+//! Extracts the surface geometry used for computing aerodynamic forces from collider shapes.
+
+use crate::prelude::*;
+use parry::shape::{SharedShape, TypedShape};
+
+/// The number of subdivisions used for tessellating rounded shapes like capsules.
+const SUBDIVISIONS: u32 = 8;
+
+/// A flat surface element of a shape, used for computing pressure forces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct AerodynamicFace {
+    /// The center of the face in the local space of the collider.
+    pub center: Vector,
+    /// The outward unit normal of the face in the local space of the collider.
+    pub normal: Vector,
+    /// The area of the face, or length in 2D.
+    pub area: f32,
+}
+
+/// A ball that is handled analytically, with the same projected area in every direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct AerodynamicBall {
+    /// The center of the ball in the local space of the collider.
+    pub center: Vector,
+    /// The projected area of the ball, or diameter in 2D.
+    pub projected_area: f32,
+}
+
+/// The surface geometry of a collider used for computing aerodynamic forces.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct AerodynamicGeometry {
+    /// Flat faces that receive pressure on their outward side.
+    pub faces: Vec<AerodynamicFace>,
+    /// Balls that receive drag uniformly.
+    pub balls: Vec<AerodynamicBall>,
+}
+
+impl AerodynamicGeometry {
+    /// Computes the aerodynamic geometry of the given shape in its local space.
+    pub fn from_shape(shape: &SharedShape) -> Self {
+        let mut geometry = Self::default();
+        geometry.add_shape(shape, Vector::ZERO, Rot::IDENTITY);
+        geometry
+    }
+
+    /// Returns the largest distance from the given point to a face or ball,
+    /// used as a characteristic length of the geometry.
+    pub fn max_distance_from(&self, point: Vector) -> f32 {
+        let faces = self.faces.iter().map(|face| face.center.distance(point));
+        let balls = self.balls.iter().map(|ball| ball.center.distance(point));
+        faces.chain(balls).fold(0.0, f32::max)
+    }
+
+    fn add_shape(&mut self, shape: &SharedShape, translation: Vector, rotation: Rot) {
+        match shape.as_typed_shape() {
+            TypedShape::Ball(ball) => {
+                let radius = ball.radius.f32();
+                #[cfg(feature = "2d")]
+                let projected_area = 2.0 * radius;
+                #[cfg(feature = "3d")]
+                let projected_area = core::f32::consts::PI * radius * radius;
+                self.balls.push(AerodynamicBall {
+                    center: translation,
+                    projected_area,
+                });
+            }
+            #[cfg(feature = "2d")]
+            TypedShape::Cuboid(cuboid) => {
+                self.add_polygon(&cuboid.to_polyline(), translation, rotation);
+            }
+            #[cfg(feature = "2d")]
+            TypedShape::ConvexPolygon(polygon) => {
+                self.add_polygon(polygon.points(), translation, rotation);
+            }
+            #[cfg(feature = "2d")]
+            TypedShape::Capsule(capsule) => {
+                self.add_polygon(&capsule.to_polyline(SUBDIVISIONS), translation, rotation);
+            }
+            #[cfg(feature = "2d")]
+            TypedShape::RoundCuboid(round_shape) => {
+                self.add_polygon(
+                    &round_shape.to_polyline(SUBDIVISIONS),
+                    translation,
+                    rotation,
+                );
+            }
+            #[cfg(feature = "2d")]
+            TypedShape::RoundConvexPolygon(round_shape) => {
+                self.add_polygon(
+                    &round_shape.to_polyline(SUBDIVISIONS),
+                    translation,
+                    rotation,
+                );
+            }
+            // Segments and polylines are open, so both sides receive pressure.
+            #[cfg(feature = "2d")]
+            TypedShape::Segment(segment) => {
+                self.add_two_sided_edge(segment.a.f32(), segment.b.f32(), translation, rotation);
+            }
+            #[cfg(feature = "2d")]
+            TypedShape::Polyline(polyline) => {
+                for segment in polyline.segments() {
+                    self.add_two_sided_edge(
+                        segment.a.f32(),
+                        segment.b.f32(),
+                        translation,
+                        rotation,
+                    );
+                }
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::Cuboid(cuboid) => {
+                let (vertices, indices) = cuboid.to_trimesh();
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::ConvexPolyhedron(polyhedron) => {
+                let (vertices, indices) = polyhedron.to_trimesh();
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::Capsule(capsule) => {
+                let (vertices, indices) = capsule.to_trimesh(SUBDIVISIONS, SUBDIVISIONS / 2);
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::Cylinder(cylinder) => {
+                let (vertices, indices) = cylinder.to_trimesh(SUBDIVISIONS);
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::Cone(cone) => {
+                let (vertices, indices) = cone.to_trimesh(SUBDIVISIONS);
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            // Rounded shapes ignore the rounding and use the inner shape.
+            #[cfg(feature = "3d")]
+            TypedShape::RoundCuboid(round_shape) => {
+                let (vertices, indices) = round_shape.inner_shape.to_trimesh();
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::RoundConvexPolyhedron(round_shape) => {
+                let (vertices, indices) = round_shape.inner_shape.to_trimesh();
+                self.add_trimesh(&vertices, &indices, translation, rotation);
+            }
+            #[cfg(feature = "3d")]
+            TypedShape::TriMesh(trimesh) => {
+                self.add_trimesh(trimesh.vertices(), trimesh.indices(), translation, rotation);
+            }
+            // A single triangle is open, so both sides receive pressure.
+            #[cfg(feature = "3d")]
+            TypedShape::Triangle(triangle) => {
+                let vertices = [triangle.a, triangle.b, triangle.c];
+                self.add_trimesh(&vertices, &[[0, 1, 2], [0, 2, 1]], translation, rotation);
+            }
+            TypedShape::Compound(compound) => {
+                for (sub_pos, shape) in compound.shapes() {
+                    let sub_translation = sub_pos.translation.f32();
+                    #[cfg(feature = "2d")]
+                    let sub_rotation = Rot::from_sin_cos(
+                        sub_pos.rotation.sin().f32(),
+                        sub_pos.rotation.cos().f32(),
+                    );
+                    #[cfg(feature = "3d")]
+                    let sub_rotation = sub_pos.rotation.f32();
+
+                    self.add_shape(
+                        shape,
+                        translation + rotation * sub_translation,
+                        rotation * sub_rotation,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the edges of a closed counterclockwise polygon as faces.
+    #[cfg(feature = "2d")]
+    fn add_polygon(
+        &mut self,
+        vertices: &[parry::math::Vector],
+        translation: Vector,
+        rotation: Rot,
+    ) {
+        for i in 0..vertices.len() {
+            let a = vertices[i].f32();
+            let b = vertices[(i + 1) % vertices.len()].f32();
+            self.add_edge(a, b, translation, rotation);
+        }
+    }
+
+    /// Adds an edge with the outward normal on its right side.
+    #[cfg(feature = "2d")]
+    fn add_edge(&mut self, a: Vector, b: Vector, translation: Vector, rotation: Rot) {
+        let edge = b - a;
+        let area = edge.length();
+        if area <= f32::EPSILON {
+            return;
+        }
+        self.faces.push(AerodynamicFace {
+            center: translation + rotation * a.midpoint(b),
+            normal: rotation * -edge.perp() / area,
+            area,
+        });
+    }
+
+    #[cfg(feature = "2d")]
+    fn add_two_sided_edge(&mut self, a: Vector, b: Vector, translation: Vector, rotation: Rot) {
+        self.add_edge(a, b, translation, rotation);
+        self.add_edge(b, a, translation, rotation);
+    }
+
+    /// Adds the triangles of a mesh with counterclockwise winding as faces.
+    #[cfg(feature = "3d")]
+    fn add_trimesh(
+        &mut self,
+        vertices: &[parry::math::Vector],
+        indices: &[[u32; 3]],
+        translation: Vector,
+        rotation: Rot,
+    ) {
+        for [i1, i2, i3] in indices {
+            let a = vertices[*i1 as usize].f32();
+            let b = vertices[*i2 as usize].f32();
+            let c = vertices[*i3 as usize].f32();
+
+            let cross = (b - a).cross(c - a);
+            let double_area = cross.length();
+            if double_area <= f32::EPSILON {
+                continue;
+            }
+
+            self.faces.push(AerodynamicFace {
+                center: translation + rotation * ((a + b + c) / 3.0),
+                normal: rotation * (cross / double_area),
+                area: 0.5 * double_area,
+            });
+        }
+    }
+}
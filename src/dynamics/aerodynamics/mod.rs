@@ -0,0 +1,379 @@
+//! Shape-based aerodynamic drag and lift for rigid bodies moving through air or another medium.
+//!
+//! # Overview
+//!
+//! [`LinearDamping`] and [`AngularDamping`] slow down bodies uniformly, regardless of their shape
+//! and orientation. This is often good enough, but objects like paper planes, parachutes,
+//! and falling leaves need forces that depend on how they are oriented relative to the airflow.
+//!
+//! The [`Aerodynamics`] component computes drag and lift from the geometry of a [`Collider`].
+//! The surface of the collider is split into flat faces, and each face that is facing into the airflow
+//! receives a pressure force along its normal:
+//!
+//! ```text
+//! F = -normal * 0.5 * density * drag_coefficient * area * (velocity · normal)²
+//! ```
+//!
+//! where `velocity` is the velocity of the face relative to the [wind](Atmosphere::wind_velocity).
+//! The part of the force that is perpendicular to the airflow is lift, and can be scaled separately
+//! with [`Aerodynamics::lift_coefficient`]. All faces also receive skin friction that opposes the flow
+//! along their surface, scaled by [`Aerodynamics::friction_coefficient`].
+//!
+//! Because forces are applied at the faces, bodies also receive torque,
+//! which for example damps tumbling and makes a dart fly tip-first.
+//!
+//! Balls are handled analytically, receiving drag based on their projected area with no lift.
+//!
+//! The density of the medium and the wind velocity are configured globally with the [`Atmosphere`] resource.
+//!
+//! The forces are computed once per time step and applied over all [substeps](SubstepCount)
+//! using the [`Forces`] API. Like [`Gravity`], aerodynamic forces do not wake up [sleeping](Sleeping) bodies.
+//!
+//! # Usage
+//!
+//! Aerodynamic forces are applied by the [`AerodynamicsPlugin`], which is not included in [`PhysicsPlugins`]
+//! and must be added separately. Add the [`Aerodynamics`] component to an entity with a [`Collider`] attached to a dynamic rigid body:
+//!
+//! ```
+#![cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#![cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+//! use bevy::prelude::*;
+//!
+//! fn setup(mut commands: Commands) {
+//!     // A thin sheet of paper that glides and flutters as it falls.
+//!     commands.spawn((
+//!         RigidBody::Dynamic,
+#![cfg_attr(feature = "2d", doc = "        Collider::rectangle(0.2, 0.002),")]
+#![cfg_attr(feature = "3d", doc = "        Collider::cuboid(0.2, 0.002, 0.3),")]
+//!         ColliderDensity(700.0),
+//!         Aerodynamics::default(),
+//!         Transform::from_xyz(0.0, 10.0, 0.0),
+//!     ));
+//! }
+//! ```
+//!
+#![cfg_attr(
+    feature = "2d",
+    doc = "In 2D, areas are lengths, and the density of the medium is per unit area."
+)]
+#![cfg_attr(feature = "2d", doc = "")]
+//! # Supported Shapes
+//!
+//! Balls are handled analytically, while cuboids, convex hulls, and capsules are handled as polygons
+#![cfg_attr(
+    feature = "2d",
+    doc = "(with capsules tessellated). Segments and polylines receive pressure on both sides."
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "or polyhedra (with capsules, cylinders, and cones tessellated). Triangle meshes are assumed to be closed,",
+    doc = "with outward-facing triangles, and single triangles receive pressure on both sides."
+)]
+//! Compound shapes combine the results of their subshapes, and rounded shapes ignore their rounding.
+//!
+//! The face data is computed when the [`Aerodynamics`] component is added, and whenever the [`Collider`] changes.
+
+mod geometry;
+#[cfg(all(test, any(feature = "parry-f32", feature = "parry-f64")))]
+mod tests;
+
+use crate::{
+    dynamics::{
+        force_field,
+        integrator::{self, IntegrationSystems},
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+use geometry::AerodynamicGeometry;
+
+/// A plugin for applying aerodynamic drag and lift to rigid bodies with the [`Aerodynamics`] component.
+///
+/// See the [module-level documentation](self) for more information.
+pub struct AerodynamicsPlugin;
+
+impl Plugin for AerodynamicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Atmosphere>();
+
+        app.add_systems(
+            PhysicsSchedule,
+            (update_aerodynamic_geometry, apply_aerodynamic_forces)
+                .chain()
+                .in_set(IntegrationSystems::UpdateVelocityIncrements)
+                .after(ForceSystems::ApplyConstantForces)
+                .after(force_field::apply_force_fields)
+                .before(integrator::pre_process_velocity_increments),
+        );
+    }
+}
+
+/// The medium that bodies with [`Aerodynamics`] move through, such as air.
+///
+/// **Default**: [`Atmosphere::AIR`]
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct Atmosphere {
+    /// The density of the medium.
+    #[cfg_attr(
+        feature = "2d",
+        doc = "The unit is typically kg/m², as densities are per unit area in 2D."
+    )]
+    #[cfg_attr(feature = "3d", doc = "The unit is typically kg/m³.")]
+    ///
+    /// **Default**: `1.225`, the density of air at sea level
+    pub density: f32,
+    /// The velocity of the wind in world space. The unit is typically m/s.
+    ///
+    /// **Default**: Zero
+    pub wind_velocity: Vector,
+}
+
+impl Default for Atmosphere {
+    fn default() -> Self {
+        Self::AIR
+    }
+}
+
+impl Atmosphere {
+    /// Air at sea level, with a density of `1.225` and no wind.
+    pub const AIR: Self = Self::new(1.225);
+
+    /// Creates a new [`Atmosphere`] with the given density and no wind.
+    #[inline]
+    pub const fn new(density: f32) -> Self {
+        Self {
+            density,
+            wind_velocity: Vector::ZERO,
+        }
+    }
+
+    /// Sets the velocity of the wind in world space.
+    #[inline]
+    pub const fn with_wind_velocity(mut self, wind_velocity: Vector) -> Self {
+        self.wind_velocity = wind_velocity;
+        self
+    }
+}
+
+/// Enables shape-based aerodynamic drag and lift for a [`Collider`] attached to a dynamic rigid body.
+///
+/// The forces are computed from the geometry of the collider, the density of the [`Atmosphere`],
+/// and the velocity of the body relative to the wind.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+#[require(AerodynamicSurface)]
+pub struct Aerodynamics {
+    /// The coefficient of the pressure force on each face. Higher values increase both drag and lift.
+    ///
+    /// **Default**: `1.0`
+    pub drag_coefficient: f32,
+    /// The coefficient of lift, scaling the part of the pressure force that is perpendicular to the airflow.
+    /// A value of `0.0` disables lift.
+    ///
+    /// **Default**: `1.0`
+    pub lift_coefficient: f32,
+    /// The coefficient of skin friction, opposing the flow along the surface of each face.
+    /// This limits the speed of gliding bodies.
+    ///
+    /// **Default**: `0.05`
+    pub friction_coefficient: f32,
+}
+
+impl Default for Aerodynamics {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 0.05)
+    }
+}
+
+impl Aerodynamics {
+    /// Creates a new [`Aerodynamics`] component with the given drag, lift, and skin friction coefficients.
+    #[inline]
+    pub const fn new(
+        drag_coefficient: f32,
+        lift_coefficient: f32,
+        friction_coefficient: f32,
+    ) -> Self {
+        Self {
+            drag_coefficient,
+            lift_coefficient,
+            friction_coefficient,
+        }
+    }
+
+    /// Sets the coefficient of the pressure force on each face.
+    #[inline]
+    pub const fn with_drag_coefficient(mut self, drag_coefficient: f32) -> Self {
+        self.drag_coefficient = drag_coefficient;
+        self
+    }
+
+    /// Sets the coefficient of lift.
+    #[inline]
+    pub const fn with_lift_coefficient(mut self, lift_coefficient: f32) -> Self {
+        self.lift_coefficient = lift_coefficient;
+        self
+    }
+
+    /// Sets the coefficient of skin friction.
+    #[inline]
+    pub const fn with_friction_coefficient(mut self, friction_coefficient: f32) -> Self {
+        self.friction_coefficient = friction_coefficient;
+        self
+    }
+}
+
+/// The precomputed surface geometry of a collider with [`Aerodynamics`].
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+struct AerodynamicSurface(AerodynamicGeometry);
+
+/// Recomputes the [`AerodynamicSurface`] of colliders when [`Aerodynamics`] is added or the [`Collider`] changes.
+fn update_aerodynamic_geometry(
+    mut query: Query<
+        (&Collider, &mut AerodynamicSurface),
+        Or<(Added<AerodynamicSurface>, Changed<Collider>)>,
+    >,
+) {
+    for (collider, mut surface) in &mut query {
+        surface.0 = AerodynamicGeometry::from_shape(collider.shape_scaled());
+    }
+}
+
+/// Applies aerodynamic drag and lift to dynamic rigid bodies with colliders that have [`Aerodynamics`].
+fn apply_aerodynamic_forces(
+    colliders: Query<(
+        &Aerodynamics,
+        &AerodynamicSurface,
+        &ColliderOf,
+        &Position,
+        &Rotation,
+    )>,
+    mut bodies: Query<(
+        &RigidBody,
+        Forces,
+        &ComputedMass,
+        &ComputedAngularInertia,
+        &ComputedCenterOfMass,
+    )>,
+    atmosphere: Res<Atmosphere>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+    let wind_velocity = atmosphere.wind_velocity;
+
+    for (aerodynamics, surface, collider_of, position, rotation) in &colliders {
+        let Ok((rb, mut forces, mass, angular_inertia, center_of_mass)) =
+            bodies.get_mut(collider_of.body)
+        else {
+            continue;
+        };
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let rotation = Rot::from(*rotation);
+        let linear_velocity = forces.linear_velocity();
+        let angular_velocity = forces.angular_velocity();
+
+        // Offsets are computed relative to the position of the collider.
+        let center_of_mass =
+            (forces.position() - position.0).f32() + forces.rotation() * center_of_mass.0;
+
+        let velocity_at = |offset: Vector| {
+            #[cfg(feature = "2d")]
+            {
+                linear_velocity + angular_velocity * offset.perp() - wind_velocity
+            }
+            #[cfg(feature = "3d")]
+            {
+                linear_velocity + angular_velocity.cross(offset) - wind_velocity
+            }
+        };
+
+        let pressure_factor = 0.5 * atmosphere.density * aerodynamics.drag_coefficient;
+        let friction_factor = 0.5 * atmosphere.density * aerodynamics.friction_coefficient;
+        let mut force = Vector::ZERO;
+        let mut torque = AngularVector::ZERO;
+
+        for face in &surface.0.faces {
+            let lever_arm = rotation * face.center - center_of_mass;
+            let velocity = velocity_at(lever_arm);
+            let normal = rotation * face.normal;
+
+            let normal_speed = velocity.dot(normal);
+
+            // Skin friction opposes the flow along the surface on every face.
+            let tangential_velocity = velocity - normal * normal_speed;
+            let mut face_force =
+                -tangential_velocity * friction_factor * face.area * tangential_velocity.length();
+
+            // Only faces moving into the medium receive pressure.
+            if normal_speed > 0.0 {
+                let pressure_force = -normal * pressure_factor * face.area * normal_speed.powi(2);
+
+                // Split the force into drag along the airflow and lift perpendicular to it.
+                let flow_direction = velocity.normalize_or_zero();
+                let drag = flow_direction * pressure_force.dot(flow_direction);
+                let lift = pressure_force - drag;
+                face_force += drag + lift * aerodynamics.lift_coefficient;
+            }
+
+            force += face_force;
+            torque += cross(lever_arm, face_force);
+        }
+
+        for ball in &surface.0.balls {
+            let lever_arm = rotation * ball.center - center_of_mass;
+            let velocity = velocity_at(lever_arm);
+            let ball_force = -velocity * pressure_factor * ball.projected_area * velocity.length();
+
+            force += ball_force;
+            torque += cross(lever_arm, ball_force);
+        }
+
+        // Drag is stiff for light bodies with a large surface area. Limit the forces so that they
+        // can't reverse the velocity relative to the medium within a single time step.
+        let relative_speed = (linear_velocity - wind_velocity).length();
+        let delta_speed = force.length() * mass.inverse() * delta_secs;
+        if delta_speed > relative_speed {
+            let scale = relative_speed / delta_speed;
+            force *= scale;
+            torque *= scale;
+        }
+
+        #[cfg(feature = "2d")]
+        let angular_acceleration = angular_inertia.inverse() * torque;
+        #[cfg(feature = "3d")]
+        let angular_acceleration = angular_inertia.rotated(forces.rotation()).inverse() * torque;
+
+        let characteristic_length = surface
+            .0
+            .max_distance_from(rotation.inverse() * center_of_mass)
+            .max(0.01);
+        #[cfg(feature = "2d")]
+        let (angular_speed, delta_angular_speed) = (
+            angular_velocity.abs(),
+            angular_acceleration.abs() * delta_secs,
+        );
+        #[cfg(feature = "3d")]
+        let (angular_speed, delta_angular_speed) = (
+            angular_velocity.length(),
+            angular_acceleration.length() * delta_secs,
+        );
+        let max_delta_angular_speed = angular_speed + relative_speed / characteristic_length;
+        if delta_angular_speed > max_delta_angular_speed {
+            torque *= max_delta_angular_speed / delta_angular_speed;
+        }
+
+        // Like gravity, aerodynamic forces do not wake up sleeping bodies.
+        let mut forces = forces.non_waking();
+        forces.apply_force(force);
+        forces.apply_torque(torque);
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "3d")]
+use core::f32::consts::FRAC_PI_2;
+
+use approx::assert_relative_eq;
+use bevy::prelude::*;
+
+use crate::{prelude::*, tests::utils::create_app};
+
+/// Tests that the pressure drag of a plate is much larger when it falls flat than when it falls edge-first.
+#[test]
+fn aerodynamic_drag_depends_on_orientation() {
+    let mut app = create_app();
+    app.add_plugins(AerodynamicsPlugin);
+    app.finish();
+
+    // A thin 1 kg plate with an area of 1 m² facing down, and the same plate falling edge-first.
+    // Only pressure drag is used, without lift or skin friction.
+    let flat_plate = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 0.1),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 0.1, 1.0),
+            ColliderDensity(10.0),
+            Aerodynamics::new(1.0, 0.0, 0.0),
+            LockedAxes::ROTATION_LOCKED,
+            Position(RVector::X * -2.0),
+        ))
+        .id();
+    let edge_plate = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 0.1),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 0.1, 1.0),
+            ColliderDensity(10.0),
+            Aerodynamics::new(1.0, 0.0, 0.0),
+            LockedAxes::ROTATION_LOCKED,
+            Position(RVector::X * 2.0),
+            #[cfg(feature = "2d")]
+            Rotation::degrees(90.0),
+            #[cfg(feature = "3d")]
+            Rotation(Quat::from_rotation_z(FRAC_PI_2)),
+        ))
+        .id();
+
+    // Run simulation for 3 seconds.
+    for _ in 0..192 {
+        app.update();
+    }
+
+    let flat_velocity = app.world().get::<LinearVelocity>(flat_plate).unwrap();
+    let edge_velocity = app.world().get::<LinearVelocity>(edge_plate).unwrap();
+
+    // The flat plate reaches its terminal velocity of sqrt(2 * m * g / (density * area)) ≈ 4.0 m/s.
+    assert_relative_eq!(flat_velocity.y, -4.0, epsilon = 0.1);
+    assert!(edge_velocity.y < -8.0);
+}
@@ -0,0 +1,364 @@
+//! Buoyancy and drag for rigid bodies submerged in [fluid volumes](FluidVolume).
+//!
+//! # Overview
+//!
+//! A [`FluidVolume`] is a [`Sensor`] collider that applies buoyancy and drag to dynamic rigid bodies
+//! whose colliders overlap it. This can be used for water, lava, or any other liquid that objects
+//! should float in, such as for boats, floating debris, and swimming characters.
+//!
+//! For each collider touching a fluid volume, the submerged volume and its centroid,
+//! the *center of buoyancy*, are computed from the shape of the collider. The buoyant force
+//! is the weight of the displaced fluid:
+//!
+//! ```text
+//! F = fluid_density * submerged_volume * -gravity
+//! ```
+//!
+//! Here, `gravity` is the gravity acting on the body, taking [gravity fields](GravityField) into account.
+//! [`GravityScale`] does not affect buoyancy, as it only scales the gravity acting on the body itself.
+//!
+//! The buoyant force is applied at the center of buoyancy, so bodies also tilt towards a stable orientation.
+//! Linear and angular drag are scaled by the submerged fraction of the collider,
+//! and linear drag pulls bodies towards the [flow velocity](FluidVolume::flow_velocity) of the fluid.
+//!
+//! The forces are computed at each [substep](SubstepCount) from the current positions and velocities
+//! of the bodies, so buoyancy reacts to bodies bobbing and tilting within a time step.
+//! Buoyancy does not wake up [sleeping](Sleeping) bodies.
+//!
+//! # Usage
+//!
+//! Fluids are simulated by the [`FluidPlugin`], which is not included in [`PhysicsPlugins`]
+//! and must be added separately. Add the [`FluidVolume`] component to an entity with a [`Collider`]:
+//!
+//! ```
+#![cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#![cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+//! use bevy::prelude::*;
+//!
+//! fn setup(mut commands: Commands) {
+//!     // A pool of water. `FluidVolume` makes the collider a `Sensor` automatically.
+//!     commands.spawn((
+#![cfg_attr(feature = "2d", doc = "        Collider::rectangle(20.0, 5.0),")]
+#![cfg_attr(feature = "3d", doc = "        Collider::cuboid(20.0, 5.0, 20.0),")]
+//!         FluidVolume::WATER.with_linear_drag(0.5),
+//!         Transform::from_xyz(0.0, -2.5, 0.0),
+//!     ));
+//!
+//!     // A wooden crate that floats with about half of its volume submerged.
+//!     commands.spawn((
+//!         RigidBody::Dynamic,
+#![cfg_attr(feature = "2d", doc = "        Collider::rectangle(1.0, 1.0),")]
+#![cfg_attr(feature = "3d", doc = "        Collider::cuboid(1.0, 1.0, 1.0),")]
+//!         ColliderDensity(500.0),
+//!         Transform::from_xyz(0.0, 2.0, 0.0),
+//!     ));
+//! }
+//! ```
+//!
+//! Note that the default [`ColliderDensity`] is `1.0`, which is very light compared to water.
+//! Bodies are only partially submerged if their density is lower than that of the fluid.
+#![cfg_attr(
+    feature = "2d",
+    doc = "In 2D, densities are per unit area, and the submerged volume is an area."
+)]
+//!
+//! # Fluid Surface
+//!
+//! The surface of the fluid is at the top of the bounding box of the fluid volume's collider, where "up" is
+//! the direction opposite to the gravity acting on the submerged body. This is [`Gravity`],
+//! unless the body is inside a [`GravityField`], in which case the gravity of the field is used instead.
+//! The surface can be animated using [`FluidVolume::wave_height`],
+//! which is sampled at the position of each submerged collider.
+//!
+//! The fluid is assumed to extend horizontally across the whole submerged part of each touching collider.
+//!
+//! # Supported Shapes
+//!
+//! Balls are handled analytically. Cuboids, convex hulls, and capsules are handled as polygons
+#![cfg_attr(feature = "2d", doc = "(with capsules tessellated),")]
+#![cfg_attr(
+    feature = "3d",
+    doc = "or polyhedra (with capsules, cylinders, and cones tessellated),"
+)]
+//! and compound shapes combine the results of their subshapes. Rounded shapes ignore their rounding.
+//! Other shapes such as triangle meshes and heightfields do not receive buoyancy.
+
+mod submerged_volume;
+#[cfg(all(test, any(feature = "parry-f32", feature = "parry-f64")))]
+mod tests;
+
+use crate::{
+    dynamics::{
+        force_field::GravityFieldAccelerations,
+        integrator::{self, CustomVelocityIntegration, IntegrationSystems},
+        solver::solver_body::{SolverBodies, SolverBodyIndex},
+    },
+    math::cross,
+    prelude::*,
+};
+use bevy::prelude::*;
+use submerged_volume::{SurfacePlane, submerged_volume};
+
+/// A plugin for applying buoyancy and drag to rigid bodies submerged in [fluid volumes](FluidVolume).
+///
+/// See the [module-level documentation](self) for more information.
+pub struct FluidPlugin;
+
+impl Plugin for FluidPlugin {
+    fn build(&self, app: &mut App) {
+        // Buoyancy depends on the submerged volume, which changes as bodies move and rotate,
+        // so the forces are computed in the substepping loop.
+        app.add_systems(
+            SubstepSchedule,
+            apply_fluid_forces
+                .in_set(IntegrationSystems::Velocity)
+                .after(ForceSystems::ApplyLocalAcceleration)
+                .before(integrator::integrate_velocities),
+        );
+    }
+}
+
+/// A function that returns the height offset of a fluid surface at the given world position
+/// and elapsed physics time in seconds.
+///
+/// The offset is along the up direction, opposite to the gravity acting on the submerged body.
+pub type WaveHeightFn = fn(position: Vector, elapsed_secs: f32) -> f32;
+
+/// A [`Sensor`] collider that applies buoyancy and drag to overlapping dynamic rigid bodies.
+///
+/// See the [module-level documentation](self) for more information.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A river with gentle waves that carries floating bodies along the X axis.
+///     commands.spawn((
+#[cfg_attr(feature = "2d", doc = "        Collider::rectangle(100.0, 5.0),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::cuboid(100.0, 5.0, 10.0),")]
+///         FluidVolume::WATER
+#[cfg_attr(
+    feature = "2d",
+    doc = "            .with_flow_velocity(Vec2::new(2.0, 0.0))"
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "            .with_flow_velocity(Vec3::new(2.0, 0.0, 0.0))"
+)]
+///             .with_wave_height(|position, time| 0.2 * (position.x + time).sin()),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+#[require(Sensor)]
+pub struct FluidVolume {
+    /// The density of the fluid.
+    #[cfg_attr(
+        feature = "2d",
+        doc = "The unit is typically kg/m², as densities are per unit area in 2D."
+    )]
+    #[cfg_attr(feature = "3d", doc = "The unit is typically kg/m³.")]
+    ///
+    /// **Default**: `1000.0`, the density of water
+    pub density: f32,
+    /// The coefficient of linear drag, scaled by the submerged fraction of a collider.
+    /// It works like [`LinearDamping`], pulling the velocity of submerged bodies
+    /// towards the [`flow_velocity`](Self::flow_velocity).
+    ///
+    /// **Default**: `1.0`
+    pub linear_drag: f32,
+    /// The coefficient of angular drag, scaled by the submerged fraction of a collider.
+    /// It works like [`AngularDamping`].
+    ///
+    /// **Default**: `1.0`
+    pub angular_drag: f32,
+    /// The velocity of the fluid in world space, used for currents and rivers.
+    ///
+    /// **Default**: Zero
+    pub flow_velocity: Vector,
+    /// An optional function for animating the height of the fluid surface,
+    /// for example to simulate waves.
+    ///
+    /// **Default**: `None`
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub wave_height: Option<WaveHeightFn>,
+}
+
+impl Default for FluidVolume {
+    fn default() -> Self {
+        Self::WATER
+    }
+}
+
+impl FluidVolume {
+    /// A fluid volume with the density of water, `1000.0`, and default drag.
+    pub const WATER: Self = Self::new(1000.0);
+
+    /// Creates a new [`FluidVolume`] with the given density and default drag.
+    #[inline]
+    pub const fn new(density: f32) -> Self {
+        Self {
+            density,
+            linear_drag: 1.0,
+            angular_drag: 1.0,
+            flow_velocity: Vector::ZERO,
+            wave_height: None,
+        }
+    }
+
+    /// Sets the coefficient of linear drag.
+    #[inline]
+    pub const fn with_linear_drag(mut self, linear_drag: f32) -> Self {
+        self.linear_drag = linear_drag;
+        self
+    }
+
+    /// Sets the coefficient of angular drag.
+    #[inline]
+    pub const fn with_angular_drag(mut self, angular_drag: f32) -> Self {
+        self.angular_drag = angular_drag;
+        self
+    }
+
+    /// Sets the velocity of the fluid in world space.
+    #[inline]
+    pub const fn with_flow_velocity(mut self, flow_velocity: Vector) -> Self {
+        self.flow_velocity = flow_velocity;
+        self
+    }
+
+    /// Sets the function used for animating the height of the fluid surface.
+    #[inline]
+    pub const fn with_wave_height(mut self, wave_height: WaveHeightFn) -> Self {
+        self.wave_height = Some(wave_height);
+        self
+    }
+}
+
+/// Applies buoyancy and drag to dynamic rigid bodies with colliders touching a [`FluidVolume`].
+///
+/// This runs in the substepping loop, using the positions and velocities of the bodies at each substep.
+pub(crate) fn apply_fluid_forces(
+    fluids: Query<(Entity, &FluidVolume, &Collider, &Position, &Rotation)>,
+    colliders: Query<(&Collider, &ColliderOf, &Position, &Rotation), Without<Sensor>>,
+    bodies: Query<
+        (
+            &SolverBodyIndex,
+            &Position,
+            &Rotation,
+            &ComputedCenterOfMass,
+        ),
+        Without<CustomVelocityIntegration>,
+    >,
+    mut solver_bodies: ResMut<SolverBodies>,
+    contact_graph: Res<ContactGraph>,
+    gravity: Res<Gravity>,
+    gravity_fields: Option<Res<GravityFieldAccelerations>>,
+    time: Res<Time<Substeps>>,
+    physics_time: Res<Time<Physics>>,
+) {
+    let delta_secs = time.delta_secs();
+    let elapsed_secs = physics_time.elapsed_secs();
+
+    for (fluid_entity, fluid, fluid_collider, fluid_position, fluid_rotation) in &fluids {
+        // The AABB is computed without margins to get the exact top of the shape.
+        let fluid_aabb = fluid_collider.aabb(fluid_position.0, *fluid_rotation, 0.0);
+        let aabb_center = fluid_aabb.min.midpoint(fluid_aabb.max);
+        let aabb_half_extents = fluid_aabb.size() * 0.5;
+
+        for contact_pair in contact_graph.contact_pairs_with(fluid_entity) {
+            if !contact_pair.is_touching() {
+                continue;
+            }
+
+            let other = if contact_pair.collider1 == fluid_entity {
+                contact_pair.collider2
+            } else {
+                contact_pair.collider1
+            };
+
+            let Ok((collider, collider_of, collider_position, collider_rotation)) =
+                colliders.get(other)
+            else {
+                continue;
+            };
+            let Ok((index, body_position, body_rotation, center_of_mass)) =
+                bodies.get(collider_of.body)
+            else {
+                continue;
+            };
+
+            // Sleeping bodies don't have a solver body, so buoyancy does not wake them up.
+            let Some(inertia) = solver_bodies.get_inertia(*index) else {
+                continue;
+            };
+            let inv_mass = inertia.effective_inv_mass();
+            let inv_angular_inertia = inertia.effective_inv_angular_inertia();
+            let Some(body) = solver_bodies.get_mut(*index) else {
+                continue;
+            };
+            if !body.flags.is_dynamic() {
+                continue;
+            }
+
+            // Use the gravity acting on the body, which may be overridden by a gravity field.
+            let body_gravity = gravity_fields.as_ref().map_or(gravity.0, |fields| {
+                fields.get_or(collider_of.body, gravity.0)
+            });
+            let up = (-body_gravity).try_normalize().unwrap_or(Vector::Y);
+
+            // The surface is at the top of the AABB along the up direction.
+            let surface_height = up.dot(aabb_center) + up.abs().dot(aabb_half_extents);
+
+            // Compute the pose of the collider at the current substep.
+            // The body moves and rotates around its center of mass during the substeps.
+            let old_center_of_mass = body_position.f32() + *body_rotation * center_of_mass.0;
+            let center_of_mass = old_center_of_mass + body.delta_position;
+            let offset = body.delta_rotation * (collider_position.f32() - old_center_of_mass);
+            let position = center_of_mass + offset;
+            let rotation = body.delta_rotation * Rot::from(*collider_rotation);
+
+            // Compute the submerged volume in the local space of the collider.
+            let wave_offset = fluid
+                .wave_height
+                .map_or(0.0, |wave_height| wave_height(position, elapsed_secs));
+            let plane = SurfacePlane {
+                normal: rotation.inverse() * up,
+                height: surface_height + wave_offset - up.dot(position),
+            };
+            let submerged = submerged_volume(collider.shape_scaled(), plane);
+
+            if submerged.volume <= 0.0 {
+                continue;
+            }
+
+            let total_volume = collider.mass(1.0);
+            let submerged_fraction = (submerged.volume / total_volume).min(1.0);
+            let center_of_buoyancy = position + rotation * submerged.centroid;
+            let locked_axes = body.flags.locked_axes();
+
+            // Apply buoyancy at the center of buoyancy.
+            let buoyancy = -body_gravity * fluid.density * submerged.volume;
+            let torque = cross(center_of_buoyancy - center_of_mass, buoyancy);
+            body.linear_velocity += inv_mass * buoyancy * delta_secs;
+            body.angular_velocity += inv_angular_inertia * torque * delta_secs;
+
+            // Apply drag relative to the flow of the fluid.
+            let relative_velocity = body.linear_velocity - fluid.flow_velocity;
+            body.linear_velocity += locked_axes.apply_to_vec(
+                -fluid.linear_drag * submerged_fraction * relative_velocity * delta_secs,
+            );
+            body.angular_velocity += locked_axes.apply_to_angular_velocity(
+                -fluid.angular_drag * submerged_fraction * body.angular_velocity * delta_secs,
+            );
+        }
+    }
+}
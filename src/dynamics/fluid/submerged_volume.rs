@@ -0,0 +1,315 @@
+//! Computes the submerged volume and center of buoyancy of collider shapes below a fluid surface.
+
+use crate::prelude::*;
+use parry::shape::{SharedShape, TypedShape};
+
+/// The number of subdivisions used for tessellating rounded shapes like capsules.
+const SUBDIVISIONS: u32 = 8;
+
+/// The submerged part of a shape below a fluid surface.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct SubmergedVolume {
+    /// The submerged volume, or area in 2D.
+    pub volume: f32,
+    /// The centroid of the submerged volume in the local space of the shape.
+    /// This is the center of buoyancy.
+    pub centroid: Vector,
+}
+
+impl SubmergedVolume {
+    /// Combines two submerged volumes, weighting the centroids by their volumes.
+    fn merge(self, other: Self) -> Self {
+        let volume = self.volume + other.volume;
+        if volume <= f32::EPSILON {
+            return Self::default();
+        }
+        Self {
+            volume,
+            centroid: (self.centroid * self.volume + other.centroid * other.volume) / volume,
+        }
+    }
+}
+
+/// A plane representing the fluid surface in the local space of a shape.
+///
+/// Points `p` with `normal.dot(p) < height` are submerged.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct SurfacePlane {
+    /// The up direction of the fluid surface.
+    pub normal: Vector,
+    /// The signed height of the surface along the [`normal`](Self::normal).
+    pub height: f32,
+}
+
+impl SurfacePlane {
+    /// Returns the signed depth of the given point above the surface.
+    /// Negative values are submerged.
+    #[inline]
+    fn depth(&self, point: Vector) -> f32 {
+        self.normal.dot(point) - self.height
+    }
+
+    /// Transforms the plane into the local space of a shape at the given pose.
+    #[inline]
+    fn to_local(self, translation: Vector, rotation: Rot) -> Self {
+        Self {
+            normal: rotation.inverse() * self.normal,
+            height: self.height - self.normal.dot(translation),
+        }
+    }
+}
+
+/// Computes the submerged volume and center of buoyancy of the given shape
+/// below the given surface plane, expressed in the local space of the shape.
+///
+/// Balls are handled analytically, while cuboids, convex shapes, and capsules
+/// are handled as (tessellated) polygons or polyhedra. Compound shapes are handled
+/// by combining the results of their subshapes. Other shapes are not supported,
+/// and have no submerged volume.
+pub(super) fn submerged_volume(shape: &SharedShape, plane: SurfacePlane) -> SubmergedVolume {
+    match shape.as_typed_shape() {
+        TypedShape::Ball(ball) => submerged_ball(ball.radius.f32(), plane),
+        #[cfg(feature = "2d")]
+        TypedShape::Cuboid(cuboid) => submerged_polygon(&cuboid.to_polyline(), plane),
+        #[cfg(feature = "2d")]
+        TypedShape::ConvexPolygon(polygon) => submerged_polygon(polygon.points(), plane),
+        #[cfg(feature = "2d")]
+        TypedShape::Capsule(capsule) => {
+            submerged_polygon(&capsule.to_polyline(SUBDIVISIONS), plane)
+        }
+        #[cfg(feature = "2d")]
+        TypedShape::RoundCuboid(round_shape) => {
+            submerged_polygon(&round_shape.to_polyline(SUBDIVISIONS), plane)
+        }
+        #[cfg(feature = "2d")]
+        TypedShape::RoundConvexPolygon(round_shape) => {
+            submerged_polygon(&round_shape.to_polyline(SUBDIVISIONS), plane)
+        }
+        #[cfg(feature = "3d")]
+        TypedShape::Cuboid(cuboid) => {
+            let (vertices, indices) = cuboid.to_trimesh();
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        #[cfg(feature = "3d")]
+        TypedShape::ConvexPolyhedron(polyhedron) => {
+            let (vertices, indices) = polyhedron.to_trimesh();
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        #[cfg(feature = "3d")]
+        TypedShape::Capsule(capsule) => {
+            let (vertices, indices) = capsule.to_trimesh(SUBDIVISIONS, SUBDIVISIONS / 2);
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        #[cfg(feature = "3d")]
+        TypedShape::Cylinder(cylinder) => {
+            let (vertices, indices) = cylinder.to_trimesh(SUBDIVISIONS);
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        #[cfg(feature = "3d")]
+        TypedShape::Cone(cone) => {
+            let (vertices, indices) = cone.to_trimesh(SUBDIVISIONS);
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        // Rounded shapes ignore the rounding and use the inner shape.
+        #[cfg(feature = "3d")]
+        TypedShape::RoundCuboid(round_shape) => {
+            let (vertices, indices) = round_shape.inner_shape.to_trimesh();
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        #[cfg(feature = "3d")]
+        TypedShape::RoundConvexPolyhedron(round_shape) => {
+            let (vertices, indices) = round_shape.inner_shape.to_trimesh();
+            submerged_polyhedron(&vertices, &indices, plane)
+        }
+        TypedShape::Compound(compound) => {
+            compound
+                .shapes()
+                .iter()
+                .fold(SubmergedVolume::default(), |acc, (sub_pos, shape)| {
+                    let translation = sub_pos.translation.f32();
+                    #[cfg(feature = "2d")]
+                    let rotation = Rot::from_sin_cos(
+                        sub_pos.rotation.sin().f32(),
+                        sub_pos.rotation.cos().f32(),
+                    );
+                    #[cfg(feature = "3d")]
+                    let rotation = sub_pos.rotation.f32();
+
+                    let mut sub_volume =
+                        submerged_volume(shape, plane.to_local(translation, rotation));
+                    sub_volume.centroid = translation + rotation * sub_volume.centroid;
+                    acc.merge(sub_volume)
+                })
+        }
+        _ => SubmergedVolume::default(),
+    }
+}
+
+/// Computes the submerged area and centroid of a circle analytically.
+#[cfg(feature = "2d")]
+fn submerged_ball(radius: f32, plane: SurfacePlane) -> SubmergedVolume {
+    use core::f32::consts::PI;
+
+    // The depth of the lowest point of the circle below the surface.
+    let depth = (plane.height + radius).clamp(0.0, 2.0 * radius);
+
+    if depth <= 0.0 {
+        return SubmergedVolume::default();
+    } else if depth >= 2.0 * radius {
+        return SubmergedVolume {
+            volume: PI * radius * radius,
+            centroid: Vector::ZERO,
+        };
+    }
+
+    // The half-angle of the circular segment.
+    let half_angle = ((radius - depth) / radius).acos();
+    let (sin, cos) = half_angle.sin_cos();
+    let area = radius * radius * (half_angle - sin * cos);
+
+    // The distance from the center of the circle to the centroid of the segment.
+    let distance = 2.0 * radius * sin * sin * sin / (3.0 * (half_angle - sin * cos));
+
+    SubmergedVolume {
+        volume: area,
+        centroid: -plane.normal * distance,
+    }
+}
+
+/// Computes the submerged volume and centroid of a sphere analytically.
+#[cfg(feature = "3d")]
+fn submerged_ball(radius: f32, plane: SurfacePlane) -> SubmergedVolume {
+    use core::f32::consts::PI;
+
+    // The height of the submerged spherical cap.
+    let depth = (plane.height + radius).clamp(0.0, 2.0 * radius);
+
+    if depth <= 0.0 {
+        return SubmergedVolume::default();
+    }
+
+    let volume = PI * depth * depth * (3.0 * radius - depth) / 3.0;
+
+    // The distance from the center of the sphere to the centroid of the spherical cap.
+    let distance = 3.0 * (2.0 * radius - depth).powi(2) / (4.0 * (3.0 * radius - depth));
+
+    SubmergedVolume {
+        volume,
+        centroid: -plane.normal * distance,
+    }
+}
+
+/// Computes the submerged area and centroid of a closed polygon.
+///
+/// Each edge is clipped against the surface, and the area is accumulated
+/// from triangles formed with a reference point on the surface.
+/// The closing edge along the surface contributes no area, so it can be omitted.
+#[cfg(feature = "2d")]
+fn submerged_polygon(vertices: &[parry::math::Vector], plane: SurfacePlane) -> SubmergedVolume {
+    let reference = plane.normal * plane.height;
+
+    let mut area = 0.0;
+    let mut weighted_centroid = Vector::ZERO;
+
+    for i in 0..vertices.len() {
+        let a = vertices[i].f32();
+        let b = vertices[(i + 1) % vertices.len()].f32();
+
+        let Some([a, b]) = clip_segment(a, b, plane) else {
+            continue;
+        };
+
+        let triangle_area = 0.5 * (a - reference).perp_dot(b - reference);
+        area += triangle_area;
+        weighted_centroid += triangle_area * (reference + a + b) / 3.0;
+    }
+
+    if area.abs() <= f32::EPSILON {
+        return SubmergedVolume::default();
+    }
+
+    SubmergedVolume {
+        volume: area.abs(),
+        centroid: weighted_centroid / area,
+    }
+}
+
+/// Clips the segment from `a` to `b` to the submerged side of the surface.
+#[cfg(feature = "2d")]
+fn clip_segment(a: Vector, b: Vector, plane: SurfacePlane) -> Option<[Vector; 2]> {
+    let depth_a = plane.depth(a);
+    let depth_b = plane.depth(b);
+
+    match (depth_a < 0.0, depth_b < 0.0) {
+        (true, true) => Some([a, b]),
+        (false, false) => None,
+        (true, false) => Some([a, a.lerp(b, depth_a / (depth_a - depth_b))]),
+        (false, true) => Some([a.lerp(b, depth_a / (depth_a - depth_b)), b]),
+    }
+}
+
+/// Computes the submerged volume and centroid of a closed triangle mesh.
+///
+/// Each triangle is clipped against the surface, and the volume is accumulated
+/// from tetrahedra formed with a reference point on the surface. The cap along
+/// the surface contributes no volume, so it does not need to be constructed.
+///
+/// This is based on "Exact Buoyancy for Polyhedra" by Erin Catto in Game Programming Gems 6.
+#[cfg(feature = "3d")]
+fn submerged_polyhedron(
+    vertices: &[parry::math::Vector],
+    indices: &[[u32; 3]],
+    plane: SurfacePlane,
+) -> SubmergedVolume {
+    let reference = plane.normal * plane.height;
+
+    let mut volume = 0.0;
+    let mut weighted_centroid = Vector::ZERO;
+
+    let mut add_tetrahedron = |a: Vector, b: Vector, c: Vector| {
+        let tetrahedron_volume = (a - reference).dot((b - reference).cross(c - reference)) / 6.0;
+        volume += tetrahedron_volume;
+        weighted_centroid += tetrahedron_volume * (reference + a + b + c) / 4.0;
+    };
+
+    for [i1, i2, i3] in indices {
+        let triangle = [
+            vertices[*i1 as usize].f32(),
+            vertices[*i2 as usize].f32(),
+            vertices[*i3 as usize].f32(),
+        ];
+
+        // Clip the triangle against the surface, resulting in up to four vertices.
+        let mut clipped = [Vector::ZERO; 4];
+        let mut count = 0;
+        for i in 0..3 {
+            let a = triangle[i];
+            let b = triangle[(i + 1) % 3];
+            let depth_a = plane.depth(a);
+            let depth_b = plane.depth(b);
+
+            if depth_a < 0.0 {
+                clipped[count] = a;
+                count += 1;
+            }
+            if (depth_a < 0.0) != (depth_b < 0.0) {
+                clipped[count] = a.lerp(b, depth_a / (depth_a - depth_b));
+                count += 1;
+            }
+        }
+
+        // Triangulate the clipped polygon as a fan.
+        for i in 1..count.saturating_sub(1) {
+            add_tetrahedron(clipped[0], clipped[i], clipped[i + 1]);
+        }
+    }
+
+    if volume.abs() <= f32::EPSILON {
+        return SubmergedVolume::default();
+    }
+
+    SubmergedVolume {
+        volume: volume.abs(),
+        centroid: weighted_centroid / volume,
+    }
+}
@@ -0,0 +1,59 @@
+use approx::assert_relative_eq;
+use bevy::prelude::*;
+
+use crate::{prelude::*, tests::utils::create_app};
+
+/// Tests that a light body floats half-submerged in a [`FluidVolume`], while a heavy body sinks.
+#[test]
+fn bodies_float_in_fluid_volume() {
+    let mut app = create_app();
+    app.add_plugins(FluidPlugin);
+    app.finish();
+
+    // A pool of water with the surface at `y = 0`.
+    app.world_mut().spawn((
+        #[cfg(feature = "2d")]
+        Collider::rectangle(20.0, 10.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(20.0, 10.0, 20.0),
+        // Use strong drag so that the bodies settle quickly.
+        FluidVolume::WATER.with_linear_drag(5.0),
+        Position(RVector::NEG_Y * 5.0),
+    ));
+
+    let light_box = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 1.0, 1.0),
+            ColliderDensity(500.0),
+            Position(RVector::X * -2.0 + RVector::Y),
+        ))
+        .id();
+    let heavy_box = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::rectangle(1.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 1.0, 1.0),
+            ColliderDensity(2000.0),
+            Position(RVector::X * 2.0 + RVector::Y),
+        ))
+        .id();
+
+    // Run simulation for 10 seconds.
+    for _ in 0..640 {
+        app.update();
+    }
+
+    let light_position = app.world().get::<Position>(light_box).unwrap();
+    let heavy_position = app.world().get::<Position>(heavy_box).unwrap();
+
+    assert_relative_eq!(light_position.y, 0.0, epsilon = 0.05);
+    assert!(heavy_position.y < -2.0);
+}
@@ -0,0 +1,627 @@
+//! Force fields that apply forces to rigid bodies inside [`Sensor`] regions,
+//! and [gravity fields](GravityField) that override [`Gravity`].
+//!
+//! # Overview
+//!
+//! A [`ForceField`] is a [`Sensor`] collider that accelerates the dynamic rigid bodies
+//! whose colliders overlap it. The shape of the region is determined by the collider,
+//! and the behavior of the field by its [`ForceFieldKind`]:
+//!
+//! - [`Radial`](ForceFieldKind::Radial): Attracts or repels bodies from the center of the field.
+//! - [`Directional`](ForceFieldKind::Directional): Accelerates bodies in a fixed direction.
+//! - [`Wind`](ForceFieldKind::Wind): Drags bodies towards the velocity of the wind.
+//! - [`Vortex`](ForceFieldKind::Vortex): Swirls bodies around the center of the field.
+//!
+//! The strength of a field can be reduced further away from its center with a [`ForceFieldFalloff`],
+//! and varied over time and space with [`ForceFieldTurbulence`].
+//!
+//! A [`GravityField`] instead *replaces* [`Gravity`] for the bodies inside it. This can be used
+//! for planets with radial gravity, zero-g rooms, or areas with sideways gravity.
+//! If a body is inside several gravity fields, the one with the highest
+//! [priority](GravityField::priority) is used.
+//!
+//! # Usage
+//!
+//! Fields are applied by the [`ForceFieldPlugin`], which is not included in [`PhysicsPlugins`]
+//! and must be added separately.
+//!
+//! ```
+#![cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#![cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+//! use bevy::prelude::*;
+//!
+//! fn setup(mut commands: Commands) {
+//!     // A black hole that pulls in bodies within a radius of 10 meters.
+//!     commands.spawn((
+#![cfg_attr(feature = "2d", doc = "        Collider::circle(10.0),")]
+#![cfg_attr(feature = "3d", doc = "        Collider::sphere(10.0),")]
+//!         ForceField::radial(50.0).with_falloff(ForceFieldFalloff::Linear { radius: 10.0 }),
+//!     ));
+//!
+//!     // A gusty wind blowing along the X axis.
+//!     commands.spawn((
+#![cfg_attr(feature = "2d", doc = "        Collider::rectangle(20.0, 10.0),")]
+#![cfg_attr(feature = "3d", doc = "        Collider::cuboid(20.0, 10.0, 20.0),")]
+#![cfg_attr(
+    feature = "2d",
+    doc = "        ForceField::wind(Vec2::new(8.0, 0.0), 0.5).with_turbulence(3.0, 0.5),"
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "        ForceField::wind(Vec3::new(8.0, 0.0, 0.0), 0.5).with_turbulence(3.0, 0.5),"
+)]
+//!     ));
+//!
+//!     // A zero-g room.
+//!     commands.spawn((
+#![cfg_attr(feature = "2d", doc = "        Collider::rectangle(10.0, 10.0),")]
+#![cfg_attr(feature = "3d", doc = "        Collider::cuboid(10.0, 10.0, 10.0),")]
+//!         GravityField::ZERO,
+//!     ));
+//! }
+//! ```
+//!
+//! # Filtering and Sleeping
+//!
+//! Fields use the contacts of their [`Sensor`] colliders, so they respect [`CollisionLayers`]
+//! like any other sensor. Only dynamic bodies are affected.
+//!
+//! Force fields only wake up [sleeping](Sleeping) bodies if the field actually accelerates them,
+//! for example not outside the radius of a [`ForceFieldFalloff`]. Like [`Gravity`],
+//! gravity fields never wake up sleeping bodies.
+//!
+//! The accelerations are computed once per time step, and applied over all [substeps](SubstepCount).
+//! [`GravityScale`] scales the acceleration of [`GravityField`]s, but not of [`ForceField`]s.
+
+#[cfg(all(test, any(feature = "parry-f32", feature = "parry-f64")))]
+mod tests;
+
+use crate::{
+    dynamics::integrator::{self, IntegrationSystems},
+    prelude::*,
+};
+use bevy::{ecs::entity::EntityHashMap, prelude::*};
+
+/// A plugin for applying [force fields](ForceField) and [gravity fields](GravityField) to rigid bodies.
+///
+/// See the [module-level documentation](self) for more information.
+pub struct ForceFieldPlugin;
+
+impl Plugin for ForceFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GravityFieldAccelerations>();
+
+        app.add_systems(
+            PhysicsSchedule,
+            (apply_gravity_fields, apply_force_fields)
+                .chain()
+                .in_set(IntegrationSystems::UpdateVelocityIncrements)
+                .after(ForceSystems::ApplyConstantForces)
+                .before(integrator::pre_process_velocity_increments),
+        );
+    }
+}
+
+/// A [`Sensor`] collider that accelerates overlapping dynamic rigid bodies.
+///
+/// See the [module-level documentation](self) for more information.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A tornado that swirls bodies around and pulls them inwards.
+///     commands.spawn((
+#[cfg_attr(feature = "2d", doc = "        Collider::circle(15.0),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::cylinder(15.0, 40.0),")]
+///         ForceField::vortex(20.0, 5.0).with_falloff(ForceFieldFalloff::Smooth { radius: 15.0 }),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+#[require(Sensor)]
+pub struct ForceField {
+    /// The behavior of the force field.
+    pub kind: ForceFieldKind,
+    /// How the strength of the field decreases with distance from its center.
+    ///
+    /// **Default**: [`ForceFieldFalloff::None`]
+    pub falloff: ForceFieldFalloff,
+    /// Random-looking variation added to the acceleration of the field.
+    ///
+    /// **Default**: `None`
+    pub turbulence: Option<ForceFieldTurbulence>,
+}
+
+impl ForceField {
+    /// Creates a new [`ForceField`] with the given kind, no falloff, and no turbulence.
+    #[inline]
+    pub const fn new(kind: ForceFieldKind) -> Self {
+        Self {
+            kind,
+            falloff: ForceFieldFalloff::None,
+            turbulence: None,
+        }
+    }
+
+    /// Creates a [radial](ForceFieldKind::Radial) force field with the given strength.
+    ///
+    /// A positive strength attracts bodies towards the center, while a negative strength repels them.
+    #[inline]
+    pub const fn radial(strength: f32) -> Self {
+        Self::new(ForceFieldKind::Radial { strength })
+    }
+
+    /// Creates a [directional](ForceFieldKind::Directional) force field
+    /// with the given acceleration in the local space of the field.
+    #[inline]
+    pub const fn directional(acceleration: Vector) -> Self {
+        Self::new(ForceFieldKind::Directional { acceleration })
+    }
+
+    /// Creates a [wind](ForceFieldKind::Wind) force field with the given velocity
+    /// in the local space of the field, and the given drag coefficient.
+    #[inline]
+    pub const fn wind(velocity: Vector, drag: f32) -> Self {
+        Self::new(ForceFieldKind::Wind { velocity, drag })
+    }
+
+    /// Creates a [vortex](ForceFieldKind::Vortex) force field with the given
+    /// tangential and inward strengths.
+    #[inline]
+    pub const fn vortex(strength: f32, inward_strength: f32) -> Self {
+        Self::new(ForceFieldKind::Vortex {
+            strength,
+            inward_strength,
+        })
+    }
+
+    /// Sets the [falloff](ForceFieldFalloff) of the field.
+    #[inline]
+    pub const fn with_falloff(mut self, falloff: ForceFieldFalloff) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Sets the [turbulence](ForceFieldTurbulence) of the field
+    /// with the given strength and frequency.
+    #[inline]
+    pub const fn with_turbulence(mut self, strength: f32, frequency: f32) -> Self {
+        self.turbulence = Some(ForceFieldTurbulence {
+            strength,
+            frequency,
+        });
+        self
+    }
+
+    /// Computes the acceleration applied by the field to a body.
+    ///
+    /// `offset` is the position of the body relative to the center of the field,
+    /// and `rotation` is the rotation of the field.
+    pub fn acceleration(
+        &self,
+        offset: Vector,
+        rotation: Rot,
+        velocity: Vector,
+        elapsed_secs: f32,
+    ) -> Vector {
+        let acceleration = match self.kind {
+            ForceFieldKind::Radial { strength } => -offset.normalize_or_zero() * strength,
+            ForceFieldKind::Directional { acceleration } => rotation * acceleration,
+            ForceFieldKind::Wind {
+                velocity: wind_velocity,
+                drag,
+            } => (rotation * wind_velocity - velocity) * drag,
+            ForceFieldKind::Vortex {
+                strength,
+                inward_strength,
+            } => {
+                #[cfg(feature = "2d")]
+                let radial = offset;
+                #[cfg(feature = "3d")]
+                let radial = offset.reject_from_normalized(rotation * Vector::Y);
+                let radial = radial.normalize_or_zero();
+
+                #[cfg(feature = "2d")]
+                let tangent = radial.perp();
+                #[cfg(feature = "3d")]
+                let tangent = (rotation * Vector::Y).cross(radial);
+
+                tangent * strength - radial * inward_strength
+            }
+        };
+
+        let turbulence = self.turbulence.map_or(Vector::ZERO, |turbulence| {
+            turbulence.sample(offset, elapsed_secs)
+        });
+
+        (acceleration + turbulence) * self.falloff.factor(offset.length())
+    }
+}
+
+/// The behavior of a [`ForceField`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub enum ForceFieldKind {
+    /// Accelerates bodies towards the center of the field.
+    ///
+    /// A positive strength attracts bodies, while a negative strength repels them.
+    Radial {
+        /// The acceleration towards the center of the field. The unit is typically m/s².
+        strength: f32,
+    },
+    /// Accelerates bodies in a fixed direction, for example for updrafts or conveyor zones.
+    Directional {
+        /// The acceleration in the local space of the field. The unit is typically m/s².
+        acceleration: Vector,
+    },
+    /// Drags bodies towards the velocity of the wind.
+    Wind {
+        /// The velocity of the wind in the local space of the field. The unit is typically m/s.
+        velocity: Vector,
+        /// The drag coefficient. Higher values make bodies reach the wind velocity faster.
+        /// The unit is typically 1/s.
+        drag: f32,
+    },
+    /// Swirls bodies around the center of the field.
+    #[cfg_attr(
+        feature = "3d",
+        doc = "",
+        doc = "The axis of rotation is the local Y axis of the field."
+    )]
+    Vortex {
+        /// The tangential acceleration around the center of the field. The unit is typically m/s².
+        ///
+        /// Positive values swirl bodies counterclockwise around the axis.
+        strength: f32,
+        /// The acceleration towards the axis of the field. The unit is typically m/s².
+        inward_strength: f32,
+    },
+}
+
+/// Describes how the strength of a [`ForceField`] or [`GravityField`]
+/// decreases with distance from the center of the field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq)]
+pub enum ForceFieldFalloff {
+    /// The strength is constant everywhere inside the field.
+    #[default]
+    None,
+    /// The strength decreases linearly from full strength at the center to zero at the radius.
+    Linear {
+        /// The distance at which the strength reaches zero.
+        radius: f32,
+    },
+    /// The strength decreases smoothly from full strength at the center to zero at the radius.
+    Smooth {
+        /// The distance at which the strength reaches zero.
+        radius: f32,
+    },
+    /// The strength decreases with the square of the distance, like gravity.
+    /// The strength is full at a distance of one unit.
+    InverseSquare {
+        /// The minimum distance used for computing the falloff,
+        /// preventing extreme accelerations near the center.
+        min_distance: f32,
+    },
+}
+
+impl ForceFieldFalloff {
+    /// Returns the factor by which the strength of a field is multiplied at the given distance.
+    pub fn factor(self, distance: f32) -> f32 {
+        match self {
+            Self::None => 1.0,
+            Self::Linear { radius } => (1.0 - distance / radius).max(0.0),
+            Self::Smooth { radius } => {
+                let t = (1.0 - distance / radius).clamp(0.0, 1.0);
+                t * t * (3.0 - 2.0 * t)
+            }
+            Self::InverseSquare { min_distance } => 1.0 / distance.max(min_distance).powi(2),
+        }
+    }
+}
+
+/// Random-looking variation added to the acceleration of a [`ForceField`], like gusts of wind.
+///
+/// The turbulence is deterministic, and varies smoothly over time and space.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct ForceFieldTurbulence {
+    /// The maximum acceleration added by the turbulence. The unit is typically m/s².
+    pub strength: f32,
+    /// How quickly the turbulence changes over time and space. The unit is typically 1/s.
+    pub frequency: f32,
+}
+
+impl ForceFieldTurbulence {
+    /// Samples the turbulent acceleration at the given position and time.
+    pub fn sample(&self, position: Vector, elapsed_secs: f32) -> Vector {
+        let p = position * self.frequency;
+        let t = elapsed_secs * self.frequency;
+
+        // A sum of sines with incommensurate frequencies for each axis.
+        // Each axis is in the range [-1, 1].
+        #[cfg(feature = "2d")]
+        let noise = Vector::new(
+            0.5 * ((p.y * 1.7 + t * 1.3).sin() + (p.x * 0.9 - t * 2.1).sin()),
+            0.5 * ((p.x * 1.3 + t * 0.7).sin() + (p.y * 2.3 + t * 1.9).sin()),
+        );
+        #[cfg(feature = "3d")]
+        let noise = Vector::new(
+            0.5 * ((p.y * 1.7 + t * 1.3).sin() + (p.z * 0.9 - t * 2.1).sin()),
+            0.5 * ((p.z * 1.3 + t * 0.7).sin() + (p.x * 2.3 + t * 1.9).sin()),
+            0.5 * ((p.x * 1.1 - t * 1.7).sin() + (p.y * 0.8 + t * 2.3).sin()),
+        );
+
+        noise * self.strength
+    }
+}
+
+/// A [`Sensor`] collider that replaces [`Gravity`] for the dynamic rigid bodies inside it.
+///
+/// If a body is inside several gravity fields, the one with the highest [priority](Self::priority) is used.
+/// The acceleration is scaled by the [`GravityScale`] of the body.
+///
+/// See the [module-level documentation](self) for more information.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A small planet with gravity pulling towards its center.
+///     commands.spawn((
+///         RigidBody::Static,
+#[cfg_attr(feature = "2d", doc = "        Collider::circle(50.0),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::sphere(50.0),")]
+///     ));
+///     commands.spawn((
+#[cfg_attr(feature = "2d", doc = "        Collider::circle(100.0),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::sphere(100.0),")]
+///         GravityField::radial(9.81),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+#[require(Sensor)]
+pub struct GravityField {
+    /// The gravitational acceleration inside the field.
+    pub gravity: GravityFieldKind,
+    /// How the strength of [radial](GravityFieldKind::Radial) gravity
+    /// decreases with distance from the center of the field.
+    ///
+    /// **Default**: [`ForceFieldFalloff::None`]
+    pub falloff: ForceFieldFalloff,
+    /// The priority of the field. If a body is inside several gravity fields,
+    /// the one with the highest priority is used.
+    ///
+    /// **Default**: `0`
+    pub priority: i32,
+}
+
+impl GravityField {
+    /// A gravity field with no gravity, for zero-g areas.
+    pub const ZERO: Self = Self::directional(Vector::ZERO);
+
+    /// Creates a [`GravityField`] with the given gravitational acceleration
+    /// in the local space of the field.
+    #[inline]
+    pub const fn directional(gravity: Vector) -> Self {
+        Self {
+            gravity: GravityFieldKind::Directional(gravity),
+            falloff: ForceFieldFalloff::None,
+            priority: 0,
+        }
+    }
+
+    /// Creates a [`GravityField`] with the given gravitational acceleration
+    /// towards the center of the field, for example for planets.
+    #[inline]
+    pub const fn radial(strength: f32) -> Self {
+        Self {
+            gravity: GravityFieldKind::Radial(strength),
+            falloff: ForceFieldFalloff::None,
+            priority: 0,
+        }
+    }
+
+    /// Sets the [falloff](ForceFieldFalloff) of the field.
+    #[inline]
+    pub const fn with_falloff(mut self, falloff: ForceFieldFalloff) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Sets the priority of the field.
+    #[inline]
+    pub const fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Computes the gravitational acceleration of the field for a body.
+    ///
+    /// `offset` is the position of the body relative to the center of the field,
+    /// and `rotation` is the rotation of the field.
+    pub fn acceleration(&self, offset: Vector, rotation: Rot) -> Vector {
+        match self.gravity {
+            GravityFieldKind::Directional(gravity) => rotation * gravity,
+            GravityFieldKind::Radial(strength) => {
+                -offset.normalize_or_zero() * strength * self.falloff.factor(offset.length())
+            }
+        }
+    }
+}
+
+/// The gravitational acceleration of a [`GravityField`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub enum GravityFieldKind {
+    /// Gravity in a fixed direction in the local space of the field. The unit is typically m/s².
+    Directional(Vector),
+    /// Gravity towards the center of the field with the given strength. The unit is typically m/s².
+    Radial(f32),
+}
+
+/// The gravitational accelerations of the [`GravityField`]s acting on rigid bodies in the current time step,
+/// without [`GravityScale`]. Bodies that are not inside a gravity field are affected by [`Gravity`] instead.
+#[derive(Resource, Default)]
+pub(crate) struct GravityFieldAccelerations(pub EntityHashMap<Vector>);
+
+impl GravityFieldAccelerations {
+    /// Returns the gravitational acceleration acting on the given body,
+    /// falling back to the given global gravity if the body is not inside a gravity field.
+    pub fn get_or(&self, body: Entity, gravity: Vector) -> Vector {
+        self.0.get(&body).copied().unwrap_or(gravity)
+    }
+}
+
+/// Calls `f` once for each rigid body with colliders touching the given field collider.
+fn for_each_body_in_field(
+    field: Entity,
+    contact_graph: &ContactGraph,
+    colliders: &Query<&ColliderOf, Without<Sensor>>,
+    visited: &mut EntityHashMap<()>,
+    mut f: impl FnMut(Entity),
+) {
+    visited.clear();
+
+    for contact_pair in contact_graph.contact_pairs_with(field) {
+        if !contact_pair.is_touching() {
+            continue;
+        }
+
+        let other = if contact_pair.collider1 == field {
+            contact_pair.collider2
+        } else {
+            contact_pair.collider1
+        };
+
+        // Bodies with several colliders inside the field are only affected once.
+        if let Ok(collider_of) = colliders.get(other)
+            && visited.insert(collider_of.body, ()).is_none()
+        {
+            f(collider_of.body);
+        }
+    }
+}
+
+/// Replaces [`Gravity`] for dynamic rigid bodies inside a [`GravityField`].
+pub(crate) fn apply_gravity_fields(
+    fields: Query<(Entity, &GravityField, &Position, &Rotation)>,
+    colliders: Query<&ColliderOf, Without<Sensor>>,
+    mut bodies: Query<(&RigidBody, Forces, Option<&GravityScale>)>,
+    contact_graph: Res<ContactGraph>,
+    gravity: Res<Gravity>,
+    mut accelerations: ResMut<GravityFieldAccelerations>,
+    mut visited: Local<EntityHashMap<()>>,
+    // The field with the highest priority for each body.
+    mut body_fields: Local<EntityHashMap<(i32, Entity)>>,
+) {
+    body_fields.clear();
+    accelerations.0.clear();
+
+    for (field_entity, field, ..) in &fields {
+        for_each_body_in_field(
+            field_entity,
+            &contact_graph,
+            &colliders,
+            &mut visited,
+            |body| {
+                let entry = body_fields
+                    .entry(body)
+                    .or_insert((field.priority, field_entity));
+                // Use the entity as a tiebreaker for determinism.
+                if (field.priority, field_entity) > *entry {
+                    *entry = (field.priority, field_entity);
+                }
+            },
+        );
+    }
+
+    for (&body, &(_, field_entity)) in body_fields.iter() {
+        let Ok((_, field, field_position, field_rotation)) = fields.get(field_entity) else {
+            continue;
+        };
+        let Ok((rb, mut forces, gravity_scale)) = bodies.get_mut(body) else {
+            continue;
+        };
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let offset = (forces.position() - field_position.0).f32();
+        let field_gravity = field.acceleration(offset, Rot::from(*field_rotation));
+        let gravity_scale = gravity_scale.map_or(1.0, |scale| scale.0);
+        accelerations.0.insert(body, field_gravity);
+
+        // Cancel global gravity and apply the gravity of the field instead.
+        // Like global gravity, this does not wake up sleeping bodies.
+        forces
+            .non_waking()
+            .apply_linear_acceleration((field_gravity - gravity.0) * gravity_scale);
+    }
+}
+
+/// Applies the acceleration of [`ForceField`]s to dynamic rigid bodies inside them.
+pub(crate) fn apply_force_fields(
+    fields: Query<(Entity, &ForceField, &Position, &Rotation)>,
+    colliders: Query<&ColliderOf, Without<Sensor>>,
+    mut bodies: Query<(&RigidBody, Forces)>,
+    contact_graph: Res<ContactGraph>,
+    time: Res<Time>,
+    mut visited: Local<EntityHashMap<()>>,
+) {
+    let elapsed_secs = time.elapsed_secs();
+
+    for (field_entity, field, field_position, field_rotation) in &fields {
+        let field_rotation = Rot::from(*field_rotation);
+
+        for_each_body_in_field(
+            field_entity,
+            &contact_graph,
+            &colliders,
+            &mut visited,
+            |body| {
+                let Ok((rb, mut forces)) = bodies.get_mut(body) else {
+                    return;
+                };
+                if !rb.is_dynamic() {
+                    return;
+                }
+
+                let offset = (forces.position() - field_position.0).f32();
+                let acceleration = field.acceleration(
+                    offset,
+                    field_rotation,
+                    forces.linear_velocity(),
+                    elapsed_secs,
+                );
+
+                // This only wakes up the body if the acceleration is non-zero.
+                forces.apply_linear_acceleration(acceleration);
+            },
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use approx::assert_relative_eq;
+use bevy::prelude::*;
+
+use crate::{prelude::*, tests::utils::create_app};
+
+/// Tests that [`ForceField`]s and [`GravityField`]s only affect the bodies inside them.
+#[test]
+fn force_fields_and_gravity_fields_affect_bodies_inside() {
+    let mut app = create_app();
+    app.add_plugins(ForceFieldPlugin);
+    app.finish();
+
+    // A zero-g room and an updraft with an acceleration of twice the gravity.
+    app.world_mut().spawn((
+        #[cfg(feature = "2d")]
+        Collider::rectangle(4.0, 4.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(4.0, 4.0, 4.0),
+        GravityField::ZERO,
+        Position(RVector::X * -5.0),
+    ));
+    app.world_mut().spawn((
+        #[cfg(feature = "2d")]
+        Collider::rectangle(4.0, 4.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(4.0, 4.0, 4.0),
+        ForceField::directional(Vector::Y * 19.62),
+        Position(RVector::X * 5.0),
+    ));
+
+    let floating_ball = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::circle(0.5),
+            #[cfg(feature = "3d")]
+            Collider::sphere(0.5),
+            Position(RVector::X * -5.0),
+        ))
+        .id();
+    let rising_ball = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::circle(0.5),
+            #[cfg(feature = "3d")]
+            Collider::sphere(0.5),
+            Position(RVector::X * 5.0),
+        ))
+        .id();
+    let falling_ball = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::circle(0.5),
+            #[cfg(feature = "3d")]
+            Collider::sphere(0.5),
+        ))
+        .id();
+
+    for _ in 0..30 {
+        app.update();
+    }
+
+    let floating_velocity = app.world().get::<LinearVelocity>(floating_ball).unwrap();
+    let rising_velocity = app.world().get::<LinearVelocity>(rising_ball).unwrap();
+    let falling_velocity = app.world().get::<LinearVelocity>(falling_ball).unwrap();
+
+    assert_relative_eq!(floating_velocity.y, 0.0, epsilon = 0.01);
+    assert!(rising_velocity.y > 1.0);
+    assert!(falling_velocity.y < -1.0);
+}
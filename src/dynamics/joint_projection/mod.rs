@@ -0,0 +1,428 @@
+//! **Joint projection** keeps trees of rigid bodies connected by joints from stretching.
+//!
+//! Regular [joints](dynamics::joints) constrain the full positions and orientations of the connected bodies,
+//! and the solver iteratively tries to correct any error. Long chains and heavy loads can make these joints
+//! stretch visibly, since the corrections cannot fully propagate through the chain in a limited number of iterations.
+//!
+//! Bodies connected by [`ProjectedJoint`]s are simulated as regular bodies in maximal coordinates,
+//! and are solved together with contacts and other joints. After each substep, their positions and velocities
+//! are projected onto the joints: the joint coordinates, such as the angles of revolute joints, are measured
+//! from the solved poses, and the links are rebuilt from the root outwards so that the joints are satisfied exactly.
+//!
+//! This is not a reduced-coordinate solver. The bodies are not simulated in joint space,
+//! so the joints only stop stretching, and the rest of the simulation behaves like with regular joints.
+//! This makes joint projection well suited for long chains, ropes, and other mechanisms
+//! where joint stretching is unacceptable.
+//!
+//! # Creating a Joint Tree
+//!
+//! A joint tree consists of a root body with the [`JointProjectionRoot`] component, and child links
+//! connected to it or to each other by [`ProjectedJoint`]s. Like other joints, projected joints
+//! are spawned as their own entities. Each link must have exactly one joint connecting it to its parent,
+//! forming a tree with the root body at the top.
+//!
+//! ```
+#![cfg_attr(feature = "2d", doc = "use avian2d::{math::RVector, prelude::*};")]
+#![cfg_attr(feature = "3d", doc = "use avian3d::{math::RVector, prelude::*};")]
+//! use bevy::prelude::*;
+//!
+//! fn setup(mut commands: Commands) {
+//!     // A static root body for the chain.
+//!     let root = commands
+//!         .spawn((RigidBody::Static, JointProjectionRoot, Position(RVector::ZERO)))
+//!         .id();
+//!
+//!     // A chain of links connected by revolute joints.
+//!     let mut parent = root;
+//!     for i in 1..=10 {
+//!         let link = commands
+//!             .spawn((
+//!                 RigidBody::Dynamic,
+#![cfg_attr(feature = "2d", doc = "                Collider::circle(0.25),")]
+#![cfg_attr(feature = "3d", doc = "                Collider::sphere(0.25),")]
+//!                 Position(RVector::NEG_Y * i as f32 * 0.5),
+//!             ))
+//!             .id();
+//!
+//!         commands.spawn(
+//!             ProjectedJoint::revolute(parent, link)
+//!                 .with_anchor(RVector::NEG_Y * (i as f32 - 0.5) * 0.5),
+//!         );
+//!
+//!         parent = link;
+//!     }
+//! }
+//! ```
+//!
+//! The root can be a dynamic body, in which case the whole tree moves freely,
+//! or a static or kinematic body that the tree is attached to. All links must be dynamic.
+//!
+//! # Interaction With Other Bodies
+//!
+//! The links of a joint tree are regular [rigid bodies](RigidBody). They collide with other bodies
+//! through the normal narrow phase and contact solver, can be affected by forces and gravity,
+//! and can even be connected to other bodies with regular [joints](dynamics::joints).
+//!
+//! The projection happens at the end of each substep, after the contacts and other joints have been solved.
+//! The velocity projection is weighted by the masses and angular inertias of the links, so contact impulses
+//! applied to one link are distributed through the whole tree based on its joint-space inertia,
+//! using Featherstone's articulated-body algorithm.
+//!
+//! Like with other joints, the connected bodies can still collide with each other by default.
+//! This can be disabled by adding [`JointCollisionDisabled`] to the projected joint.
+//!
+//! # Limitations
+//!
+//! Projected joints do not support limits or motors. To drive a joint tree,
+//! apply [forces](crate::dynamics::rigid_body::forces) to its links.
+//!
+//! The cost of the projection grows linearly with the number of links. Loops are not supported: if a link
+//! is connected to several parents, only one of the joints is used.
+
+mod solver;
+
+use crate::{
+    dynamics::{
+        joints::{EntityConstraint, JointSystems},
+        solver::schedule::SubstepSolverSystems,
+    },
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A plugin for [projecting](self) trees of bodies onto their [`ProjectedJoint`]s.
+pub struct JointProjectionPlugin;
+
+impl Plugin for JointProjectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<solver::JointTrees>();
+
+        app.add_systems(
+            PhysicsSchedule,
+            (
+                update_local_frames.in_set(JointSystems::PrepareLocalFrames),
+                solver::prepare_joint_trees.in_set(SolverSystems::PrepareJoints),
+            ),
+        );
+
+        app.configure_sets(
+            SubstepSchedule,
+            JointProjectionSystems::Project
+                .after(SubstepSolverSystems::Relax)
+                .before(SubstepSolverSystems::Damping),
+        );
+
+        #[cfg(feature = "xpbd_joints")]
+        app.configure_sets(
+            SubstepSchedule,
+            JointProjectionSystems::Project
+                .after(crate::dynamics::solver::xpbd::XpbdSolverSystems::VelocityProjection),
+        );
+
+        app.add_systems(
+            SubstepSchedule,
+            solver::project_joint_trees.in_set(JointProjectionSystems::Project),
+        );
+    }
+}
+
+/// System sets for [joint projection](self) in the [`SubstepSchedule`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JointProjectionSystems {
+    /// Projects the positions and velocities of the links of each joint tree
+    /// onto its joint coordinates.
+    Project,
+}
+
+/// A component that marks a [`RigidBody`] as the root of a tree of [projected joints](self).
+///
+/// The links of the tree are connected to the root, or to each other,
+/// with [`ProjectedJoint`]s. See the [module-level documentation](self) for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct JointProjectionRoot;
+
+/// The type of a [`ProjectedJoint`], determining the degrees of freedom
+/// of the child link relative to its parent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub enum ProjectedJointType {
+    /// Allows rotation about the [`axis`](ProjectedJoint::axis) at the joint anchor.
+    ///
+    /// In 2D, the axis is ignored.
+    #[default]
+    Revolute,
+    /// Allows translation along the [`axis`](ProjectedJoint::axis).
+    Prismatic,
+    /// Allows free rotation about the joint anchor.
+    #[cfg(feature = "3d")]
+    Spherical,
+}
+
+impl ProjectedJointType {
+    /// Returns the number of degrees of freedom allowed by the joint.
+    #[inline]
+    pub const fn dof_count(&self) -> usize {
+        match self {
+            Self::Revolute | Self::Prismatic => 1,
+            #[cfg(feature = "3d")]
+            Self::Spherical => 3,
+        }
+    }
+}
+
+/// A joint that connects a child link to its parent in a tree of [projected joints](self).
+///
+/// Unlike regular [joints](dynamics::joints), projected joints are satisfied exactly after each substep,
+/// so the connected bodies never drift apart. They do not support limits or motors.
+/// See the [module-level documentation](self) for more information.
+///
+/// Each projected joint is defined by a [`JointFrame`] on each body, the [`ProjectedJointType`],
+/// and an [`axis`](Self::axis) expressed in the joint frames.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+pub struct ProjectedJoint {
+    /// The parent body of the joint.
+    pub body1: Entity,
+    /// The child body of the joint.
+    pub body2: Entity,
+    /// The type of the joint.
+    pub joint_type: ProjectedJointType,
+    /// The reference frame of the parent body, defining the joint anchor and basis
+    /// relative to the body transform.
+    pub frame1: JointFrame,
+    /// The reference frame of the child body, defining the joint anchor and basis
+    /// relative to the body transform.
+    pub frame2: JointFrame,
+    /// The axis of rotation or translation in the joint frames.
+    ///
+    /// By default, this is the z-axis for revolute joints and the x-axis for prismatic joints.
+    pub axis: Vector,
+}
+
+impl EntityConstraint<2> for ProjectedJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.body1, self.body2]
+    }
+}
+
+impl ProjectedJoint {
+    /// Creates a new [`ProjectedJoint`] of the given type between a parent and child body.
+    #[inline]
+    pub const fn new(parent: Entity, child: Entity, joint_type: ProjectedJointType) -> Self {
+        Self {
+            body1: parent,
+            body2: child,
+            joint_type,
+            frame1: JointFrame::IDENTITY,
+            frame2: JointFrame::IDENTITY,
+            #[cfg(feature = "2d")]
+            axis: Vector::X,
+            #[cfg(feature = "3d")]
+            axis: match joint_type {
+                ProjectedJointType::Prismatic => Vector::X,
+                _ => Vector::Z,
+            },
+        }
+    }
+
+    /// Creates a new revolute [`ProjectedJoint`] between a parent and child body.
+    #[inline]
+    pub const fn revolute(parent: Entity, child: Entity) -> Self {
+        Self::new(parent, child, ProjectedJointType::Revolute)
+    }
+
+    /// Creates a new prismatic [`ProjectedJoint`] between a parent and child body.
+    #[inline]
+    pub const fn prismatic(parent: Entity, child: Entity) -> Self {
+        Self::new(parent, child, ProjectedJointType::Prismatic)
+    }
+
+    /// Creates a new spherical [`ProjectedJoint`] between a parent and child body.
+    #[inline]
+    #[cfg(feature = "3d")]
+    pub const fn spherical(parent: Entity, child: Entity) -> Self {
+        Self::new(parent, child, ProjectedJointType::Spherical)
+    }
+
+    /// Sets the axis of rotation or translation in the joint frames.
+    #[inline]
+    pub const fn with_axis(mut self, axis: Vector) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the local [`JointFrame`] of the parent body.
+    #[inline]
+    pub fn with_local_frame1(mut self, frame: impl Into<Isometry>) -> Self {
+        self.frame1 = JointFrame::local(frame);
+        self
+    }
+
+    /// Sets the local [`JointFrame`] of the child body.
+    #[inline]
+    pub fn with_local_frame2(mut self, frame: impl Into<Isometry>) -> Self {
+        self.frame2 = JointFrame::local(frame);
+        self
+    }
+
+    /// Sets the global anchor point on both bodies.
+    ///
+    /// This configures the [`JointAnchor`] of each [`JointFrame`].
+    #[inline]
+    pub const fn with_anchor(mut self, anchor: RVector) -> Self {
+        self.frame1.anchor = JointAnchor::FromGlobal(anchor);
+        self.frame2.anchor = JointAnchor::FromGlobal(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the parent body.
+    ///
+    /// This configures the [`JointAnchor`] of the parent [`JointFrame`].
+    #[inline]
+    pub const fn with_local_anchor1(mut self, anchor: Vector) -> Self {
+        self.frame1.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the child body.
+    ///
+    /// This configures the [`JointAnchor`] of the child [`JointFrame`].
+    #[inline]
+    pub const fn with_local_anchor2(mut self, anchor: Vector) -> Self {
+        self.frame2.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the global basis for both bodies.
+    ///
+    /// This configures the [`JointBasis`] of each [`JointFrame`].
+    #[inline]
+    pub fn with_basis(mut self, basis: impl Into<Rot>) -> Self {
+        let basis = basis.into();
+        self.frame1.basis = JointBasis::FromGlobal(basis);
+        self.frame2.basis = JointBasis::FromGlobal(basis);
+        self
+    }
+
+    /// Sets the local basis for the parent body.
+    ///
+    /// This configures the [`JointBasis`] of the parent [`JointFrame`].
+    #[inline]
+    pub fn with_local_basis1(mut self, basis: impl Into<Rot>) -> Self {
+        self.frame1.basis = JointBasis::Local(basis.into());
+        self
+    }
+
+    /// Sets the local basis for the child body.
+    ///
+    /// This configures the [`JointBasis`] of the child [`JointFrame`].
+    #[inline]
+    pub fn with_local_basis2(mut self, basis: impl Into<Rot>) -> Self {
+        self.frame2.basis = JointBasis::Local(basis.into());
+        self
+    }
+
+    /// Returns the local anchor point on the parent body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor1(&self) -> Option<Vector> {
+        match self.frame1.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Returns the local anchor point on the child body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor2(&self) -> Option<Vector> {
+        match self.frame2.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+}
+
+impl MapEntities for ProjectedJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body1 = entity_mapper.get_mapped(self.body1);
+        self.body2 = entity_mapper.get_mapped(self.body2);
+    }
+}
+
+fn update_local_frames(
+    mut joints: Query<&mut ProjectedJoint, Changed<ProjectedJoint>>,
+    bodies: Query<(&Position, &Rotation)>,
+) {
+    for mut joint in &mut joints {
+        if matches!(joint.frame1.anchor, JointAnchor::Local(_))
+            && matches!(joint.frame2.anchor, JointAnchor::Local(_))
+            && matches!(joint.frame1.basis, JointBasis::Local(_))
+            && matches!(joint.frame2.basis, JointBasis::Local(_))
+        {
+            continue;
+        }
+
+        let Ok([(pos1, rot1), (pos2, rot2)]) = bodies.get_many(joint.entities()) else {
+            continue;
+        };
+
+        let [frame1, frame2] =
+            JointFrame::compute_local(joint.frame1, joint.frame2, pos1.0, pos2.0, *rot1, *rot2);
+        joint.frame1 = frame1;
+        joint.frame2 = frame2;
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<2> for ProjectedJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 2],
+        rotations: [Rotation; 2],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos1, pos2] = positions;
+        let [rot1, rot2] = rotations;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+
+        let anchor1 = pos1 + (rot1 * local_anchor1).real();
+        let anchor2 = pos2 + (rot2 * local_anchor2).real();
+
+        if let Some(anchor_color) = config.joint_anchor_color {
+            gizmos.draw_line(pos1, anchor1, anchor_color);
+            gizmos.draw_line(pos2, anchor2, anchor_color);
+        }
+
+        if let Some(separation_color) = config.joint_separation_color {
+            gizmos.draw_line(anchor1, anchor2, separation_color);
+        }
+    }
+}
@@ -0,0 +1,744 @@
+//! The joint-space projection for trees of [projected joints](super).
+//!
+//! Before the substepping loop, each tree is flattened into a [`JointTree`]
+//! with its links in breadth-first order. At the end of each substep, the solved poses of the links
+//! are projected onto the joints by measuring the joint coordinates and rebuilding the poses
+//! from the root with forward kinematics. The velocities are then projected onto the joint space
+//! by solving `H * q̇ = Jᵀ * M * v`, where `J` maps joint velocities to link velocities,
+//! `M` is the mass matrix of the links, and `H = Jᵀ * M * J` is the joint-space inertia matrix.
+//!
+//! The velocity projection is equivalent to applying the momentum `M * v` of each link as an impulse
+//! to the tree at rest, so it is solved in linear time with Featherstone's articulated-body algorithm.
+//! All spatial quantities are expressed in world space about the initial center of mass of the root.
+
+use super::{JointProjectionRoot, ProjectedJoint, ProjectedJointType};
+use crate::{
+    dynamics::solver::solver_body::{SolverBodies, SolverBodyIndex},
+    prelude::*,
+};
+use bevy::{
+    ecs::entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+};
+
+/// The number of degrees of freedom of a free rigid body, and the dimension of spatial vectors.
+#[cfg(feature = "2d")]
+const FREE_DOF_COUNT: usize = 3;
+/// The number of degrees of freedom of a free rigid body, and the dimension of spatial vectors.
+#[cfg(feature = "3d")]
+const FREE_DOF_COUNT: usize = 6;
+
+/// The [joint trees](super) prepared for the current physics step.
+#[derive(Resource, Default)]
+pub(super) struct JointTrees {
+    trees: Vec<JointTree>,
+    /// Buffers reused across joint trees and substeps.
+    scratch: ProjectionScratch,
+}
+
+/// Buffers for projecting a [`JointTree`], reused to avoid allocating every substep.
+#[derive(Default)]
+struct ProjectionScratch {
+    /// The rotations and centers of mass of the links, as solved by the other constraints.
+    poses: Vec<(Rot, Vector)>,
+    /// The rotations and centers of mass of the links, projected onto the joints.
+    projected: Vec<(Rot, Vector)>,
+    /// The world-space frame of the parent side of each joint after the projection.
+    joint_frames: Vec<(Rot, Vector)>,
+    /// The intermediate results of the articulated-body algorithm for each link.
+    links: Vec<LinkScratch>,
+}
+
+/// A tree of projected joints flattened into a list of links in breadth-first order, starting from the root.
+struct JointTree {
+    /// Whether the root body is dynamic and has its own degrees of freedom.
+    root_dynamic: bool,
+    links: Vec<TreeLink>,
+}
+
+struct TreeLink {
+    /// The solver body index, or [`SolverBodyIndex::INVALID`] for a static root.
+    index: SolverBodyIndex,
+    /// The rotation of the body at the start of the physics step.
+    start_rotation: Rot,
+    /// The center of mass of the body at the start of the physics step,
+    /// relative to the center of mass of the root.
+    start_offset: Vector,
+    local_center_of_mass: Vector,
+    mass: f32,
+    angular_inertia: ComputedAngularInertia,
+    /// The index of the parent link. Unused for the root.
+    parent: usize,
+    /// The joint connecting the link to its parent. `None` for the root.
+    joint: Option<LinkJoint>,
+}
+
+#[derive(Clone, Copy)]
+pub(super) struct LinkJoint {
+    joint_type: ProjectedJointType,
+    anchor1: Vector,
+    basis1: Rot,
+    anchor2: Vector,
+    basis2: Rot,
+    axis: Vector,
+}
+
+/// A spatial motion or force vector in world space, with the angular part first.
+///
+/// For motion vectors, the linear part is the velocity of the point at the reference point.
+/// For force vectors, the angular part is the torque or angular momentum about the reference point.
+#[derive(Clone, Copy, Default)]
+struct SpatialVector([f32; FREE_DOF_COUNT]);
+
+impl SpatialVector {
+    #[cfg(feature = "2d")]
+    #[inline]
+    fn new(angular: AngularVector, linear: Vector) -> Self {
+        Self([angular, linear.x, linear.y])
+    }
+
+    #[cfg(feature = "3d")]
+    #[inline]
+    fn new(angular: AngularVector, linear: Vector) -> Self {
+        Self([
+            angular.x, angular.y, angular.z, linear.x, linear.y, linear.z,
+        ])
+    }
+
+    /// Returns the unit vector along the given spatial axis.
+    #[inline]
+    fn unit(axis: usize) -> Self {
+        let mut vector = Self::default();
+        vector.0[axis] = 1.0;
+        vector
+    }
+
+    #[cfg(feature = "2d")]
+    #[inline]
+    fn angular(&self) -> AngularVector {
+        self.0[0]
+    }
+
+    #[cfg(feature = "3d")]
+    #[inline]
+    fn angular(&self) -> AngularVector {
+        Vector::new(self.0[0], self.0[1], self.0[2])
+    }
+
+    #[cfg(feature = "2d")]
+    #[inline]
+    fn linear(&self) -> Vector {
+        Vector::new(self.0[1], self.0[2])
+    }
+
+    #[cfg(feature = "3d")]
+    #[inline]
+    fn linear(&self) -> Vector {
+        Vector::new(self.0[3], self.0[4], self.0[5])
+    }
+
+    /// Returns the motion vector of a rotation about an `axis` through the given `point`.
+    #[inline]
+    fn rotation_about(axis: AngularVector, point: Vector) -> Self {
+        Self::new(axis, cross(axis, -point))
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> f32 {
+        self.0.iter().zip(other.0).map(|(a, b)| a * b).sum()
+    }
+
+    #[inline]
+    fn add_scaled(&mut self, other: &Self, scale: f32) {
+        for (a, b) in self.0.iter_mut().zip(other.0) {
+            *a += b * scale;
+        }
+    }
+}
+
+/// A symmetric spatial inertia in world space, mapping motion vectors to force vectors.
+#[derive(Clone, Copy)]
+struct SpatialInertia([[f32; FREE_DOF_COUNT]; FREE_DOF_COUNT]);
+
+impl SpatialInertia {
+    /// Returns the spatial inertia of a rigid body about the reference point, given its `mass`,
+    /// world-space angular `inertia` about its center of mass, and center of mass `com`.
+    #[cfg(feature = "2d")]
+    fn rigid_body(mass: f32, inertia: f32, com: Vector) -> Self {
+        Self([
+            [
+                inertia + mass * com.length_squared(),
+                -mass * com.y,
+                mass * com.x,
+            ],
+            [-mass * com.y, mass, 0.0],
+            [mass * com.x, 0.0, mass],
+        ])
+    }
+
+    /// Returns the spatial inertia of a rigid body about the reference point, given its `mass`,
+    /// world-space angular `inertia` about its center of mass, and center of mass `com`.
+    #[cfg(feature = "3d")]
+    fn rigid_body(mass: f32, inertia: Mat3, com: Vector) -> Self {
+        // The angular inertia about the reference point, using the parallel axis theorem.
+        let angular = inertia
+            + mass * (Mat3::from_diagonal(Vector::splat(com.length_squared())) - outer(com, com));
+        // Maps linear velocity to angular momentum, `com × (mass * v)`.
+        let coupling = mass * skew(com);
+
+        let mut matrix = [[0.0; FREE_DOF_COUNT]; FREE_DOF_COUNT];
+        for i in 0..3 {
+            for j in 0..3 {
+                matrix[i][j] = angular.col(j)[i];
+                matrix[i][j + 3] = coupling.col(j)[i];
+                matrix[j + 3][i] = coupling.col(j)[i];
+            }
+            matrix[i + 3][i + 3] = mass;
+        }
+        Self(matrix)
+    }
+
+    #[inline]
+    fn mul(&self, vector: &SpatialVector) -> SpatialVector {
+        SpatialVector(core::array::from_fn(|i| {
+            self.0[i].iter().zip(vector.0).map(|(a, b)| a * b).sum()
+        }))
+    }
+
+    #[inline]
+    fn add(&mut self, other: &Self) {
+        for (row, other_row) in self.0.iter_mut().zip(&other.0) {
+            for (a, b) in row.iter_mut().zip(other_row) {
+                *a += b;
+            }
+        }
+    }
+
+    /// Subtracts `scale * a * bᵀ` from the matrix.
+    #[inline]
+    fn sub_outer(&mut self, a: &SpatialVector, b: &SpatialVector, scale: f32) {
+        for (row, a) in self.0.iter_mut().zip(a.0) {
+            for (value, b) in row.iter_mut().zip(b.0) {
+                *value -= scale * a * b;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "3d")]
+#[inline]
+fn skew(v: Vector) -> Mat3 {
+    Mat3::from_cols(
+        Vector::new(0.0, v.z, -v.y),
+        Vector::new(-v.z, 0.0, v.x),
+        Vector::new(v.y, -v.x, 0.0),
+    )
+}
+
+#[cfg(feature = "3d")]
+#[inline]
+fn outer(a: Vector, b: Vector) -> Mat3 {
+    Mat3::from_cols(a * b.x, a * b.y, a * b.z)
+}
+
+#[cfg(feature = "2d")]
+#[inline]
+fn cross(angular: AngularVector, r: Vector) -> Vector {
+    Vector::new(-angular * r.y, angular * r.x)
+}
+
+#[cfg(feature = "3d")]
+#[inline]
+fn cross(angular: AngularVector, r: Vector) -> Vector {
+    angular.cross(r)
+}
+
+/// The intermediate results of the articulated-body algorithm for a link.
+#[derive(Clone, Copy)]
+struct LinkScratch {
+    /// The articulated inertia of the subtree rooted at the link.
+    inertia: SpatialInertia,
+    /// The articulated bias impulse of the subtree rooted at the link.
+    bias: SpatialVector,
+    /// The motion subspace of the joint, with a column for each degree of freedom.
+    subspace: [SpatialVector; FREE_DOF_COUNT],
+    /// The product of the articulated inertia and the motion subspace.
+    inertia_subspace: [SpatialVector; FREE_DOF_COUNT],
+    /// The inverse of the joint-space articulated inertia.
+    inverse_joint_inertia: [[f32; FREE_DOF_COUNT]; FREE_DOF_COUNT],
+    /// The joint-space bias impulse.
+    joint_bias: [f32; FREE_DOF_COUNT],
+    /// The number of degrees of freedom of the joint.
+    dof_count: usize,
+    /// The velocity of the link relative to the motion of a static or kinematic root.
+    velocity: SpatialVector,
+}
+
+impl Default for LinkScratch {
+    fn default() -> Self {
+        Self {
+            inertia: SpatialInertia([[0.0; FREE_DOF_COUNT]; FREE_DOF_COUNT]),
+            bias: SpatialVector::default(),
+            subspace: [SpatialVector::default(); FREE_DOF_COUNT],
+            inertia_subspace: [SpatialVector::default(); FREE_DOF_COUNT],
+            inverse_joint_inertia: [[0.0; FREE_DOF_COUNT]; FREE_DOF_COUNT],
+            joint_bias: [0.0; FREE_DOF_COUNT],
+            dof_count: 0,
+            velocity: SpatialVector::default(),
+        }
+    }
+}
+
+/// Flattens the tree below each [`JointProjectionRoot`] into a [`JointTree`] for the substepping loop.
+pub(super) fn prepare_joint_trees(
+    mut trees: ResMut<JointTrees>,
+    roots: Query<Entity, With<JointProjectionRoot>>,
+    joints: Query<&ProjectedJoint, Without<JointDisabled>>,
+    bodies: Query<
+        (
+            &RigidBody,
+            Option<&SolverBodyIndex>,
+            &Position,
+            &Rotation,
+            &ComputedCenterOfMass,
+            &ComputedMass,
+            &ComputedAngularInertia,
+        ),
+        Without<RigidBodyDisabled>,
+    >,
+    solver_bodies: Res<SolverBodies>,
+    mut children: Local<EntityHashMap<Vec<(Entity, LinkJoint)>>>,
+    mut entities: Local<Vec<Entity>>,
+    mut visited: Local<EntityHashSet>,
+) {
+    trees.trees.clear();
+
+    if roots.is_empty() {
+        return;
+    }
+
+    children.clear();
+
+    for joint in &joints {
+        let (
+            JointAnchor::Local(anchor1),
+            JointBasis::Local(basis1),
+            JointAnchor::Local(anchor2),
+            JointBasis::Local(basis2),
+        ) = (
+            joint.frame1.anchor,
+            joint.frame1.basis,
+            joint.frame2.anchor,
+            joint.frame2.basis,
+        )
+        else {
+            continue;
+        };
+
+        children.entry(joint.body1).or_default().push((
+            joint.body2,
+            LinkJoint {
+                joint_type: joint.joint_type,
+                anchor1,
+                basis1,
+                anchor2,
+                basis2,
+                axis: joint.axis.normalize_or_zero(),
+            },
+        ));
+    }
+
+    'roots: for root in &roots {
+        let Ok((rb, index, pos, rot, com, mass, angular_inertia)) = bodies.get(root) else {
+            continue;
+        };
+
+        let index = index
+            .copied()
+            .filter(|index| index.is_valid() && solver_bodies.contains_index(*index))
+            .unwrap_or(SolverBodyIndex::INVALID);
+
+        // Sleeping dynamic and kinematic roots have no solver body.
+        if !rb.is_static() && !index.is_valid() {
+            continue;
+        }
+
+        let root_rotation = Rot::from(*rot);
+        let root_com = pos.0 + (root_rotation * com.0).real();
+        let root_dynamic = rb.is_dynamic();
+
+        let mut tree = JointTree {
+            root_dynamic,
+            links: vec![TreeLink {
+                index,
+                start_rotation: root_rotation,
+                start_offset: Vector::ZERO,
+                local_center_of_mass: com.0,
+                mass: mass.value(),
+                angular_inertia: *angular_inertia,
+                parent: 0,
+                joint: None,
+            }],
+        };
+
+        entities.clear();
+        entities.push(root);
+        visited.clear();
+        visited.insert(root);
+        let mut next = 0;
+
+        while next < entities.len() {
+            let parent = next;
+            next += 1;
+
+            let Some(joints) = children.get(&entities[parent]) else {
+                continue;
+            };
+
+            for &(body, joint) in joints {
+                // Loops are not supported, so only the first joint connecting a body is used.
+                if !visited.insert(body) {
+                    continue;
+                }
+
+                let Ok((rb, index, pos, rot, com, mass, angular_inertia)) = bodies.get(body) else {
+                    continue;
+                };
+
+                // Only dynamic links are driven by the projection.
+                if !rb.is_dynamic() {
+                    continue;
+                }
+
+                let Some(&index) =
+                    index.filter(|index| index.is_valid() && solver_bodies.contains_index(**index))
+                else {
+                    // The link is sleeping, so the whole tree should be sleeping too.
+                    continue 'roots;
+                };
+
+                let rotation = Rot::from(*rot);
+                let world_com = pos.0 + (rotation * com.0).real();
+
+                tree.links.push(TreeLink {
+                    index,
+                    start_rotation: rotation,
+                    start_offset: (world_com - root_com).f32(),
+                    local_center_of_mass: com.0,
+                    mass: mass.value(),
+                    angular_inertia: *angular_inertia,
+                    parent,
+                    joint: Some(joint),
+                });
+                entities.push(body);
+            }
+        }
+
+        if tree.links.len() > 1 {
+            trees.trees.push(tree);
+        }
+    }
+}
+
+/// Projects the positions and velocities of the links of each joint tree onto its joints.
+pub(super) fn project_joint_trees(
+    mut solver_bodies: ResMut<SolverBodies>,
+    mut trees: ResMut<JointTrees>,
+) {
+    let JointTrees { trees, scratch } = &mut *trees;
+    for tree in trees.iter() {
+        project_tree(tree, &mut solver_bodies, scratch);
+    }
+}
+
+fn project_tree(
+    tree: &JointTree,
+    solver_bodies: &mut SolverBodies,
+    scratch: &mut ProjectionScratch,
+) {
+    let ProjectionScratch {
+        poses,
+        projected,
+        joint_frames,
+        links,
+    } = scratch;
+
+    // The current poses of the links, as solved by the other constraints.
+    poses.clear();
+    let mut root_velocity = (AngularVector::default(), Vector::ZERO);
+
+    for (i, link) in tree.links.iter().enumerate() {
+        match solver_bodies.get(link.index) {
+            Some(body) => {
+                poses.push((
+                    body.delta_rotation * link.start_rotation,
+                    link.start_offset + body.delta_position,
+                ));
+                if i == 0 && !tree.root_dynamic {
+                    root_velocity = (body.angular_velocity, body.linear_velocity);
+                }
+            }
+            None => poses.push((link.start_rotation, link.start_offset)),
+        }
+    }
+
+    // Measure the joint coordinates and rebuild the poses from the root with forward kinematics.
+    // Each joint stores the world-space frame of the parent after the projection.
+    projected.clear();
+    projected.extend_from_slice(poses);
+    joint_frames.clear();
+    joint_frames.resize(tree.links.len(), (Rot::IDENTITY, Vector::ZERO));
+
+    for (i, link) in tree.links.iter().enumerate().skip(1) {
+        let parent = &tree.links[link.parent];
+        let Some(joint) = &link.joint else {
+            continue;
+        };
+
+        let (parent_rot, parent_com) = poses[link.parent];
+        let (child_rot, child_com) = poses[i];
+
+        let frame1_rot = parent_rot * joint.basis1;
+        let frame1_pos = parent_com + parent_rot * (joint.anchor1 - parent.local_center_of_mass);
+        let frame2_rot = child_rot * joint.basis2;
+        let frame2_pos = child_com + child_rot * (joint.anchor2 - link.local_center_of_mass);
+
+        let inv_frame1_rot = frame1_rot.inverse();
+        let relative_rot = inv_frame1_rot * frame2_rot;
+        let relative_pos = inv_frame1_rot * (frame2_pos - frame1_pos);
+
+        let (relative_rot, relative_pos) = match joint.joint_type {
+            #[cfg(feature = "2d")]
+            ProjectedJointType::Revolute => (relative_rot.normalize(), Vector::ZERO),
+            #[cfg(feature = "3d")]
+            ProjectedJointType::Revolute => {
+                // Extract the twist about the hinge axis.
+                let twist = relative_rot.xyz().dot(joint.axis);
+                let angle = 2.0 * twist.atan2(relative_rot.w);
+                (Quat::from_axis_angle(joint.axis, angle), Vector::ZERO)
+            }
+            ProjectedJointType::Prismatic => {
+                (Rot::IDENTITY, joint.axis * relative_pos.dot(joint.axis))
+            }
+            #[cfg(feature = "3d")]
+            ProjectedJointType::Spherical => (relative_rot.normalize(), Vector::ZERO),
+        };
+
+        let (parent_rot, parent_com) = projected[link.parent];
+        let frame1_rot = parent_rot * joint.basis1;
+        let frame1_pos = parent_com + parent_rot * (joint.anchor1 - parent.local_center_of_mass);
+        let frame2_rot = frame1_rot * relative_rot;
+        let frame2_pos = frame1_pos + frame1_rot * relative_pos;
+
+        let child_rot = (frame2_rot * joint.basis2.inverse()).normalize();
+        let child_com = frame2_pos - child_rot * (joint.anchor2 - link.local_center_of_mass);
+
+        projected[i] = (child_rot, child_com);
+        joint_frames[i] = (frame1_rot, frame1_pos);
+    }
+
+    for (link, &(rot, com)) in tree.links.iter().zip(projected.iter()).skip(1) {
+        if let Some(body) = solver_bodies.get_mut(link.index) {
+            body.delta_rotation = rot * link.start_rotation.inverse();
+            body.delta_position = com - link.start_offset;
+        }
+    }
+
+    // Project the velocities onto the joint space. The momentum of each link is applied
+    // as an impulse to the tree at rest, relative to the motion of a static or kinematic root.
+    let root_motion = SpatialVector::new(
+        root_velocity.0,
+        root_velocity.1 - cross(root_velocity.0, projected[0].1),
+    );
+
+    links.clear();
+    links.resize(tree.links.len(), LinkScratch::default());
+
+    for (k, link) in tree.links.iter().enumerate() {
+        let com = projected[k].1;
+        let scratch = &mut links[k];
+
+        #[cfg(feature = "2d")]
+        let inertia = link.angular_inertia.value();
+        #[cfg(feature = "3d")]
+        let inertia = link
+            .angular_inertia
+            .rotated(projected[k].0)
+            .tensor()
+            .to_mat3();
+        scratch.inertia = SpatialInertia::rigid_body(link.mass, inertia, com);
+
+        if let Some(body) = solver_bodies.get(link.index) {
+            let mut velocity = SpatialVector::new(
+                body.angular_velocity,
+                body.linear_velocity - cross(body.angular_velocity, com),
+            );
+            velocity.add_scaled(&root_motion, -1.0);
+            let momentum = scratch.inertia.mul(&velocity);
+            scratch.bias.add_scaled(&momentum, -1.0);
+        }
+
+        match &link.joint {
+            Some(joint) => {
+                let (frame_rot, frame_pos) = joint_frames[k];
+                scratch.dof_count = joint.joint_type.dof_count();
+                match joint.joint_type {
+                    #[cfg(feature = "2d")]
+                    ProjectedJointType::Revolute => {
+                        scratch.subspace[0] = SpatialVector::rotation_about(1.0, frame_pos);
+                    }
+                    #[cfg(feature = "3d")]
+                    ProjectedJointType::Revolute => {
+                        let axis = frame_rot * joint.axis;
+                        scratch.subspace[0] = SpatialVector::rotation_about(axis, frame_pos);
+                    }
+                    ProjectedJointType::Prismatic => {
+                        let axis = frame_rot * joint.axis;
+                        scratch.subspace[0] = SpatialVector::new(AngularVector::default(), axis);
+                    }
+                    #[cfg(feature = "3d")]
+                    ProjectedJointType::Spherical => {
+                        for (i, axis) in [Vector::X, Vector::Y, Vector::Z].into_iter().enumerate() {
+                            scratch.subspace[i] = SpatialVector::rotation_about(axis, frame_pos);
+                        }
+                    }
+                }
+            }
+            // A dynamic root moves freely relative to the world.
+            None if tree.root_dynamic => {
+                scratch.dof_count = FREE_DOF_COUNT;
+                scratch.subspace = core::array::from_fn(SpatialVector::unit);
+            }
+            None => {}
+        }
+    }
+
+    // Accumulate the articulated inertias and bias impulses from the leaves to the root.
+    for k in (0..tree.links.len()).rev() {
+        let scratch = &mut links[k];
+        let dof_count = scratch.dof_count;
+        if dof_count == 0 {
+            continue;
+        }
+
+        for i in 0..dof_count {
+            scratch.inertia_subspace[i] = scratch.inertia.mul(&scratch.subspace[i]);
+            scratch.joint_bias[i] = -scratch.subspace[i].dot(&scratch.bias);
+        }
+        let joint_inertia = core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                if i < dof_count && j < dof_count {
+                    scratch.subspace[i].dot(&scratch.inertia_subspace[j])
+                } else {
+                    0.0
+                }
+            })
+        });
+        scratch.inverse_joint_inertia = invert(joint_inertia, dof_count);
+
+        if k == 0 {
+            continue;
+        }
+
+        // The inertia and impulse transmitted to the parent through the joint.
+        let mut inertia = scratch.inertia;
+        let mut bias = scratch.bias;
+        for i in 0..dof_count {
+            let mut weighted = SpatialVector::default();
+            for j in 0..dof_count {
+                let inverse = scratch.inverse_joint_inertia[i][j];
+                weighted.add_scaled(&scratch.inertia_subspace[j], inverse);
+                bias.add_scaled(
+                    &scratch.inertia_subspace[i],
+                    inverse * scratch.joint_bias[j],
+                );
+            }
+            inertia.sub_outer(&scratch.inertia_subspace[i], &weighted, 1.0);
+        }
+
+        let parent = &mut links[tree.links[k].parent];
+        parent.inertia.add(&inertia);
+        parent.bias.add_scaled(&bias, 1.0);
+    }
+
+    // Solve the joint velocities and propagate the link velocities from the root to the leaves.
+    for (k, link) in tree.links.iter().enumerate() {
+        let parent_velocity = if k == 0 {
+            SpatialVector::default()
+        } else {
+            links[link.parent].velocity
+        };
+
+        let scratch = &mut links[k];
+        let mut velocity = parent_velocity;
+        for i in 0..scratch.dof_count {
+            let mut joint_velocity = 0.0;
+            for j in 0..scratch.dof_count {
+                joint_velocity += scratch.inverse_joint_inertia[i][j]
+                    * (scratch.joint_bias[j] - scratch.inertia_subspace[j].dot(&parent_velocity));
+            }
+            velocity.add_scaled(&scratch.subspace[i], joint_velocity);
+        }
+        scratch.velocity = velocity;
+
+        if k == 0 && !tree.root_dynamic {
+            continue;
+        }
+
+        velocity.add_scaled(&root_motion, 1.0);
+        if let Some(body) = solver_bodies.get_mut(link.index) {
+            let com = projected[k].1;
+            body.angular_velocity = velocity.angular();
+            body.linear_velocity = velocity.linear() + cross(velocity.angular(), com);
+        }
+    }
+}
+
+/// Inverts the upper-left `n`-by-`n` block of a symmetric positive semi-definite matrix
+/// using Gauss-Jordan elimination.
+///
+/// Degenerate degrees of freedom, such as those of massless links, are given an inverse of zero.
+fn invert(
+    mut matrix: [[f32; FREE_DOF_COUNT]; FREE_DOF_COUNT],
+    n: usize,
+) -> [[f32; FREE_DOF_COUNT]; FREE_DOF_COUNT] {
+    let mut inverse = [[0.0; FREE_DOF_COUNT]; FREE_DOF_COUNT];
+    for (i, row) in inverse.iter_mut().enumerate().take(n) {
+        row[i] = 1.0;
+    }
+
+    let max_diagonal = (0..n).map(|i| matrix[i][i]).fold(0.0, f32::max);
+    let epsilon = 1e-6 * max_diagonal;
+
+    for i in 0..n {
+        // The matrix is symmetric positive semi-definite, so the diagonal can be used as the pivot.
+        let pivot = matrix[i][i];
+        if pivot <= epsilon || !pivot.is_finite() {
+            for j in 0..n {
+                matrix[i][j] = 0.0;
+                matrix[j][i] = 0.0;
+                inverse[i][j] = 0.0;
+                inverse[j][i] = 0.0;
+            }
+            continue;
+        }
+
+        let inv_pivot = pivot.recip();
+        for j in 0..n {
+            matrix[i][j] *= inv_pivot;
+            inverse[i][j] *= inv_pivot;
+        }
+        for row in 0..n {
+            let factor = matrix[row][i];
+            if row == i || factor == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                matrix[row][j] -= factor * matrix[i][j];
+                inverse[row][j] -= factor * inverse[i][j];
+            }
+        }
+    }
+
+    inverse
+}
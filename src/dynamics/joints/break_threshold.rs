@@ -0,0 +1,241 @@
+use crate::{
+    dynamics::joints::{JointSystems, joint_graph::JointGraph},
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// A component that automatically breaks a [joint](super) when the force or torque it applies
+/// exceeds a threshold.
+///
+/// The forces are read from the [`JointForces`] of the joint, which is added automatically.
+/// When either threshold is exceeded for at least [`min_duration`](Self::min_duration) seconds,
+/// the joint is broken according to its [`JointBreakAction`], and a [`JointBroken`] event is triggered.
+///
+/// Joints are broken inside the physics step, right after the joint forces have been computed,
+/// so the broken joint no longer constrains the bodies in the next step.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// # fn setup(mut commands: Commands) {
+/// #     let body1 = commands.spawn(RigidBody::Dynamic).id();
+/// #     let body2 = commands.spawn(RigidBody::Dynamic).id();
+/// #
+/// // Despawn the joint when its force exceeds 500 N for at least a tenth of a second.
+/// commands.spawn((
+///     FixedJoint::new(body1, body2),
+///     JointBreakThreshold::from_force(500.0)
+///         .with_min_duration(0.1)
+///         .with_action(JointBreakAction::Despawn),
+/// ));
+/// # }
+///
+/// fn on_joint_broken(event: On<JointBroken>) {
+///     println!("Joint {} broke with a force of {}", event.joint, event.force);
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+#[require(JointForces)]
+pub struct JointBreakThreshold {
+    /// The maximum force the joint can apply before breaking (N).
+    ///
+    /// Default: `f32::INFINITY`
+    pub max_force: f32,
+    /// The maximum torque the joint can apply before breaking (N·m).
+    ///
+    /// Default: `f32::INFINITY`
+    pub max_torque: f32,
+    /// The amount of time that a threshold must be continuously exceeded
+    /// before the joint breaks (seconds).
+    ///
+    /// Default: `0.0`
+    pub min_duration: f32,
+    /// What happens to the joint when it breaks.
+    ///
+    /// Default: [`JointBreakAction::Disable`]
+    pub action: JointBreakAction,
+    /// The amount of time that a threshold has been continuously exceeded (seconds).
+    exceeded_duration: f32,
+}
+
+impl Default for JointBreakThreshold {
+    fn default() -> Self {
+        Self::UNBREAKABLE
+    }
+}
+
+impl JointBreakThreshold {
+    /// A threshold that can never be exceeded.
+    pub const UNBREAKABLE: Self = Self::new(f32::INFINITY, f32::INFINITY);
+
+    /// Creates a new [`JointBreakThreshold`] with the given maximum force (N) and torque (N·m).
+    #[inline]
+    pub const fn new(max_force: f32, max_torque: f32) -> Self {
+        Self {
+            max_force,
+            max_torque,
+            min_duration: 0.0,
+            action: JointBreakAction::Disable,
+            exceeded_duration: 0.0,
+        }
+    }
+
+    /// Creates a new [`JointBreakThreshold`] with the given maximum force (N).
+    /// The torque is unlimited.
+    #[inline]
+    pub const fn from_force(max_force: f32) -> Self {
+        Self::new(max_force, f32::INFINITY)
+    }
+
+    /// Creates a new [`JointBreakThreshold`] with the given maximum torque (N·m).
+    /// The force is unlimited.
+    #[inline]
+    pub const fn from_torque(max_torque: f32) -> Self {
+        Self::new(f32::INFINITY, max_torque)
+    }
+
+    /// Sets the amount of time that a threshold must be continuously exceeded
+    /// before the joint breaks (seconds).
+    #[inline]
+    pub const fn with_min_duration(mut self, min_duration: f32) -> Self {
+        self.min_duration = min_duration;
+        self
+    }
+
+    /// Sets what happens to the joint when it breaks.
+    #[inline]
+    pub const fn with_action(mut self, action: JointBreakAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Returns the amount of time that a threshold has been continuously exceeded (seconds).
+    #[inline]
+    pub const fn exceeded_duration(&self) -> f32 {
+        self.exceeded_duration
+    }
+
+    /// Returns `true` if the given joint forces exceed the force or torque threshold.
+    #[inline]
+    pub fn is_exceeded_by(&self, forces: &JointForces) -> bool {
+        #[cfg(feature = "2d")]
+        let torque = forces.torque().abs();
+        #[cfg(feature = "3d")]
+        let torque = forces.torque().length();
+
+        forces.force().length() > self.max_force || torque > self.max_torque
+    }
+}
+
+/// Determines what happens to a [joint](super) when its [`JointBreakThreshold`] is exceeded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub enum JointBreakAction {
+    /// The joint is disabled by adding the [`JointDisabled`] component.
+    ///
+    /// The joint can be restored by removing the component.
+    #[default]
+    Disable,
+    /// The joint entity is despawned.
+    Despawn,
+}
+
+/// An event that is triggered when a [joint](super) is broken because its [`JointBreakThreshold`] was exceeded.
+///
+/// The event can be observed using an [observer](Observer) on the joint entity or globally,
+/// or read as a [`Message`] using a [`MessageReader`].
+#[derive(EntityEvent, Message, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct JointBroken {
+    /// The joint entity that was broken.
+    ///
+    /// For observers watching this event as an [`EntityEvent`], this is the target entity.
+    #[event_target]
+    pub joint: Entity,
+    /// The first body that was attached to the joint.
+    pub body1: Entity,
+    /// The second body that was attached to the joint.
+    pub body2: Entity,
+    /// The force applied by the joint when it broke.
+    pub force: Vector,
+    /// The torque applied by the joint when it broke.
+    pub torque: AngularVector,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_message::<JointBroken>();
+
+    app.configure_sets(
+        PhysicsSchedule,
+        JointSystems::BreakJoints
+            .after(SolverSystems::Finalize)
+            .before(SolverSystems::StoreContactImpulses),
+    );
+
+    app.add_systems(
+        PhysicsSchedule,
+        break_joints.in_set(JointSystems::BreakJoints),
+    );
+}
+
+/// Breaks joints whose [`JointForces`] exceed their [`JointBreakThreshold`].
+fn break_joints(
+    mut commands: Commands,
+    mut joints: Query<(Entity, &mut JointBreakThreshold, &JointForces), Without<JointDisabled>>,
+    joint_graph: Res<JointGraph>,
+    mut broken_writer: MessageWriter<JointBroken>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (entity, mut threshold, forces) in &mut joints {
+        if !threshold.is_exceeded_by(forces) {
+            if threshold.exceeded_duration != 0.0 {
+                threshold.exceeded_duration = 0.0;
+            }
+            continue;
+        }
+
+        threshold.exceeded_duration += delta_secs;
+
+        if threshold.exceeded_duration < threshold.min_duration {
+            continue;
+        }
+
+        threshold.exceeded_duration = 0.0;
+
+        let Some(edge) = joint_graph.get(entity) else {
+            continue;
+        };
+
+        let event = JointBroken {
+            joint: entity,
+            body1: edge.body1,
+            body2: edge.body2,
+            force: forces.force(),
+            torque: forces.torque(),
+        };
+
+        // Trigger the event before despawning so that observers on the joint entity still run.
+        broken_writer.write(event);
+        commands.trigger(event);
+
+        match threshold.action {
+            JointBreakAction::Disable => {
+                commands.entity(entity).try_insert(JointDisabled);
+            }
+            JointBreakAction::Despawn => {
+                commands.entity(entity).try_despawn();
+            }
+        }
+    }
+}
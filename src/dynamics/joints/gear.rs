@@ -0,0 +1,135 @@
+use crate::dynamics::joints::EntityConstraint;
+#[cfg(feature = "debug-plugin")]
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A gear [joint](dynamics::joints) couples the rotation of two [`RevoluteJoint`]s by a fixed ratio.
+///
+/// This can be useful for things like gearboxes, belt drives, and other mechanisms
+/// where the rotation of one body drives the rotation of another.
+///
+/// A gear joint references two revolute joints, [`joint1`](Self::joint1) and [`joint2`](Self::joint2),
+/// and the two gear bodies that they rotate, [`body1`](Self::body1) and [`body2`](Self::body2).
+/// Each gear body must be one of the bodies attached to its revolute joint, typically the one
+/// that rotates relative to a shared frame such as a gearbox housing.
+///
+/// The joint keeps the change in the angle of `joint2` equal to [`ratio`](Self::ratio) times
+/// the change in the angle of `joint1`, relative to the angles at the time the joint is first simulated.
+/// For two meshing gears with `n1` and `n2` teeth and matching hinge axes, the ratio is `-n1 / n2`.
+///
+/// The gear joint only constrains the rotation of the gear bodies. The referenced revolute joints
+/// are still responsible for attaching the gears to their frames. The bodies that the gears are attached to
+/// are treated as fixed while the gear joint is being solved, so the coupling is most accurate
+/// when the gears share a common frame that is not accelerating rapidly.
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// fn setup(mut commands: Commands) {
+///     let housing = commands.spawn(RigidBody::Static).id();
+///     let gear1 = commands.spawn(RigidBody::Dynamic).id();
+///     let gear2 = commands.spawn(RigidBody::Dynamic).id();
+///
+///     let hinge1 = commands.spawn(RevoluteJoint::new(housing, gear1)).id();
+///     let hinge2 = commands.spawn(RevoluteJoint::new(housing, gear2)).id();
+///
+///     // The second gear has twice as many teeth as the first gear,
+///     // so it rotates at half the speed in the opposite direction.
+///     commands.spawn(GearJoint::new(gear1, gear2, hinge1, hinge2).with_ratio(-0.5));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+pub struct GearJoint {
+    /// The first gear body, rotated by [`joint1`](Self::joint1).
+    pub body1: Entity,
+    /// The second gear body, rotated by [`joint2`](Self::joint2).
+    pub body2: Entity,
+    /// The [`RevoluteJoint`] entity that [`body1`](Self::body1) rotates about.
+    pub joint1: Entity,
+    /// The [`RevoluteJoint`] entity that [`body2`](Self::body2) rotates about.
+    pub joint2: Entity,
+    /// The ratio between the change in the angle of [`joint2`](Self::joint2)
+    /// and the change in the angle of [`joint1`](Self::joint1).
+    pub ratio: f32,
+    /// The joint's compliance, the inverse of stiffness (N * m / rad).
+    pub compliance: f32,
+}
+
+impl EntityConstraint<2> for GearJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.body1, self.body2]
+    }
+}
+
+impl GearJoint {
+    /// Creates a new [`GearJoint`] between two gear bodies, referencing the [`RevoluteJoint`]s
+    /// that they rotate about.
+    ///
+    /// The ratio is initialized to `1.0`, so both joints rotate at the same rate.
+    #[inline]
+    pub const fn new(body1: Entity, body2: Entity, joint1: Entity, joint2: Entity) -> Self {
+        Self {
+            body1,
+            body2,
+            joint1,
+            joint2,
+            ratio: 1.0,
+            compliance: 0.0,
+        }
+    }
+
+    /// Sets the ratio between the change in the angle of [`joint2`](Self::joint2)
+    /// and the change in the angle of [`joint1`](Self::joint1).
+    #[inline]
+    pub const fn with_ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    /// Sets the joint's compliance (inverse of stiffness, N * m / rad).
+    #[inline]
+    pub const fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = compliance;
+        self
+    }
+}
+
+impl MapEntities for GearJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body1 = entity_mapper.get_mapped(self.body1);
+        self.body2 = entity_mapper.get_mapped(self.body2);
+        self.joint1 = entity_mapper.get_mapped(self.joint1);
+        self.joint2 = entity_mapper.get_mapped(self.joint2);
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<2> for GearJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 2],
+        _rotations: [Rotation; 2],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos1, pos2] = positions;
+
+        if let Some(color) = config.joint_separation_color {
+            gizmos.draw_line(pos1, pos2, color);
+        }
+    }
+}
@@ -0,0 +1,591 @@
+use crate::{
+    dynamics::joints::{
+        EntityConstraint, JointSystems,
+        motor::{AngularMotor, LinearMotor},
+    },
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A generic [joint](dynamics::joints) that can lock, limit, or free each translational
+/// and rotational axis individually, and drive each axis with its own motor.
+///
+/// This is also known as a *configurable joint* or a *6DOF joint*. It can be used for constraints
+/// that can not be expressed with the other joint types, such as planar constraints, sliding hinges,
+/// or shoulders with separately limited swing and twist, without stacking multiple joints.
+///
+/// Each generic joint is defined by a [`JointFrame`] on each body. The axes of the joint are the
+/// local axes of the first body's [`JointFrame`]. The motion allowed along or about each axis is configured
+/// with an optional limit, where:
+///
+/// - `None` allows free motion along or about the axis.
+/// - `Some(limit)` limits the motion to the given extents.
+/// - A limit with `min` and `max` set to zero, such as [`DistanceLimit::ZERO`] or [`AngleLimit::ZERO`],
+///   locks the axis completely.
+///
+#[cfg_attr(
+    feature = "2d",
+    doc = "The rotation is limited by a single [`angular_limit`](Self::angular_limit)."
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "The relative rotation can be limited in two ways:
+
+- Per-axis [`angular_limits`](Self::angular_limits), measured using intrinsic Euler angles in the `XYZ` order.
+  To avoid singularities, the rotation about the y-axis should be kept well within the `[-pi/2, pi/2]` range.
+- A [`swing_limit`](Self::swing_limit) that limits the rotation of the x-axis of the joint frame to a cone,
+  and a [`twist_limit`](Self::twist_limit) that limits the rotation about the x-axis. These don't suffer from
+  singularities, so they are better suited for ball-and-socket joints like shoulders and hips."
+)]
+///
+/// By default, all axes are locked, so the joint behaves like a [`FixedJoint`].
+///
+/// # Example
+///
+/// A sliding hinge that can translate along and rotate about the x-axis of the joint frame:
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// # fn setup(mut commands: Commands) {
+/// #     let body1 = commands.spawn(RigidBody::Dynamic).id();
+/// #     let body2 = commands.spawn(RigidBody::Dynamic).id();
+/// #
+/// commands.spawn(
+///     GenericJoint::new(body1, body2)
+///         .with_linear_limits(JointAxis::X, -1.0, 1.0)
+#[cfg_attr(feature = "2d", doc = "        .with_free_angular_axis(),")]
+#[cfg_attr(feature = "3d", doc = "        .with_free_angular_axis(JointAxis::X),")]
+/// );
+/// # }
+/// ```
+///
+/// Each axis can also be driven by a motor. Linear axes use a [`LinearMotor`],
+/// while angular axes use an [`AngularMotor`].
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// # fn setup(mut commands: Commands) {
+/// #     let body1 = commands.spawn(RigidBody::Dynamic).id();
+/// #     let body2 = commands.spawn(RigidBody::Dynamic).id();
+/// #
+/// commands.spawn(
+///     GenericJoint::new(body1, body2)
+///         .with_free_linear_axis(JointAxis::Y)
+///         .with_linear_motor(
+///             JointAxis::Y,
+///             LinearMotor::new(MotorModel::SpringDamper {
+///                 frequency: 2.0,
+///                 damping_ratio: 1.0,
+///             })
+///             .with_target_position(0.5),
+///         ),
+/// );
+/// # }
+/// ```
+#[cfg_attr(
+    feature = "3d",
+    doc = "
+A shoulder that can swing within a cone of 60 degrees and twist by up to 45 degrees
+about the x-axis of the joint frame:
+
+```
+# use avian3d::prelude::*;
+# use bevy::prelude::*;
+# use core::f32::consts::{FRAC_PI_3, FRAC_PI_4};
+#
+# fn setup(mut commands: Commands) {
+#     let body1 = commands.spawn(RigidBody::Dynamic).id();
+#     let body2 = commands.spawn(RigidBody::Dynamic).id();
+#
+commands.spawn(
+    GenericJoint::new(body1, body2)
+        .with_swing_limits(0.0, FRAC_PI_3)
+        .with_twist_limits(-FRAC_PI_4, FRAC_PI_4),
+);
+# }
+```"
+)]
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+#[doc(alias = "ConfigurableJoint")]
+#[doc(alias = "D6Joint")]
+pub struct GenericJoint {
+    /// The first body constrained by the joint.
+    pub body1: Entity,
+    /// The second body constrained by the joint.
+    pub body2: Entity,
+    /// The reference frame of the first body, defining the joint anchor and basis
+    /// relative to the body transform.
+    pub frame1: JointFrame,
+    /// The reference frame of the second body, defining the joint anchor and basis
+    /// relative to the body transform.
+    pub frame2: JointFrame,
+    /// The extents of the allowed relative translation along each axis of the joint frame.
+    ///
+    /// `None` allows free translation along the axis.
+    pub linear_limits: [Option<DistanceLimit>; DIM],
+    /// The extents of the allowed relative rotation.
+    ///
+    /// `None` allows free rotation.
+    #[cfg(feature = "2d")]
+    pub angular_limit: Option<AngleLimit>,
+    /// The extents of the allowed relative rotation about each axis of the joint frame.
+    ///
+    /// `None` allows free rotation about the axis.
+    #[cfg(feature = "3d")]
+    pub angular_limits: [Option<AngleLimit>; 3],
+    /// The extents of the allowed rotation of the x-axis of the joint frame away from the x-axis
+    /// of the other joint frame, as a half-angle of a cone.
+    ///
+    /// `None` allows free swing.
+    #[cfg(feature = "3d")]
+    pub swing_limit: Option<AngleLimit>,
+    /// The extents of the allowed relative rotation about the x-axis of the joint frame.
+    ///
+    /// `None` allows free twist.
+    #[cfg(feature = "3d")]
+    pub twist_limit: Option<AngleLimit>,
+    /// Motors for driving the translation along each axis of the joint frame.
+    pub linear_motors: [LinearMotor; DIM],
+    /// A motor for driving the rotation.
+    #[cfg(feature = "2d")]
+    pub angular_motor: AngularMotor,
+    /// Motors for driving the rotation about each axis of the joint frame.
+    #[cfg(feature = "3d")]
+    pub angular_motors: [AngularMotor; 3],
+    /// The compliance of the translational limits (inverse of stiffness, m / N).
+    pub linear_compliance: f32,
+    /// The compliance of the rotational limits (inverse of stiffness, N * m / rad).
+    pub angular_compliance: f32,
+}
+
+/// An axis of the [`JointFrame`] of a [`GenericJoint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum JointAxis {
+    /// The x-axis of the joint frame.
+    X,
+    /// The y-axis of the joint frame.
+    Y,
+    /// The z-axis of the joint frame.
+    #[cfg(feature = "3d")]
+    Z,
+}
+
+impl JointAxis {
+    /// Returns the index of the axis, where `X` is `0`.
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the unit vector corresponding to the axis.
+    #[inline]
+    pub const fn to_vector(self) -> Vector {
+        match self {
+            Self::X => Vector::X,
+            Self::Y => Vector::Y,
+            #[cfg(feature = "3d")]
+            Self::Z => Vector::Z,
+        }
+    }
+}
+
+impl EntityConstraint<2> for GenericJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.body1, self.body2]
+    }
+}
+
+impl GenericJoint {
+    /// Creates a new [`GenericJoint`] between two entities.
+    ///
+    /// By default, all axes are locked.
+    #[inline]
+    pub const fn new(body1: Entity, body2: Entity) -> Self {
+        Self {
+            body1,
+            body2,
+            frame1: JointFrame::IDENTITY,
+            frame2: JointFrame::IDENTITY,
+            linear_limits: [Some(DistanceLimit::ZERO); DIM],
+            #[cfg(feature = "2d")]
+            angular_limit: Some(AngleLimit::ZERO),
+            #[cfg(feature = "3d")]
+            angular_limits: [Some(AngleLimit::ZERO); 3],
+            #[cfg(feature = "3d")]
+            swing_limit: None,
+            #[cfg(feature = "3d")]
+            twist_limit: None,
+            linear_motors: [LinearMotor::new_disabled(MotorModel::DEFAULT); DIM],
+            #[cfg(feature = "2d")]
+            angular_motor: AngularMotor::new_disabled(MotorModel::DEFAULT),
+            #[cfg(feature = "3d")]
+            angular_motors: [AngularMotor::new_disabled(MotorModel::DEFAULT); 3],
+            linear_compliance: 0.0,
+            angular_compliance: 0.0,
+        }
+    }
+
+    /// Sets the local [`JointFrame`] of the first body, configuring both the [`JointAnchor`] and [`JointBasis`].
+    #[inline]
+    pub fn with_local_frame1(mut self, frame: impl Into<Isometry>) -> Self {
+        self.frame1 = JointFrame::local(frame);
+        self
+    }
+
+    /// Sets the local [`JointFrame`] of the second body, configuring both the [`JointAnchor`] and [`JointBasis`].
+    #[inline]
+    pub fn with_local_frame2(mut self, frame: impl Into<Isometry>) -> Self {
+        self.frame2 = JointFrame::local(frame);
+        self
+    }
+
+    /// Sets the global anchor point on both bodies.
+    ///
+    /// This configures the [`JointAnchor`] of each [`JointFrame`].
+    #[inline]
+    pub const fn with_anchor(mut self, anchor: RVector) -> Self {
+        self.frame1.anchor = JointAnchor::FromGlobal(anchor);
+        self.frame2.anchor = JointAnchor::FromGlobal(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the first body.
+    ///
+    /// This configures the [`JointAnchor`] of the first [`JointFrame`].
+    #[inline]
+    pub const fn with_local_anchor1(mut self, anchor: Vector) -> Self {
+        self.frame1.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the second body.
+    ///
+    /// This configures the [`JointAnchor`] of the second [`JointFrame`].
+    #[inline]
+    pub const fn with_local_anchor2(mut self, anchor: Vector) -> Self {
+        self.frame2.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the global basis for both bodies.
+    ///
+    /// This configures the [`JointBasis`] of each [`JointFrame`].
+    #[inline]
+    pub fn with_basis(mut self, basis: impl Into<Rot>) -> Self {
+        let basis = basis.into();
+        self.frame1.basis = JointBasis::FromGlobal(basis);
+        self.frame2.basis = JointBasis::FromGlobal(basis);
+        self
+    }
+
+    /// Sets the local basis for the first body.
+    ///
+    /// This configures the [`JointBasis`] of the first [`JointFrame`].
+    #[inline]
+    pub fn with_local_basis1(mut self, basis: impl Into<Rot>) -> Self {
+        self.frame1.basis = JointBasis::Local(basis.into());
+        self
+    }
+
+    /// Sets the local basis for the second body.
+    ///
+    /// This configures the [`JointBasis`] of the second [`JointFrame`].
+    #[inline]
+    pub fn with_local_basis2(mut self, basis: impl Into<Rot>) -> Self {
+        self.frame2.basis = JointBasis::Local(basis.into());
+        self
+    }
+
+    /// Returns the local [`JointFrame`] of the first body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, or the [`JointBasis`] is set to
+    /// [`FromGlobal`](JointBasis::FromGlobal), and the local basis has not yet
+    /// been computed, this will return `None`.
+    #[inline]
+    pub fn local_frame1(&self) -> Option<Isometry> {
+        self.frame1.get_local_isometry()
+    }
+
+    /// Returns the local [`JointFrame`] of the second body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, or the [`JointBasis`] is set to
+    /// [`FromGlobal`](JointBasis::FromGlobal), and the local basis has not yet
+    /// been computed, this will return `None`.
+    #[inline]
+    pub fn local_frame2(&self) -> Option<Isometry> {
+        self.frame2.get_local_isometry()
+    }
+
+    /// Returns the local anchor point on the first body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor1(&self) -> Option<Vector> {
+        match self.frame1.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Returns the local anchor point on the second body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor2(&self) -> Option<Vector> {
+        match self.frame2.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Returns the local basis of the first body.
+    ///
+    /// If the [`JointBasis`] is set to [`FromGlobal`](JointBasis::FromGlobal),
+    /// and the local basis has not yet been computed, this will return `None`.
+    #[inline]
+    pub fn local_basis1(&self) -> Option<Rot> {
+        match self.frame1.basis {
+            JointBasis::Local(basis) => Some(basis),
+            _ => None,
+        }
+    }
+
+    /// Returns the local basis of the second body.
+    ///
+    /// If the [`JointBasis`] is set to [`FromGlobal`](JointBasis::FromGlobal),
+    /// and the local basis has not yet been computed, this will return `None`.
+    #[inline]
+    pub fn local_basis2(&self) -> Option<Rot> {
+        match self.frame2.basis {
+            JointBasis::Local(basis) => Some(basis),
+            _ => None,
+        }
+    }
+
+    /// Locks the translation along the given `axis`.
+    #[inline]
+    pub const fn with_locked_linear_axis(mut self, axis: JointAxis) -> Self {
+        self.linear_limits[axis.index()] = Some(DistanceLimit::ZERO);
+        self
+    }
+
+    /// Allows free translation along the given `axis`.
+    #[inline]
+    pub const fn with_free_linear_axis(mut self, axis: JointAxis) -> Self {
+        self.linear_limits[axis.index()] = None;
+        self
+    }
+
+    /// Sets the limits of the allowed translation along the given `axis`.
+    #[inline]
+    pub const fn with_linear_limits(mut self, axis: JointAxis, min: f32, max: f32) -> Self {
+        self.linear_limits[axis.index()] = Some(DistanceLimit::new(min, max));
+        self
+    }
+
+    /// Sets the motor for driving the translation along the given `axis`.
+    #[inline]
+    pub const fn with_linear_motor(mut self, axis: JointAxis, motor: LinearMotor) -> Self {
+        self.linear_motors[axis.index()] = motor;
+        self
+    }
+
+    /// Locks the rotation.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub const fn with_locked_angular_axis(mut self) -> Self {
+        self.angular_limit = Some(AngleLimit::ZERO);
+        self
+    }
+
+    /// Locks the rotation about the given `axis`.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub const fn with_locked_angular_axis(mut self, axis: JointAxis) -> Self {
+        self.angular_limits[axis.index()] = Some(AngleLimit::ZERO);
+        self
+    }
+
+    /// Allows free rotation.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub const fn with_free_angular_axis(mut self) -> Self {
+        self.angular_limit = None;
+        self
+    }
+
+    /// Allows free rotation about the given `axis`.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub const fn with_free_angular_axis(mut self, axis: JointAxis) -> Self {
+        self.angular_limits[axis.index()] = None;
+        self
+    }
+
+    /// Sets the limits of the allowed rotation.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub const fn with_angular_limits(mut self, min: f32, max: f32) -> Self {
+        self.angular_limit = Some(AngleLimit::new(min, max));
+        self
+    }
+
+    /// Sets the limits of the allowed rotation about the given `axis`.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub const fn with_angular_limits(mut self, axis: JointAxis, min: f32, max: f32) -> Self {
+        self.angular_limits[axis.index()] = Some(AngleLimit::new(min, max));
+        self
+    }
+
+    /// Sets the limits of the allowed rotation of the x-axis of the joint frame away from the x-axis
+    /// of the other joint frame, as a half-angle of a cone.
+    ///
+    /// The per-axis [`angular_limits`](Self::angular_limits) are freed, so that the rotation
+    /// is only limited by the swing and twist limits.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub const fn with_swing_limits(mut self, min: f32, max: f32) -> Self {
+        self.angular_limits = [None; 3];
+        self.swing_limit = Some(AngleLimit::new(min, max));
+        self
+    }
+
+    /// Sets the limits of the allowed relative rotation about the x-axis of the joint frame.
+    ///
+    /// The per-axis [`angular_limits`](Self::angular_limits) are freed, so that the rotation
+    /// is only limited by the swing and twist limits.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub const fn with_twist_limits(mut self, min: f32, max: f32) -> Self {
+        self.angular_limits = [None; 3];
+        self.twist_limit = Some(AngleLimit::new(min, max));
+        self
+    }
+
+    /// Sets the motor for driving the rotation.
+    #[cfg(feature = "2d")]
+    #[inline]
+    pub const fn with_angular_motor(mut self, motor: AngularMotor) -> Self {
+        self.angular_motor = motor;
+        self
+    }
+
+    /// Sets the motor for driving the rotation about the given `axis`.
+    #[cfg(feature = "3d")]
+    #[inline]
+    pub const fn with_angular_motor(mut self, axis: JointAxis, motor: AngularMotor) -> Self {
+        self.angular_motors[axis.index()] = motor;
+        self
+    }
+
+    /// Sets the compliance of the translational limits (inverse of stiffness, m / N).
+    #[inline]
+    pub const fn with_linear_compliance(mut self, compliance: f32) -> Self {
+        self.linear_compliance = compliance;
+        self
+    }
+
+    /// Sets the compliance of the rotational limits (inverse of stiffness, N * m / rad).
+    #[inline]
+    pub const fn with_angular_compliance(mut self, compliance: f32) -> Self {
+        self.angular_compliance = compliance;
+        self
+    }
+}
+
+impl MapEntities for GenericJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body1 = entity_mapper.get_mapped(self.body1);
+        self.body2 = entity_mapper.get_mapped(self.body2);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PhysicsSchedule,
+        update_local_frames.in_set(JointSystems::PrepareLocalFrames),
+    );
+}
+
+fn update_local_frames(
+    mut joints: Query<&mut GenericJoint, Changed<GenericJoint>>,
+    bodies: Query<(&Position, &Rotation)>,
+) {
+    for mut joint in &mut joints {
+        if matches!(joint.frame1.anchor, JointAnchor::Local(_))
+            && matches!(joint.frame2.anchor, JointAnchor::Local(_))
+            && matches!(joint.frame1.basis, JointBasis::Local(_))
+            && matches!(joint.frame2.basis, JointBasis::Local(_))
+        {
+            continue;
+        }
+
+        let Ok([(pos1, rot1), (pos2, rot2)]) = bodies.get_many(joint.entities()) else {
+            continue;
+        };
+
+        let [frame1, frame2] =
+            JointFrame::compute_local(joint.frame1, joint.frame2, pos1.0, pos2.0, *rot1, *rot2);
+        joint.frame1 = frame1;
+        joint.frame2 = frame2;
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<2> for GenericJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 2],
+        rotations: [Rotation; 2],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos1, pos2] = positions;
+        let [rot1, rot2] = rotations;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+
+        let anchor1 = pos1 + (rot1 * local_anchor1).real();
+        let anchor2 = pos2 + (rot2 * local_anchor2).real();
+
+        if let Some(anchor_color) = config.joint_anchor_color {
+            gizmos.draw_line(pos1, anchor1, anchor_color);
+            gizmos.draw_line(pos2, anchor2, anchor_color);
+        }
+
+        if let Some(color) = config.joint_separation_color {
+            gizmos.draw_line(anchor1, anchor2, color);
+        }
+    }
+}
@@ -33,6 +33,16 @@ Therefore, they have 3 translational DOF and 3 rotational DOF, a total of 6 DOF.
     feature = "3d",
     doc = "| [`SphericalJoint`] | -                         | 3 Rotations                 |"
 )]
+//! | [`WheelJoint`]     | 1 Translation, 1 Rotation | 1 Translation, 2 Rotations  |
+//! | [`GenericJoint`]   | Configurable              | Configurable                |
+//!
+//! Some joints instead couple the motion of other joints. A [`GearJoint`] couples the angles of two
+//! [`RevoluteJoint`]s by a ratio, and a [`RackAndPinionJoint`] couples the angle of a [`RevoluteJoint`]
+//! to the translation of a [`PrismaticJoint`]. Similarly, a [`PulleyJoint`] couples the distances of two bodies
+//! from fixed ground anchors, like a rope running over two pulleys.
+//!
+//! A [`TargetJoint`] only constrains a single body, pulling it towards a target point in world space.
+//! This is useful for things like dragging bodies with the mouse.
 //!
 //! # Using Joints
 //!
@@ -215,23 +225,40 @@ Therefore, they have 3 translational DOF and 3 rotational DOF, a total of 6 DOF.
 
 pub mod joint_graph;
 
+mod break_threshold;
 mod distance;
 mod fixed;
+mod gear;
+mod generic;
 mod motor;
 mod prismatic;
+mod pulley;
+mod rack_and_pinion;
 mod revolute;
 #[cfg(feature = "3d")]
 mod spherical;
+mod target;
 #[cfg(test)]
 mod tests;
+mod wheel;
 
+pub use break_threshold::{JointBreakAction, JointBreakThreshold, JointBroken};
 pub use distance::DistanceJoint;
 pub use fixed::FixedJoint;
+pub use gear::GearJoint;
+pub use generic::{GenericJoint, JointAxis};
+#[cfg(feature = "3d")]
+pub use motor::OrientationMotor;
+pub(crate) use motor::compute_motor_lagrange;
 pub use motor::{AngularMotor, LinearMotor, MotorModel};
 pub use prismatic::PrismaticJoint;
+pub use pulley::PulleyJoint;
+pub use rack_and_pinion::RackAndPinionJoint;
 pub use revolute::RevoluteJoint;
 #[cfg(feature = "3d")]
 pub use spherical::SphericalJoint;
+pub use target::TargetJoint;
+pub use wheel::WheelJoint;
 
 use crate::{dynamics::joints::joint_graph::JointGraph, prelude::*};
 use bevy::{
@@ -257,6 +284,11 @@ impl Plugin for JointPlugin {
             revolute::plugin,
             #[cfg(feature = "3d")]
             spherical::plugin,
+            wheel::plugin,
+            generic::plugin,
+            pulley::plugin,
+            target::plugin,
+            break_threshold::plugin,
         ));
 
         app.configure_sets(
@@ -273,6 +305,8 @@ impl Plugin for JointPlugin {
 pub enum JointSystems {
     /// A system set for preparing local [`JointFrame`]s.
     PrepareLocalFrames,
+    /// A system set for breaking joints whose forces exceed their [`JointBreakThreshold`].
+    BreakJoints,
 }
 
 /// A trait for constraints between entities.
@@ -514,6 +548,8 @@ impl AngleLimit {
 /// }
 /// ```
 ///
+/// For breaking joints automatically, consider using the [`JointBreakThreshold`] component instead.
+///
 /// Disabled joints can be re-enabled by removing the [`JointDisabled`] component.
 ///
 /// # Related Components
@@ -658,6 +694,7 @@ pub struct JointDamping {
 ///
 /// This can often be useful for determining when to "break" a joint with the [`JointDisabled`] component
 /// when its forces exceed a certain threshold. An example of this can be found in the [`JointDisabled`] documentation.
+/// To break joints automatically inside the physics step, use the [`JointBreakThreshold`] component.
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
@@ -698,6 +735,10 @@ impl JointForces {
     ///
     /// For angular motors ([`AngularMotor`]), this is the torque in N·m.
     /// For linear motors ([`LinearMotor`]), this is the force in N.
+    #[cfg_attr(
+        feature = "3d",
+        doc = "For orientation motors ([`OrientationMotor`]), this is the magnitude of the torque in N·m."
+    )]
     #[inline]
     pub const fn motor_force(&self) -> f32 {
         self.motor_force
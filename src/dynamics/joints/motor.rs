@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use core::f32::consts::TAU;
+
 /// Determines how the joint motor force/torque is computed.
 ///
 /// Different models offer trade-offs between ease of tuning and physical accuracy.
@@ -306,3 +308,178 @@ impl LinearMotor {
         self
     }
 }
+
+/// A motor for driving the relative orientation of a [`SphericalJoint`].
+///
+/// Unlike an [`AngularMotor`], which drives rotation about a single axis, an orientation motor
+/// drives all three rotational degrees of freedom towards a target rotation and/or angular velocity.
+/// This can be used for things like active ragdolls and robotic shoulders.
+///
+/// The target rotation is the desired rotation of the second body's [`JointFrame`] relative to
+/// the first body's [`JointFrame`], and the target angular velocity is expressed in the local space
+/// of the first body's [`JointFrame`].
+///
+/// ```
+/// # use avian3d::prelude::*;
+/// # use bevy::prelude::*;
+/// # use core::f32::consts::FRAC_PI_4;
+/// #
+/// # fn setup(mut commands: Commands) {
+/// #     let body1 = commands.spawn(RigidBody::Dynamic).id();
+/// #     let body2 = commands.spawn(RigidBody::Dynamic).id();
+/// #
+/// commands.spawn(
+///     SphericalJoint::new(body1, body2).with_motor(
+///         OrientationMotor::new(MotorModel::SpringDamper {
+///             frequency: 2.0,
+///             damping_ratio: 1.0,
+///         })
+///         .with_target_rotation(Quat::from_rotation_x(FRAC_PI_4)),
+///     ),
+/// );
+/// # }
+/// ```
+///
+/// [`SphericalJoint`]: crate::dynamics::joints::spherical::SphericalJoint
+/// [`JointFrame`]: crate::dynamics::joints::JointFrame
+#[cfg(feature = "3d")]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct OrientationMotor {
+    /// Whether the motor is enabled.
+    pub enabled: bool,
+    /// The target angular velocity (rad/s) in the local space of the first joint frame.
+    pub target_velocity: Vec3,
+    /// The target rotation of the second joint frame relative to the first joint frame.
+    pub target_rotation: Quat,
+    /// The maximum torque the motor can apply (N·m).
+    ///
+    /// This limits the magnitude of the total torque, not the torque about each individual axis.
+    pub max_torque: f32,
+    /// The motor model used for computing the motor torque.
+    pub motor_model: MotorModel,
+}
+
+#[cfg(feature = "3d")]
+impl Default for OrientationMotor {
+    fn default() -> Self {
+        Self::new(MotorModel::DEFAULT)
+    }
+}
+
+#[cfg(feature = "3d")]
+impl OrientationMotor {
+    /// Creates a new orientation motor with the given motor model.
+    #[inline]
+    pub const fn new(motor_model: MotorModel) -> Self {
+        Self {
+            enabled: true,
+            target_velocity: Vec3::ZERO,
+            target_rotation: Quat::IDENTITY,
+            max_torque: f32::MAX,
+            motor_model,
+        }
+    }
+
+    /// Creates a new disabled orientation motor with the given motor model.
+    ///
+    /// To enable the motor later, use [`set_enabled`](Self::set_enabled).
+    #[inline]
+    pub const fn new_disabled(motor_model: MotorModel) -> Self {
+        Self {
+            enabled: false,
+            ..Self::new(motor_model)
+        }
+    }
+
+    /// Enables or disables the motor.
+    #[inline]
+    pub const fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the target angular velocity in radians per second,
+    /// expressed in the local space of the first joint frame.
+    #[inline]
+    pub const fn with_target_velocity(mut self, velocity: Vec3) -> Self {
+        self.target_velocity = velocity;
+        self
+    }
+
+    /// Sets the target rotation of the second joint frame relative to the first joint frame.
+    #[inline]
+    pub const fn with_target_rotation(mut self, target_rotation: Quat) -> Self {
+        self.target_rotation = target_rotation;
+        self
+    }
+
+    /// Sets the maximum torque the motor can apply.
+    #[inline]
+    pub const fn with_max_torque(mut self, max_torque: f32) -> Self {
+        self.max_torque = max_torque;
+        self
+    }
+
+    /// Sets the motor model used for computing the motor torque.
+    #[inline]
+    pub const fn with_motor_model(mut self, motor_model: MotorModel) -> Self {
+        self.motor_model = motor_model;
+        self
+    }
+}
+
+/// Computes the Lagrange multiplier update for a motor driving a single axis of a joint.
+///
+/// `w_sum` is the sum of the generalized inverse masses of the bodies along the motor axis,
+/// and `max_force` is the maximum force (N) or torque (N·m) the motor can apply.
+///
+/// Returns `None` if the correction is negligible.
+pub(crate) fn compute_motor_lagrange(
+    velocity_error: f32,
+    position_error: f32,
+    w_sum: f32,
+    motor_model: MotorModel,
+    max_force: f32,
+    dt: f32,
+) -> Option<f32> {
+    let target_velocity_change = match motor_model {
+        MotorModel::SpringDamper {
+            frequency,
+            damping_ratio,
+        } => {
+            // Implicit Euler formulation for stable spring-damper behavior.
+            let omega = TAU * frequency;
+            let omega_sq = omega * omega;
+            let two_zeta_omega = 2.0 * damping_ratio * omega;
+            let inv_denominator = 1.0 / (1.0 + two_zeta_omega * dt + omega_sq * dt * dt);
+            (omega_sq * position_error + two_zeta_omega * velocity_error) * dt * inv_denominator
+        }
+        MotorModel::AccelerationBased { stiffness, damping } => {
+            damping * velocity_error + stiffness * position_error * dt
+        }
+        MotorModel::ForceBased { stiffness, damping } => {
+            // Velocity change = (stiffness * pos_error + damping * vel_error) * inv_mass
+            (stiffness * position_error + damping * velocity_error) * w_sum
+        }
+    };
+
+    let correction = target_velocity_change * dt;
+    if correction.abs() <= f32::EPSILON {
+        return None;
+    }
+
+    let delta_lagrange = correction / w_sum;
+
+    // Clamp to limit instantaneous force or torque per substep.
+    let delta_lagrange = if max_force < f32::MAX && max_force > 0.0 {
+        let max_delta = max_force * dt * dt;
+        delta_lagrange.clamp(-max_delta, max_delta)
+    } else {
+        delta_lagrange
+    };
+
+    Some(delta_lagrange)
+}
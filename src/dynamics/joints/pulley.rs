@@ -0,0 +1,253 @@
+use crate::{
+    dynamics::joints::{EntityConstraint, JointSystems},
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A pulley [joint](dynamics::joints) connects two bodies with a rope that runs over two fixed ground anchors.
+///
+/// This can be useful for things like elevators, counterweights, and block and tackle systems.
+///
+/// A pulley joint is defined by a [`JointAnchor`] on each body, and a ground anchor for each body in world space.
+/// The rope runs from the anchor on the first body to the first ground anchor, over to the second ground anchor,
+/// and down to the anchor on the second body.
+///
+/// The joint keeps `length1 + ratio * length2` at or below the [`length`](Self::length) of the rope,
+/// where `length1` and `length2` are the distances between the body anchors and their ground anchors.
+/// The rope can go slack, but it cannot push the bodies apart. A [`ratio`](Self::ratio) other than `1.0`
+/// can be used to simulate a block and tackle, where one side of the rope moves faster than the other.
+///
+/// If the [`length`](Self::length) is not set, it is computed from the initial configuration of the bodies.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+pub struct PulleyJoint {
+    /// The first body constrained by the joint.
+    pub body1: Entity,
+    /// The second body constrained by the joint.
+    pub body2: Entity,
+    /// The joint anchor point on the first body.
+    pub anchor1: JointAnchor,
+    /// The joint anchor point on the second body.
+    pub anchor2: JointAnchor,
+    /// The world-space ground anchor that the rope of the first body runs over.
+    pub ground_anchor1: RVector,
+    /// The world-space ground anchor that the rope of the second body runs over.
+    pub ground_anchor2: RVector,
+    /// The ratio by which the rope length on the second side is scaled.
+    ///
+    /// The joint keeps `length1 + ratio * length2` at or below the [`length`](Self::length) of the rope.
+    pub ratio: f32,
+    /// The total length of the rope, `length1 + ratio * length2`.
+    ///
+    /// If `None`, this is computed from the initial configuration of the bodies.
+    pub length: Option<f32>,
+    /// The joint's compliance, the inverse of stiffness (m / N).
+    pub compliance: f32,
+}
+
+impl EntityConstraint<2> for PulleyJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.body1, self.body2]
+    }
+}
+
+impl PulleyJoint {
+    /// Creates a new [`PulleyJoint`] between two entities, with the rope running over
+    /// the given world-space ground anchors.
+    #[inline]
+    pub const fn new(
+        body1: Entity,
+        body2: Entity,
+        ground_anchor1: RVector,
+        ground_anchor2: RVector,
+    ) -> Self {
+        Self {
+            body1,
+            body2,
+            anchor1: JointAnchor::ZERO,
+            anchor2: JointAnchor::ZERO,
+            ground_anchor1,
+            ground_anchor2,
+            ratio: 1.0,
+            length: None,
+            compliance: 0.0,
+        }
+    }
+
+    /// Sets the local anchor point on the first body.
+    ///
+    /// This configures the [`JointAnchor`] of the first body.
+    #[inline]
+    pub const fn with_local_anchor1(mut self, anchor: Vector) -> Self {
+        self.anchor1 = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the second body.
+    ///
+    /// This configures the [`JointAnchor`] of the second body.
+    #[inline]
+    pub const fn with_local_anchor2(mut self, anchor: Vector) -> Self {
+        self.anchor2 = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the global anchor point on the first body.
+    ///
+    /// This configures the [`JointAnchor`] of the first body.
+    #[inline]
+    pub const fn with_global_anchor1(mut self, anchor: RVector) -> Self {
+        self.anchor1 = JointAnchor::FromGlobal(anchor);
+        self
+    }
+
+    /// Sets the global anchor point on the second body.
+    ///
+    /// This configures the [`JointAnchor`] of the second body.
+    #[inline]
+    pub const fn with_global_anchor2(mut self, anchor: RVector) -> Self {
+        self.anchor2 = JointAnchor::FromGlobal(anchor);
+        self
+    }
+
+    /// Returns the local anchor point on the first body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor1(&self) -> Option<Vector> {
+        match self.anchor1 {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Returns the local anchor point on the second body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor2(&self) -> Option<Vector> {
+        match self.anchor2 {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Sets the ratio by which the rope length on the second side is scaled.
+    #[inline]
+    pub const fn with_ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    /// Sets the total length of the rope, `length1 + ratio * length2`.
+    ///
+    /// By default, this is computed from the initial configuration of the bodies.
+    #[inline]
+    pub const fn with_length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the joint's compliance (inverse of stiffness, m / N).
+    #[inline]
+    pub const fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = compliance;
+        self
+    }
+}
+
+impl MapEntities for PulleyJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body1 = entity_mapper.get_mapped(self.body1);
+        self.body2 = entity_mapper.get_mapped(self.body2);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PhysicsSchedule,
+        update_local_anchors.in_set(JointSystems::PrepareLocalFrames),
+    );
+}
+
+fn update_local_anchors(
+    mut joints: Query<&mut PulleyJoint, Changed<PulleyJoint>>,
+    bodies: Query<(&Position, &Rotation)>,
+) {
+    for mut joint in &mut joints {
+        if matches!(joint.anchor1, JointAnchor::Local(_))
+            && matches!(joint.anchor2, JointAnchor::Local(_))
+            && joint.length.is_some()
+        {
+            continue;
+        }
+
+        let Ok([(pos1, rot1), (pos2, rot2)]) = bodies.get_many(joint.entities()) else {
+            continue;
+        };
+
+        let [anchor1, anchor2] =
+            JointAnchor::compute_local(joint.anchor1, joint.anchor2, pos1.0, pos2.0, *rot1, *rot2);
+        joint.anchor1 = anchor1;
+        joint.anchor2 = anchor2;
+
+        // Compute the rope length from the initial configuration.
+        if joint.length.is_none()
+            && let (JointAnchor::Local(local_anchor1), JointAnchor::Local(local_anchor2)) =
+                (anchor1, anchor2)
+        {
+            let length1 = (pos1.0 - joint.ground_anchor1).f32() + *rot1 * local_anchor1;
+            let length2 = (pos2.0 - joint.ground_anchor2).f32() + *rot2 * local_anchor2;
+            joint.length = Some(length1.length() + joint.ratio * length2.length());
+        }
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<2> for PulleyJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 2],
+        rotations: [Rotation; 2],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos1, pos2] = positions;
+        let [rot1, rot2] = rotations;
+
+        let JointAnchor::Local(local_anchor1) = self.anchor1 else {
+            return;
+        };
+        let JointAnchor::Local(local_anchor2) = self.anchor2 else {
+            return;
+        };
+
+        let anchor1 = pos1 + (rot1 * local_anchor1).real();
+        let anchor2 = pos2 + (rot2 * local_anchor2).real();
+
+        if let Some(anchor_color) = config.joint_anchor_color {
+            gizmos.draw_line(pos1, anchor1, anchor_color);
+            gizmos.draw_line(pos2, anchor2, anchor_color);
+        }
+
+        // Draw the rope from the first body over the ground anchors to the second body.
+        if let Some(color) = config.joint_separation_color {
+            gizmos.draw_line(anchor1, self.ground_anchor1, color);
+            gizmos.draw_line(self.ground_anchor1, self.ground_anchor2, color);
+            gizmos.draw_line(self.ground_anchor2, anchor2, color);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::dynamics::joints::EntityConstraint;
+#[cfg(feature = "debug-plugin")]
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A rack-and-pinion [joint](dynamics::joints) couples the rotation of a [`RevoluteJoint`]
+/// to the translation of a [`PrismaticJoint`] by a fixed ratio.
+///
+/// This can be useful for things like steering racks, linear actuators, and cog railways,
+/// where rotation is converted into translation or vice versa.
+///
+/// A rack-and-pinion joint references a revolute joint, [`pinion_joint`](Self::pinion_joint),
+/// and a prismatic joint, [`rack_joint`](Self::rack_joint), along with the pinion body
+/// [`body1`](Self::body1) that the revolute joint rotates and the rack body [`body2`](Self::body2)
+/// that the prismatic joint translates. Each body must be one of the bodies attached to its joint.
+///
+/// The joint keeps the change in the translation of the rack joint equal to [`ratio`](Self::ratio)
+/// times the change in the angle of the pinion joint, relative to the configuration at the time
+/// the joint is first simulated. For a pinion gear that rolls along the rack without slipping,
+/// the ratio is the radius of the pinion, with the sign depending on the orientation of the joint axes.
+///
+/// The rack-and-pinion joint only constrains the rotation of the pinion and the translation of the rack.
+/// The referenced joints are still responsible for attaching the bodies to their frames. The bodies that
+/// the pinion and rack are attached to are treated as fixed while the joint is being solved, so the coupling
+/// is most accurate when they share a common frame that is not accelerating rapidly.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+pub struct RackAndPinionJoint {
+    /// The pinion body, rotated by the [`pinion_joint`](Self::pinion_joint).
+    pub body1: Entity,
+    /// The rack body, translated by the [`rack_joint`](Self::rack_joint).
+    pub body2: Entity,
+    /// The [`RevoluteJoint`] entity that the pinion body rotates about.
+    pub pinion_joint: Entity,
+    /// The [`PrismaticJoint`] entity that the rack body slides along.
+    pub rack_joint: Entity,
+    /// The ratio between the change in the translation of the [`rack_joint`](Self::rack_joint)
+    /// and the change in the angle of the [`pinion_joint`](Self::pinion_joint) (m / rad).
+    pub ratio: f32,
+    /// The joint's compliance, the inverse of stiffness (m / N).
+    pub compliance: f32,
+}
+
+impl EntityConstraint<2> for RackAndPinionJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.body1, self.body2]
+    }
+}
+
+impl RackAndPinionJoint {
+    /// Creates a new [`RackAndPinionJoint`] between a pinion body and a rack body, referencing
+    /// the [`RevoluteJoint`] that the pinion rotates about and the [`PrismaticJoint`] that the rack slides along.
+    ///
+    /// The ratio is initialized to `1.0`, so the rack translates by one meter per radian of pinion rotation.
+    #[inline]
+    pub const fn new(
+        pinion_body: Entity,
+        rack_body: Entity,
+        pinion_joint: Entity,
+        rack_joint: Entity,
+    ) -> Self {
+        Self {
+            body1: pinion_body,
+            body2: rack_body,
+            pinion_joint,
+            rack_joint,
+            ratio: 1.0,
+            compliance: 0.0,
+        }
+    }
+
+    /// Sets the ratio between the change in the translation of the [`rack_joint`](Self::rack_joint)
+    /// and the change in the angle of the [`pinion_joint`](Self::pinion_joint) (m / rad).
+    ///
+    /// For a pinion gear that rolls along the rack without slipping, this is the radius of the pinion.
+    #[inline]
+    pub const fn with_ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    /// Sets the joint's compliance (inverse of stiffness, m / N).
+    #[inline]
+    pub const fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = compliance;
+        self
+    }
+}
+
+impl MapEntities for RackAndPinionJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body1 = entity_mapper.get_mapped(self.body1);
+        self.body2 = entity_mapper.get_mapped(self.body2);
+        self.pinion_joint = entity_mapper.get_mapped(self.pinion_joint);
+        self.rack_joint = entity_mapper.get_mapped(self.rack_joint);
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<2> for RackAndPinionJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 2],
+        _rotations: [Rotation; 2],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos1, pos2] = positions;
+
+        if let Some(color) = config.joint_separation_color {
+            gizmos.draw_line(pos1, pos2, color);
+        }
+    }
+}
@@ -26,6 +26,9 @@ use bevy::{
 /// of the allowed swing as a half-angle.
 ///
 #[doc = include_str!("./images/swing_twist_limit.svg")]
+///
+/// The joint can also drive the relative orientation of the bodies towards a target rotation
+/// and/or angular velocity using an [`OrientationMotor`]. The motor is disabled by default.
 #[derive(Component, Clone, Debug, PartialEq, Reflect)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
@@ -58,6 +61,8 @@ pub struct SphericalJoint {
     pub swing_compliance: f32,
     /// The compliance for twist (inverse of stiffness, N * m / rad).
     pub twist_compliance: f32,
+    /// A motor for driving the relative orientation of the bodies.
+    pub motor: OrientationMotor,
 }
 
 impl EntityConstraint<2> for SphericalJoint {
@@ -84,6 +89,7 @@ impl SphericalJoint {
             point_compliance: 0.0,
             swing_compliance: 0.0,
             twist_compliance: 0.0,
+            motor: OrientationMotor::new_disabled(MotorModel::DEFAULT),
         }
     }
 
@@ -317,6 +323,13 @@ impl SphericalJoint {
         self.twist_compliance = compliance;
         self
     }
+
+    /// Sets the motor for the joint.
+    #[inline]
+    pub const fn with_motor(mut self, motor: OrientationMotor) -> Self {
+        self.motor = motor;
+        self
+    }
 }
 
 impl MapEntities for SphericalJoint {
@@ -0,0 +1,220 @@
+use crate::{
+    dynamics::{
+        joints::{EntityConstraint, JointSystems},
+        solver::islands::WakeBody,
+    },
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A target [joint](dynamics::joints) pulls an anchor point on a single body towards a [`target`](Self::target)
+/// point in world space.
+///
+/// Unlike the other joints, a target joint only constrains one body. The pull is modeled as a soft spring-damper
+/// defined by a [`MotorModel`], and the force it can apply is limited by [`max_force`](Self::max_force).
+/// This makes it well suited for interactively dragging bodies with the mouse, since the body
+/// still responds to collisions and other joints instead of being teleported.
+///
+/// The [`target`](Self::target) can be moved freely every frame. If the body is [`Sleeping`],
+/// it is woken up when the target changes.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::{math::RVector, prelude::*};")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::{math::RVector, prelude::*};")]
+/// # use bevy::prelude::*;
+/// #
+/// # fn setup(mut commands: Commands) {
+/// #     let body = commands.spawn(RigidBody::Dynamic).id();
+/// #
+/// // Pull the body towards the origin with a soft spring.
+/// commands.spawn(
+///     TargetJoint::new(body, RVector::ZERO)
+///         .with_motor_model(MotorModel::SpringDamper {
+///             frequency: 2.0,
+///             damping_ratio: 0.7,
+///         })
+///         .with_max_force(1000.0),
+/// );
+/// # }
+/// ```
+#[cfg_attr(
+    feature = "bevy_picking",
+    doc = r#"
+The [`PhysicsPickingPlugin`](crate::picking::PhysicsPickingPlugin) can also create target joints automatically
+for dragging bodies that have the [`PhysicsDraggable`](crate::picking::PhysicsDraggable) component."#
+)]
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+pub struct TargetJoint {
+    /// The body constrained by the joint.
+    pub body: Entity,
+    /// The joint anchor point on the body.
+    pub anchor: JointAnchor,
+    /// The world-space point that the anchor is pulled towards.
+    pub target: RVector,
+    /// The model used for pulling the anchor towards the target.
+    ///
+    /// Default: [`MotorModel::DEFAULT`]
+    pub motor_model: MotorModel,
+    /// The maximum force the joint can apply (N).
+    ///
+    /// Default: `f32::MAX`
+    pub max_force: f32,
+}
+
+impl EntityConstraint<1> for TargetJoint {
+    fn entities(&self) -> [Entity; 1] {
+        [self.body]
+    }
+}
+
+impl TargetJoint {
+    /// Creates a new [`TargetJoint`] that pulls the given body towards a world-space `target`.
+    #[inline]
+    pub const fn new(body: Entity, target: RVector) -> Self {
+        Self {
+            body,
+            anchor: JointAnchor::ZERO,
+            target,
+            motor_model: MotorModel::DEFAULT,
+            max_force: f32::MAX,
+        }
+    }
+
+    /// Sets the local anchor point on the body.
+    ///
+    /// This configures the [`JointAnchor`] of the body.
+    #[inline]
+    pub const fn with_local_anchor(mut self, anchor: Vector) -> Self {
+        self.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the global anchor point on the body.
+    ///
+    /// This configures the [`JointAnchor`] of the body.
+    #[inline]
+    pub const fn with_global_anchor(mut self, anchor: RVector) -> Self {
+        self.anchor = JointAnchor::FromGlobal(anchor);
+        self
+    }
+
+    /// Returns the local anchor point on the body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor(&self) -> Option<Vector> {
+        match self.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Sets the world-space point that the anchor is pulled towards.
+    #[inline]
+    pub const fn with_target(mut self, target: RVector) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the model used for pulling the anchor towards the target.
+    #[inline]
+    pub const fn with_motor_model(mut self, motor_model: MotorModel) -> Self {
+        self.motor_model = motor_model;
+        self
+    }
+
+    /// Sets the maximum force the joint can apply (N).
+    #[inline]
+    pub const fn with_max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force;
+        self
+    }
+}
+
+impl MapEntities for TargetJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body = entity_mapper.get_mapped(self.body);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PhysicsSchedule,
+        (update_local_anchors, wake_dragged_bodies)
+            .chain()
+            .in_set(JointSystems::PrepareLocalFrames),
+    );
+}
+
+fn update_local_anchors(
+    mut joints: Query<&mut TargetJoint, Changed<TargetJoint>>,
+    bodies: Query<(&Position, &Rotation)>,
+) {
+    for mut joint in &mut joints {
+        let JointAnchor::FromGlobal(global_anchor) = joint.anchor else {
+            continue;
+        };
+
+        let Ok((pos, rot)) = bodies.get(joint.body) else {
+            continue;
+        };
+
+        joint.anchor = JointAnchor::Local(rot.inverse() * (global_anchor - pos.0).f32());
+    }
+}
+
+/// Wakes up [`Sleeping`] bodies whose [`TargetJoint`] has changed.
+fn wake_dragged_bodies(
+    mut commands: Commands,
+    joints: Query<&TargetJoint, (Changed<TargetJoint>, Without<JointDisabled>)>,
+    sleeping_bodies: Query<(), With<Sleeping>>,
+) {
+    for joint in &joints {
+        if sleeping_bodies.contains(joint.body) {
+            commands.queue_silenced(WakeBody(joint.body));
+        }
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<1> for TargetJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 1],
+        rotations: [Rotation; 1],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos] = positions;
+        let [rot] = rotations;
+
+        let JointAnchor::Local(local_anchor) = self.anchor else {
+            return;
+        };
+
+        let anchor = pos + (rot * local_anchor).real();
+
+        if let Some(anchor_color) = config.joint_anchor_color {
+            gizmos.draw_line(pos, anchor, anchor_color);
+        }
+
+        if let Some(separation_color) = config.joint_separation_color {
+            gizmos.draw_line(anchor, self.target, separation_color);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "3d")]
+use core::f32::consts::{PI, TAU};
 use core::time::Duration;
 
 #[cfg(feature = "2d")]
@@ -851,3 +853,1119 @@ fn prismatic_motor_combined_position_velocity() {
         displacement
     );
 }
+
+/// Tests that the motor of a wheel joint spins the wheel,
+/// and that the suspension pulls the wheel back to its rest position.
+#[test]
+fn wheel_joint_motor_and_suspension() {
+    let mut app = create_app();
+    app.finish();
+
+    let chassis = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let wheel = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Position(RVector::NEG_Y * 0.5),
+            Mass(1.0),
+            #[cfg(feature = "2d")]
+            AngularInertia(1.0),
+            #[cfg(feature = "3d")]
+            AngularInertia::new(Vec3::splat(1.0)),
+        ))
+        .id();
+
+    // The suspension rests at one unit below the chassis.
+    app.world_mut().spawn(
+        WheelJoint::new(chassis, wheel)
+            .with_local_anchor1(Vector::NEG_Y)
+            .with_suspension_spring(5.0, 1.0)
+            .with_motor(AngularMotor {
+                target_velocity: 2.0,
+                max_torque: 100.0,
+                motor_model: MotorModel::AccelerationBased {
+                    stiffness: 0.0,
+                    damping: 10.0,
+                },
+                ..default()
+            }),
+    );
+
+    app.update();
+
+    // Run for 2 seconds.
+    let duration = 2.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let body_ref = app.world().entity(wheel);
+    let position = body_ref.get::<Position>().unwrap().0;
+    let angular_velocity = body_ref.get::<AngularVelocity>().unwrap().0;
+
+    assert!(
+        (position.y + 1.0).abs() < 0.05,
+        "Suspension should return to its rest position: {}",
+        position.y
+    );
+    assert!(
+        position.x.abs() < 0.01,
+        "Wheel should stay on the slider axis: {}",
+        position.x
+    );
+
+    #[cfg(feature = "2d")]
+    assert_relative_eq!(angular_velocity, 2.0, epsilon = 0.5);
+    #[cfg(feature = "3d")]
+    assert!(
+        (angular_velocity.x - 2.0).abs() < 0.5,
+        "Wheel should spin about the spin axis: {}",
+        angular_velocity
+    );
+}
+
+/// Tests that an orientation motor on a spherical joint drives the attached body
+/// towards the target rotation.
+#[cfg(feature = "3d")]
+#[test]
+fn spherical_motor_target_rotation() {
+    let mut app = create_app();
+    app.finish();
+
+    let anchor = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let dynamic = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Position(RVector::ZERO),
+            Mass(1.0),
+            AngularInertia::new(Vec3::splat(1.0)),
+        ))
+        .id();
+
+    let target_rotation = Quat::from_rotation_x(0.8) * Quat::from_rotation_z(-0.5);
+    app.world_mut().spawn(
+        SphericalJoint::new(anchor, dynamic).with_motor(
+            OrientationMotor::new(MotorModel::SpringDamper {
+                frequency: 2.0,
+                damping_ratio: 1.0,
+            })
+            .with_target_rotation(target_rotation),
+        ),
+    );
+
+    app.update();
+
+    // Run simulation for 3 seconds to let the spring settle.
+    let duration = 3.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let body_ref = app.world().entity(dynamic);
+    let rotation = body_ref.get::<Rotation>().unwrap();
+
+    let angle_to_target = rotation.0.angle_between(target_rotation);
+    assert!(
+        angle_to_target < 0.05,
+        "Orientation motor should reach the target rotation, remaining angle: {}",
+        angle_to_target
+    );
+}
+
+/// Tests that a generic joint with a free linear axis and a free angular axis
+/// only allows translation along and rotation about that axis.
+#[test]
+fn generic_joint_sliding_hinge() {
+    let mut app = create_app();
+    app.finish();
+
+    let anchor = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let dynamic = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Position(RVector::ZERO),
+            Mass(1.0),
+            #[cfg(feature = "2d")]
+            AngularInertia(1.0),
+            #[cfg(feature = "3d")]
+            AngularInertia::new(Vec3::splat(1.0)),
+            LinearVelocity(Vector::ONE),
+            #[cfg(feature = "2d")]
+            AngularVelocity(1.0),
+            #[cfg(feature = "3d")]
+            AngularVelocity(Vector::ONE),
+        ))
+        .id();
+
+    #[cfg(feature = "2d")]
+    let joint = GenericJoint::new(anchor, dynamic)
+        .with_free_linear_axis(JointAxis::X)
+        .with_free_angular_axis();
+    #[cfg(feature = "3d")]
+    let joint = GenericJoint::new(anchor, dynamic)
+        .with_free_linear_axis(JointAxis::X)
+        .with_free_angular_axis(JointAxis::X);
+    app.world_mut().spawn(joint);
+
+    app.update();
+
+    // Run for 1 second.
+    let duration = 1.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let body_ref = app.world().entity(dynamic);
+    let position = body_ref.get::<Position>().unwrap().0;
+    let rotation = body_ref.get::<Rotation>().unwrap();
+
+    assert!(
+        position.x > 0.5,
+        "Body should slide along the free axis: {}",
+        position.x
+    );
+    assert!(
+        position.y.abs() < 0.01,
+        "Body should not move along locked axes: {}",
+        position
+    );
+
+    #[cfg(feature = "2d")]
+    assert!(
+        rotation.as_radians() > 0.5,
+        "Body should rotate freely: {}",
+        rotation.as_radians()
+    );
+    #[cfg(feature = "3d")]
+    {
+        assert!(
+            position.z.abs() < 0.01,
+            "Body should not move along locked axes: {}",
+            position
+        );
+        let (axis, angle) = rotation.to_axis_angle();
+        assert!(
+            angle > 0.5 && axis.dot(Vec3::X) > 0.99,
+            "Body should only rotate about the free axis: axis {}, angle {}",
+            axis,
+            angle
+        );
+    }
+}
+
+/// Tests that a linear motor on a free axis of a generic joint drives the body
+/// towards the target position.
+#[test]
+fn generic_joint_linear_motor() {
+    let mut app = create_app();
+    app.finish();
+
+    let anchor = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let dynamic = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Position(RVector::ZERO),
+            Mass(1.0),
+            #[cfg(feature = "2d")]
+            AngularInertia(1.0),
+            #[cfg(feature = "3d")]
+            AngularInertia::new(Vec3::splat(1.0)),
+        ))
+        .id();
+
+    let target_position = 0.5;
+    app.world_mut().spawn(
+        GenericJoint::new(anchor, dynamic)
+            .with_free_linear_axis(JointAxis::Y)
+            .with_linear_motor(
+                JointAxis::Y,
+                LinearMotor::new(MotorModel::SpringDamper {
+                    frequency: 2.0,
+                    damping_ratio: 1.0,
+                })
+                .with_target_position(target_position),
+            ),
+    );
+
+    app.update();
+
+    // Run simulation for 3 seconds to let the spring settle.
+    let duration = 3.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let body_ref = app.world().entity(dynamic);
+    let position = body_ref.get::<Position>().unwrap().0;
+
+    assert!(
+        (position.y - target_position).abs() < 0.05,
+        "Linear motor should reach the target position: {}",
+        position.y
+    );
+    assert!(
+        position.x.abs() < 0.01,
+        "Body should not move along locked axes: {}",
+        position.x
+    );
+}
+
+/// Tests that the swing and twist limits of a generic joint keep the rotation
+/// within a cone and twist range, even past the singularity of Euler angle limits.
+#[cfg(feature = "3d")]
+#[test]
+fn generic_joint_swing_twist_limits() {
+    let mut app = create_app();
+    app.finish();
+
+    let anchor = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let dynamic = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Position(RVector::ZERO),
+            Mass(1.0),
+            AngularInertia::new(Vec3::splat(1.0)),
+            AngularVelocity(Vec3::new(5.0, 5.0, 5.0)),
+        ))
+        .id();
+
+    // Allow swinging past 90 degrees, where Euler angle limits would be singular.
+    let swing_limit = 1.6;
+    let twist_limit = 0.4;
+    app.world_mut().spawn(
+        GenericJoint::new(anchor, dynamic)
+            .with_swing_limits(0.0, swing_limit)
+            .with_twist_limits(-twist_limit, twist_limit),
+    );
+
+    app.update();
+
+    // Run for 1 second.
+    let duration = 1.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+
+        let rotation = app.world().entity(dynamic).get::<Rotation>().unwrap().0;
+
+        // Decompose the rotation into a swing of the x-axis and a twist about it.
+        let swing = (rotation * Vec3::X).angle_between(Vec3::X);
+        let twist = 2.0 * rotation.x.atan2(rotation.w);
+        let twist = (twist + PI).rem_euclid(TAU) - PI;
+
+        assert!(
+            swing < swing_limit + 0.05,
+            "Swing should stay within the cone: {}",
+            swing
+        );
+        assert!(
+            twist.abs() < twist_limit + 0.05,
+            "Twist should stay within the limits: {}",
+            twist
+        );
+    }
+}
+
+/// Returns the angle of the body's local x axis in the xy plane.
+fn planar_angle(rotation: &Rotation) -> f32 {
+    let x_axis = *rotation * Vector::X;
+    x_axis.y.atan2(x_axis.x)
+}
+
+/// Tests that a gear joint couples the angles of two revolute joints by the gear ratio.
+#[test]
+fn gear_joint_couples_revolute_joints() {
+    let mut app = create_app();
+    app.finish();
+
+    let mut spawn_gear = |position: RVector| {
+        let housing = app
+            .world_mut()
+            .spawn((RigidBody::Static, Position(position)))
+            .id();
+        let gear = app
+            .world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                Position(position),
+                Mass(1.0),
+                #[cfg(feature = "2d")]
+                AngularInertia(1.0),
+                #[cfg(feature = "3d")]
+                AngularInertia::new(Vec3::splat(1.0)),
+            ))
+            .id();
+        (housing, gear)
+    };
+
+    let (housing1, gear1) = spawn_gear(RVector::ZERO);
+    let (housing2, gear2) = spawn_gear(RVector::X * 3.0);
+
+    // Drive the first gear with a motor.
+    let hinge1 = app
+        .world_mut()
+        .spawn(
+            RevoluteJoint::new(housing1, gear1).with_motor(AngularMotor {
+                target_velocity: 1.0,
+                max_torque: 100.0,
+                motor_model: MotorModel::AccelerationBased {
+                    stiffness: 0.0,
+                    damping: 10.0,
+                },
+                ..default()
+            }),
+        )
+        .id();
+    let hinge2 = app
+        .world_mut()
+        .spawn(RevoluteJoint::new(housing2, gear2))
+        .id();
+
+    let ratio = -0.5;
+    app.world_mut()
+        .spawn(GearJoint::new(gear1, gear2, hinge1, hinge2).with_ratio(ratio));
+
+    app.update();
+
+    // Run simulation for 1 second.
+    let duration = 1.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let angle1 = planar_angle(app.world().entity(gear1).get::<Rotation>().unwrap());
+    let angle2 = planar_angle(app.world().entity(gear2).get::<Rotation>().unwrap());
+
+    assert!(angle1 > 0.5, "The driving gear should rotate: {}", angle1);
+    assert!(
+        (angle2 - ratio * angle1).abs() < 0.01,
+        "The driven gear should rotate by the gear ratio: {} vs {}",
+        angle2,
+        ratio * angle1
+    );
+}
+
+/// Tests that a rack-and-pinion joint couples the angle of a revolute joint
+/// to the translation of a prismatic joint.
+#[test]
+fn rack_and_pinion_joint_couples_joints() {
+    let mut app = create_app();
+    app.finish();
+
+    let housing = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let mut spawn_body = || {
+        app.world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                Position(RVector::ZERO),
+                Mass(1.0),
+                #[cfg(feature = "2d")]
+                AngularInertia(1.0),
+                #[cfg(feature = "3d")]
+                AngularInertia::new(Vec3::splat(1.0)),
+            ))
+            .id()
+    };
+
+    let pinion = spawn_body();
+    let rack = spawn_body();
+
+    // Drive the pinion with a motor.
+    let hinge = app
+        .world_mut()
+        .spawn(
+            RevoluteJoint::new(housing, pinion).with_motor(AngularMotor {
+                target_velocity: 1.0,
+                max_torque: 100.0,
+                motor_model: MotorModel::AccelerationBased {
+                    stiffness: 0.0,
+                    damping: 10.0,
+                },
+                ..default()
+            }),
+        )
+        .id();
+    let slider = app
+        .world_mut()
+        .spawn(PrismaticJoint::new(housing, rack).with_slider_axis(Vector::X))
+        .id();
+
+    let ratio = 0.5;
+    app.world_mut()
+        .spawn(RackAndPinionJoint::new(pinion, rack, hinge, slider).with_ratio(ratio));
+
+    app.update();
+
+    // Run simulation for 1 second.
+    let duration = 1.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let angle = planar_angle(app.world().entity(pinion).get::<Rotation>().unwrap());
+    let translation = app
+        .world()
+        .entity(rack)
+        .get::<Position>()
+        .unwrap()
+        .0
+        .f32()
+        .x;
+
+    assert!(angle > 0.5, "The pinion should rotate: {}", angle);
+    assert!(
+        (translation - ratio * angle).abs() < 0.01,
+        "The rack should translate by the ratio: {} vs {}",
+        translation,
+        ratio * angle
+    );
+}
+
+/// Tests that a pulley joint keeps the total rope length constant,
+/// letting a heavier body lift a lighter one.
+#[test]
+fn pulley_joint_keeps_rope_length() {
+    let mut app = create_app();
+    app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+    app.finish();
+
+    let mut spawn_body = |position: RVector, mass: f32| {
+        app.world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                Position(position),
+                Mass(mass),
+                #[cfg(feature = "2d")]
+                AngularInertia(1.0),
+                #[cfg(feature = "3d")]
+                AngularInertia::new(Vec3::splat(1.0)),
+            ))
+            .id()
+    };
+
+    let heavy = spawn_body(RVector::NEG_X, 2.0);
+    let light = spawn_body(RVector::X, 1.0);
+
+    let ground_anchor1 = RVector::Y * 2.0 - RVector::X;
+    let ground_anchor2 = RVector::Y * 2.0 + RVector::X;
+    app.world_mut().spawn(PulleyJoint::new(
+        heavy,
+        light,
+        ground_anchor1,
+        ground_anchor2,
+    ));
+
+    app.update();
+
+    // Run simulation for 0.5 seconds.
+    let duration = 0.5;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let heavy_position = app.world().entity(heavy).get::<Position>().unwrap().0;
+    let light_position = app.world().entity(light).get::<Position>().unwrap().0;
+    let length1 = (heavy_position - ground_anchor1).f32().length();
+    let length2 = (light_position - ground_anchor2).f32().length();
+
+    assert!(
+        heavy_position.y < -0.2,
+        "The heavier body should descend: {}",
+        heavy_position.y
+    );
+    assert!(
+        light_position.y > 0.2,
+        "The lighter body should be lifted: {}",
+        light_position.y
+    );
+    assert!(
+        (length1 + length2 - 4.0).abs() < 0.01,
+        "The rope length should be preserved: {}",
+        length1 + length2
+    );
+}
+
+/// Tests that joints break when their force exceeds the [`JointBreakThreshold`],
+/// and that joints below the threshold are left intact.
+#[test]
+fn joint_breaks_when_force_exceeds_threshold() {
+    #[derive(Resource, Default)]
+    struct BrokenJoints(Vec<Entity>);
+
+    let mut app = create_app();
+    app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+    app.init_resource::<BrokenJoints>();
+    app.add_observer(|event: On<JointBroken>, mut broken: ResMut<BrokenJoints>| {
+        broken.0.push(event.joint);
+    });
+    app.finish();
+
+    let anchor = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+
+    let mut spawn_body = |position: RVector| {
+        app.world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                Position(position),
+                Mass(10.0),
+                #[cfg(feature = "2d")]
+                AngularInertia(1.0),
+                #[cfg(feature = "3d")]
+                AngularInertia::new(Vec3::splat(1.0)),
+            ))
+            .id()
+    };
+
+    let body1 = spawn_body(RVector::NEG_Y);
+    let body2 = spawn_body(RVector::NEG_Y + RVector::X);
+    let body3 = spawn_body(RVector::NEG_Y - RVector::X);
+
+    // The weight of each body is around 98 N.
+    let disabled_joint = app
+        .world_mut()
+        .spawn((
+            DistanceJoint::new(anchor, body1).with_local_anchor2(Vector::Y),
+            JointBreakThreshold::from_force(50.0),
+        ))
+        .id();
+    let despawned_joint = app
+        .world_mut()
+        .spawn((
+            DistanceJoint::new(anchor, body2)
+                .with_local_anchor1(Vector::X)
+                .with_local_anchor2(Vector::Y),
+            JointBreakThreshold::from_force(50.0).with_action(JointBreakAction::Despawn),
+        ))
+        .id();
+    let intact_joint = app
+        .world_mut()
+        .spawn((
+            DistanceJoint::new(anchor, body3)
+                .with_local_anchor1(Vector::NEG_X)
+                .with_local_anchor2(Vector::Y),
+            JointBreakThreshold::from_force(500.0),
+        ))
+        .id();
+
+    // Run simulation for 0.5 seconds.
+    let duration = 0.5;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    assert!(
+        app.world()
+            .entity(disabled_joint)
+            .contains::<JointDisabled>(),
+        "The joint should be disabled"
+    );
+    assert!(
+        app.world().get_entity(despawned_joint).is_err(),
+        "The joint should be despawned"
+    );
+    assert!(
+        !app.world().entity(intact_joint).contains::<JointDisabled>(),
+        "The joint should not break below the threshold"
+    );
+
+    let broken_joints = &app.world().resource::<BrokenJoints>().0;
+    assert_eq!(
+        broken_joints,
+        &[disabled_joint, despawned_joint],
+        "Each broken joint should trigger exactly one event"
+    );
+
+    // The bodies of the broken joints should fall freely.
+    let position1 = app.world().entity(body1).get::<Position>().unwrap().0;
+    let position3 = app.world().entity(body3).get::<Position>().unwrap().0;
+    assert!(
+        position1.y < -1.5,
+        "The body of the broken joint should fall: {}",
+        position1.y
+    );
+    assert!(
+        (position3.y + 1.0).abs() < 0.05,
+        "The body of the intact joint should hang: {}",
+        position3.y
+    );
+}
+
+/// Tests that a target joint pulls the anchor of a body towards the target,
+/// and that the body follows when the target is moved.
+#[test]
+fn target_joint_pulls_body_to_target() {
+    let mut app = create_app();
+    app.finish();
+
+    let body = app
+        .world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Position(RVector::ZERO),
+            Mass(1.0),
+            #[cfg(feature = "2d")]
+            AngularInertia(1.0),
+            #[cfg(feature = "3d")]
+            AngularInertia::new(Vec3::splat(1.0)),
+        ))
+        .id();
+
+    let joint = app
+        .world_mut()
+        .spawn(TargetJoint::new(body, RVector::X * 2.0).with_local_anchor(Vector::X * 0.5))
+        .id();
+
+    // Run simulation for 2 seconds.
+    let duration = 2.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let anchor = |app: &App| {
+        let entity = app.world().entity(body);
+        let position = entity.get::<Position>().unwrap().0;
+        let rotation = *entity.get::<Rotation>().unwrap();
+        position + (rotation * Vector::X * 0.5).real()
+    };
+
+    let distance = (anchor(&app) - RVector::X * 2.0).f32().length();
+    assert!(
+        distance < 0.01,
+        "The anchor should reach the target: {distance}"
+    );
+
+    // Move the target.
+    app.world_mut()
+        .get_mut::<TargetJoint>(joint)
+        .unwrap()
+        .target = RVector::Y * 2.0;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let distance = (anchor(&app) - RVector::Y * 2.0).f32().length();
+    assert!(
+        distance < 0.01,
+        "The anchor should follow the target: {distance}"
+    );
+}
+
+#[test]
+fn projected_joint_chain_does_not_stretch() {
+    let mut app = create_app();
+    app.insert_resource(SubstepCount(4));
+    app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+    app.finish();
+
+    let root = app
+        .world_mut()
+        .spawn((
+            RigidBody::Static,
+            JointProjectionRoot,
+            Position(RVector::ZERO),
+        ))
+        .id();
+
+    // A horizontal chain of heavy links that swings down under gravity.
+    let mut parent = root;
+    let mut joints = Vec::new();
+
+    for i in 1..=20 {
+        let link = app
+            .world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                Position(RVector::X * i as f32 * 0.5),
+                Mass(if i == 20 { 100.0 } else { 1.0 }),
+                #[cfg(feature = "2d")]
+                AngularInertia(0.1),
+                #[cfg(feature = "3d")]
+                AngularInertia::new(Vec3::splat(0.1)),
+            ))
+            .id();
+
+        let joint = app
+            .world_mut()
+            .spawn(
+                ProjectedJoint::revolute(parent, link)
+                    .with_anchor(RVector::X * (i as f32 - 0.5) * 0.5),
+            )
+            .id();
+
+        joints.push(joint);
+        parent = link;
+    }
+
+    let last_link = parent;
+
+    // Run simulation for 2 seconds.
+    let duration = 2.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    for &joint in &joints {
+        let joint = app.world().get::<ProjectedJoint>(joint).unwrap();
+        let anchor = |entity: Entity, local_anchor: Vector| {
+            let entity = app.world().entity(entity);
+            let position = entity.get::<Position>().unwrap().0;
+            let rotation = *entity.get::<Rotation>().unwrap();
+            position + (rotation * local_anchor).real()
+        };
+
+        let anchor1 = anchor(joint.body1, joint.local_anchor1().unwrap());
+        let anchor2 = anchor(joint.body2, joint.local_anchor2().unwrap());
+        let separation = (anchor2 - anchor1).f32().length();
+
+        assert!(
+            separation < 1e-3,
+            "The projected joint should not stretch: {separation}"
+        );
+    }
+
+    let last_position = app.world().get::<Position>(last_link).unwrap().0;
+    assert!(
+        last_position.y < -1.0,
+        "The chain should swing down under gravity: {last_position}"
+    );
+}
+
+/// Tests that the impulse-based joint solver keeps hanging bodies attached,
+/// respects joint limits, and reports the forces applied by the joints.
+#[test]
+fn impulse_joints_hold_hanging_bodies() {
+    use crate::dynamics::solver::{JointSolver, SolverConfig};
+
+    let mut app = create_app();
+    app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+    app.finish();
+
+    app.world_mut().resource_mut::<SolverConfig>().joint_solver = JointSolver::Impulse;
+
+    let mut spawn_body = |rigid_body: RigidBody, position: RVector| {
+        app.world_mut()
+            .spawn((
+                rigid_body,
+                Position(position),
+                Mass(1.0),
+                #[cfg(feature = "2d")]
+                AngularInertia(1.0),
+                #[cfg(feature = "3d")]
+                AngularInertia::new(Vec3::splat(1.0)),
+            ))
+            .id()
+    };
+
+    // A pendulum that swings down from a horizontal position.
+    let pendulum_anchor = spawn_body(RigidBody::Static, RVector::ZERO);
+    let pendulum = spawn_body(RigidBody::Dynamic, RVector::X);
+
+    // A body hanging from a fixed joint.
+    let fixed_anchor = spawn_body(RigidBody::Static, RVector::X * 3.0);
+    let fixed_body = spawn_body(RigidBody::Dynamic, (RVector::X * 3.0 + RVector::NEG_Y));
+
+    // A body hanging from a distance joint.
+    let distance_anchor = spawn_body(RigidBody::Static, RVector::X * 6.0);
+    let distance_body = spawn_body(RigidBody::Dynamic, (RVector::X * 6.0 + RVector::NEG_Y));
+
+    // A body sliding down until it reaches the lower limit of a prismatic joint.
+    let prismatic_anchor = spawn_body(RigidBody::Static, RVector::X * 9.0);
+    let prismatic_body = spawn_body(RigidBody::Dynamic, RVector::X * 9.0);
+
+    app.world_mut()
+        .spawn(RevoluteJoint::new(pendulum_anchor, pendulum).with_anchor(RVector::ZERO));
+    let fixed_joint = app
+        .world_mut()
+        .spawn((
+            FixedJoint::new(fixed_anchor, fixed_body).with_anchor(RVector::X * 3.0),
+            JointForces::new(),
+        ))
+        .id();
+    app.world_mut().spawn(
+        DistanceJoint::new(distance_anchor, distance_body)
+            .with_local_anchor2(Vector::Y)
+            .with_limits(0.0, 0.0),
+    );
+    app.world_mut().spawn(
+        PrismaticJoint::new(prismatic_anchor, prismatic_body)
+            .with_slider_axis(Vector::Y)
+            .with_limits(-0.5, 0.5),
+    );
+
+    // Run simulation for 1 second.
+    let duration = 1.0;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let position = |entity: Entity| app.world().get::<Position>(entity).unwrap().0;
+
+    let pendulum_position = position(pendulum);
+    assert!(
+        (pendulum_position.f32().length() - 1.0).abs() < 1e-2,
+        "The pendulum should stay attached: {pendulum_position}"
+    );
+    assert!(
+        pendulum_position.y < -0.5,
+        "The pendulum should swing down: {pendulum_position}"
+    );
+
+    let fixed_offset = (position(fixed_body) - (RVector::X * 3.0 + RVector::NEG_Y)).f32();
+    assert!(
+        fixed_offset.length() < 1e-2,
+        "The body of the fixed joint should hang in place: {fixed_offset}"
+    );
+
+    let distance_offset = (position(distance_body) - (RVector::X * 6.0 + RVector::NEG_Y)).f32();
+    assert!(
+        distance_offset.length() < 1e-2,
+        "The body of the distance joint should hang in place: {distance_offset}"
+    );
+
+    let prismatic_offset =
+        (position(prismatic_body) - (RVector::X * 9.0 + RVector::NEG_Y * 0.5)).f32();
+    assert!(
+        prismatic_offset.length() < 1e-2,
+        "The body of the prismatic joint should rest at the lower limit: {prismatic_offset}"
+    );
+
+    // The fixed joint should support the weight of the body.
+    let force = app.world().get::<JointForces>(fixed_joint).unwrap().force();
+    assert!(
+        (force.length() - 9.81).abs() < 0.1,
+        "The fixed joint should support the weight of the body: {force}"
+    );
+}
+
+/// Tests that the impulse-based joint solver falls back to XPBD
+/// when there are joints that it does not support.
+#[cfg(feature = "xpbd_joints")]
+#[test]
+fn impulse_joints_fall_back_to_xpbd_for_unsupported_joints() {
+    use crate::dynamics::solver::{JointSolver, SolverConfig};
+
+    let mut app = create_app();
+    app.finish();
+
+    app.world_mut().resource_mut::<SolverConfig>().joint_solver = JointSolver::Impulse;
+
+    let body1 = app
+        .world_mut()
+        .spawn((RigidBody::Static, Position(RVector::ZERO)))
+        .id();
+    let body2 = app
+        .world_mut()
+        .spawn((RigidBody::Dynamic, Position(RVector::NEG_Y), Mass(1.0)))
+        .id();
+    app.world_mut().spawn(RevoluteJoint::new(body1, body2));
+
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<SolverConfig>().joint_solver,
+        JointSolver::Impulse,
+        "Supported joints should keep the impulse-based solver"
+    );
+
+    // A disabled unsupported joint should not affect the solver.
+    let wheel_joint = app
+        .world_mut()
+        .spawn((WheelJoint::new(body1, body2), JointDisabled))
+        .id();
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<SolverConfig>().joint_solver,
+        JointSolver::Impulse,
+        "Disabled joints should not affect the solver"
+    );
+
+    // Enabling the unsupported joint should fall back to XPBD for all joints.
+    app.world_mut()
+        .entity_mut(wheel_joint)
+        .remove::<JointDisabled>();
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<SolverConfig>().joint_solver,
+        JointSolver::Xpbd,
+        "Unsupported joints should fall back to the XPBD solver"
+    );
+}
+
+/// Tests that a ragdoll is constructed from a skeleton, that its bodies follow the animated bones
+/// when it is fully animated, and that the bones follow the bodies when it is fully simulated.
+#[cfg(all(feature = "3d", feature = "default-collider"))]
+#[test]
+fn ragdoll_blends_between_skeleton_and_bodies() {
+    let mut app = create_app();
+    app.add_plugins(RagdollPlugin::default());
+    app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+    app.finish();
+
+    // A simple leg skeleton.
+    let root = app
+        .world_mut()
+        .spawn(Transform::from_xyz(0.0, 2.0, 0.0))
+        .id();
+    let hips = app
+        .world_mut()
+        .spawn((Name::new("Hips"), Transform::default(), ChildOf(root)))
+        .id();
+    let thigh = app
+        .world_mut()
+        .spawn((
+            Name::new("Thigh"),
+            Transform::from_xyz(0.0, -0.1, 0.0),
+            ChildOf(hips),
+        ))
+        .id();
+    let shin = app
+        .world_mut()
+        .spawn((
+            Name::new("Shin"),
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            ChildOf(thigh),
+        ))
+        .id();
+    app.world_mut().spawn((
+        Name::new("Foot"),
+        Transform::from_xyz(0.0, -0.5, 0.0),
+        ChildOf(shin),
+    ));
+
+    app.world_mut().entity_mut(root).insert(
+        RagdollConstructor::new(10.0)
+            .with_bone("Thigh", RagdollBoneConfig::capsule(0.1, 0.6))
+            .with_bone(
+                "Shin",
+                RagdollBoneConfig::capsule(0.08, 0.4).with_swing_limits(0.0, 1.0),
+            )
+            .with_blend_weight(0.0),
+    );
+
+    app.update();
+
+    let ragdoll = app.world().get::<Ragdoll>(root).unwrap().clone();
+    let [thigh_bone, shin_bone] = ragdoll.bones() else {
+        panic!("The ragdoll should have two bones: {ragdoll:?}");
+    };
+    assert_eq!(thigh_bone.bone, thigh);
+    assert_eq!(shin_bone.bone, shin);
+    assert!(
+        thigh_bone.joint.is_none(),
+        "The root bone should not have a joint"
+    );
+    let shin_joint = shin_bone.joint.expect("The child bone should have a joint");
+    assert!(
+        app.world()
+            .entity(shin_joint)
+            .contains::<JointCollisionDisabled>(),
+        "Neighboring bodies should not collide"
+    );
+
+    // Run simulation for 0.5 seconds with the ragdoll fully animated.
+    let duration = 0.5;
+    let steps = (duration / TIMESTEP) as usize;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let body_offset = |app: &App, bone: Entity, body: Entity| {
+        let bone_position = app
+            .world()
+            .get::<GlobalTransform>(bone)
+            .unwrap()
+            .translation();
+        let body_position = app.world().get::<Position>(body).unwrap().0;
+        (body_position - bone_position.real()).f32().length()
+    };
+
+    for bone in ragdoll.bones() {
+        let offset = body_offset(&app, bone.bone, bone.body);
+        assert!(
+            offset < 1e-3,
+            "The bodies of an animated ragdoll should follow the bones: {offset}"
+        );
+    }
+
+    // Simulate the ragdoll.
+    app.world_mut()
+        .get_mut::<Ragdoll>(root)
+        .unwrap()
+        .blend_weight = 1.0;
+
+    for _ in 0..steps {
+        app.update();
+    }
+
+    let shin_position = app.world().get::<Position>(shin_bone.body).unwrap().0;
+    assert!(
+        shin_position.y < 0.5,
+        "The simulated ragdoll should fall: {shin_position}"
+    );
+
+    for bone in ragdoll.bones() {
+        let offset = body_offset(&app, bone.bone, bone.body);
+        assert!(
+            offset < 1e-3,
+            "The bones of a simulated ragdoll should follow the bodies: {offset}"
+        );
+    }
+}
@@ -0,0 +1,476 @@
+use crate::{
+    dynamics::joints::{
+        EntityConstraint, JointSystems,
+        motor::{AngularMotor, LinearMotor},
+    },
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A wheel [joint](dynamics::joints) connects a wheel to a chassis with a spring-damped suspension
+/// along the [`slider_axis`](Self::slider_axis), while allowing the wheel to spin freely.
+///
+/// This can be useful for things like cars, carts, and other wheeled vehicles. Compared to combining
+/// a [`PrismaticJoint`] and a [`RevoluteJoint`] with an intermediate body, a wheel joint is cheaper
+/// to solve and more stable.
+///
+/// Each wheel joint is defined by a [`JointFrame`] on each body, a [`slider_axis`](Self::slider_axis)
+/// along which the suspension moves, and an optional [`DistanceLimit`] that defines the extents of the suspension travel.
+#[cfg_attr(
+    feature = "2d",
+    doc = "The first body is the chassis, and the second body is the wheel, which can rotate freely about its anchor point."
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "The first body is the chassis, and the second body is the wheel, which can rotate freely about the [`spin_axis`](Self::spin_axis)."
+)]
+///
+/// The [`suspension`](Self::suspension) is a [`LinearMotor`] targeting the rest position of the wheel.
+/// By default, it uses a [`MotorModel::SpringDamper`], but it can also be reconfigured for active suspension.
+/// The joint can also include an [`AngularMotor`] for driving the rotation of the wheel.
+#[cfg_attr(
+    feature = "3d",
+    doc = "
+The wheel can optionally be steered about the [`slider_axis`](Self::slider_axis) by configuring a [`steering_limit`](Self::steering_limit)
+and driving the steering angle with the [`steering_motor`](Self::steering_motor). Without a steering limit, steering is locked."
+)]
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, MapEntities, PartialEq)]
+pub struct WheelJoint {
+    /// The first body constrained by the joint, typically the chassis.
+    pub body1: Entity,
+    /// The second body constrained by the joint, typically the wheel.
+    pub body2: Entity,
+    /// The reference frame of the first body, defining the joint anchor and basis
+    /// relative to the body transform.
+    pub frame1: JointFrame,
+    /// The reference frame of the second body, defining the joint anchor and basis
+    /// relative to the body transform.
+    pub frame2: JointFrame,
+    /// The local axis along which the suspension moves.
+    ///
+    /// By default, this is the y-axis.
+    pub slider_axis: Vector,
+    /// The local axis about which the wheel can spin.
+    ///
+    /// By default, this is the x-axis.
+    #[cfg(feature = "3d")]
+    pub spin_axis: Vec3,
+    /// The extents of the allowed suspension travel along the [`slider_axis`](Self::slider_axis).
+    pub suspension_limits: Option<DistanceLimit>,
+    /// The spring-damper suspension along the [`slider_axis`](Self::slider_axis).
+    ///
+    /// The [`target_position`](LinearMotor::target_position) is the rest position of the suspension.
+    pub suspension: LinearMotor,
+    /// The extents of the allowed steering rotation about the [`slider_axis`](Self::slider_axis).
+    ///
+    /// If `None`, steering is locked.
+    #[cfg(feature = "3d")]
+    pub steering_limit: Option<AngleLimit>,
+    /// A motor for driving the steering angle about the [`slider_axis`](Self::slider_axis).
+    ///
+    /// This only has an effect if a [`steering_limit`](Self::steering_limit) is set.
+    #[cfg(feature = "3d")]
+    pub steering_motor: AngularMotor,
+    /// The compliance used for aligning the positions of the bodies to the [`slider_axis`](Self::slider_axis) (inverse of stiffness, m / N).
+    pub align_compliance: f32,
+    /// The compliance used for aligning the wheel with the [`spin_axis`](Self::spin_axis) (inverse of stiffness, N * m / rad).
+    #[cfg(feature = "3d")]
+    pub angle_compliance: f32,
+    /// The compliance of the suspension and steering limits (inverse of stiffness).
+    pub limit_compliance: f32,
+    /// A motor for driving the rotation of the wheel.
+    pub motor: AngularMotor,
+}
+
+impl EntityConstraint<2> for WheelJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.body1, self.body2]
+    }
+}
+
+impl WheelJoint {
+    /// The default [`slider_axis`](Self::slider_axis) for a wheel joint.
+    pub const DEFAULT_SLIDER_AXIS: Vector = Vector::Y;
+
+    /// The default [`spin_axis`](Self::spin_axis) for a wheel joint.
+    #[cfg(feature = "3d")]
+    pub const DEFAULT_SPIN_AXIS: Vec3 = Vec3::X;
+
+    /// The default [`suspension`](Self::suspension) for a wheel joint:
+    /// an underdamped spring-damper with a frequency of 4 Hz, targeting a rest position of zero.
+    pub const DEFAULT_SUSPENSION: LinearMotor = LinearMotor::new(MotorModel::SpringDamper {
+        frequency: 4.0,
+        damping_ratio: 0.7,
+    });
+
+    /// Creates a new [`WheelJoint`] between two entities.
+    #[inline]
+    pub const fn new(body1: Entity, body2: Entity) -> Self {
+        Self {
+            body1,
+            body2,
+            frame1: JointFrame::IDENTITY,
+            frame2: JointFrame::IDENTITY,
+            slider_axis: Self::DEFAULT_SLIDER_AXIS,
+            #[cfg(feature = "3d")]
+            spin_axis: Self::DEFAULT_SPIN_AXIS,
+            suspension_limits: None,
+            suspension: Self::DEFAULT_SUSPENSION,
+            #[cfg(feature = "3d")]
+            steering_limit: None,
+            #[cfg(feature = "3d")]
+            steering_motor: AngularMotor::new_disabled(MotorModel::DEFAULT),
+            align_compliance: 0.0,
+            #[cfg(feature = "3d")]
+            angle_compliance: 0.0,
+            limit_compliance: 0.0,
+            motor: AngularMotor::new_disabled(MotorModel::DEFAULT),
+        }
+    }
+
+    /// Sets the [`slider_axis`](Self::slider_axis) along which the suspension moves.
+    ///
+    /// The axis should be a unit vector. By default, this is the y-axis.
+    #[inline]
+    pub const fn with_slider_axis(mut self, axis: Vector) -> Self {
+        self.slider_axis = axis;
+        self
+    }
+
+    /// Sets the [`spin_axis`](Self::spin_axis) about which the wheel can spin.
+    ///
+    /// The axis should be a unit vector perpendicular to the [`slider_axis`](Self::slider_axis).
+    /// By default, this is the x-axis.
+    #[inline]
+    #[cfg(feature = "3d")]
+    pub const fn with_spin_axis(mut self, axis: Vec3) -> Self {
+        self.spin_axis = axis;
+        self
+    }
+
+    /// Sets the local [`JointFrame`] of the first body, configuring both the [`JointAnchor`] and [`JointBasis`].
+    #[inline]
+    pub fn with_local_frame1(mut self, frame: impl Into<Isometry>) -> Self {
+        self.frame1 = JointFrame::local(frame);
+        self
+    }
+
+    /// Sets the local [`JointFrame`] of the second body, configuring both the [`JointAnchor`] and [`JointBasis`].
+    #[inline]
+    pub fn with_local_frame2(mut self, frame: impl Into<Isometry>) -> Self {
+        self.frame2 = JointFrame::local(frame);
+        self
+    }
+
+    /// Sets the global anchor point on both bodies.
+    ///
+    /// This configures the [`JointAnchor`] of each [`JointFrame`].
+    #[inline]
+    pub const fn with_anchor(mut self, anchor: RVector) -> Self {
+        self.frame1.anchor = JointAnchor::FromGlobal(anchor);
+        self.frame2.anchor = JointAnchor::FromGlobal(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the first body.
+    ///
+    /// This configures the [`JointAnchor`] of the first [`JointFrame`].
+    #[inline]
+    pub const fn with_local_anchor1(mut self, anchor: Vector) -> Self {
+        self.frame1.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the local anchor point on the second body.
+    ///
+    /// This configures the [`JointAnchor`] of the second [`JointFrame`].
+    #[inline]
+    pub const fn with_local_anchor2(mut self, anchor: Vector) -> Self {
+        self.frame2.anchor = JointAnchor::Local(anchor);
+        self
+    }
+
+    /// Sets the global basis for both bodies.
+    ///
+    /// This configures the [`JointBasis`] of each [`JointFrame`].
+    #[inline]
+    pub fn with_basis(mut self, basis: impl Into<Rot>) -> Self {
+        let basis = basis.into();
+        self.frame1.basis = JointBasis::FromGlobal(basis);
+        self.frame2.basis = JointBasis::FromGlobal(basis);
+        self
+    }
+
+    /// Sets the local basis for the first body.
+    ///
+    /// This configures the [`JointBasis`] of the first [`JointFrame`].
+    #[inline]
+    pub fn with_local_basis1(mut self, basis: impl Into<Rot>) -> Self {
+        self.frame1.basis = JointBasis::Local(basis.into());
+        self
+    }
+
+    /// Sets the local basis for the second body.
+    ///
+    /// This configures the [`JointBasis`] of the second [`JointFrame`].
+    #[inline]
+    pub fn with_local_basis2(mut self, basis: impl Into<Rot>) -> Self {
+        self.frame2.basis = JointBasis::Local(basis.into());
+        self
+    }
+
+    /// Returns the local [`JointFrame`] of the first body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, or the [`JointBasis`] is set to
+    /// [`FromGlobal`](JointBasis::FromGlobal), and the local basis has not yet
+    /// been computed, this will return `None`.
+    #[inline]
+    pub fn local_frame1(&self) -> Option<Isometry> {
+        self.frame1.get_local_isometry()
+    }
+
+    /// Returns the local [`JointFrame`] of the second body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, or the [`JointBasis`] is set to
+    /// [`FromGlobal`](JointBasis::FromGlobal), and the local basis has not yet
+    /// been computed, this will return `None`.
+    #[inline]
+    pub fn local_frame2(&self) -> Option<Isometry> {
+        self.frame2.get_local_isometry()
+    }
+
+    /// Returns the local anchor point on the first body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor1(&self) -> Option<Vector> {
+        match self.frame1.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Returns the local anchor point on the second body.
+    ///
+    /// If the [`JointAnchor`] is set to [`FromGlobal`](JointAnchor::FromGlobal),
+    /// and the local anchor has not yet been computed, this will return `None`.
+    #[inline]
+    pub const fn local_anchor2(&self) -> Option<Vector> {
+        match self.frame2.anchor {
+            JointAnchor::Local(anchor) => Some(anchor),
+            _ => None,
+        }
+    }
+
+    /// Returns the local basis of the first body.
+    ///
+    /// If the [`JointBasis`] is set to [`FromGlobal`](JointBasis::FromGlobal),
+    /// and the local basis has not yet been computed, this will return `None`.
+    #[inline]
+    pub fn local_basis1(&self) -> Option<Rot> {
+        match self.frame1.basis {
+            JointBasis::Local(basis) => Some(basis),
+            _ => None,
+        }
+    }
+
+    /// Returns the local basis of the second body.
+    ///
+    /// If the [`JointBasis`] is set to [`FromGlobal`](JointBasis::FromGlobal),
+    /// and the local basis has not yet been computed, this will return `None`.
+    #[inline]
+    pub fn local_basis2(&self) -> Option<Rot> {
+        match self.frame2.basis {
+            JointBasis::Local(basis) => Some(basis),
+            _ => None,
+        }
+    }
+
+    /// Returns the local slider axis of the first body.
+    ///
+    /// This is equivalent to rotating the [`slider_axis`](Self::slider_axis)
+    /// by the local basis of [`frame1`](Self::frame1).
+    ///
+    /// If the [`JointBasis`] is set to [`FromGlobal`](JointBasis::FromGlobal),
+    /// and the local basis has not yet been computed, this will return `None`.
+    #[inline]
+    pub fn local_slider_axis1(&self) -> Option<Vector> {
+        match self.frame1.basis {
+            JointBasis::Local(basis) => Some(basis * self.slider_axis),
+            _ => None,
+        }
+    }
+
+    /// Returns the local spin axis of the second body.
+    ///
+    /// This is equivalent to rotating the [`spin_axis`](Self::spin_axis)
+    /// by the local basis of [`frame2`](Self::frame2).
+    ///
+    /// If the [`JointBasis`] is set to [`FromGlobal`](JointBasis::FromGlobal),
+    /// and the local basis has not yet been computed, this will return `None`.
+    #[inline]
+    #[cfg(feature = "3d")]
+    pub fn local_spin_axis2(&self) -> Option<Vec3> {
+        match self.frame2.basis {
+            JointBasis::Local(basis) => Some(basis * self.spin_axis),
+            _ => None,
+        }
+    }
+
+    /// Sets the limits of the suspension travel along the [`slider_axis`](Self::slider_axis).
+    #[inline]
+    pub const fn with_suspension_limits(mut self, min: f32, max: f32) -> Self {
+        self.suspension_limits = Some(DistanceLimit::new(min, max));
+        self
+    }
+
+    /// Sets the [`suspension`](Self::suspension) of the joint.
+    #[inline]
+    pub const fn with_suspension(mut self, suspension: LinearMotor) -> Self {
+        self.suspension = suspension;
+        self
+    }
+
+    /// Sets the spring-damper parameters of the [`suspension`](Self::suspension).
+    ///
+    /// The `frequency` is in Hz, and a `damping_ratio` of 1.0 corresponds to critical damping.
+    #[inline]
+    pub const fn with_suspension_spring(mut self, frequency: f32, damping_ratio: f32) -> Self {
+        self.suspension.motor_model = MotorModel::SpringDamper {
+            frequency,
+            damping_ratio,
+        };
+        self
+    }
+
+    /// Sets the limits of the allowed steering rotation about the [`slider_axis`](Self::slider_axis).
+    #[inline]
+    #[cfg(feature = "3d")]
+    pub const fn with_steering_limits(mut self, min: f32, max: f32) -> Self {
+        self.steering_limit = Some(AngleLimit::new(min, max));
+        self
+    }
+
+    /// Sets the motor for driving the steering angle.
+    #[inline]
+    #[cfg(feature = "3d")]
+    pub const fn with_steering_motor(mut self, motor: AngularMotor) -> Self {
+        self.steering_motor = motor;
+        self
+    }
+
+    /// Sets the compliance of the axis alignment constraint (inverse of stiffness, m / N).
+    #[inline]
+    pub const fn with_align_compliance(mut self, compliance: f32) -> Self {
+        self.align_compliance = compliance;
+        self
+    }
+
+    /// Sets the compliance of the angular constraint (inverse of stiffness, N * m / rad).
+    #[inline]
+    #[cfg(feature = "3d")]
+    pub const fn with_angle_compliance(mut self, compliance: f32) -> Self {
+        self.angle_compliance = compliance;
+        self
+    }
+
+    /// Sets the compliance of the suspension and steering limits (inverse of stiffness).
+    #[inline]
+    pub const fn with_limit_compliance(mut self, compliance: f32) -> Self {
+        self.limit_compliance = compliance;
+        self
+    }
+
+    /// Sets the motor for driving the rotation of the wheel.
+    #[inline]
+    pub const fn with_motor(mut self, motor: AngularMotor) -> Self {
+        self.motor = motor;
+        self
+    }
+}
+
+impl MapEntities for WheelJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.body1 = entity_mapper.get_mapped(self.body1);
+        self.body2 = entity_mapper.get_mapped(self.body2);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PhysicsSchedule,
+        update_local_frames.in_set(JointSystems::PrepareLocalFrames),
+    );
+}
+
+fn update_local_frames(
+    mut joints: Query<&mut WheelJoint, Changed<WheelJoint>>,
+    bodies: Query<(&Position, &Rotation)>,
+) {
+    for mut joint in &mut joints {
+        if matches!(joint.frame1.anchor, JointAnchor::Local(_))
+            && matches!(joint.frame2.anchor, JointAnchor::Local(_))
+            && matches!(joint.frame1.basis, JointBasis::Local(_))
+            && matches!(joint.frame2.basis, JointBasis::Local(_))
+        {
+            continue;
+        }
+
+        let Ok([(pos1, rot1), (pos2, rot2)]) = bodies.get_many(joint.entities()) else {
+            continue;
+        };
+
+        let [frame1, frame2] =
+            JointFrame::compute_local(joint.frame1, joint.frame2, pos1.0, pos2.0, *rot1, *rot2);
+        joint.frame1 = frame1;
+        joint.frame2 = frame2;
+    }
+}
+
+#[cfg(feature = "debug-plugin")]
+impl DebugRenderConstraint<2> for WheelJoint {
+    type Context = ();
+
+    fn debug_render(
+        &self,
+        positions: [RVector; 2],
+        rotations: [Rotation; 2],
+        _context: &mut Self::Context,
+        gizmos: &mut Gizmos<PhysicsGizmos>,
+        config: &PhysicsGizmos,
+    ) {
+        let [pos1, pos2] = positions;
+        let [rot1, rot2] = rotations;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+
+        let anchor1 = pos1 + (rot1 * local_anchor1).real();
+        let anchor2 = pos2 + (rot2 * local_anchor2).real();
+
+        if let Some(anchor_color) = config.joint_anchor_color {
+            gizmos.draw_line(pos1, anchor1, anchor_color);
+            gizmos.draw_line(pos2, anchor2, anchor_color);
+        }
+
+        if let Some(color) = config.joint_separation_color {
+            gizmos.draw_line(anchor1, anchor2, color);
+        }
+    }
+}
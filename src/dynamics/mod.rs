@@ -11,6 +11,20 @@
 //! - Collision response, preventing objects from overlapping each other,
 //!   considering properties such as [`Friction`] and [`Restitution`].
 //! - [Joints](joints) connecting rigid bodies to each other.
+//! - [Joint projection](joint_projection) for trees of bodies whose joints never stretch.
+//! - [Force fields](force_field) and gravity fields that affect bodies inside sensor regions.
+#![cfg_attr(
+    feature = "default-collider",
+    doc = "- [Aerodynamic drag and lift](aerodynamics) based on the shapes of colliders."
+)]
+#![cfg_attr(
+    feature = "default-collider",
+    doc = "- [Buoyancy and drag](fluid) for bodies submerged in fluid volumes."
+)]
+#![cfg_attr(
+    all(feature = "3d", feature = "default-collider"),
+    doc = "- [Ragdolls](ragdoll) constructed from skeletons and blended with animation."
+)]
 //! - Everything else related to the physical behavior and properties of rigid bodies.
 //!
 //! Rigid body dynamics does *not* include:
@@ -61,14 +75,37 @@
 //! [Gauss-Seidel]: https://en.wikipedia.org/wiki/Gauss%E2%80%93Seidel_method
 //! [Semi-implicit Euler]: https://en.wikipedia.org/wiki/Semi-implicit_Euler_method
 
+#[cfg(feature = "default-collider")]
+pub mod aerodynamics;
 pub mod ccd;
+#[cfg(feature = "default-collider")]
+pub mod fluid;
+pub mod force_field;
 pub mod integrator;
+pub mod joint_projection;
 pub mod joints;
+#[cfg(all(feature = "3d", feature = "default-collider"))]
+pub mod ragdoll;
 pub mod rigid_body;
 pub mod solver;
 
 /// Re-exports common types related to the rigid body dynamics functionality.
 pub mod prelude {
+    #[cfg(feature = "default-collider")]
+    pub use super::aerodynamics::{Aerodynamics, AerodynamicsPlugin, Atmosphere};
+    #[cfg(feature = "default-collider")]
+    pub use super::fluid::{FluidPlugin, FluidVolume, WaveHeightFn};
+    pub use super::force_field::{
+        ForceField, ForceFieldFalloff, ForceFieldKind, ForceFieldPlugin, ForceFieldTurbulence,
+        GravityField, GravityFieldKind,
+    };
+    #[cfg(all(feature = "3d", feature = "default-collider"))]
+    pub use super::ragdoll::{
+        Ragdoll, RagdollBody, RagdollBone, RagdollBoneConfig, RagdollBoneShape, RagdollConstructor,
+        RagdollPlugin, RagdollReady, RagdollSystems,
+    };
+    #[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+    pub use super::rigid_body::forces::{RadialImpulse, RadialImpulseFalloff, RadialImpulses};
     pub(crate) use super::rigid_body::mass_properties::{ComputeMassProperties, MassProperties};
     #[cfg(feature = "xpbd_joints")]
     pub use super::solver::xpbd::{XpbdSolverPlugin, XpbdVelocityProjection};
@@ -78,10 +115,16 @@ pub mod prelude {
         integrator::{
             CustomPositionIntegration, CustomVelocityIntegration, Gravity, IntegratorPlugin,
         },
+        joint_projection::{
+            JointProjectionPlugin, JointProjectionRoot, JointProjectionSystems, ProjectedJoint,
+            ProjectedJointType,
+        },
         joints::{
-            AngleLimit, AngularMotor, DistanceJoint, DistanceLimit, FixedJoint, JointAnchor,
-            JointBasis, JointCollisionDisabled, JointDamping, JointDisabled, JointForces,
-            JointFrame, JointPlugin, LinearMotor, MotorModel, PrismaticJoint, RevoluteJoint,
+            AngleLimit, AngularMotor, DistanceJoint, DistanceLimit, FixedJoint, GearJoint,
+            GenericJoint, JointAnchor, JointAxis, JointBasis, JointBreakAction,
+            JointBreakThreshold, JointBroken, JointCollisionDisabled, JointDamping, JointDisabled,
+            JointForces, JointFrame, JointPlugin, LinearMotor, MotorModel, PrismaticJoint,
+            PulleyJoint, RackAndPinionJoint, RevoluteJoint, TargetJoint, WheelJoint,
             joint_graph::JointGraph,
         },
         rigid_body::{
@@ -89,7 +132,8 @@ pub mod prelude {
             forces::{
                 ConstantAngularAcceleration, ConstantForce, ConstantLinearAcceleration,
                 ConstantLocalForce, ConstantLocalLinearAcceleration, ConstantTorque, ForcePlugin,
-                ForceSystems, Forces, ReadRigidBodyForces, RigidBodyForces, WriteRigidBodyForces,
+                ForceSystems, Forces, ForcesReadOnly, ReadRigidBodyForces, RigidBodyForces,
+                WriteRigidBodyForces,
             },
             mass_properties::{
                 MassPropertiesExt, MassPropertyHelper, MassPropertyPlugin,
@@ -111,6 +155,7 @@ pub mod prelude {
         },
         solver::{
             PhysicsLengthUnit, SolverPlugin, SolverPlugins,
+            impulse_joints::ImpulseJointSolverPlugin,
             islands::{
                 IslandPlugin, IslandSleepingPlugin, SleepBody, SleepIslands, WakeBody, WakeIslands,
             },
@@ -122,7 +167,7 @@ pub mod prelude {
     };
     #[cfg(feature = "3d")]
     pub use super::{
-        joints::SphericalJoint,
+        joints::{OrientationMotor, SphericalJoint},
         rigid_body::forces::{ConstantLocalAngularAcceleration, ConstantLocalTorque},
     };
 }
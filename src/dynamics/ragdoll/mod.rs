@@ -0,0 +1,713 @@
+//! **Ragdolls** are skeletons of rigid bodies connected by [`SphericalJoint`]s,
+//! typically used for simulating limp characters.
+//!
+//! Instead of spawning a body and joint for each bone by hand, a ragdoll can be constructed
+//! automatically from an existing skeleton, such as the joints of a skinned glTF mesh.
+//!
+//! # Creating a Ragdoll
+//!
+//! Ragdolls are constructed by the [`RagdollPlugin`], which is not included in [`PhysicsPlugins`]
+//! and must be added separately.
+//!
+//! Add the [`RagdollConstructor`] component to the root of a skeleton hierarchy, and configure
+//! the bones that should be simulated by their [`Name`] using [`RagdollBoneConfig`]:
+//!
+//! ```no_run
+//! use avian3d::prelude::*;
+//! use bevy::prelude::*;
+//!
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn((
+//!         WorldAssetRoot(asset_server.load("character.gltf#Scene0")),
+//!         RagdollConstructor::new(70.0)
+//!             .with_bone("Hips", RagdollBoneConfig::sphere(0.15, 0.2))
+//!             .with_bone(
+//!                 "Spine",
+//!                 RagdollBoneConfig::capsule(0.12, 0.3).with_swing_limits(0.0, 0.5),
+//!             )
+//!             .with_bone(
+//!                 "Head",
+//!                 RagdollBoneConfig::sphere(0.1, 0.1)
+//!                     .with_swing_limits(0.0, 0.6)
+//!                     .with_twist_limits(-0.5, 0.5),
+//!             )
+//!             .with_bone("LeftUpLeg", RagdollBoneConfig::capsule(0.08, 0.1))
+//!             .with_bone(
+//!                 "LeftLeg",
+//!                 RagdollBoneConfig::capsule(0.06, 0.05).with_swing_limits(0.0, 1.5),
+//!             ),
+//!     ));
+//! }
+//! ```
+//!
+//! For each configured bone, a dynamic [`RigidBody`] is spawned at the bone's global transform,
+//! with a collider spanning the bone towards its child bones and a [`Mass`] given by
+//! the bone's [`mass_fraction`](RagdollBoneConfig::mass_fraction) of the total [`RagdollConstructor::mass`].
+//!
+//! Each body is connected to the body of its closest configured ancestor bone with a [`SphericalJoint`]
+//! at the bone's origin. The twist axis of the joint points along the bone, and the swing and twist limits
+//! are taken from the configuration of the child bone. Neighboring bodies do not collide with each other,
+//! as the joints are spawned with [`JointCollisionDisabled`].
+//!
+//! The bodies and joints are spawned as their own entities, and the bodies are marked with the [`RagdollBody`] component.
+//! Once the ragdoll has been constructed, the [`RagdollConstructor`] is replaced with a [`Ragdoll`] component
+//! that stores the bones, bodies, and joints of the ragdoll, and the [`RagdollReady`] event is triggered.
+//! If none of the configured bones are found in the hierarchy, a warning is logged,
+//! and the constructor is removed without inserting a [`Ragdoll`].
+//!
+//! If the constructor is used on a scene, such as one spawned by a `WorldAssetRoot`, it will
+//! wait until the scene is loaded before constructing the ragdoll. Note that this requires
+//! the `bevy_scene` feature to be enabled.
+//!
+//! # Blending With Animation
+//!
+//! The [`Ragdoll::blend_weight`] controls how the pose of the skeleton is blended between animation
+//! and physics:
+//!
+//! - At `0.0`, the ragdoll is fully *animated*. The bodies are [kinematic](RigidBody::Kinematic),
+//!   and they follow the animated bones using velocities, so they can still push other bodies around.
+//! - At `1.0`, the ragdoll is fully *simulated*. The bodies are dynamic, and the bones follow the bodies.
+//! - In between, the bodies are dynamic, and the pose of each simulated bone is interpolated
+//!   between its animated pose and the pose of its body.
+//!
+//! Switching from an animated ragdoll to a simulated one is seamless, as the bodies keep the velocities
+//! of the animation. This is useful for characters that go limp when they are hit, for example.
+//!
+//! The animated pose is read from the [`Transform`] of each bone before the bones are written
+//! in [`RagdollSystems::WriteBones`]. If the bones are animated, for example with `bevy_animation`,
+//! make sure that the animation is applied before this system set.
+//!
+//! # Limitations
+//!
+//! Ragdolls are currently only supported in 3D. The bodies are placed at the origins of the bones,
+//! and the scale of the skeleton is not applied to the configured radii and lengths.
+
+use crate::{math::*, prelude::*};
+#[cfg(feature = "bevy_scene")]
+use bevy::world_serialization::{
+    WorldAssetRoot, WorldInstance as SceneInstance, WorldInstanceSpawner,
+};
+use bevy::{
+    ecs::{entity::EntityHashMap, intern::Interned, schedule::ScheduleLabel},
+    platform::collections::HashMap,
+    prelude::*,
+    transform::TransformSystems,
+};
+
+/// A plugin for constructing [ragdolls](self) from skeletons and blending them with animation.
+pub struct RagdollPlugin {
+    schedule: Interned<dyn ScheduleLabel>,
+}
+
+impl RagdollPlugin {
+    /// Creates a [`RagdollPlugin`] with the schedule that is used for running the [`PhysicsSchedule`].
+    ///
+    /// The default schedule is `FixedPostUpdate`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Default for RagdollPlugin {
+    fn default() -> Self {
+        Self::new(FixedPostUpdate)
+    }
+}
+
+impl Plugin for RagdollPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            (
+                RagdollSystems::WriteBones.before(TransformSystems::Propagate),
+                RagdollSystems::Construct.after(TransformSystems::Propagate),
+            ),
+        );
+
+        app.add_systems(
+            PostUpdate,
+            (
+                (update_ragdoll_body_types, write_ragdoll_bones)
+                    .chain()
+                    .in_set(RagdollSystems::WriteBones),
+                construct_ragdolls.in_set(RagdollSystems::Construct),
+            ),
+        );
+
+        app.add_systems(
+            self.schedule,
+            follow_animated_bones
+                .after(PhysicsSystems::First)
+                .before(PhysicsSystems::Prepare),
+        );
+
+        // Character controllers can push ragdoll bodies, so follow the animation first.
+        #[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+        app.configure_sets(
+            self.schedule,
+            CharacterControllerSystems.after(follow_animated_bones),
+        );
+
+        app.add_observer(despawn_ragdoll_entities);
+    }
+}
+
+/// System sets for [ragdolls](self) in `PostUpdate`.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RagdollSystems {
+    /// Updates the [`RigidBody`] types of ragdoll bodies based on the [`Ragdoll::blend_weight`],
+    /// and writes the [`Transform`]s of simulated bones based on the poses of their bodies.
+    ///
+    /// Runs before [`TransformSystems::Propagate`].
+    WriteBones,
+    /// Constructs ragdolls for entities with a [`RagdollConstructor`].
+    ///
+    /// Runs after [`TransformSystems::Propagate`], so that the global transforms of the bones are up to date.
+    Construct,
+}
+
+/// A component that constructs a [ragdoll](self) from the skeleton hierarchy of the entity.
+///
+/// Bones are matched to their [`RagdollBoneConfig`] by [`Name`]. Bones without a configuration are not simulated,
+/// but they still follow their closest simulated ancestor.
+///
+/// Once the ragdoll has been constructed, this component is replaced with a [`Ragdoll`],
+/// and the [`RagdollReady`] event is triggered.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct RagdollConstructor {
+    /// The total mass of the ragdoll, distributed among the bones based on their
+    /// [`mass_fraction`](RagdollBoneConfig::mass_fraction).
+    pub mass: f32,
+    /// The initial [`Ragdoll::blend_weight`] of the ragdoll.
+    ///
+    /// `1.0` by default, meaning that the ragdoll is fully simulated.
+    pub blend_weight: f32,
+    /// The configuration of the simulated bones by [`Name`].
+    pub bones: HashMap<String, RagdollBoneConfig>,
+}
+
+impl RagdollConstructor {
+    /// Creates a new [`RagdollConstructor`] with the given total mass and no simulated bones.
+    ///
+    /// Bones can be configured using [`with_bone`](Self::with_bone).
+    pub fn new(mass: f32) -> Self {
+        Self {
+            mass,
+            blend_weight: 1.0,
+            bones: HashMap::default(),
+        }
+    }
+
+    /// Specifies the [`RagdollBoneConfig`] for the bone with the given `name`.
+    pub fn with_bone(mut self, name: &str, config: RagdollBoneConfig) -> Self {
+        self.bones.insert(name.to_string(), config);
+        self
+    }
+
+    /// Sets the initial [`Ragdoll::blend_weight`] of the ragdoll.
+    pub fn with_blend_weight(mut self, blend_weight: f32) -> Self {
+        self.blend_weight = blend_weight;
+        self
+    }
+}
+
+/// The shape of the collider generated for a bone of a [ragdoll](self).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq)]
+pub enum RagdollBoneShape {
+    /// A capsule spanning the length of the bone.
+    #[default]
+    Capsule,
+    /// A sphere at the center of the bone.
+    Sphere,
+    /// A cuboid spanning the length of the bone, with a square cross section.
+    Cuboid,
+}
+
+/// Configuration for a simulated bone of a [ragdoll](self) constructed using [`RagdollConstructor`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct RagdollBoneConfig {
+    /// The shape of the collider generated for the bone.
+    pub shape: RagdollBoneShape,
+    /// The radius of the collider. For cuboids, this is half of the side length of the cross section.
+    pub radius: f32,
+    /// The length of the bone.
+    ///
+    /// If `None`, the length is the distance to the child bones, or twice the radius if the bone has no children.
+    pub length: Option<f32>,
+    /// The extents of the allowed swing of the bone relative to its parent as a half-angle.
+    /// See [`SphericalJoint::swing_limit`].
+    pub swing_limit: Option<AngleLimit>,
+    /// The extents of the allowed twist of the bone about its own axis relative to its parent.
+    /// See [`SphericalJoint::twist_limit`].
+    pub twist_limit: Option<AngleLimit>,
+    /// The fraction of the total [`RagdollConstructor::mass`] given to the bone.
+    pub mass_fraction: f32,
+}
+
+impl RagdollBoneConfig {
+    /// Creates a new [`RagdollBoneConfig`] with the given shape, radius, and mass fraction.
+    /// The joint connecting the bone to its parent is not limited.
+    pub const fn new(shape: RagdollBoneShape, radius: f32, mass_fraction: f32) -> Self {
+        Self {
+            shape,
+            radius,
+            length: None,
+            swing_limit: None,
+            twist_limit: None,
+            mass_fraction,
+        }
+    }
+
+    /// Creates a new [`RagdollBoneConfig`] with a [capsule](RagdollBoneShape::Capsule) collider.
+    pub const fn capsule(radius: f32, mass_fraction: f32) -> Self {
+        Self::new(RagdollBoneShape::Capsule, radius, mass_fraction)
+    }
+
+    /// Creates a new [`RagdollBoneConfig`] with a [sphere](RagdollBoneShape::Sphere) collider.
+    pub const fn sphere(radius: f32, mass_fraction: f32) -> Self {
+        Self::new(RagdollBoneShape::Sphere, radius, mass_fraction)
+    }
+
+    /// Creates a new [`RagdollBoneConfig`] with a [cuboid](RagdollBoneShape::Cuboid) collider.
+    pub const fn cuboid(half_width: f32, mass_fraction: f32) -> Self {
+        Self::new(RagdollBoneShape::Cuboid, half_width, mass_fraction)
+    }
+
+    /// Sets the length of the bone, overriding the distance to its child bones.
+    pub const fn with_length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the extents of the allowed swing of the bone relative to its parent as a half-angle.
+    pub const fn with_swing_limits(mut self, min: f32, max: f32) -> Self {
+        self.swing_limit = Some(AngleLimit::new(min, max));
+        self
+    }
+
+    /// Sets the extents of the allowed twist of the bone about its own axis relative to its parent.
+    pub const fn with_twist_limits(mut self, min: f32, max: f32) -> Self {
+        self.twist_limit = Some(AngleLimit::new(min, max));
+        self
+    }
+}
+
+/// Triggered when a [`RagdollConstructor`] has finished constructing a [`Ragdoll`].
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct RagdollReady {
+    /// The entity that held the [`RagdollConstructor`].
+    pub entity: Entity,
+}
+
+/// A [ragdoll](self) constructed from a skeleton using a [`RagdollConstructor`].
+///
+/// Removing this component or despawning the entity also despawns the bodies and joints of the ragdoll.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct Ragdoll {
+    /// Controls how the pose of the skeleton is blended between animation and physics, in the `[0, 1]` range.
+    ///
+    /// At `0.0`, the bodies are kinematic and follow the animated bones.
+    /// At `1.0`, the bodies are dynamic and the bones follow the bodies.
+    /// In between, the bodies are dynamic and the bones are interpolated between the animated pose
+    /// and the simulated pose.
+    pub blend_weight: f32,
+    bones: Vec<RagdollBone>,
+}
+
+impl Ragdoll {
+    /// Returns the simulated bones of the ragdoll, ordered such that parents come before their children.
+    pub fn bones(&self) -> &[RagdollBone] {
+        &self.bones
+    }
+
+    /// Returns `true` if the ragdoll is fully animated, and its bodies are kinematic.
+    pub fn is_animated(&self) -> bool {
+        self.blend_weight <= 0.0
+    }
+}
+
+/// A simulated bone of a [`Ragdoll`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct RagdollBone {
+    /// The bone entity in the skeleton.
+    pub bone: Entity,
+    /// The rigid body simulating the bone.
+    pub body: Entity,
+    /// The [`SphericalJoint`] connecting the body to the body of its parent bone,
+    /// or `None` if the bone has no simulated ancestor.
+    pub joint: Option<Entity>,
+}
+
+/// A component for the rigid bodies of a [`Ragdoll`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct RagdollBody {
+    /// The entity with the [`Ragdoll`] component.
+    pub ragdoll: Entity,
+    /// The bone entity in the skeleton.
+    pub bone: Entity,
+}
+
+/// Constructs ragdolls for entities with a [`RagdollConstructor`].
+fn construct_ragdolls(
+    mut commands: Commands,
+    #[cfg(feature = "bevy_scene")] scene_spawner: Option<Res<WorldInstanceSpawner>>,
+    #[cfg(feature = "bevy_scene")] scenes: Query<&WorldAssetRoot>,
+    #[cfg(feature = "bevy_scene")] scene_instances: Query<&SceneInstance>,
+    constructors: Query<(Entity, &RagdollConstructor)>,
+    children: Query<&Children>,
+    parents: Query<&ChildOf>,
+    bones: Query<(Option<&Name>, &GlobalTransform)>,
+) {
+    for (root, constructor) in &constructors {
+        #[cfg(feature = "bevy_scene")]
+        {
+            if scenes.contains(root) {
+                if let (Ok(scene_instance), Some(scene_spawner)) =
+                    (scene_instances.get(root), &scene_spawner)
+                {
+                    if !scene_spawner.instance_is_ready(**scene_instance) {
+                        // Wait for the scene to be ready
+                        continue;
+                    }
+                } else {
+                    // SceneInstance is added in the SpawnScene schedule, so it might not be available yet
+                    continue;
+                }
+            }
+        }
+
+        let mut ragdoll_bones = Vec::new();
+        let mut bodies = EntityHashMap::<Entity>::default();
+
+        for bone in children.iter_descendants_depth_first(root) {
+            let Ok((Some(name), global_transform)) = bones.get(bone) else {
+                continue;
+            };
+            let Some(config) = constructor.bones.get(name.as_str()) else {
+                continue;
+            };
+
+            let (_, rotation, translation) = global_transform.to_scale_rotation_translation();
+
+            // The bone extends towards the average position of its configured child bones,
+            // or all of its child bones if none of them are configured.
+            let child_positions = |configured_only: bool| {
+                children
+                    .get(bone)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|&child| bones.get(child).ok())
+                    .filter(|(name, _)| {
+                        !configured_only
+                            || name
+                                .is_some_and(|name| constructor.bones.contains_key(name.as_str()))
+                    })
+                    .map(|(_, transform)| transform.translation())
+                    .collect::<Vec<_>>()
+            };
+            let mut tail_positions = child_positions(true);
+            if tail_positions.is_empty() {
+                tail_positions = child_positions(false);
+            }
+
+            let tail = (!tail_positions.is_empty())
+                .then(|| {
+                    let center = tail_positions.iter().sum::<Vec3>() / tail_positions.len() as f32;
+                    rotation.inverse() * (center - translation)
+                })
+                .filter(|tail| tail.length_squared() > f32::EPSILON);
+            let axis = tail.map_or(Vec3::Y, |tail| tail.normalize());
+            let length = config
+                .length
+                .or(tail.map(|tail| tail.length()))
+                .unwrap_or(2.0 * config.radius);
+
+            let body = commands
+                .spawn((
+                    if constructor.blend_weight > 0.0 {
+                        RigidBody::Dynamic
+                    } else {
+                        RigidBody::Kinematic
+                    },
+                    bone_collider(config, axis, length),
+                    Mass(constructor.mass * config.mass_fraction),
+                    Transform::from_translation(translation).with_rotation(rotation),
+                    RagdollBody {
+                        ragdoll: root,
+                        bone,
+                    },
+                ))
+                .id();
+
+            // Connect the body to the body of the closest configured ancestor.
+            let parent_body = parents
+                .iter_ancestors(bone)
+                .take_while(|&ancestor| ancestor != root)
+                .find_map(|ancestor| bodies.get(&ancestor).copied());
+
+            let joint = parent_body.map(|parent_body| {
+                let parent_bone = ragdoll_bones
+                    .iter()
+                    .find(|ragdoll_bone: &&RagdollBone| ragdoll_bone.body == parent_body)
+                    .map(|ragdoll_bone| ragdoll_bone.bone)
+                    .unwrap_or(root);
+                let parent_transform = bones
+                    .get(parent_bone)
+                    .map(|(_, transform)| transform.compute_transform())
+                    .unwrap_or_default();
+
+                let parent_rotation_inverse = parent_transform.rotation.inverse();
+                let local_frame1 = Isometry3d::new(
+                    parent_rotation_inverse * (translation - parent_transform.translation),
+                    parent_rotation_inverse * rotation,
+                );
+
+                let mut joint = SphericalJoint::new(parent_body, body)
+                    .with_local_frame1(local_frame1)
+                    .with_local_frame2(Isometry3d::IDENTITY)
+                    .with_twist_axis(axis);
+                joint.swing_limit = config.swing_limit;
+                joint.twist_limit = config.twist_limit;
+
+                commands.spawn((joint, JointCollisionDisabled)).id()
+            });
+
+            bodies.insert(bone, body);
+            ragdoll_bones.push(RagdollBone { bone, body, joint });
+        }
+
+        if ragdoll_bones.is_empty() {
+            warn!(
+                "Tried to construct a ragdoll for entity {root} via {constructor:#?}, \
+                    but none of the configured bones were found in its hierarchy."
+            );
+            commands.entity(root).remove::<RagdollConstructor>();
+            continue;
+        }
+
+        commands
+            .entity(root)
+            .remove::<RagdollConstructor>()
+            .insert(Ragdoll {
+                blend_weight: constructor.blend_weight,
+                bones: ragdoll_bones,
+            });
+
+        commands.trigger(RagdollReady { entity: root });
+    }
+}
+
+/// Creates the collider for a bone extending along the given local `axis` for the given `length`.
+fn bone_collider(config: &RagdollBoneConfig, axis: Vec3, length: f32) -> Collider {
+    let radius = config.radius;
+    let center = axis * length * 0.5;
+
+    match config.shape {
+        RagdollBoneShape::Capsule => {
+            // Shorten the segment by the radius on both ends so that the capsule spans the bone.
+            let half_segment = (length * 0.5 - radius).max(0.0);
+            Collider::capsule_endpoints(
+                radius,
+                center - axis * half_segment,
+                center + axis * half_segment,
+            )
+        }
+        RagdollBoneShape::Sphere => Collider::compound(vec![(
+            center.real(),
+            Quat::IDENTITY,
+            Collider::sphere(radius),
+        )]),
+        RagdollBoneShape::Cuboid => Collider::compound(vec![(
+            center.real(),
+            Quat::from_rotation_arc(Vec3::Y, axis),
+            Collider::cuboid(2.0 * radius, length, 2.0 * radius),
+        )]),
+    }
+}
+
+/// Updates the [`RigidBody`] types of ragdoll bodies based on the [`Ragdoll::blend_weight`].
+fn update_ragdoll_body_types(
+    mut commands: Commands,
+    ragdolls: Query<&Ragdoll, Changed<Ragdoll>>,
+    bodies: Query<&RigidBody, With<RagdollBody>>,
+) {
+    for ragdoll in &ragdolls {
+        let rigid_body = if ragdoll.is_animated() {
+            RigidBody::Kinematic
+        } else {
+            RigidBody::Dynamic
+        };
+
+        for bone in &ragdoll.bones {
+            if bodies.get(bone.body).is_ok_and(|body| *body != rigid_body) {
+                commands.entity(bone.body).insert(rigid_body);
+            }
+        }
+    }
+}
+
+/// Drives the kinematic bodies of animated ragdolls towards the global transforms of their bones using velocities.
+fn follow_animated_bones(
+    ragdolls: Query<&Ragdoll>,
+    bones: Query<&GlobalTransform>,
+    mut bodies: Query<
+        (
+            &Position,
+            &Rotation,
+            &mut LinearVelocity,
+            &mut AngularVelocity,
+        ),
+        With<RagdollBody>,
+    >,
+    time: Res<Time<Physics>>,
+) {
+    let delta_secs = time.delta_secs();
+
+    if delta_secs == 0.0 {
+        return;
+    }
+
+    for ragdoll in ragdolls.iter().filter(|ragdoll| ragdoll.is_animated()) {
+        for bone in &ragdoll.bones {
+            let Ok(bone_transform) = bones.get(bone.bone) else {
+                continue;
+            };
+            let Ok((position, rotation, mut linear_velocity, mut angular_velocity)) =
+                bodies.get_mut(bone.body)
+            else {
+                continue;
+            };
+
+            let (_, target_rotation, target_translation) =
+                bone_transform.to_scale_rotation_translation();
+
+            linear_velocity.0 = (target_translation.real() - position.0).f32() / delta_secs;
+
+            // Take the shortest path towards the target rotation.
+            let mut delta_rotation = target_rotation * rotation.0.inverse();
+            if delta_rotation.w < 0.0 {
+                delta_rotation = -delta_rotation;
+            }
+            let (axis, angle) = delta_rotation.to_axis_angle();
+            angular_velocity.0 = axis * angle / delta_secs;
+        }
+    }
+}
+
+/// Writes the [`Transform`]s of simulated bones based on the poses of their bodies
+/// and the [`Ragdoll::blend_weight`].
+fn write_ragdoll_bones(
+    ragdolls: Query<(Entity, &Ragdoll)>,
+    bodies: Query<&Transform, With<RagdollBody>>,
+    mut transforms: Query<&mut Transform, Without<RagdollBody>>,
+    global_transforms: Query<&GlobalTransform>,
+    parents: Query<&ChildOf>,
+    mut simulated_poses: Local<EntityHashMap<Transform>>,
+    mut poses: Local<EntityHashMap<(GlobalTransform, GlobalTransform)>>,
+    mut chain: Local<Vec<Entity>>,
+    mut new_local_transforms: Local<Vec<(Entity, Transform)>>,
+) {
+    new_local_transforms.clear();
+
+    for (root, ragdoll) in &ragdolls {
+        if ragdoll.is_animated() {
+            continue;
+        }
+
+        let blend_weight = ragdoll.blend_weight.min(1.0);
+
+        simulated_poses.clear();
+        simulated_poses.extend(ragdoll.bones.iter().filter_map(|bone| {
+            bodies
+                .get(bone.body)
+                .ok()
+                .map(|transform| (bone.bone, *transform))
+        }));
+
+        // The animated and blended global transforms of the bones and their ancestors up to the root.
+        let root_transform = global_transforms.get(root).copied().unwrap_or_default();
+        poses.clear();
+        poses.insert(root, (root_transform, root_transform));
+
+        for bone in &ragdoll.bones {
+            // Collect the ancestors of the bone whose poses have not been computed yet.
+            chain.clear();
+            chain.push(bone.bone);
+            let mut ancestor = bone.bone;
+            while let Ok(child_of) = parents.get(ancestor) {
+                ancestor = child_of.parent();
+                if poses.contains_key(&ancestor) {
+                    break;
+                }
+                chain.push(ancestor);
+            }
+
+            // Compute the poses from the top down.
+            for &entity in chain.iter().rev() {
+                let (parent_animated, parent_blended) = parents
+                    .get(entity)
+                    .ok()
+                    .and_then(|child_of| poses.get(&child_of.parent()).copied())
+                    .unwrap_or_default();
+                let local_transform = transforms.get(entity).copied().unwrap_or_default();
+
+                let animated = parent_animated.mul_transform(local_transform);
+                let blended = if let Some(simulated) = simulated_poses.get(&entity) {
+                    let (scale, rotation, translation) = animated.to_scale_rotation_translation();
+                    let blended = GlobalTransform::from(Transform {
+                        translation: translation.lerp(simulated.translation, blend_weight),
+                        rotation: rotation.slerp(simulated.rotation, blend_weight),
+                        scale,
+                    });
+                    new_local_transforms.push((entity, blended.reparented_to(&parent_blended)));
+                    blended
+                } else {
+                    parent_blended.mul_transform(local_transform)
+                };
+
+                poses.insert(entity, (animated, blended));
+            }
+        }
+    }
+
+    for (entity, local_transform) in new_local_transforms.drain(..) {
+        if let Ok(mut transform) = transforms.get_mut(entity) {
+            *transform = local_transform;
+        }
+    }
+}
+
+/// Despawns the bodies and joints of a [`Ragdoll`] when the component is removed.
+fn despawn_ragdoll_entities(
+    trigger: On<Remove, Ragdoll>,
+    mut commands: Commands,
+    ragdolls: Query<&Ragdoll>,
+) {
+    let Ok(ragdoll) = ragdolls.get(trigger.entity) else {
+        return;
+    };
+
+    for bone in &ragdoll.bones {
+        if let Some(joint) = bone.joint {
+            commands.entity(joint).try_despawn();
+        }
+        commands.entity(bone.body).try_despawn();
+    }
+}
@@ -170,6 +170,24 @@
 //! }
 //! ```
 //!
+//! ## Radial Impulses
+//!
+//! For explosions and other impulse bursts that affect all bodies within a radius,
+//! the [`RadialImpulses`] system parameter can be used. It finds the affected bodies
+//! with a spatial query, applies the impulse at the closest point on each collider,
+//! and can optionally let static geometry block the impulse.
+//!
+//! ```
+#![cfg_attr(feature = "2d", doc = "# use avian2d::{math::RVector, prelude::*};")]
+#![cfg_attr(feature = "3d", doc = "# use avian3d::{math::RVector, prelude::*};")]
+//! # use bevy::prelude::*;
+//! #
+//! fn explode(mut radial_impulses: RadialImpulses) {
+//!     // Apply an impulse of up to 50 N⋅s to bodies within 3 meters of the origin.
+//!     radial_impulses.apply(&RadialImpulse::new(RVector::ZERO, 3.0, 50.0));
+//! }
+//! ```
+//!
 //! # Applying Forces vs. Modifying Velocity
 //!
 //! It is possible to achieve similar effects by directly modifying the [`LinearVelocity`]
@@ -206,14 +224,18 @@
 
 mod plugin;
 mod query_data;
+#[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+mod radial_impulse;
 #[cfg(test)]
 mod tests;
 
 pub use plugin::{ForcePlugin, ForceSystems};
 pub use query_data::{
-    Forces, ForcesItem, NonWakingForcesItem, ReadRigidBodyForces, RigidBodyForces,
-    WriteRigidBodyForces,
+    Forces, ForcesItem, ForcesReadOnly, ForcesReadOnlyItem, NonWakingForcesItem,
+    ReadRigidBodyForces, RigidBodyForces, WriteRigidBodyForces,
 };
+#[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+pub use radial_impulse::{RadialImpulse, RadialImpulseFalloff, RadialImpulses};
 
 use crate::prelude::*;
 use bevy::prelude::*;
@@ -0,0 +1,278 @@
+//! Radial impulses for explosions and other impulse bursts.
+//!
+//! See [`RadialImpulses`].
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{entity::EntityHashMap, system::SystemParam},
+    prelude::*,
+};
+
+/// A radial impulse that pushes dynamic rigid bodies away from a center point,
+/// for example for explosions. Applied using the [`RadialImpulses`] system parameter.
+///
+/// The impulse is applied at the point on each collider closest to the [`center`](Self::center),
+/// so bodies are also set spinning depending on where they are hit.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct RadialImpulse {
+    /// The center of the impulse in world space.
+    pub center: RVector,
+    /// The radius within which bodies are affected.
+    pub radius: f32,
+    /// The magnitude of the impulse at the center. The unit is typically N⋅s or kg⋅m/s.
+    pub impulse: f32,
+    /// How the impulse decreases with distance from the center.
+    ///
+    /// **Default**: [`RadialImpulseFalloff::Linear`]
+    pub falloff: RadialImpulseFalloff,
+    /// If `true`, the impulse is blocked for colliders that are not directly visible
+    /// from the center, with static colliders acting as cover.
+    ///
+    /// **Default**: `false`
+    pub occlusion: bool,
+    /// A filter that determines which colliders are affected, and which colliders can occlude the impulse.
+    ///
+    /// **Default**: [`SpatialQueryFilter::default()`]
+    pub filter: SpatialQueryFilter,
+}
+
+impl RadialImpulse {
+    /// Creates a new [`RadialImpulse`] with the given center, radius, and impulse magnitude.
+    ///
+    /// By default, the impulse falls off linearly, and is not occluded.
+    pub fn new(center: RVector, radius: f32, impulse: f32) -> Self {
+        Self {
+            center,
+            radius,
+            impulse,
+            falloff: RadialImpulseFalloff::Linear,
+            occlusion: false,
+            filter: SpatialQueryFilter::default(),
+        }
+    }
+
+    /// Sets the [falloff](RadialImpulseFalloff) of the impulse.
+    pub fn with_falloff(mut self, falloff: RadialImpulseFalloff) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Sets whether the impulse is blocked by static colliders between the center and the affected colliders.
+    pub fn with_occlusion(mut self, occlusion: bool) -> Self {
+        self.occlusion = occlusion;
+        self
+    }
+
+    /// Sets the [`SpatialQueryFilter`] that determines which colliders are affected,
+    /// and which colliders can occlude the impulse.
+    pub fn with_filter(mut self, filter: SpatialQueryFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Returns the magnitude of the impulse at the given distance from the center.
+    pub fn impulse_at_distance(&self, distance: f32) -> f32 {
+        if distance > self.radius {
+            return 0.0;
+        }
+
+        let t = if self.radius > 0.0 {
+            1.0 - distance / self.radius
+        } else {
+            1.0
+        };
+
+        match self.falloff {
+            RadialImpulseFalloff::Constant => self.impulse,
+            RadialImpulseFalloff::Linear => self.impulse * t,
+            RadialImpulseFalloff::Quadratic => self.impulse * t * t,
+        }
+    }
+}
+
+/// Describes how a [`RadialImpulse`] decreases with distance from its center.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq)]
+pub enum RadialImpulseFalloff {
+    /// The impulse is the same everywhere within the radius.
+    Constant,
+    /// The impulse decreases linearly from full strength at the center to zero at the radius.
+    #[default]
+    Linear,
+    /// The impulse decreases quadratically from full strength at the center to zero at the radius.
+    Quadratic,
+}
+
+/// A system parameter for applying [radial impulses](RadialImpulse) to dynamic rigid bodies,
+/// for example for explosions.
+///
+/// Colliders within the radius are found using the [`ColliderTrees`](crate::collider_tree::ColliderTrees),
+/// and the impulse is applied at the point on each collider closest to the center,
+/// so bodies also receive torque. Each body is affected at most once, using its closest collider.
+/// [`Sensor`] colliders are ignored.
+///
+/// Like other impulses applied with [`Forces`], radial impulses wake up the [islands](crate::dynamics::solver::islands)
+/// of the affected bodies if they are [sleeping](Sleeping). Bodies outside of the radius are not woken up.
+///
+/// Radial impulses modify velocities immediately, so they should be applied outside of the [`PhysicsSchedule`],
+/// for example in `FixedUpdate`.
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "use avian2d::{math::RVector, prelude::*};")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::{math::RVector, prelude::*};")]
+/// use bevy::prelude::*;
+///
+/// fn explode(mut radial_impulses: RadialImpulses) {
+///     // Push bodies within 5 meters away from the origin,
+///     // with static geometry providing cover.
+///     radial_impulses.apply(
+///         &RadialImpulse::new(RVector::ZERO, 5.0, 100.0)
+///             .with_falloff(RadialImpulseFalloff::Quadratic)
+///             .with_occlusion(true),
+///     );
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct RadialImpulses<'w, 's> {
+    spatial_query: SpatialQuery<'w, 's>,
+    colliders: Query<
+        'w,
+        's,
+        (
+            &'static Collider,
+            &'static Position,
+            &'static Rotation,
+            Option<&'static ColliderOf>,
+        ),
+        Without<Sensor>,
+    >,
+    rigid_bodies: Query<'w, 's, &'static RigidBody>,
+    forces: Query<'w, 's, Forces>,
+    // The closest point and distance to the center for each affected body.
+    closest_points: Local<'s, EntityHashMap<(RVector, f32)>>,
+}
+
+impl RadialImpulses<'_, '_> {
+    /// Applies the given [`RadialImpulse`] to the dynamic rigid bodies within its radius.
+    ///
+    /// Returns the number of bodies that the impulse was applied to.
+    pub fn apply(&mut self, radial_impulse: &RadialImpulse) -> usize {
+        let center = radial_impulse.center;
+        self.closest_points.clear();
+
+        #[cfg(feature = "2d")]
+        let shape = Collider::circle(radial_impulse.radius);
+        #[cfg(feature = "3d")]
+        let shape = Collider::sphere(radial_impulse.radius);
+
+        // Find the closest point on each dynamic body within the radius.
+        self.spatial_query.shape_intersections_callback(
+            &shape,
+            center,
+            Rot::IDENTITY,
+            &radial_impulse.filter,
+            |entity| {
+                let Ok((collider, position, rotation, Some(collider_of))) =
+                    self.colliders.get(entity)
+                else {
+                    return true;
+                };
+                if !self
+                    .rigid_bodies
+                    .get(collider_of.body)
+                    .is_ok_and(RigidBody::is_dynamic)
+                {
+                    return true;
+                }
+
+                let (point, _) = collider.project_point(position.0, *rotation, center, true);
+                let distance = (point - center).length().f32();
+
+                let closest = self
+                    .closest_points
+                    .entry(collider_of.body)
+                    .or_insert((point, distance));
+                if distance < closest.1 {
+                    *closest = (point, distance);
+                }
+
+                true
+            },
+        );
+
+        let mut count = 0;
+
+        for (&body, &(point, distance)) in self.closest_points.iter() {
+            if radial_impulse.occlusion && self.is_occluded(radial_impulse, body, point, distance) {
+                continue;
+            }
+
+            let magnitude = radial_impulse.impulse_at_distance(distance);
+            if magnitude == 0.0 {
+                continue;
+            }
+
+            let Ok(mut forces) = self.forces.get_mut(body) else {
+                continue;
+            };
+
+            // Push away from the center. If the center is inside the collider,
+            // push the body away from the center through its center of mass.
+            let direction = (point - center)
+                .f32()
+                .try_normalize()
+                .or_else(|| (forces.position() - center).f32().try_normalize())
+                .unwrap_or(Vector::Y);
+
+            forces.apply_linear_impulse_at_point(direction * magnitude, point);
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns `true` if a static collider blocks the line from the center of the impulse to the given point.
+    fn is_occluded(
+        &self,
+        radial_impulse: &RadialImpulse,
+        body: Entity,
+        point: RVector,
+        distance: f32,
+    ) -> bool {
+        let Ok(direction) = Dir::new((point - radial_impulse.center).f32()) else {
+            // The center is inside the collider.
+            return false;
+        };
+
+        self.spatial_query
+            .cast_ray_predicate(
+                radial_impulse.center,
+                direction,
+                distance,
+                true,
+                &radial_impulse.filter,
+                &|entity| {
+                    // Only static geometry that does not belong to the body provides cover.
+                    match self.colliders.get(entity) {
+                        Ok((.., Some(collider_of))) => {
+                            collider_of.body != body
+                                && self
+                                    .rigid_bodies
+                                    .get(collider_of.body)
+                                    .is_ok_and(RigidBody::is_static)
+                        }
+                        Ok((.., None)) => true,
+                        Err(_) => false,
+                    }
+                },
+            )
+            .is_some_and(|hit| hit.distance < distance - 1e-4)
+    }
+}
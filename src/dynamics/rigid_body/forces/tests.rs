@@ -652,3 +652,55 @@ fn apply_local_angular_acceleration() {
         );
     assert!(diff < 0.1, "angle difference {diff} is not less than 0.1");
 }
+
+#[test]
+#[cfg(feature = "default-collider")]
+fn apply_radial_impulse() {
+    let mut app = create_app();
+    app.insert_resource(Gravity::ZERO);
+    app.finish();
+
+    // Apply a radial impulse at the origin once the colliders have been initialized.
+    app.add_systems(
+        FixedUpdate,
+        |mut radial_impulses: RadialImpulses, mut steps: Local<usize>| {
+            *steps += 1;
+            if *steps == 5 {
+                radial_impulses
+                    .apply(&RadialImpulse::new(RVector::ZERO, 5.0, 10.0).with_occlusion(true));
+            }
+        },
+    );
+
+    #[cfg(feature = "2d")]
+    let (ball, wall) = (Collider::circle(0.5), Collider::rectangle(0.2, 4.0));
+    #[cfg(feature = "3d")]
+    let (ball, wall) = (Collider::sphere(0.5), Collider::cuboid(0.2, 4.0, 4.0));
+
+    let mut spawn_ball = |x: f32| {
+        app.world_mut()
+            .spawn((
+                RigidBody::Dynamic,
+                ball.clone(),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ))
+            .id()
+    };
+
+    // A body within the radius, a body outside of the radius, and a body behind a wall.
+    let near = spawn_ball(2.0);
+    let far = spawn_ball(8.0);
+    let occluded = spawn_ball(-3.0);
+    app.world_mut()
+        .spawn((RigidBody::Static, wall, Transform::from_xyz(-1.5, 0.0, 0.0)));
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let velocity = |entity: Entity| app.world().get::<LinearVelocity>(entity).unwrap().0;
+
+    assert!(velocity(near).x > 1.0);
+    assert_eq!(velocity(far), Vector::ZERO);
+    assert_eq!(velocity(occluded), Vector::ZERO);
+}
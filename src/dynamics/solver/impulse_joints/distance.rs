@@ -0,0 +1,170 @@
+use super::shared::{
+    ImpulseJointContext, LinearJacobian, compute_limit_impulse, compute_row_impulse,
+};
+use super::{ImpulseJoint, ImpulseJointSolverData};
+use crate::{
+    dynamics::solver::solver_body::{SolverBody, SolverBodyInertia},
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the impulse-based joint solver for a [`DistanceJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct DistanceJointImpulseSolverData {
+    pub(super) world_r1: Vector,
+    pub(super) world_r2: Vector,
+    pub(super) center_difference: Vector,
+    /// The direction between the anchors at the start of the time step, used for reporting forces.
+    pub(super) direction: Vector,
+    /// The accumulated impulse for a fixed distance, when the minimum and maximum distances are equal.
+    pub(super) impulse: f32,
+    pub(super) lower_impulse: f32,
+    pub(super) upper_impulse: f32,
+}
+
+impl ImpulseJointSolverData for DistanceJointImpulseSolverData {
+    fn linear_impulse(&self) -> Vector {
+        (self.impulse + self.lower_impulse - self.upper_impulse) * self.direction
+    }
+}
+
+impl DistanceJointImpulseSolverData {
+    /// Returns the Jacobian of the constraint along the current direction between the anchors,
+    /// and the current distance between the anchors.
+    ///
+    /// Returns `None` if the anchors coincide.
+    fn jacobian(&self, body1: &SolverBody, body2: &SolverBody) -> Option<(LinearJacobian, f32)> {
+        let world_r1 = body1.delta_rotation * self.world_r1;
+        let world_r2 = body2.delta_rotation * self.world_r2;
+        let separation = (body2.delta_position - body1.delta_position)
+            + (world_r2 - world_r1)
+            + self.center_difference;
+
+        let distance = separation.length();
+        if distance <= f32::EPSILON {
+            return None;
+        }
+
+        let jacobian = LinearJacobian {
+            direction: separation / distance,
+            arm1: world_r1,
+            arm2: world_r2,
+        };
+
+        Some((jacobian, distance))
+    }
+}
+
+impl ImpulseJoint for DistanceJoint {
+    type SolverData = DistanceJointImpulseSolverData;
+
+    fn prepare(
+        &self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut DistanceJointImpulseSolverData,
+    ) {
+        let [body1, body2] = bodies;
+
+        let JointAnchor::Local(local_anchor1) = self.anchor1 else {
+            return;
+        };
+        let JointAnchor::Local(local_anchor2) = self.anchor2 else {
+            return;
+        };
+
+        solver_data.world_r1 = body1.rotation * (local_anchor1 - body1.center_of_mass.0);
+        solver_data.world_r2 = body2.rotation * (local_anchor2 - body2.center_of_mass.0);
+        solver_data.center_difference = (body2.position.0 - body1.position.0).f32()
+            + (body2.rotation * body2.center_of_mass.0 - body1.rotation * body1.center_of_mass.0);
+
+        let separation =
+            solver_data.center_difference + solver_data.world_r2 - solver_data.world_r1;
+        solver_data.direction = separation.normalize_or_zero();
+
+        // Reset the accumulated impulses of rows that are no longer in use.
+        if self.limits.min == self.limits.max {
+            solver_data.lower_impulse = 0.0;
+            solver_data.upper_impulse = 0.0;
+        } else {
+            solver_data.impulse = 0.0;
+        }
+    }
+
+    fn warm_start(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut DistanceJointImpulseSolverData,
+        coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let Some((jacobian, _)) = solver_data.jacobian(body1, body2) else {
+            return;
+        };
+
+        let impulse = solver_data.impulse + solver_data.lower_impulse - solver_data.upper_impulse;
+        jacobian.apply(body1, body2, inertia1, inertia2, coefficient * impulse);
+    }
+
+    fn solve(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut DistanceJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let Some((jacobian, distance)) = solver_data.jacobian(body1, body2) else {
+            return;
+        };
+
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+
+        if self.limits.min == self.limits.max {
+            // The distance is fixed.
+            let impulse = compute_row_impulse(
+                inverse_mass,
+                jacobian.velocity(body1, body2),
+                distance - self.limits.min,
+                solver_data.impulse,
+                context.softness(self.compliance),
+            );
+            solver_data.impulse += impulse;
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+            return;
+        }
+
+        // Lower limit
+        {
+            let position_error = distance - self.limits.min;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.lower_impulse,
+                context.limit_softness(position_error, self.compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+        }
+
+        // Upper limit
+        {
+            let position_error = self.limits.max - distance;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                -jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.upper_impulse,
+                context.limit_softness(position_error, self.compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, -impulse);
+        }
+    }
+}
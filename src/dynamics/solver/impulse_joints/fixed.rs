@@ -0,0 +1,106 @@
+use super::shared::{AngleLockPart, ImpulseJointContext, PointConstraintPart};
+use super::{ImpulseJoint, ImpulseJointSolverData};
+use crate::{
+    dynamics::solver::solver_body::{SolverBody, SolverBodyInertia},
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the impulse-based joint solver for a [`FixedJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct FixedJointImpulseSolverData {
+    pub(super) point_constraint: PointConstraintPart,
+    pub(super) angle_constraint: AngleLockPart,
+}
+
+impl ImpulseJointSolverData for FixedJointImpulseSolverData {
+    fn linear_impulse(&self) -> Vector {
+        self.point_constraint.impulse
+    }
+
+    fn angular_impulse(&self) -> AngularVector {
+        self.angle_constraint.impulse
+    }
+}
+
+impl ImpulseJoint for FixedJoint {
+    type SolverData = FixedJointImpulseSolverData;
+
+    fn prepare(
+        &self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut FixedJointImpulseSolverData,
+    ) {
+        let [body1, body2] = bodies;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+        let Some(local_basis1) = self.local_basis1() else {
+            return;
+        };
+        let Some(local_basis2) = self.local_basis2() else {
+            return;
+        };
+
+        solver_data
+            .point_constraint
+            .prepare(bodies, local_anchor1, local_anchor2);
+        solver_data.angle_constraint.prepare(
+            (*body1.rotation).into(),
+            (*body2.rotation).into(),
+            local_basis1,
+            local_basis2,
+        );
+    }
+
+    fn warm_start(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut FixedJointImpulseSolverData,
+        coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        solver_data
+            .point_constraint
+            .warm_start(body1, body2, inertia1, inertia2, coefficient);
+        solver_data
+            .angle_constraint
+            .warm_start(body1, body2, inertia1, inertia2, coefficient);
+    }
+
+    fn solve(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut FixedJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        solver_data.angle_constraint.solve(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            context.softness(self.angle_compliance),
+        );
+        solver_data.point_constraint.solve(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            context.softness(self.point_compliance),
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! An impulse-based solver for [joints](dynamics::joints).
+//!
+//! Like contacts, joints solved by this solver use [soft constraints](super::softness_parameters)
+//! with substepping, warm starting, and relaxation. The joints are solved in the same [`SubstepSolverSystems`]
+//! as contacts, which tends to make mechanisms and stacks that rely on both joints and contacts more stable.
+//!
+//! The impulse-based joint solver is used if [`SolverConfig::joint_solver`] is set to [`JointSolver::Impulse`].
+//! Otherwise, joints are solved using [Extended Position-Based Dynamics (XPBD)](super::xpbd).
+//!
+//! Below are the joints currently supported by the impulse-based solver.
+//!
+//! - [`FixedJoint`]
+//! - [`RevoluteJoint`]
+//! - [`PrismaticJoint`]
+#![cfg_attr(feature = "3d", doc = "- [`SphericalJoint`]")]
+//! - [`DistanceJoint`]
+//!
+//! Other joints require the XPBD solver and the `xpbd_joints` feature. If any of them exist while
+//! [`JointSolver::Impulse`] is selected, the solver falls back to [`JointSolver::Xpbd`] with a warning,
+//! so that all joints are solved by the same solver. Without the `xpbd_joints` feature, these joints are not simulated.
+//!
+//! [Projected joints](dynamics::joint_projection) are handled separately, and work with both solvers.
+//!
+//! # Softness
+//!
+//! Rigid joint constraints, those with a compliance of zero, are softened using the [`JointSoftnessCoefficients`].
+//! These are computed from the [`SolverConfig::joint_damping_ratio`] and a frequency that is twice
+//! the one used for contacts, so that joints are stiffer than contacts.
+//!
+//! Constraints with a non-zero compliance are treated as implicit springs with a stiffness of `1.0 / compliance`.
+//!
+//! [`SubstepSolverSystems`]: super::schedule::SubstepSolverSystems
+
+mod distance;
+mod fixed;
+mod prismatic;
+mod revolute;
+mod shared;
+#[cfg(feature = "3d")]
+mod spherical;
+
+pub use distance::DistanceJointImpulseSolverData;
+pub use fixed::FixedJointImpulseSolverData;
+pub use prismatic::PrismaticJointImpulseSolverData;
+pub use revolute::RevoluteJointImpulseSolverData;
+pub(crate) use shared::ImpulseJointContext;
+pub use shared::{AngleLockPart, PointConstraintPart};
+#[cfg(feature = "3d")]
+pub use spherical::SphericalJointImpulseSolverData;
+
+use crate::{
+    dynamics::{
+        joints::EntityConstraint,
+        solver::{
+            JointSolver, SolverConfig,
+            schedule::SubstepSolverSystems,
+            softness_parameters::{SoftnessCoefficients, SoftnessParameters},
+            solver_body::{
+                SolverBodies, SolverBody, SolverBodyIndex, SolverBodyInertia,
+                for_each_joint_body_pair,
+            },
+        },
+    },
+    prelude::*,
+};
+use bevy::{ecs::component::Mutable, prelude::*};
+
+/// A plugin for solving [joints](dynamics::joints) with an impulse-based solver.
+///
+/// The systems only run if [`SolverConfig::joint_solver`] is set to [`JointSolver::Impulse`].
+///
+/// See the [module-level documentation](self) for more information.
+pub struct ImpulseJointSolverPlugin;
+
+impl Plugin for ImpulseJointSolverPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<JointSoftnessCoefficients>();
+
+        app.register_required_components::<FixedJoint, FixedJointImpulseSolverData>();
+        app.register_required_components::<RevoluteJoint, RevoluteJointImpulseSolverData>();
+        #[cfg(feature = "3d")]
+        app.register_required_components::<SphericalJoint, SphericalJointImpulseSolverData>();
+        app.register_required_components::<PrismaticJoint, PrismaticJointImpulseSolverData>();
+        app.register_required_components::<DistanceJoint, DistanceJointImpulseSolverData>();
+
+        app.add_systems(
+            PhysicsSchedule,
+            (
+                check_unsupported_joints
+                    .run_if(uses_impulse_joints)
+                    .in_set(PhysicsStepSystems::First)
+                    .before(super::plugin::update_contact_softness),
+                update_joint_softness
+                    .after(check_unsupported_joints)
+                    .before(PhysicsStepSystems::Solver),
+            ),
+        );
+
+        // Prepare joints before the substepping loop.
+        let prepare_joints = (
+            prepare_impulse_joint::<FixedJoint>,
+            prepare_impulse_joint::<RevoluteJoint>,
+            #[cfg(feature = "3d")]
+            prepare_impulse_joint::<SphericalJoint>,
+            prepare_impulse_joint::<PrismaticJoint>,
+            prepare_impulse_joint::<DistanceJoint>,
+        )
+            .chain()
+            .run_if(uses_impulse_joints)
+            .in_set(SolverSystems::PrepareJoints);
+        #[cfg(feature = "xpbd_joints")]
+        let prepare_joints =
+            prepare_joints.after(crate::dynamics::solver::xpbd::joints::prepare_xpbd_target_joints);
+        app.add_systems(PhysicsSchedule, prepare_joints);
+
+        // Warm start the joints before contacts.
+        app.add_systems(
+            SubstepSchedule,
+            (
+                warm_start_impulse_joint::<FixedJoint>,
+                warm_start_impulse_joint::<RevoluteJoint>,
+                #[cfg(feature = "3d")]
+                warm_start_impulse_joint::<SphericalJoint>,
+                warm_start_impulse_joint::<PrismaticJoint>,
+                warm_start_impulse_joint::<DistanceJoint>,
+            )
+                .chain()
+                .run_if(uses_impulse_joints)
+                .in_set(SubstepSolverSystems::WarmStart)
+                .before(super::plugin::warm_start),
+        );
+
+        // Solve joints with a position bias before contacts.
+        app.add_systems(
+            SubstepSchedule,
+            (
+                solve_impulse_joint::<FixedJoint, true>,
+                solve_impulse_joint::<RevoluteJoint, true>,
+                #[cfg(feature = "3d")]
+                solve_impulse_joint::<SphericalJoint, true>,
+                solve_impulse_joint::<PrismaticJoint, true>,
+                solve_impulse_joint::<DistanceJoint, true>,
+            )
+                .chain()
+                .run_if(uses_impulse_joints)
+                .in_set(SubstepSolverSystems::SolveConstraints)
+                .before(super::plugin::solve_contacts::<true>),
+        );
+
+        // Relax joint velocities before contacts.
+        app.add_systems(
+            SubstepSchedule,
+            (
+                solve_impulse_joint::<FixedJoint, false>,
+                solve_impulse_joint::<RevoluteJoint, false>,
+                #[cfg(feature = "3d")]
+                solve_impulse_joint::<SphericalJoint, false>,
+                solve_impulse_joint::<PrismaticJoint, false>,
+                solve_impulse_joint::<DistanceJoint, false>,
+            )
+                .chain()
+                .run_if(uses_impulse_joints)
+                .in_set(SubstepSolverSystems::Relax)
+                .before(super::plugin::solve_contacts::<false>),
+        );
+
+        // Write back the forces applied by the joints.
+        let writeback_forces = (
+            writeback_impulse_joint_forces::<FixedJoint>,
+            writeback_impulse_joint_forces::<RevoluteJoint>,
+            #[cfg(feature = "3d")]
+            writeback_impulse_joint_forces::<SphericalJoint>,
+            writeback_impulse_joint_forces::<PrismaticJoint>,
+            writeback_impulse_joint_forces::<DistanceJoint>,
+        )
+            .chain()
+            .run_if(uses_impulse_joints)
+            .in_set(SolverSystems::Finalize);
+        #[cfg(feature = "xpbd_joints")]
+        let writeback_forces = writeback_forces
+            .after(crate::dynamics::solver::xpbd::joints::writeback_target_joint_forces);
+        app.add_systems(PhysicsSchedule, writeback_forces);
+    }
+}
+
+/// The [`SoftnessCoefficients`] used for rigid joint constraints solved by the impulse-based joint solver.
+///
+/// **Note**: This resource is updated automatically and not intended to be modified manually.
+/// Use the [`SolverConfig`] resource instead for tuning joint behavior.
+#[derive(Resource, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct JointSoftnessCoefficients(pub SoftnessCoefficients);
+
+impl Default for JointSoftnessCoefficients {
+    fn default() -> Self {
+        Self(SoftnessParameters::new(2.0, 60.0).compute_coefficients(1.0 / 60.0))
+    }
+}
+
+fn update_joint_softness(
+    mut coefficients: ResMut<JointSoftnessCoefficients>,
+    solver_config: Res<SolverConfig>,
+    physics_time: Res<Time<Physics>>,
+    substep_time: Res<Time<Substeps>>,
+) {
+    if solver_config.is_changed() || physics_time.is_changed() || substep_time.is_changed() {
+        let dt = physics_time.delta_secs();
+        let h = substep_time.delta_secs();
+
+        // Use twice the frequency of contacts to make joints stiffer than contacts.
+        // See `update_contact_softness` for how the contact frequency is computed.
+        let max_hz = 1.0 / (dt * 2.0);
+        let hz = 2.0 * solver_config.contact_frequency_factor * max_hz.min(0.25 / h);
+
+        coefficients.0 =
+            SoftnessParameters::new(solver_config.joint_damping_ratio, hz).compute_coefficients(h);
+    }
+}
+
+/// A query filter for enabled joints that are not supported by the impulse-based joint solver.
+type UnsupportedJointFilter = (
+    Or<(
+        With<WheelJoint>,
+        With<GenericJoint>,
+        With<GearJoint>,
+        With<RackAndPinionJoint>,
+        With<PulleyJoint>,
+        With<TargetJoint>,
+    )>,
+    Without<JointDisabled>,
+);
+
+/// Switches to [`JointSolver::Xpbd`] if there are joints that the impulse-based joint solver does not support,
+/// so that all joints are solved by the same solver.
+#[cfg(feature = "xpbd_joints")]
+fn check_unsupported_joints(
+    mut solver_config: ResMut<SolverConfig>,
+    unsupported_joints: Query<(), UnsupportedJointFilter>,
+) {
+    if !unsupported_joints.is_empty() {
+        warn!(
+            "The impulse-based joint solver does not support wheel, generic, gear, rack and pinion, pulley, or target joints. Falling back to `JointSolver::Xpbd`."
+        );
+        solver_config.joint_solver = JointSolver::Xpbd;
+    }
+}
+
+/// Warns if there are joints that the impulse-based joint solver does not support,
+/// as they cannot be solved without the `xpbd_joints` feature.
+#[cfg(not(feature = "xpbd_joints"))]
+fn check_unsupported_joints(unsupported_joints: Query<(), UnsupportedJointFilter>) {
+    if !unsupported_joints.is_empty() {
+        warn_once!(
+            "The impulse-based joint solver does not support wheel, generic, gear, rack and pinion, pulley, or target joints. Enable the `xpbd_joints` feature to simulate them."
+        );
+    }
+}
+
+/// A run condition that returns `true` if joints should be solved with the impulse-based joint solver.
+fn uses_impulse_joints(solver_config: Res<SolverConfig>) -> bool {
+    solver_config.joint_solver == JointSolver::Impulse
+}
+
+/// A joint that can be solved with the impulse-based joint solver.
+pub(crate) trait ImpulseJoint: EntityConstraint<2> {
+    /// The solver data stored for the joint, such as world-space anchors and accumulated impulses.
+    type SolverData: Component<Mutability = Mutable> + ImpulseJointSolverData;
+
+    /// Prepares the joint for the substepping loop.
+    ///
+    /// The accumulated impulses should be kept for warm starting.
+    fn prepare(&self, bodies: [&RigidBodyQueryReadOnlyItem; 2], solver_data: &mut Self::SolverData);
+
+    /// Applies the accumulated impulses scaled by the given `coefficient`.
+    fn warm_start(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut Self::SolverData,
+        coefficient: f32,
+    );
+
+    /// Solves the joint for the given bodies.
+    fn solve(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut Self::SolverData,
+        context: &ImpulseJointContext,
+    );
+}
+
+/// Solver data for a joint solved with the impulse-based joint solver.
+pub(crate) trait ImpulseJointSolverData {
+    /// Returns the accumulated linear impulse applied to the second body in a substep.
+    fn linear_impulse(&self) -> Vector;
+
+    /// Returns the accumulated angular impulse applied to the second body in a substep.
+    fn angular_impulse(&self) -> AngularVector {
+        AngularVector::default()
+    }
+
+    /// Returns the motor impulse applied in the latest substep.
+    fn motor_impulse(&self) -> f32 {
+        0.0
+    }
+}
+
+/// Prepares the impulse-based joints of a given type for the substepping loop.
+fn prepare_impulse_joint<C: Component + ImpulseJoint>(
+    bodies: Query<RigidBodyQueryReadOnly, Without<RigidBodyDisabled>>,
+    mut joints: Query<(&C, &mut C::SolverData), (Without<RigidBody>, Without<JointDisabled>)>,
+) {
+    for (joint, mut solver_data) in &mut joints {
+        if let Ok([body1, body2]) = bodies.get_many(joint.entities()) {
+            joint.prepare([&body1, &body2], &mut solver_data);
+        }
+    }
+}
+
+/// Warm starts the impulse-based joints of a given type.
+fn warm_start_impulse_joint<C: Component + ImpulseJoint>(
+    mut solver_bodies: ResMut<SolverBodies>,
+    index_query: Query<&SolverBodyIndex, Without<RigidBodyDisabled>>,
+    mut joints: Query<(&C, &mut C::SolverData), (Without<RigidBody>, Without<JointDisabled>)>,
+    solver_config: Res<SolverConfig>,
+) {
+    for_each_joint_body_pair(
+        &mut solver_bodies,
+        &index_query,
+        &mut joints,
+        |joint, mut solver_data, bodies, inertias| {
+            joint.warm_start(
+                bodies,
+                inertias,
+                &mut solver_data,
+                solver_config.warm_start_coefficient,
+            );
+        },
+    );
+}
+
+/// Solves the impulse-based joints of a given type.
+///
+/// If `USE_BIAS` is `false`, no position bias is applied to rigid constraints,
+/// and only velocities are relaxed.
+fn solve_impulse_joint<C: Component + ImpulseJoint, const USE_BIAS: bool>(
+    mut solver_bodies: ResMut<SolverBodies>,
+    index_query: Query<&SolverBodyIndex, Without<RigidBodyDisabled>>,
+    mut joints: Query<(&C, &mut C::SolverData), (Without<RigidBody>, Without<JointDisabled>)>,
+    softness: Res<JointSoftnessCoefficients>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    let context = ImpulseJointContext {
+        delta_secs,
+        inv_delta_secs: delta_secs.recip_or_zero(),
+        softness: softness.0,
+        use_bias: USE_BIAS,
+    };
+
+    for_each_joint_body_pair(
+        &mut solver_bodies,
+        &index_query,
+        &mut joints,
+        |joint, mut solver_data, bodies, inertias| {
+            joint.solve(bodies, inertias, &mut solver_data, &context);
+        },
+    );
+}
+
+/// Writes back the forces applied by the impulse-based joints of a given type to their [`JointForces`].
+fn writeback_impulse_joint_forces<C: Component + ImpulseJoint>(
+    mut joints: Query<(&C::SolverData, &mut JointForces)>,
+    time: Res<Time<Substeps>>,
+) {
+    // The accumulated impulses are the impulses applied in a single substep.
+    let inv_delta_secs = time.delta_secs().recip_or_zero();
+
+    for (solver_data, mut forces) in &mut joints {
+        // Like the XPBD solver, report the forces applied to the first body.
+        forces.set_force(-solver_data.linear_impulse() * inv_delta_secs);
+        forces.set_torque(-solver_data.angular_impulse() * inv_delta_secs);
+        forces.set_motor_force(solver_data.motor_impulse() * inv_delta_secs);
+    }
+}
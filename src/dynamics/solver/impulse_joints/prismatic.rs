@@ -0,0 +1,302 @@
+use super::shared::{
+    AngleLockPart, ImpulseJointContext, LinearJacobian, apply_linear_impulse,
+    compute_limit_impulse, compute_row_impulse,
+};
+use super::{ImpulseJoint, ImpulseJointSolverData};
+use crate::{
+    dynamics::{
+        joints::compute_motor_lagrange,
+        solver::solver_body::{SolverBody, SolverBodyInertia},
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the impulse-based joint solver for a [`PrismaticJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct PrismaticJointImpulseSolverData {
+    pub(super) angle_constraint: AngleLockPart,
+    pub(super) world_r1: Vector,
+    pub(super) world_r2: Vector,
+    pub(super) center_difference: Vector,
+    /// The world-space slider axis of the first body.
+    pub(super) free_axis1: Vector,
+    /// The accumulated impulse perpendicular to the slider axis.
+    pub(super) perpendicular_impulse: Vector,
+    pub(super) lower_limit_impulse: f32,
+    pub(super) upper_limit_impulse: f32,
+    /// The motor impulse applied in the latest substep.
+    pub(super) motor_impulse: f32,
+}
+
+impl ImpulseJointSolverData for PrismaticJointImpulseSolverData {
+    fn linear_impulse(&self) -> Vector {
+        let axial_impulse =
+            self.lower_limit_impulse - self.upper_limit_impulse + self.motor_impulse;
+        self.perpendicular_impulse + axial_impulse * self.free_axis1
+    }
+
+    fn angular_impulse(&self) -> AngularVector {
+        self.angle_constraint.impulse
+    }
+
+    fn motor_impulse(&self) -> f32 {
+        self.motor_impulse
+    }
+}
+
+impl PrismaticJointImpulseSolverData {
+    /// Returns the Jacobian of a constraint row along the current slider axis,
+    /// and the current separation between the anchors.
+    fn slider_jacobian(&self, body1: &SolverBody, body2: &SolverBody) -> (LinearJacobian, Vector) {
+        let world_r1 = body1.delta_rotation * self.world_r1;
+        let world_r2 = body2.delta_rotation * self.world_r2;
+        let separation = (body2.delta_position - body1.delta_position)
+            + (world_r2 - world_r1)
+            + self.center_difference;
+        let axis = body1.delta_rotation * self.free_axis1;
+
+        // The first arm extends to the anchor of the second body, since the axis is attached to the first body.
+        let jacobian = LinearJacobian {
+            direction: axis,
+            arm1: world_r1 + separation,
+            arm2: world_r2,
+        };
+
+        (jacobian, separation)
+    }
+}
+
+impl ImpulseJoint for PrismaticJoint {
+    type SolverData = PrismaticJointImpulseSolverData;
+
+    fn prepare(
+        &self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut PrismaticJointImpulseSolverData,
+    ) {
+        let [body1, body2] = bodies;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+        let Some(local_basis1) = self.local_basis1() else {
+            return;
+        };
+        let Some(local_basis2) = self.local_basis2() else {
+            return;
+        };
+
+        solver_data.angle_constraint.prepare(
+            (*body1.rotation).into(),
+            (*body2.rotation).into(),
+            local_basis1,
+            local_basis2,
+        );
+
+        solver_data.world_r1 = body1.rotation * (local_anchor1 - body1.center_of_mass.0);
+        solver_data.world_r2 = body2.rotation * (local_anchor2 - body2.center_of_mass.0);
+        solver_data.center_difference = (body2.position.0 - body1.position.0).f32()
+            + (body2.rotation * body2.center_of_mass.0 - body1.rotation * body1.center_of_mass.0);
+        solver_data.free_axis1 = Rot::from(*body1.rotation) * local_basis1 * self.slider_axis;
+    }
+
+    fn warm_start(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut PrismaticJointImpulseSolverData,
+        coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let (jacobian, _) = solver_data.slider_jacobian(body1, body2);
+        let axial_impulse = solver_data.lower_limit_impulse - solver_data.upper_limit_impulse;
+        let impulse = solver_data.perpendicular_impulse + axial_impulse * jacobian.direction;
+
+        apply_linear_impulse(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            jacobian.arm1,
+            jacobian.arm2,
+            coefficient * impulse,
+        );
+        solver_data
+            .angle_constraint
+            .warm_start(body1, body2, inertia1, inertia2, coefficient);
+    }
+
+    fn solve(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut PrismaticJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        // Solve motors before limits to give limits higher priority.
+        if context.use_bias {
+            self.solve_motor(body1, body2, inertia1, inertia2, solver_data, context);
+        }
+
+        self.solve_limits(body1, body2, inertia1, inertia2, solver_data, context);
+
+        solver_data.angle_constraint.solve(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            context.softness(self.angle_compliance),
+        );
+
+        self.solve_perpendicular(body1, body2, inertia1, inertia2, solver_data, context);
+    }
+}
+
+impl PrismaticJoint {
+    /// Applies motor impulses to drive the joint towards the target velocity and/or position.
+    fn solve_motor(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut PrismaticJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        solver_data.motor_impulse = 0.0;
+
+        let motor = &self.motor;
+
+        if !motor.enabled {
+            return;
+        }
+
+        let (jacobian, separation) = solver_data.slider_jacobian(body1, body2);
+        let position = separation.dot(jacobian.direction);
+
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+        if inverse_mass <= f32::EPSILON {
+            return;
+        }
+
+        let velocity_error = motor.target_velocity - jacobian.velocity(body1, body2);
+        let position_error = motor.target_position - position;
+
+        let Some(delta_lagrange) = compute_motor_lagrange(
+            velocity_error,
+            position_error,
+            inverse_mass,
+            motor.motor_model,
+            motor.max_force,
+            context.delta_secs,
+        ) else {
+            return;
+        };
+
+        // The Lagrange multiplier update is a position-level impulse.
+        let impulse = delta_lagrange * context.inv_delta_secs;
+        solver_data.motor_impulse = impulse;
+        jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+    }
+
+    /// Applies impulses to keep the translation along the slider axis within the [`limits`](Self::limits).
+    fn solve_limits(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut PrismaticJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let Some(limits) = self.limits else {
+            solver_data.lower_limit_impulse = 0.0;
+            solver_data.upper_limit_impulse = 0.0;
+            return;
+        };
+
+        let (jacobian, separation) = solver_data.slider_jacobian(body1, body2);
+        let position = separation.dot(jacobian.direction);
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+
+        // Lower limit
+        {
+            let position_error = position - limits.min;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.lower_limit_impulse,
+                context.limit_softness(position_error, self.limit_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+        }
+
+        // Upper limit
+        {
+            let position_error = limits.max - position;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                -jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.upper_limit_impulse,
+                context.limit_softness(position_error, self.limit_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, -impulse);
+        }
+    }
+
+    /// Applies impulses to prevent relative translation perpendicular to the slider axis.
+    fn solve_perpendicular(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut PrismaticJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let (slider_jacobian, separation) = solver_data.slider_jacobian(body1, body2);
+        let axis = slider_jacobian.direction;
+
+        #[cfg(feature = "2d")]
+        let perpendicular_axes = [axis.perp()];
+        #[cfg(feature = "3d")]
+        let perpendicular_axes = {
+            let (b, c) = axis.any_orthonormal_pair();
+            [b, c]
+        };
+
+        let softness = context.softness(self.align_compliance);
+
+        for direction in perpendicular_axes {
+            let jacobian = LinearJacobian {
+                direction,
+                ..slider_jacobian
+            };
+
+            let impulse = compute_row_impulse(
+                jacobian.inverse_mass(inertia1, inertia2),
+                jacobian.velocity(body1, body2),
+                separation.dot(direction),
+                solver_data.perpendicular_impulse.dot(direction),
+                softness,
+            );
+
+            solver_data.perpendicular_impulse += impulse * direction;
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use super::shared::{
+    AngularJacobian, ImpulseJointContext, PointConstraintPart, apply_angular_impulse,
+    compute_limit_impulse,
+};
+use super::{ImpulseJoint, ImpulseJointSolverData};
+use crate::{
+    dynamics::{
+        joints::compute_motor_lagrange,
+        solver::solver_body::{SolverBody, SolverBodyInertia},
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+use core::f32::consts::{PI, TAU};
+
+/// Constraint data required by the impulse-based joint solver for a [`RevoluteJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct RevoluteJointImpulseSolverData {
+    pub(super) point_constraint: PointConstraintPart,
+    #[cfg(feature = "2d")]
+    pub(super) rotation_difference: f32,
+    #[cfg(feature = "3d")]
+    pub(super) a1: Vector,
+    #[cfg(feature = "3d")]
+    pub(super) a2: Vector,
+    #[cfg(feature = "3d")]
+    pub(super) b1: Vector,
+    #[cfg(feature = "3d")]
+    pub(super) b2: Vector,
+    /// The accumulated angular impulse keeping the hinge axes aligned.
+    #[cfg(feature = "3d")]
+    pub(super) align_impulse: Vector,
+    pub(super) lower_limit_impulse: f32,
+    pub(super) upper_limit_impulse: f32,
+    /// The motor impulse applied in the latest substep.
+    pub(super) motor_impulse: f32,
+}
+
+impl ImpulseJointSolverData for RevoluteJointImpulseSolverData {
+    fn linear_impulse(&self) -> Vector {
+        self.point_constraint.impulse
+    }
+
+    fn angular_impulse(&self) -> AngularVector {
+        let limit_impulse = self.lower_limit_impulse - self.upper_limit_impulse;
+        #[cfg(feature = "2d")]
+        {
+            limit_impulse + self.motor_impulse
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.align_impulse + (limit_impulse + self.motor_impulse) * self.a1
+        }
+    }
+
+    fn motor_impulse(&self) -> f32 {
+        self.motor_impulse
+    }
+}
+
+impl ImpulseJoint for RevoluteJoint {
+    type SolverData = RevoluteJointImpulseSolverData;
+
+    fn prepare(
+        &self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut RevoluteJointImpulseSolverData,
+    ) {
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+        let Some(local_basis1) = self.local_basis1() else {
+            return;
+        };
+        let Some(local_basis2) = self.local_basis2() else {
+            return;
+        };
+
+        solver_data
+            .point_constraint
+            .prepare(bodies, local_anchor1, local_anchor2);
+
+        let basis1 = Rot::from(*bodies[0].rotation) * local_basis1;
+        let basis2 = Rot::from(*bodies[1].rotation) * local_basis2;
+
+        #[cfg(feature = "2d")]
+        {
+            solver_data.rotation_difference = basis1.angle_to(basis2);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let reference_axis = self.hinge_axis.any_orthonormal_vector();
+            solver_data.a1 = basis1 * self.hinge_axis;
+            solver_data.a2 = basis2 * self.hinge_axis;
+            solver_data.b1 = basis1 * reference_axis;
+            solver_data.b2 = basis2 * reference_axis;
+        }
+    }
+
+    fn warm_start(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut RevoluteJointImpulseSolverData,
+        coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let (axis, _) = solver_data.hinge_axis_and_angle(body1, body2);
+        let limit_impulse = solver_data.lower_limit_impulse - solver_data.upper_limit_impulse;
+
+        #[cfg(feature = "2d")]
+        let angular_impulse = limit_impulse * axis;
+        #[cfg(feature = "3d")]
+        let angular_impulse = solver_data.align_impulse + limit_impulse * axis;
+
+        apply_angular_impulse(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            coefficient * angular_impulse,
+        );
+        solver_data
+            .point_constraint
+            .warm_start(body1, body2, inertia1, inertia2, coefficient);
+    }
+
+    fn solve(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut RevoluteJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        // Solve motors before limits to give limits higher priority.
+        if context.use_bias {
+            self.solve_motor(body1, body2, inertia1, inertia2, solver_data, context);
+        }
+
+        self.solve_angle_limits(body1, body2, inertia1, inertia2, solver_data, context);
+
+        #[cfg(feature = "3d")]
+        self.solve_hinge_alignment(body1, body2, inertia1, inertia2, solver_data, context);
+
+        solver_data.point_constraint.solve(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            context.softness(self.point_compliance),
+        );
+    }
+}
+
+impl RevoluteJointImpulseSolverData {
+    /// Returns the current hinge axis and the angle of the second body about it relative to the first body.
+    fn hinge_axis_and_angle(&self, body1: &SolverBody, body2: &SolverBody) -> (AngularVector, f32) {
+        #[cfg(feature = "2d")]
+        {
+            let angle =
+                self.rotation_difference + body1.delta_rotation.angle_to(body2.delta_rotation);
+            (1.0, angle)
+        }
+        #[cfg(feature = "3d")]
+        {
+            let a1 = body1.delta_rotation * self.a1;
+            let b1 = body1.delta_rotation * self.b1;
+            let b2 = body2.delta_rotation * self.b2;
+            let sin_angle = b1.cross(b2).dot(a1);
+            let cos_angle = b1.dot(b2);
+            (a1, sin_angle.atan2(cos_angle))
+        }
+    }
+}
+
+impl RevoluteJoint {
+    /// Applies motor impulses to drive the joint towards the target velocity and/or position.
+    fn solve_motor(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut RevoluteJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        solver_data.motor_impulse = 0.0;
+
+        let motor = &self.motor;
+
+        if !motor.enabled {
+            return;
+        }
+
+        let (axis, angle) = solver_data.hinge_axis_and_angle(body1, body2);
+        let jacobian = AngularJacobian { axis };
+
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+        if inverse_mass <= f32::EPSILON {
+            return;
+        }
+
+        let velocity_error = motor.target_velocity - jacobian.velocity(body1, body2);
+
+        // Wrap position error to [-PI, PI] for shortest path rotation.
+        let raw_error = motor.target_position - angle;
+        let position_error = (raw_error + PI).rem_euclid(TAU) - PI;
+
+        let Some(delta_lagrange) = compute_motor_lagrange(
+            velocity_error,
+            position_error,
+            inverse_mass,
+            motor.motor_model,
+            motor.max_torque,
+            context.delta_secs,
+        ) else {
+            return;
+        };
+
+        // The Lagrange multiplier update is a position-level impulse.
+        let impulse = delta_lagrange * context.inv_delta_secs;
+        solver_data.motor_impulse = impulse;
+        jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+    }
+
+    /// Applies impulses to keep the angle of the bodies about the hinge axis within the [`angle_limit`](Self::angle_limit).
+    fn solve_angle_limits(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut RevoluteJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let Some(angle_limit) = self.angle_limit else {
+            solver_data.lower_limit_impulse = 0.0;
+            solver_data.upper_limit_impulse = 0.0;
+            return;
+        };
+
+        let (axis, angle) = solver_data.hinge_axis_and_angle(body1, body2);
+        let jacobian = AngularJacobian { axis };
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+
+        // Lower limit
+        {
+            let position_error = angle - angle_limit.min;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.lower_limit_impulse,
+                context.limit_softness(position_error, self.limit_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+        }
+
+        // Upper limit
+        {
+            let position_error = angle_limit.max - angle;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                -jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.upper_limit_impulse,
+                context.limit_softness(position_error, self.limit_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, -impulse);
+        }
+    }
+
+    /// Applies impulses to keep the hinge axes of the bodies aligned.
+    #[cfg(feature = "3d")]
+    fn solve_hinge_alignment(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut RevoluteJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let a1 = body1.delta_rotation * solver_data.a1;
+        let a2 = body2.delta_rotation * solver_data.a2;
+
+        // The two axes perpendicular to the hinge axis of the first body.
+        let (b, c) = a1.any_orthonormal_pair();
+
+        let error = a1.cross(a2);
+        let velocity = body2.angular_velocity - body1.angular_velocity;
+        let accumulated = Vec2::new(
+            solver_data.align_impulse.dot(b),
+            solver_data.align_impulse.dot(c),
+        );
+
+        let softness = context.softness(self.align_compliance);
+
+        let inv_inertia = inertia1.effective_inv_angular_inertia().to_mat3()
+            + inertia2.effective_inv_angular_inertia().to_mat3();
+        let k = Mat2::from_cols(
+            Vec2::new(b.dot(inv_inertia * b), c.dot(inv_inertia * b)),
+            Vec2::new(b.dot(inv_inertia * c), c.dot(inv_inertia * c)),
+        ) + Mat2::from_diagonal(Vec2::splat(softness.gamma));
+
+        let rhs = Vec2::new(velocity.dot(b), velocity.dot(c))
+            + softness.bias * Vec2::new(error.dot(b), error.dot(c))
+            + softness.gamma * accumulated;
+
+        let determinant = k.determinant();
+        let solution = if determinant.abs() > f32::EPSILON {
+            k.inverse() * rhs
+        } else {
+            Vec2::ZERO
+        };
+        let impulse = -softness.mass_scale * solution - softness.impulse_scale * accumulated;
+        let angular_impulse = impulse.x * b + impulse.y * c;
+
+        solver_data.align_impulse += angular_impulse;
+
+        apply_angular_impulse(body1, body2, inertia1, inertia2, angular_impulse);
+    }
+}
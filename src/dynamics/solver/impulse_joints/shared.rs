@@ -0,0 +1,504 @@
+use crate::{
+    dynamics::solver::{
+        softness_parameters::SoftnessCoefficients,
+        solver_body::{SolverBody, SolverBodyInertia},
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use glam_matrix_extras::SymmetricMat2;
+#[cfg(feature = "3d")]
+use glam_matrix_extras::SymmetricMat3;
+
+/// The softness of a constraint row or block, used for computing impulses.
+///
+/// This extends [`SoftnessCoefficients`] with a `gamma` term for constraints
+/// with a non-zero compliance, which behave like implicit springs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ConstraintSoftness {
+    /// The coefficient used for scaling how strongly impulses are biased based on the position error.
+    pub bias: f32,
+    /// The coefficient used for scaling the effective mass "seen" by the constraint.
+    pub mass_scale: f32,
+    /// The coefficient used for scaling the accumulated impulse that is subtracted from the total impulse.
+    pub impulse_scale: f32,
+    /// The compliance divided by the square of the time step. Added to the inverse effective mass.
+    pub gamma: f32,
+}
+
+impl ConstraintSoftness {
+    /// A rigid constraint without a position bias, used for relaxing velocities.
+    pub const RIGID: Self = Self {
+        bias: 0.0,
+        mass_scale: 1.0,
+        impulse_scale: 0.0,
+        gamma: 0.0,
+    };
+}
+
+impl From<SoftnessCoefficients> for ConstraintSoftness {
+    fn from(coefficients: SoftnessCoefficients) -> Self {
+        Self {
+            bias: coefficients.bias,
+            mass_scale: coefficients.mass_scale,
+            impulse_scale: coefficients.impulse_scale,
+            gamma: 0.0,
+        }
+    }
+}
+
+/// Shared data for solving impulse-based joints in a substep.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ImpulseJointContext {
+    /// The substep time step (s).
+    pub delta_secs: f32,
+    /// The inverse of the substep time step (1/s).
+    pub inv_delta_secs: f32,
+    /// The [`SoftnessCoefficients`] used for joint constraints with zero compliance.
+    pub softness: SoftnessCoefficients,
+    /// If `false`, no position bias is applied to rigid constraints, and only velocities are relaxed.
+    pub use_bias: bool,
+}
+
+impl ImpulseJointContext {
+    /// Returns the [`ConstraintSoftness`] for a constraint with the given `compliance`.
+    pub fn softness(&self, compliance: f32) -> ConstraintSoftness {
+        if compliance > 0.0 {
+            // Compliant constraints are implicit springs. They are applied in the relaxation pass too,
+            // as the spring force should not be relaxed away.
+            ConstraintSoftness {
+                bias: self.inv_delta_secs,
+                mass_scale: 1.0,
+                impulse_scale: 0.0,
+                gamma: compliance * self.inv_delta_secs * self.inv_delta_secs,
+            }
+        } else if self.use_bias {
+            self.softness.into()
+        } else {
+            ConstraintSoftness::RIGID
+        }
+    }
+
+    /// Returns the [`ConstraintSoftness`] for a limit with the given `compliance`,
+    /// where a positive `position_error` means that the limit has not been reached yet.
+    pub fn limit_softness(&self, position_error: f32, compliance: f32) -> ConstraintSoftness {
+        if position_error > 0.0 {
+            // Speculative: Allow the bodies to approach the limit, but not cross it within the substep.
+            ConstraintSoftness {
+                bias: self.inv_delta_secs,
+                ..ConstraintSoftness::RIGID
+            }
+        } else {
+            self.softness(compliance)
+        }
+    }
+}
+
+/// Computes the impulse for a scalar constraint row.
+///
+/// `inverse_mass` is the inverse effective mass of the row, and `accumulated_impulse`
+/// is the total impulse applied by the row so far.
+pub(crate) fn compute_row_impulse(
+    inverse_mass: f32,
+    velocity: f32,
+    position_error: f32,
+    accumulated_impulse: f32,
+    softness: ConstraintSoftness,
+) -> f32 {
+    let effective_mass = (inverse_mass + softness.gamma).recip_or_zero();
+    -softness.mass_scale
+        * effective_mass
+        * (velocity + softness.bias * position_error + softness.gamma * accumulated_impulse)
+        - softness.impulse_scale * accumulated_impulse
+}
+
+/// Computes the impulse for an inequality constraint row, clamping the `accumulated_impulse` to be non-negative.
+///
+/// Returns the change in the accumulated impulse.
+pub(crate) fn compute_limit_impulse(
+    inverse_mass: f32,
+    velocity: f32,
+    position_error: f32,
+    accumulated_impulse: &mut f32,
+    softness: ConstraintSoftness,
+) -> f32 {
+    let impulse = compute_row_impulse(
+        inverse_mass,
+        velocity,
+        position_error,
+        *accumulated_impulse,
+        softness,
+    );
+    let new_impulse = (*accumulated_impulse + impulse).max(0.0);
+    let applied_impulse = new_impulse - *accumulated_impulse;
+    *accumulated_impulse = new_impulse;
+    applied_impulse
+}
+
+/// Returns the inverse effective mass of a body about the given angular `axis`.
+#[inline]
+pub(crate) fn angular_inverse_mass(inv_inertia: SymmetricTensor, axis: AngularVector) -> f32 {
+    #[cfg(feature = "2d")]
+    {
+        inv_inertia * axis * axis
+    }
+    #[cfg(feature = "3d")]
+    {
+        axis.dot(inv_inertia * axis)
+    }
+}
+
+/// Applies an angular impulse to the second body, and the opposite impulse to the first body.
+#[inline]
+pub(crate) fn apply_angular_impulse(
+    body1: &mut SolverBody,
+    body2: &mut SolverBody,
+    inertia1: &SolverBodyInertia,
+    inertia2: &SolverBodyInertia,
+    impulse: AngularVector,
+) {
+    body1.angular_velocity -= inertia1.effective_inv_angular_inertia() * impulse;
+    body2.angular_velocity += inertia2.effective_inv_angular_inertia() * impulse;
+}
+
+/// Applies a linear impulse at the given arms relative to the centers of mass of the bodies.
+///
+/// The impulse is applied to the second body, and the opposite impulse to the first body.
+#[inline]
+pub(crate) fn apply_linear_impulse(
+    body1: &mut SolverBody,
+    body2: &mut SolverBody,
+    inertia1: &SolverBodyInertia,
+    inertia2: &SolverBodyInertia,
+    arm1: Vector,
+    arm2: Vector,
+    impulse: Vector,
+) {
+    body1.linear_velocity -= impulse * inertia1.effective_inv_mass();
+    body1.angular_velocity -= inertia1.effective_inv_angular_inertia() * cross(arm1, impulse);
+    body2.linear_velocity += impulse * inertia2.effective_inv_mass();
+    body2.angular_velocity += inertia2.effective_inv_angular_inertia() * cross(arm2, impulse);
+}
+
+/// The Jacobian of a constraint row along a linear `direction` with the given arms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LinearJacobian {
+    pub direction: Vector,
+    pub arm1: Vector,
+    pub arm2: Vector,
+}
+
+impl LinearJacobian {
+    /// Returns the relative velocity along the constraint direction.
+    #[inline]
+    pub fn velocity(&self, body1: &SolverBody, body2: &SolverBody) -> f32 {
+        self.direction
+            .dot(body2.velocity_at_point(self.arm2) - body1.velocity_at_point(self.arm1))
+    }
+
+    /// Returns the inverse effective mass of the constraint row.
+    #[inline]
+    pub fn inverse_mass(&self, inertia1: &SolverBodyInertia, inertia2: &SolverBodyInertia) -> f32 {
+        let linear = (inertia1.effective_inv_mass() + inertia2.effective_inv_mass())
+            * self.direction
+            * self.direction;
+        linear.element_sum()
+            + angular_inverse_mass(
+                inertia1.effective_inv_angular_inertia(),
+                cross(self.arm1, self.direction),
+            )
+            + angular_inverse_mass(
+                inertia2.effective_inv_angular_inertia(),
+                cross(self.arm2, self.direction),
+            )
+    }
+
+    /// Applies an impulse of the given magnitude along the constraint direction.
+    #[inline]
+    pub fn apply(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        impulse: f32,
+    ) {
+        apply_linear_impulse(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            self.arm1,
+            self.arm2,
+            impulse * self.direction,
+        );
+    }
+}
+
+/// The Jacobian of a constraint row about an angular `axis`.
+///
+/// In 2D, the axis is `1.0` or `-1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct AngularJacobian {
+    pub axis: AngularVector,
+}
+
+impl AngularJacobian {
+    /// Returns the relative angular velocity about the constraint axis.
+    #[inline]
+    pub fn velocity(&self, body1: &SolverBody, body2: &SolverBody) -> f32 {
+        #[cfg(feature = "2d")]
+        {
+            (body2.angular_velocity - body1.angular_velocity) * self.axis
+        }
+        #[cfg(feature = "3d")]
+        {
+            (body2.angular_velocity - body1.angular_velocity).dot(self.axis)
+        }
+    }
+
+    /// Returns the inverse effective mass of the constraint row.
+    #[inline]
+    pub fn inverse_mass(&self, inertia1: &SolverBodyInertia, inertia2: &SolverBodyInertia) -> f32 {
+        angular_inverse_mass(inertia1.effective_inv_angular_inertia(), self.axis)
+            + angular_inverse_mass(inertia2.effective_inv_angular_inertia(), self.axis)
+    }
+
+    /// Applies an angular impulse of the given magnitude about the constraint axis.
+    #[inline]
+    pub fn apply(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        impulse: f32,
+    ) {
+        apply_angular_impulse(body1, body2, inertia1, inertia2, impulse * self.axis);
+    }
+}
+
+/// A point-to-point constraint that makes the anchor points of two bodies coincide.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct PointConstraintPart {
+    /// The world-space anchor point relative to the center of mass of the first body.
+    pub world_r1: Vector,
+    /// The world-space anchor point relative to the center of mass of the second body.
+    pub world_r2: Vector,
+    /// The difference in center of mass positions between the two bodies.
+    pub center_difference: Vector,
+    /// The accumulated impulse applied to the second body.
+    pub impulse: Vector,
+}
+
+impl PointConstraintPart {
+    /// Prepares the constraint with the given bodies and local anchor points.
+    pub(crate) fn prepare(
+        &mut self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        local_anchor1: Vector,
+        local_anchor2: Vector,
+    ) {
+        let [body1, body2] = bodies;
+
+        self.world_r1 = body1.rotation * (local_anchor1 - body1.center_of_mass.0);
+        self.world_r2 = body2.rotation * (local_anchor2 - body2.center_of_mass.0);
+        self.center_difference = (body2.position.0 - body1.position.0).f32()
+            + (body2.rotation * body2.center_of_mass.0 - body1.rotation * body1.center_of_mass.0);
+    }
+
+    /// Returns the current world-space anchor points relative to the centers of mass,
+    /// and the separation between the anchor points.
+    #[inline]
+    pub(crate) fn current_anchors(&self, body1: &SolverBody, body2: &SolverBody) -> [Vector; 3] {
+        let world_r1 = body1.delta_rotation * self.world_r1;
+        let world_r2 = body2.delta_rotation * self.world_r2;
+        let separation = (body2.delta_position - body1.delta_position)
+            + (world_r2 - world_r1)
+            + self.center_difference;
+        [world_r1, world_r2, separation]
+    }
+
+    /// Applies the accumulated impulse scaled by the given coefficient.
+    pub(crate) fn warm_start(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        coefficient: f32,
+    ) {
+        let world_r1 = body1.delta_rotation * self.world_r1;
+        let world_r2 = body2.delta_rotation * self.world_r2;
+        apply_linear_impulse(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            world_r1,
+            world_r2,
+            coefficient * self.impulse,
+        );
+    }
+
+    /// Solves the constraint for the given bodies.
+    pub(crate) fn solve(
+        &mut self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        softness: ConstraintSoftness,
+    ) {
+        let [world_r1, world_r2, separation] = self.current_anchors(body1, body2);
+
+        let velocity = body2.velocity_at_point(world_r2) - body1.velocity_at_point(world_r1);
+        let rhs = velocity + softness.bias * separation + softness.gamma * self.impulse;
+
+        let inv_mass_sum = inertia1.effective_inv_mass() + inertia2.effective_inv_mass();
+        let inv_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        // The effective mass matrix K = J * M^-1 * J^T of the point constraint.
+        #[cfg(feature = "2d")]
+        let solution = {
+            let (r1, r2) = (world_r1, world_r2);
+            let k = SymmetricMat2::new(
+                inv_mass_sum.x
+                    + inv_inertia1 * r1.y * r1.y
+                    + inv_inertia2 * r2.y * r2.y
+                    + softness.gamma,
+                -inv_inertia1 * r1.x * r1.y - inv_inertia2 * r2.x * r2.y,
+                inv_mass_sum.y
+                    + inv_inertia1 * r1.x * r1.x
+                    + inv_inertia2 * r2.x * r2.x
+                    + softness.gamma,
+            );
+            k.inverse_or_zero().mul_vec2(rhs)
+        };
+        #[cfg(feature = "3d")]
+        let solution = {
+            let k = SymmetricMat3::from_diagonal(inv_mass_sum + Vec3::splat(softness.gamma))
+                + inv_inertia1.skew(world_r1)
+                + inv_inertia2.skew(world_r2);
+            k.inverse_or_zero() * rhs
+        };
+
+        let impulse = -softness.mass_scale * solution - softness.impulse_scale * self.impulse;
+        self.impulse += impulse;
+
+        apply_linear_impulse(
+            body1, body2, inertia1, inertia2, world_r1, world_r2, impulse,
+        );
+    }
+}
+
+/// A constraint that locks the relative rotation of two bodies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub struct AngleLockPart {
+    /// The rotation difference between the joint frames of the bodies at the start of the time step.
+    #[cfg(feature = "2d")]
+    pub rotation_difference: f32,
+    /// The rotation difference between the joint frames of the bodies at the start of the time step.
+    #[cfg(feature = "3d")]
+    pub rotation_difference: Quat,
+    /// The accumulated angular impulse applied to the second body.
+    pub impulse: AngularVector,
+}
+
+impl AngleLockPart {
+    /// Prepares the constraint with the given rotations and local basis orientations.
+    pub(crate) fn prepare(
+        &mut self,
+        rotation1: Rot,
+        rotation2: Rot,
+        local_basis1: Rot,
+        local_basis2: Rot,
+    ) {
+        #[cfg(feature = "2d")]
+        {
+            self.rotation_difference =
+                (rotation1 * local_basis1).angle_to(rotation2 * local_basis2);
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.rotation_difference =
+                (rotation2 * local_basis2) * (rotation1 * local_basis1).inverse();
+        }
+    }
+
+    /// Returns the current rotation error of the second body relative to the first body.
+    #[inline]
+    pub(crate) fn rotation_error(&self, body1: &SolverBody, body2: &SolverBody) -> AngularVector {
+        #[cfg(feature = "2d")]
+        {
+            self.rotation_difference + body1.delta_rotation.angle_to(body2.delta_rotation)
+        }
+        #[cfg(feature = "3d")]
+        {
+            let mut error =
+                body2.delta_rotation * self.rotation_difference * body1.delta_rotation.inverse();
+            if error.w < 0.0 {
+                error = -error;
+            }
+            2.0 * error.xyz()
+        }
+    }
+
+    /// Applies the accumulated impulse scaled by the given coefficient.
+    pub(crate) fn warm_start(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        coefficient: f32,
+    ) {
+        apply_angular_impulse(body1, body2, inertia1, inertia2, coefficient * self.impulse);
+    }
+
+    /// Solves the constraint for the given bodies.
+    pub(crate) fn solve(
+        &mut self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        softness: ConstraintSoftness,
+    ) {
+        let error = self.rotation_error(body1, body2);
+        let velocity = body2.angular_velocity - body1.angular_velocity;
+
+        let inv_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        #[cfg(feature = "2d")]
+        let impulse = compute_row_impulse(
+            inv_inertia1 + inv_inertia2,
+            velocity,
+            error,
+            self.impulse,
+            softness,
+        );
+        #[cfg(feature = "3d")]
+        let impulse = {
+            let k = inv_inertia1
+                + inv_inertia2
+                + SymmetricMat3::from_diagonal(Vec3::splat(softness.gamma));
+            let rhs = velocity + softness.bias * error + softness.gamma * self.impulse;
+            -softness.mass_scale * (k.inverse_or_zero() * rhs)
+                - softness.impulse_scale * self.impulse
+        };
+
+        self.impulse += impulse;
+
+        apply_angular_impulse(body1, body2, inertia1, inertia2, impulse);
+    }
+}
@@ -0,0 +1,363 @@
+use super::shared::{
+    AngularJacobian, ImpulseJointContext, PointConstraintPart, angular_inverse_mass,
+    apply_angular_impulse, compute_limit_impulse,
+};
+use super::{ImpulseJoint, ImpulseJointSolverData};
+use crate::{
+    dynamics::{
+        joints::compute_motor_lagrange,
+        solver::solver_body::{SolverBody, SolverBodyInertia},
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the impulse-based joint solver for a [`SphericalJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct SphericalJointImpulseSolverData {
+    pub(super) point_constraint: PointConstraintPart,
+    pub(super) twist_axis1: Vector,
+    pub(super) twist_axis2: Vector,
+    /// An axis perpendicular to the twist axis of the first body, used for measuring the twist angle.
+    pub(super) reference_axis1: Vector,
+    /// An axis perpendicular to the twist axis of the second body, used for measuring the twist angle.
+    pub(super) reference_axis2: Vector,
+    /// The world-space joint frame of the first body.
+    pub(super) basis1: Quat,
+    /// The world-space joint frame of the second body.
+    pub(super) basis2: Quat,
+    /// The axis about which the swing limit was last applied.
+    pub(super) swing_limit_axis: Vector,
+    /// The axis about which the twist limit was last applied.
+    pub(super) twist_limit_axis: Vector,
+    pub(super) swing_lower_impulse: f32,
+    pub(super) swing_upper_impulse: f32,
+    pub(super) twist_lower_impulse: f32,
+    pub(super) twist_upper_impulse: f32,
+    /// The motor impulse applied in the latest substep.
+    pub(super) motor_impulse: Vector,
+}
+
+impl ImpulseJointSolverData for SphericalJointImpulseSolverData {
+    fn linear_impulse(&self) -> Vector {
+        self.point_constraint.impulse
+    }
+
+    fn angular_impulse(&self) -> AngularVector {
+        (self.swing_lower_impulse - self.swing_upper_impulse) * self.swing_limit_axis
+            + (self.twist_lower_impulse - self.twist_upper_impulse) * self.twist_limit_axis
+            + self.motor_impulse
+    }
+
+    fn motor_impulse(&self) -> f32 {
+        self.motor_impulse.length()
+    }
+}
+
+impl SphericalJointImpulseSolverData {
+    /// Returns the axis about which the twist axis of the second body swings away from the twist axis
+    /// of the first body, and the swing angle in the `[0, pi]` range.
+    ///
+    /// Returns `None` if the twist axes are parallel.
+    fn swing(&self, body1: &SolverBody, body2: &SolverBody) -> Option<(Vector, f32)> {
+        let a1 = body1.delta_rotation * self.twist_axis1;
+        let a2 = body2.delta_rotation * self.twist_axis2;
+        let (axis, sin_angle) = Dir3::new_and_length(a1.cross(a2)).ok()?;
+        Some((*axis, sin_angle.atan2(a1.dot(a2))))
+    }
+
+    /// Returns the twist axis halfway between the twist axes of the bodies,
+    /// and the twist angle of the second body about it in the `[-pi, pi]` range.
+    ///
+    /// Returns `None` if the twist axes point in opposite directions.
+    fn twist(&self, body1: &SolverBody, body2: &SolverBody) -> Option<(Vector, f32)> {
+        let a1 = body1.delta_rotation * self.twist_axis1;
+        let a2 = body2.delta_rotation * self.twist_axis2;
+        let axis = Dir3::new(a1 + a2).ok()?;
+
+        // Project the reference axes onto the plane perpendicular to the twist axis.
+        let b1 = body1.delta_rotation * self.reference_axis1;
+        let b2 = body2.delta_rotation * self.reference_axis2;
+        let b1 = b1.reject_from_normalized(*axis);
+        let b2 = b2.reject_from_normalized(*axis);
+
+        let sin_angle = b1.cross(b2).dot(*axis);
+        let cos_angle = b1.dot(b2);
+        Some((*axis, sin_angle.atan2(cos_angle)))
+    }
+}
+
+impl ImpulseJoint for SphericalJoint {
+    type SolverData = SphericalJointImpulseSolverData;
+
+    fn prepare(
+        &self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut SphericalJointImpulseSolverData,
+    ) {
+        let [body1, body2] = bodies;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+        let Some(local_basis1) = self.local_basis1() else {
+            return;
+        };
+        let Some(local_basis2) = self.local_basis2() else {
+            return;
+        };
+
+        solver_data
+            .point_constraint
+            .prepare(bodies, local_anchor1, local_anchor2);
+
+        solver_data.basis1 = body1.rotation.0 * local_basis1;
+        solver_data.basis2 = body2.rotation.0 * local_basis2;
+
+        let reference_axis = self.twist_axis.any_orthonormal_vector();
+        solver_data.twist_axis1 = solver_data.basis1 * self.twist_axis;
+        solver_data.twist_axis2 = solver_data.basis2 * self.twist_axis;
+        solver_data.reference_axis1 = solver_data.basis1 * reference_axis;
+        solver_data.reference_axis2 = solver_data.basis2 * reference_axis;
+    }
+
+    fn warm_start(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut SphericalJointImpulseSolverData,
+        coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let mut angular_impulse = Vector::ZERO;
+        if let Some((axis, _)) = solver_data.swing(body1, body2) {
+            angular_impulse +=
+                (solver_data.swing_lower_impulse - solver_data.swing_upper_impulse) * axis;
+        }
+        if let Some((axis, _)) = solver_data.twist(body1, body2) {
+            angular_impulse +=
+                (solver_data.twist_lower_impulse - solver_data.twist_upper_impulse) * axis;
+        }
+
+        apply_angular_impulse(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            coefficient * angular_impulse,
+        );
+        solver_data
+            .point_constraint
+            .warm_start(body1, body2, inertia1, inertia2, coefficient);
+    }
+
+    fn solve(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut SphericalJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        // Solve motors before limits to give limits higher priority.
+        if context.use_bias {
+            self.solve_motor(body1, body2, inertia1, inertia2, solver_data, context);
+        }
+
+        self.solve_swing_limit(body1, body2, inertia1, inertia2, solver_data, context);
+        self.solve_twist_limit(body1, body2, inertia1, inertia2, solver_data, context);
+
+        solver_data.point_constraint.solve(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            context.softness(self.point_compliance),
+        );
+    }
+}
+
+impl SphericalJoint {
+    /// Applies motor impulses to drive the joint towards the target rotation and/or angular velocity.
+    fn solve_motor(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut SphericalJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        solver_data.motor_impulse = Vector::ZERO;
+
+        let motor = &self.motor;
+
+        if !motor.enabled {
+            return;
+        }
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        // The current world-space joint frames.
+        let basis1 = body1.delta_rotation * solver_data.basis1;
+        let basis2 = body2.delta_rotation * solver_data.basis2;
+
+        // The rotation that would take the second joint frame to its target orientation,
+        // following the shortest path.
+        let mut error_rotation = basis1 * motor.target_rotation * basis2.inverse();
+        if error_rotation.w < 0.0 {
+            error_rotation = -error_rotation;
+        }
+        let position_error = error_rotation.to_scaled_axis();
+
+        let target_velocity = basis1 * motor.target_velocity;
+        let velocity_error = target_velocity - (body2.angular_velocity - body1.angular_velocity);
+
+        // Drive each axis of the first joint frame independently,
+        // and clamp the total torque at the end.
+        let mut delta_lagrange = Vector::ZERO;
+
+        for axis in [basis1 * Vec3::X, basis1 * Vec3::Y, basis1 * Vec3::Z] {
+            let inverse_mass = angular_inverse_mass(inv_angular_inertia1, axis)
+                + angular_inverse_mass(inv_angular_inertia2, axis);
+
+            if inverse_mass <= f32::EPSILON {
+                continue;
+            }
+
+            if let Some(axis_lagrange) = compute_motor_lagrange(
+                velocity_error.dot(axis),
+                position_error.dot(axis),
+                inverse_mass,
+                motor.motor_model,
+                f32::MAX,
+                context.delta_secs,
+            ) {
+                delta_lagrange += axis_lagrange * axis;
+            }
+        }
+
+        if motor.max_torque < f32::MAX && motor.max_torque > 0.0 {
+            delta_lagrange = delta_lagrange
+                .clamp_length_max(motor.max_torque * context.delta_secs * context.delta_secs);
+        }
+
+        // The Lagrange multiplier update is a position-level impulse.
+        let impulse = delta_lagrange * context.inv_delta_secs;
+        solver_data.motor_impulse = impulse;
+        apply_angular_impulse(body1, body2, inertia1, inertia2, impulse);
+    }
+
+    /// Applies impulses to keep the swing angle within the [`swing_limit`](Self::swing_limit).
+    fn solve_swing_limit(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut SphericalJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let Some(swing_limit) = self.swing_limit else {
+            solver_data.swing_lower_impulse = 0.0;
+            solver_data.swing_upper_impulse = 0.0;
+            return;
+        };
+
+        let Some((axis, angle)) = solver_data.swing(body1, body2) else {
+            return;
+        };
+        solver_data.swing_limit_axis = axis;
+
+        let jacobian = AngularJacobian { axis };
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+
+        // Lower limit
+        if swing_limit.min > 0.0 {
+            let position_error = angle - swing_limit.min;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.swing_lower_impulse,
+                context.limit_softness(position_error, self.swing_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+        }
+
+        // Upper limit
+        {
+            let position_error = swing_limit.max - angle;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                -jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.swing_upper_impulse,
+                context.limit_softness(position_error, self.swing_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, -impulse);
+        }
+    }
+
+    /// Applies impulses to keep the twist angle within the [`twist_limit`](Self::twist_limit).
+    fn solve_twist_limit(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut SphericalJointImpulseSolverData,
+        context: &ImpulseJointContext,
+    ) {
+        let Some(twist_limit) = self.twist_limit else {
+            solver_data.twist_lower_impulse = 0.0;
+            solver_data.twist_upper_impulse = 0.0;
+            return;
+        };
+
+        let Some((axis, angle)) = solver_data.twist(body1, body2) else {
+            return;
+        };
+        solver_data.twist_limit_axis = axis;
+
+        let jacobian = AngularJacobian { axis };
+        let inverse_mass = jacobian.inverse_mass(inertia1, inertia2);
+
+        // Lower limit
+        {
+            let position_error = angle - twist_limit.min;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.twist_lower_impulse,
+                context.limit_softness(position_error, self.twist_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, impulse);
+        }
+
+        // Upper limit
+        {
+            let position_error = twist_limit.max - angle;
+            let impulse = compute_limit_impulse(
+                inverse_mass,
+                -jacobian.velocity(body1, body2),
+                position_error,
+                &mut solver_data.twist_upper_impulse,
+                context.limit_softness(position_error, self.twist_compliance),
+            );
+            jacobian.apply(body1, body2, inertia1, inertia2, -impulse);
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub use plugin::*;
 
 pub mod constraint_graph;
 pub mod contact;
+pub mod impulse_joints;
 pub mod islands;
 pub mod schedule;
 pub mod softness_parameters;
@@ -39,6 +40,8 @@ use bevy::{app::PluginGroupBuilder, prelude::*};
 /// | [`IslandPlugin`]                  | Manages [simulation islands](dynamics::solver::islands) for sleeping and waking.                                                                           |
 /// | [`IslandSleepingPlugin`]          | Manages sleeping and waking of [simulation islands](dynamics::solver::islands).                                                                            |
 /// | [`JointGraphPlugin`]              | Manages the [`JointGraph`] for each joint type.                                                          |
+/// | [`JointProjectionPlugin`]         | Keeps [projected joints](dynamics::joint_projection) from stretching by projecting links onto their joints.                                                |
+/// | [`ImpulseJointSolverPlugin`]      | Solves joints using an [impulse-based solver](dynamics::solver::impulse_joints) if configured with [`SolverConfig::joint_solver`].                        |
 /// | [`XpbdSolverPlugin`]              | Solves joints using Extended Position-Based Dynamics (XPBD). Requires the `xpbd_joints` feature.                                                           |
 ///
 /// Refer to the documentation of the plugins for more information about their responsibilities and implementations.
@@ -70,7 +73,15 @@ impl PluginGroup for SolverPlugins {
             .add(JointGraphPlugin::<FixedJoint>::default())
             .add(JointGraphPlugin::<RevoluteJoint>::default())
             .add(JointGraphPlugin::<PrismaticJoint>::default())
-            .add(JointGraphPlugin::<DistanceJoint>::default());
+            .add(JointGraphPlugin::<DistanceJoint>::default())
+            .add(JointGraphPlugin::<WheelJoint>::default())
+            .add(JointGraphPlugin::<GenericJoint>::default())
+            .add(JointGraphPlugin::<GearJoint>::default())
+            .add(JointGraphPlugin::<RackAndPinionJoint>::default())
+            .add(JointGraphPlugin::<PulleyJoint>::default())
+            .add(JointGraphPlugin::<ProjectedJoint>::default())
+            .add(JointProjectionPlugin)
+            .add(ImpulseJointSolverPlugin);
 
         #[cfg(feature = "3d")]
         let builder = builder.add(JointGraphPlugin::<SphericalJoint>::default());
@@ -42,8 +42,9 @@ use core::cmp::Ordering;
 /// [Speculative collision](dynamics::ccd#speculative-collision) is used by default to prevent tunneling.
 /// Optional [sweep-based Continuous Collision Detection (CCD)](dynamics::ccd#swept-ccd) is handled by the [`CcdPlugin`].
 ///
-/// [Joints](dynamics::joints) and user constraints are currently solved using [Extended Position-Based Dynamics (XPBD)](super::xpbd)
-/// if the `xpbd_joints` feature is enabled. In the future, they may transition to an impulse-based approach as well.
+/// [Joints](dynamics::joints) and user constraints are solved using [Extended Position-Based Dynamics (XPBD)](super::xpbd)
+/// if the `xpbd_joints` feature is enabled. Common joint types can instead be solved with the same
+/// [impulse-based approach](super::impulse_joints) as contacts by configuring [`SolverConfig::joint_solver`].
 ///
 /// ## Solver Bodies
 ///
@@ -173,6 +174,11 @@ impl Plugin for SolverPlugin {
                 joint_damping::<SphericalJoint>,
                 joint_damping::<PrismaticJoint>,
                 joint_damping::<DistanceJoint>,
+                joint_damping::<WheelJoint>,
+                joint_damping::<GenericJoint>,
+                joint_damping::<GearJoint>,
+                joint_damping::<RackAndPinionJoint>,
+                joint_damping::<PulleyJoint>,
             )
                 .chain()
                 .in_set(SubstepSolverSystems::Damping),
@@ -585,6 +591,20 @@ pub struct SolverConfig {
     ///
     /// Default: `1`
     pub restitution_iterations: usize,
+
+    /// The solver used for [joints](dynamics::joints).
+    ///
+    /// Default: [`JointSolver::Xpbd`] if the `xpbd_joints` feature is enabled,
+    /// and [`JointSolver::Impulse`] otherwise.
+    pub joint_solver: JointSolver,
+
+    /// The damping ratio used for stabilizing joints solved with [`JointSolver::Impulse`].
+    ///
+    /// Lower values make joints more compliant or "springy" when they are pulled apart,
+    /// while higher values make them return to their rest state without oscillation.
+    ///
+    /// Default: `2.0`
+    pub joint_damping_ratio: f32,
 }
 
 impl Default for SolverConfig {
@@ -596,10 +616,38 @@ impl Default for SolverConfig {
             warm_start_coefficient: 1.0,
             restitution_threshold: 1.0,
             restitution_iterations: 1,
+            joint_solver: JointSolver::default(),
+            joint_damping_ratio: 2.0,
         }
     }
 }
 
+/// The solver used for [joints](dynamics::joints), configured with [`SolverConfig::joint_solver`].
+///
+/// The impulse-based solver currently supports [`FixedJoint`], [`RevoluteJoint`], [`PrismaticJoint`],
+#[cfg_attr(feature = "3d", doc = "[`SphericalJoint`], ")]
+/// and [`DistanceJoint`]. Other joints require the `xpbd_joints` feature, and if any of them exist,
+/// [`JointSolver::Impulse`] falls back to [`JointSolver::Xpbd`] with a warning.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default, PartialEq)]
+pub enum JointSolver {
+    /// Joints are solved with the same impulse-based solver as contacts,
+    /// using [soft constraints](super::softness_parameters), warm starting, and relaxation.
+    ///
+    /// This tends to behave better than XPBD in large stacks and mechanisms
+    /// that also rely on contacts, since joints and contacts are solved together.
+    ///
+    /// See the [`impulse_joints`](super::impulse_joints) module for more information.
+    #[cfg_attr(not(feature = "xpbd_joints"), default)]
+    Impulse,
+    /// Joints are solved using [Extended Position-Based Dynamics (XPBD)](super::xpbd).
+    #[cfg(feature = "xpbd_joints")]
+    #[default]
+    Xpbd,
+}
+
 /// The [`SoftnessCoefficients`] used for contacts.
 ///
 /// **Note**: This resource is updated automatically and not intended to be modified manually.
@@ -622,7 +670,7 @@ impl Default for ContactSoftnessCoefficients {
     }
 }
 
-fn update_contact_softness(
+pub(super) fn update_contact_softness(
     mut coefficients: ResMut<ContactSoftnessCoefficients>,
     solver_config: Res<SolverConfig>,
     physics_time: Res<Time<Physics>>,
@@ -763,7 +811,7 @@ fn prepare_contact_constraints(
 /// Warm starts the solver by applying the impulses from the previous frame or substep.
 ///
 /// See [`SubstepSolverSystems::WarmStart`] for more information.
-fn warm_start(
+pub(super) fn warm_start(
     mut solver_bodies: ResMut<SolverBodies>,
     mut constraint_graph: ResMut<ConstraintGraph>,
     solver_config: Res<SolverConfig>,
@@ -849,7 +897,7 @@ fn warm_start_internal(
 /// See [`SubstepSolverSystems::SolveConstraints`] and [`SubstepSolverSystems::Relax`] for more information.
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
-fn solve_contacts<const USE_BIAS: bool>(
+pub(super) fn solve_contacts<const USE_BIAS: bool>(
     mut solver_bodies: ResMut<SolverBodies>,
     mut constraint_graph: ResMut<ConstraintGraph>,
     solver_config: Res<SolverConfig>,
@@ -13,14 +13,19 @@ mod plugin;
 
 pub use plugin::SolverBodyPlugin;
 
-use core::marker::PhantomData;
+use core::{cmp::Ordering, marker::PhantomData, ops::Deref};
 
 use bevy::prelude::*;
 
 use super::Rot;
 #[cfg(feature = "3d")]
 use crate::prelude::ComputedAngularInertia;
-use crate::{SymmetricTensor, math::Vector, prelude::LockedAxes};
+use crate::{
+    SymmetricTensor,
+    dynamics::joints::EntityConstraint,
+    math::Vector,
+    prelude::{LockedAxes, RigidBodyDisabled},
+};
 
 // The `SolverBody` layout is inspired by `b2BodyState` in Box2D v3.
 
@@ -827,3 +832,68 @@ impl SolverBodiesAccess<'_> {
         }
     }
 }
+
+/// Calls the given function for each joint with the solver bodies and inertias of the two jointed bodies.
+///
+/// Bodies without a solver body, such as static bodies, are replaced by [`SolverBody::DUMMY`]
+/// and [`SolverBodyInertia::DUMMY`]. If one of the bodies has a higher [dominance](crate::prelude::Dominance)
+/// than the other, its inertia is replaced by [`SolverBodyInertia::DUMMY`] so that it is not affected by the joint,
+/// like a static body. Joints where both entities map to the same solver body are skipped.
+pub(crate) fn for_each_joint_body_pair<J, D>(
+    solver_bodies: &mut SolverBodies,
+    index_query: &Query<&SolverBodyIndex, Without<RigidBodyDisabled>>,
+    joints: impl IntoIterator<Item = (J, D)>,
+    mut f: impl FnMut(J, D, [&mut SolverBody; 2], [&SolverBodyInertia; 2]),
+) where
+    J: Deref,
+    J::Target: EntityConstraint<2>,
+{
+    let access = solver_bodies.access();
+
+    let mut dummy_body1 = SolverBody::DUMMY;
+    let mut dummy_body2 = SolverBody::DUMMY;
+
+    for (joint, solver_data) in joints {
+        let [entity1, entity2] = joint.entities();
+
+        // Map the two jointed entities to their solver body indices, using an invalid index
+        // for static bodies without an associated solver body.
+        let index1 = index_query
+            .get(entity1)
+            .copied()
+            .unwrap_or(SolverBodyIndex::INVALID);
+        let index2 = index_query
+            .get(entity2)
+            .copied()
+            .unwrap_or(SolverBodyIndex::INVALID);
+
+        if index1 == index2 {
+            continue;
+        }
+
+        let (mut body1, mut inertia1) = (&mut dummy_body1, &SolverBodyInertia::DUMMY);
+        let (mut body2, mut inertia2) = (&mut dummy_body2, &SolverBodyInertia::DUMMY);
+
+        // Get the solver bodies for the two jointed bodies.
+        //
+        // SAFETY: The two jointed bodies are distinct, and joints are processed serially here.
+        let (b1, b2) = unsafe { access.get_pair_unchecked_mut(index1, index2) };
+        if let Some((body, inertia)) = b1 {
+            body1 = body;
+            inertia1 = inertia;
+        }
+        if let Some((body, inertia)) = b2 {
+            body2 = body;
+            inertia2 = inertia;
+        }
+
+        // If a body has a higher dominance, it is treated as a static or kinematic body.
+        match (inertia1.dominance() - inertia2.dominance()).cmp(&0) {
+            Ordering::Greater => inertia1 = &SolverBodyInertia::DUMMY,
+            Ordering::Less => inertia2 = &SolverBodyInertia::DUMMY,
+            _ => {}
+        }
+
+        f(joint, solver_data, [body1, body2], [inertia1, inertia2]);
+    }
+}
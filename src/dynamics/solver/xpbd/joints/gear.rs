@@ -0,0 +1,214 @@
+use super::CoupledCoordinate;
+use crate::{
+    dynamics::solver::{
+        solver_body::{SolverBody, SolverBodyInertia},
+        xpbd::*,
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the XPBD constraint solver for a [`GearJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct GearJointSolverData {
+    pub(super) coordinate1: CoupledCoordinate,
+    pub(super) coordinate2: CoupledCoordinate,
+    /// The value of `angle2 - ratio * angle1` when the joint was first prepared,
+    /// or when the ratio or the frames of the coupled joints were last changed.
+    pub(super) reference: Option<f32>,
+    /// The ratio that the [`reference`](Self::reference) was measured with.
+    pub(super) reference_ratio: f32,
+    pub(super) total_lagrange: f32,
+}
+
+impl XpbdConstraintSolverData for GearJointSolverData {
+    fn clear_lagrange_multipliers(&mut self) {
+        self.total_lagrange = 0.0;
+    }
+
+    fn total_rotation_lagrange(&self) -> AngularVector {
+        // The torque applied to the second gear.
+        #[cfg(feature = "2d")]
+        {
+            self.coordinate2.sign * self.total_lagrange
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.coordinate2.sign * self.total_lagrange * self.coordinate2.axis
+        }
+    }
+}
+
+impl XpbdConstraint<2> for GearJoint {
+    type SolverData = GearJointSolverData;
+
+    fn prepare(
+        &mut self,
+        _bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut GearJointSolverData,
+    ) {
+        // The joint angles are measured by `update_gear_joint_coordinates`.
+        // Measure a new reference if the ratio or the frames of the coupled joints have changed.
+        if solver_data.reference_ratio != self.ratio
+            || solver_data.coordinate1.frame_changed
+            || solver_data.coordinate2.frame_changed
+        {
+            solver_data.reference = None;
+            solver_data.reference_ratio = self.ratio;
+        }
+
+        if !solver_data.coordinate1.is_valid || !solver_data.coordinate2.is_valid {
+            return;
+        }
+
+        // Use the initial configuration as the reference that the gears are kept at.
+        let offset = solver_data.coordinate2.value - self.ratio * solver_data.coordinate1.value;
+        solver_data.reference.get_or_insert(offset);
+    }
+
+    fn solve(
+        &mut self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut GearJointSolverData,
+        dt: f32,
+    ) {
+        let Some(reference) = solver_data.reference else {
+            return;
+        };
+        if !solver_data.coordinate1.is_valid || !solver_data.coordinate2.is_valid {
+            return;
+        }
+
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        let coordinate1 = &solver_data.coordinate1;
+        let coordinate2 = &solver_data.coordinate2;
+
+        // Compute the current joint angles.
+        let angle1 = coordinate1.value + coordinate1.sign * delta_angle(body1, coordinate1.axis);
+        let angle2 = coordinate2.value + coordinate2.sign * delta_angle(body2, coordinate2.axis);
+
+        // C = angle2 - ratio * angle1 - reference
+        let c = angle2 - self.ratio * angle1 - reference;
+
+        // Compute the generalized inverse masses. The gradient for the first gear is scaled by the ratio.
+        #[cfg(feature = "2d")]
+        let (w1, w2) = (
+            self.ratio * self.ratio * inv_angular_inertia1,
+            inv_angular_inertia2,
+        );
+        #[cfg(feature = "3d")]
+        let (w1, w2) = (
+            self.ratio
+                * self.ratio
+                * AngularConstraint::compute_generalized_inverse_mass(
+                    self,
+                    inv_angular_inertia1,
+                    coordinate1.axis,
+                ),
+            AngularConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_angular_inertia2,
+                coordinate2.axis,
+            ),
+        );
+
+        let delta_lagrange = compute_lagrange_update(
+            solver_data.total_lagrange,
+            c,
+            &[w1, w2],
+            self.compliance,
+            dt,
+        );
+
+        if delta_lagrange.abs() <= f32::EPSILON {
+            return;
+        }
+
+        solver_data.total_lagrange += delta_lagrange;
+
+        // Rotate the gears along the constraint gradients.
+        let impulse1 = -self.ratio * coordinate1.sign * delta_lagrange;
+        let impulse2 = coordinate2.sign * delta_lagrange;
+
+        #[cfg(feature = "2d")]
+        {
+            let delta_angle = Self::get_delta_rot(inv_angular_inertia1, impulse1);
+            body1.delta_rotation = body1.delta_rotation.add_angle_fast(delta_angle);
+            let delta_angle = Self::get_delta_rot(inv_angular_inertia2, impulse2);
+            body2.delta_rotation = body2.delta_rotation.add_angle_fast(delta_angle);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let delta_quat = Self::get_delta_rot(inv_angular_inertia1, impulse1 * coordinate1.axis);
+            body1.delta_rotation = delta_quat * body1.delta_rotation;
+            let delta_quat = Self::get_delta_rot(inv_angular_inertia2, impulse2 * coordinate2.axis);
+            body2.delta_rotation = delta_quat * body2.delta_rotation;
+        }
+    }
+}
+
+/// Returns the angle that the body has rotated by about the given axis during the current time step.
+///
+/// In 2D, the axis is ignored.
+pub(super) fn delta_angle(body: &SolverBody, axis: Vector) -> f32 {
+    #[cfg(feature = "2d")]
+    {
+        let _ = axis;
+        body.delta_rotation.as_radians()
+    }
+    #[cfg(feature = "3d")]
+    {
+        // Extract the twist of the delta rotation about the axis.
+        let delta_rotation = body.delta_rotation;
+        2.0 * delta_rotation.xyz().dot(axis).atan2(delta_rotation.w)
+    }
+}
+
+/// Measures the angles of the [`RevoluteJoint`]s referenced by each [`GearJoint`].
+pub(crate) fn update_gear_joint_coordinates(
+    mut gear_joints: Query<
+        (&GearJoint, &mut GearJointSolverData),
+        (Without<RigidBody>, Without<JointDisabled>),
+    >,
+    revolute_joints: Query<&RevoluteJoint>,
+    bodies: Query<(&Position, &Rotation), Without<RigidBodyDisabled>>,
+) {
+    for (gear_joint, mut solver_data) in &mut gear_joints {
+        let solver_data = &mut *solver_data;
+
+        for (coordinate, joint_entity, body) in [
+            (
+                &mut solver_data.coordinate1,
+                gear_joint.joint1,
+                gear_joint.body1,
+            ),
+            (
+                &mut solver_data.coordinate2,
+                gear_joint.joint2,
+                gear_joint.body2,
+            ),
+        ] {
+            coordinate.is_valid = false;
+
+            let Ok(joint) = revolute_joints.get(joint_entity) else {
+                continue;
+            };
+            let Ok(joint_bodies) = bodies.get_many([joint.body1, joint.body2]) else {
+                continue;
+            };
+
+            coordinate.measure_revolute(joint, body, joint_bodies);
+        }
+    }
+}
+
+impl AngularConstraint for GearJoint {}
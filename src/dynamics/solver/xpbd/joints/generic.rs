@@ -0,0 +1,652 @@
+use super::compute_motor_lagrange;
+use crate::{
+    dynamics::solver::{
+        solver_body::{SolverBody, SolverBodyInertia},
+        xpbd::*,
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+use core::f32::consts::{PI, TAU};
+
+/// Constraint data required by the XPBD constraint solver for a [`GenericJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct GenericJointSolverData {
+    pub(super) world_r1: Vector,
+    pub(super) world_r2: Vector,
+    pub(super) center_difference: Vector,
+    pub(super) basis1: Rot,
+    #[cfg(feature = "2d")]
+    pub(super) rotation_difference: f32,
+    #[cfg(feature = "3d")]
+    pub(super) basis2: Rot,
+    pub(super) total_position_lagrange: Vector,
+    pub(super) total_rotation_lagrange: AngularVector,
+    /// Accumulated linear motor Lagrange multipliers for this frame.
+    pub(super) total_linear_motor_lagrange: Vector,
+    /// Accumulated angular motor Lagrange multipliers for this frame.
+    pub(super) total_angular_motor_lagrange: AngularVector,
+    /// Linear motor Lagrange multipliers from the previous frame, used for warm starting.
+    /// This is zeroed after being applied in the first substep.
+    pub(super) warm_start_linear_motor_lagrange: Vector,
+    /// Angular motor Lagrange multipliers from the previous frame, used for warm starting.
+    /// This is zeroed after being applied in the first substep.
+    pub(super) warm_start_angular_motor_lagrange: AngularVector,
+}
+
+impl XpbdConstraintSolverData for GenericJointSolverData {
+    fn clear_lagrange_multipliers(&mut self) {
+        self.total_position_lagrange = Vector::ZERO;
+        self.total_rotation_lagrange = AngularVector::default();
+        // Save motor lagrange for warm starting before clearing.
+        self.warm_start_linear_motor_lagrange = self.total_linear_motor_lagrange;
+        self.warm_start_angular_motor_lagrange = self.total_angular_motor_lagrange;
+        self.total_linear_motor_lagrange = Vector::ZERO;
+        self.total_angular_motor_lagrange = AngularVector::default();
+    }
+
+    fn total_position_lagrange(&self) -> Vector {
+        self.total_position_lagrange
+    }
+
+    fn total_rotation_lagrange(&self) -> AngularVector {
+        self.total_rotation_lagrange
+    }
+
+    fn total_motor_lagrange(&self) -> f32 {
+        // Report the linear motor force if there is one, and the angular motor torque otherwise.
+        if self.total_linear_motor_lagrange != Vector::ZERO {
+            return self.total_linear_motor_lagrange.length();
+        }
+
+        #[cfg(feature = "2d")]
+        {
+            self.total_angular_motor_lagrange.abs()
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.total_angular_motor_lagrange.length()
+        }
+    }
+}
+
+impl XpbdConstraint<2> for GenericJoint {
+    type SolverData = GenericJointSolverData;
+
+    fn prepare(
+        &mut self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut GenericJointSolverData,
+    ) {
+        let [body1, body2] = bodies;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+        let Some(local_basis1) = self.local_basis1() else {
+            return;
+        };
+        let Some(local_basis2) = self.local_basis2() else {
+            return;
+        };
+
+        solver_data.world_r1 = body1.rotation * (local_anchor1 - body1.center_of_mass.0);
+        solver_data.world_r2 = body2.rotation * (local_anchor2 - body2.center_of_mass.0);
+        solver_data.center_difference = (body2.position.0 - body1.position.0).f32()
+            + (body2.rotation * body2.center_of_mass.0 - body1.rotation * body1.center_of_mass.0);
+
+        // Prepare the world-space joint frame bases.
+        solver_data.basis1 = Rot::from(*body1.rotation) * local_basis1;
+        #[cfg(feature = "2d")]
+        {
+            solver_data.rotation_difference = solver_data
+                .basis1
+                .angle_to(Rot::from(*body2.rotation) * local_basis2);
+        }
+        #[cfg(feature = "3d")]
+        {
+            solver_data.basis2 = Rot::from(*body2.rotation) * local_basis2;
+        }
+    }
+
+    fn solve(
+        &mut self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        // Solve motors before limits to give limits higher priority.
+        self.apply_angular_motors(body1, body2, inertia1, inertia2, solver_data, dt);
+        self.apply_angular_limits(body1, body2, inertia1, inertia2, solver_data, dt);
+        #[cfg(feature = "3d")]
+        {
+            self.apply_swing_limit(body1, body2, inertia1, inertia2, solver_data, dt);
+            self.apply_twist_limit(body1, body2, inertia1, inertia2, solver_data, dt);
+        }
+
+        self.apply_linear_motors(body1, body2, inertia1, inertia2, solver_data, dt);
+        self.apply_linear_limits(body1, body2, inertia1, inertia2, solver_data, dt);
+    }
+
+    fn warm_start_motors(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut GenericJointSolverData,
+        _dt: f32,
+        warm_start_coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let inv_mass1 = inertia1.effective_inv_mass();
+        let inv_mass2 = inertia2.effective_inv_mass();
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        let world_r1 = body1.delta_rotation * solver_data.world_r1;
+        let world_r2 = body2.delta_rotation * solver_data.world_r2;
+
+        let impulse = warm_start_coefficient * solver_data.warm_start_linear_motor_lagrange;
+
+        body1.linear_velocity -= impulse * inv_mass1;
+        body2.linear_velocity += impulse * inv_mass2;
+        body1.angular_velocity -= inv_angular_inertia1 * cross(world_r1, impulse);
+        body2.angular_velocity += inv_angular_inertia2 * cross(world_r2, impulse);
+
+        let angular_impulse =
+            warm_start_coefficient * solver_data.warm_start_angular_motor_lagrange;
+
+        body1.angular_velocity -= inv_angular_inertia1 * angular_impulse;
+        body2.angular_velocity += inv_angular_inertia2 * angular_impulse;
+
+        solver_data.warm_start_linear_motor_lagrange = Vector::ZERO;
+        solver_data.warm_start_angular_motor_lagrange = AngularVector::default();
+    }
+}
+
+impl GenericJoint {
+    /// Returns the current intrinsic `XYZ` Euler angles of the relative rotation of the joint frames,
+    /// along with the world-space axes about which each angle is measured.
+    #[cfg(feature = "3d")]
+    fn euler_angles_and_axes(
+        body1: &SolverBody,
+        body2: &SolverBody,
+        solver_data: &GenericJointSolverData,
+    ) -> ([f32; 3], [Vector; 3]) {
+        let basis1 = body1.delta_rotation * solver_data.basis1;
+        let basis2 = body2.delta_rotation * solver_data.basis2;
+
+        let (x, y, z) = (basis1.inverse() * basis2).to_euler(EulerRot::XYZ);
+
+        // The x-angle is measured about the x-axis of the first frame, the z-angle about
+        // the z-axis of the second frame, and the y-angle about the y-axis in between.
+        let axes = [
+            basis1 * Vector::X,
+            basis1 * Quat::from_rotation_x(x) * Vector::Y,
+            basis2 * Vector::Z,
+        ];
+
+        ([x, y, z], axes)
+    }
+
+    /// Applies angle limits to limit the relative rotation of the bodies.
+    fn apply_angular_limits(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        #[cfg(feature = "2d")]
+        let correction = {
+            let Some(angular_limit) = self.angular_limit else {
+                return;
+            };
+            let rotation_difference = solver_data.rotation_difference
+                + body1.delta_rotation.angle_to(body2.delta_rotation);
+            let Some(correction) = angular_limit.compute_correction(rotation_difference, PI) else {
+                return;
+            };
+            correction
+        };
+        #[cfg(feature = "3d")]
+        let correction = {
+            if self.angular_limits.iter().all(Option::is_none) {
+                return;
+            }
+
+            let (angles, axes) = Self::euler_angles_and_axes(body1, body2, solver_data);
+
+            let mut correction = Vector::ZERO;
+            for ((limit, angle), axis) in self.angular_limits.iter().zip(angles).zip(axes) {
+                if let Some(limit) = limit {
+                    correction += (angle - angle.clamp(limit.min, limit.max)) * axis;
+                }
+            }
+            correction
+        };
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        solver_data.total_rotation_lagrange += self.align_orientation(
+            body1,
+            body2,
+            inv_angular_inertia1,
+            inv_angular_inertia2,
+            correction,
+            0.0,
+            self.angular_compliance,
+            dt,
+        );
+    }
+
+    /// Applies an angle limit to limit the rotation of the x-axis of the second joint frame
+    /// to a cone around the x-axis of the first joint frame.
+    #[cfg(feature = "3d")]
+    fn apply_swing_limit(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        let Some(swing_limit) = self.swing_limit else {
+            return;
+        };
+
+        let a1 = body1.delta_rotation * solver_data.basis1 * Vector::X;
+        let a2 = body2.delta_rotation * solver_data.basis2 * Vector::X;
+
+        // The swing is measured about the axis perpendicular to both twist axes.
+        let n = a1.cross(a2);
+        let n_magnitude = n.length();
+
+        if n_magnitude <= f32::EPSILON {
+            return;
+        }
+
+        let n = n / n_magnitude;
+
+        let Some(correction) = swing_limit.compute_correction(n, a1, a2, PI) else {
+            return;
+        };
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        solver_data.total_rotation_lagrange += self.align_orientation(
+            body1,
+            body2,
+            inv_angular_inertia1,
+            inv_angular_inertia2,
+            correction,
+            0.0,
+            self.angular_compliance,
+            dt,
+        );
+    }
+
+    /// Applies an angle limit to limit the relative rotation about the x-axes of the joint frames.
+    #[cfg(feature = "3d")]
+    fn apply_twist_limit(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        let Some(twist_limit) = self.twist_limit else {
+            return;
+        };
+
+        let basis1 = body1.delta_rotation * solver_data.basis1;
+        let basis2 = body2.delta_rotation * solver_data.basis2;
+        let a1 = basis1 * Vector::X;
+        let a2 = basis2 * Vector::X;
+
+        // The twist is measured about the axis halfway between the twist axes.
+        let n = a1 + a2;
+        let n_magnitude = n.length();
+
+        if n_magnitude <= f32::EPSILON {
+            return;
+        }
+
+        let n = n / n_magnitude;
+
+        // Project the y-axes of the joint frames onto the plane perpendicular to the twist axis.
+        let b1 = basis1 * Vector::Y;
+        let b2 = basis2 * Vector::Y;
+        let n1 = b1 - n.dot(b1) * n;
+        let n2 = b2 - n.dot(b2) * n;
+        let n1_magnitude = n1.length();
+        let n2_magnitude = n2.length();
+
+        if n1_magnitude <= f32::EPSILON || n2_magnitude <= f32::EPSILON {
+            return;
+        }
+
+        let n1 = n1 / n1_magnitude;
+        let n2 = n2 / n2_magnitude;
+
+        // The twist is poorly defined when the bodies are swung far apart,
+        // so the correction is limited to avoid instability.
+        let max_correction = if a1.dot(a2) > -0.5 { 2.0 * PI } else { dt };
+
+        let Some(correction) = twist_limit.compute_correction(n, n1, n2, max_correction) else {
+            return;
+        };
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        solver_data.total_rotation_lagrange += self.align_orientation(
+            body1,
+            body2,
+            inv_angular_inertia1,
+            inv_angular_inertia2,
+            correction,
+            0.0,
+            self.angular_compliance,
+            dt,
+        );
+    }
+
+    /// Applies motor torques to drive the rotation towards the target velocities and/or positions.
+    fn apply_angular_motors(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        #[cfg(feature = "2d")]
+        {
+            let motor = &self.angular_motor;
+
+            if !motor.enabled {
+                return;
+            }
+
+            let current_angle = solver_data.rotation_difference
+                + body1.delta_rotation.angle_to(body2.delta_rotation);
+            let relative_angular_velocity = body2.angular_velocity - body1.angular_velocity;
+
+            let w_sum = inv_angular_inertia1 + inv_angular_inertia2;
+
+            if w_sum <= f32::EPSILON {
+                return;
+            }
+
+            let velocity_error = motor.target_velocity - relative_angular_velocity;
+
+            // Wrap position error to [-PI, PI] for shortest path rotation.
+            let raw_error = motor.target_position - current_angle;
+            let position_error = (raw_error + PI).rem_euclid(TAU) - PI;
+
+            let Some(delta_lagrange) = compute_motor_lagrange(
+                velocity_error,
+                position_error,
+                w_sum,
+                motor.motor_model,
+                motor.max_torque,
+                dt,
+            ) else {
+                return;
+            };
+
+            solver_data.total_angular_motor_lagrange += delta_lagrange;
+            solver_data.total_rotation_lagrange += delta_lagrange;
+
+            // Positive delta_lagrange increases body2's angular velocity relative to body1.
+            self.apply_angular_lagrange_update(
+                body1,
+                body2,
+                inv_angular_inertia1,
+                inv_angular_inertia2,
+                delta_lagrange,
+            );
+        }
+        #[cfg(feature = "3d")]
+        {
+            if !self.angular_motors.iter().any(|motor| motor.enabled) {
+                return;
+            }
+
+            let (angles, axes) = Self::euler_angles_and_axes(body1, body2, solver_data);
+
+            for ((motor, current_angle), axis) in self.angular_motors.iter().zip(angles).zip(axes) {
+                if !motor.enabled {
+                    continue;
+                }
+
+                let relative_angular_velocity =
+                    (body2.angular_velocity - body1.angular_velocity).dot(axis);
+
+                let w_sum = AngularConstraint::compute_generalized_inverse_mass(
+                    self,
+                    inv_angular_inertia1,
+                    axis,
+                ) + AngularConstraint::compute_generalized_inverse_mass(
+                    self,
+                    inv_angular_inertia2,
+                    axis,
+                );
+
+                if w_sum <= f32::EPSILON {
+                    continue;
+                }
+
+                let velocity_error = motor.target_velocity - relative_angular_velocity;
+
+                // Wrap position error to [-PI, PI] for shortest path rotation.
+                let raw_error = motor.target_position - current_angle;
+                let position_error = (raw_error + PI).rem_euclid(TAU) - PI;
+
+                let Some(delta_lagrange) = compute_motor_lagrange(
+                    velocity_error,
+                    position_error,
+                    w_sum,
+                    motor.motor_model,
+                    motor.max_torque,
+                    dt,
+                ) else {
+                    continue;
+                };
+
+                solver_data.total_angular_motor_lagrange += delta_lagrange * axis;
+                solver_data.total_rotation_lagrange += delta_lagrange * axis;
+
+                // Positive delta_lagrange increases body2's angular velocity relative to body1.
+                self.apply_angular_lagrange_update(
+                    body1,
+                    body2,
+                    inv_angular_inertia1,
+                    inv_angular_inertia2,
+                    delta_lagrange,
+                    axis,
+                );
+            }
+        }
+    }
+
+    /// Applies distance limits to limit the relative translation of the bodies along each axis.
+    fn apply_linear_limits(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        if self.linear_limits.iter().all(Option::is_none) {
+            return;
+        }
+
+        // Compute the effective inverse masses and angular inertias of the bodies.
+        let inv_mass1 = inertia1.effective_inv_mass();
+        let inv_mass2 = inertia2.effective_inv_mass();
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        let world_r1 = body1.delta_rotation * solver_data.world_r1;
+        let world_r2 = body2.delta_rotation * solver_data.world_r2;
+
+        let basis1 = body1.delta_rotation * solver_data.basis1;
+        let separation = (body2.delta_position - body1.delta_position)
+            + (world_r2 - world_r1)
+            + solver_data.center_difference;
+
+        let mut delta_x = Vector::ZERO;
+
+        for (i, limit) in self.linear_limits.iter().enumerate() {
+            if let Some(limit) = limit {
+                let axis = basis1 * Vector::AXES[i];
+                delta_x += limit.compute_correction_along_axis(separation, axis);
+            }
+        }
+
+        let magnitude = delta_x.length();
+
+        if magnitude <= f32::EPSILON {
+            return;
+        }
+
+        let dir = delta_x / magnitude;
+
+        // Compute generalized inverse masses
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(
+            self,
+            inv_mass1.max_element(),
+            inv_angular_inertia1,
+            world_r1,
+            dir,
+        );
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(
+            self,
+            inv_mass2.max_element(),
+            inv_angular_inertia2,
+            world_r2,
+            dir,
+        );
+
+        // Compute Lagrange multiplier update
+        let delta_lagrange =
+            compute_lagrange_update(0.0, magnitude, &[w1, w2], self.linear_compliance, dt);
+        let impulse = delta_lagrange * dir;
+        solver_data.total_position_lagrange += impulse;
+
+        // Apply positional correction to align the positions of the bodies
+        self.apply_positional_impulse(
+            body1, body2, inertia1, inertia2, impulse, world_r1, world_r2,
+        );
+    }
+
+    /// Applies motor forces to drive the translation towards the target velocities and/or positions.
+    fn apply_linear_motors(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut GenericJointSolverData,
+        dt: f32,
+    ) {
+        if !self.linear_motors.iter().any(|motor| motor.enabled) {
+            return;
+        }
+
+        let inv_mass1 = inertia1.effective_inv_mass();
+        let inv_mass2 = inertia2.effective_inv_mass();
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        for (i, motor) in self.linear_motors.iter().enumerate() {
+            if !motor.enabled {
+                continue;
+            }
+
+            let axis = body1.delta_rotation * solver_data.basis1 * Vector::AXES[i];
+            let world_r1 = body1.delta_rotation * solver_data.world_r1;
+            let world_r2 = body2.delta_rotation * solver_data.world_r2;
+
+            let separation = (body2.delta_position - body1.delta_position)
+                + (world_r2 - world_r1)
+                + solver_data.center_difference;
+            let current_position = separation.dot(axis);
+            let current_velocity = (body2.linear_velocity - body1.linear_velocity).dot(axis);
+
+            let w1 = PositionConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_mass1.max_element(),
+                inv_angular_inertia1,
+                world_r1,
+                axis,
+            );
+            let w2 = PositionConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_mass2.max_element(),
+                inv_angular_inertia2,
+                world_r2,
+                axis,
+            );
+
+            let w_sum = w1 + w2;
+            if w_sum <= f32::EPSILON {
+                continue;
+            }
+
+            let velocity_error = motor.target_velocity - current_velocity;
+            let position_error = motor.target_position - current_position;
+
+            let Some(delta_lagrange) = compute_motor_lagrange(
+                velocity_error,
+                position_error,
+                w_sum,
+                motor.motor_model,
+                motor.max_force,
+                dt,
+            ) else {
+                continue;
+            };
+
+            let impulse = delta_lagrange * axis;
+            solver_data.total_linear_motor_lagrange += impulse;
+            solver_data.total_position_lagrange += impulse;
+
+            // Negate impulse: apply_positional_impulse convention is opposite to motor direction.
+            self.apply_positional_impulse(
+                body1, body2, inertia1, inertia2, -impulse, world_r1, world_r2,
+            );
+        }
+    }
+}
+
+impl PositionConstraint for GenericJoint {}
+
+impl AngularConstraint for GenericJoint {}
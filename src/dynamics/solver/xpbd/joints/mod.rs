@@ -1,18 +1,37 @@
 //! XPBD joint constraints.
 
 mod shared;
+use shared::{CoupledCoordinate, compute_motor_lagrange};
 pub use shared::{FixedAngleConstraintShared, PointConstraintShared};
 
 mod distance;
 mod fixed;
+mod gear;
+mod generic;
 mod prismatic;
+mod pulley;
+mod rack_and_pinion;
 mod revolute;
 #[cfg(feature = "3d")]
 mod spherical;
+mod target;
+mod wheel;
 
 pub use distance::DistanceJointSolverData;
 pub use fixed::FixedJointSolverData;
+pub use gear::GearJointSolverData;
+pub(crate) use gear::update_gear_joint_coordinates;
+pub use generic::GenericJointSolverData;
 pub use prismatic::PrismaticJointSolverData;
+pub use pulley::PulleyJointSolverData;
+pub use rack_and_pinion::RackAndPinionJointSolverData;
+pub(crate) use rack_and_pinion::update_rack_and_pinion_joint_coordinates;
 pub use revolute::RevoluteJointSolverData;
 #[cfg(feature = "3d")]
 pub use spherical::SphericalJointSolverData;
+pub use target::TargetJointSolverData;
+pub(crate) use target::{
+    on_add_target_joint, on_remove_target_joint, prepare_xpbd_target_joints,
+    solve_xpbd_target_joints, writeback_target_joint_forces,
+};
+pub use wheel::WheelJointSolverData;
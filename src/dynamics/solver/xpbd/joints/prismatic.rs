@@ -1,18 +1,13 @@
-use super::FixedAngleConstraintShared;
+use super::{FixedAngleConstraintShared, compute_motor_lagrange};
 use crate::{
-    dynamics::{
-        joints::MotorModel,
-        solver::{
-            solver_body::{SolverBody, SolverBodyInertia},
-            xpbd::*,
-        },
+    dynamics::solver::{
+        solver_body::{SolverBody, SolverBodyInertia},
+        xpbd::*,
     },
     prelude::*,
 };
 use bevy::prelude::*;
 
-use core::f32::consts::TAU;
-
 /// Constraint data required by the XPBD constraint solver for a [`PrismaticJoint`].
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -300,40 +295,15 @@ impl PrismaticJoint {
         let velocity_error = motor.target_velocity - current_velocity;
         let position_error = motor.target_position - current_position;
 
-        let target_velocity_change = match motor.motor_model {
-            MotorModel::SpringDamper {
-                frequency,
-                damping_ratio,
-            } => {
-                // Implicit Euler formulation for stable spring-damper behavior.
-                let omega = TAU * frequency;
-                let omega_sq = omega * omega;
-                let two_zeta_omega = 2.0 * damping_ratio * omega;
-                let inv_denominator = 1.0 / (1.0 + two_zeta_omega * dt + omega_sq * dt * dt);
-                (omega_sq * position_error + two_zeta_omega * velocity_error) * dt * inv_denominator
-            }
-            MotorModel::AccelerationBased { stiffness, damping } => {
-                damping * velocity_error + stiffness * position_error * dt
-            }
-            MotorModel::ForceBased { stiffness, damping } => {
-                // Velocity change = (stiffness * pos_error + damping * vel_error) * inv_mass
-                (stiffness * position_error + damping * velocity_error) * w_sum
-            }
-        };
-
-        let correction = target_velocity_change * dt;
-        if correction.abs() <= f32::EPSILON {
+        let Some(delta_lagrange) = compute_motor_lagrange(
+            velocity_error,
+            position_error,
+            w_sum,
+            motor.motor_model,
+            motor.max_force,
+            dt,
+        ) else {
             return;
-        }
-
-        let delta_lagrange = correction / w_sum;
-
-        // Clamp to limit instantaneous force per substep.
-        let delta_lagrange = if motor.max_force < f32::MAX && motor.max_force > 0.0 {
-            let max_delta = motor.max_force * dt * dt;
-            delta_lagrange.clamp(-max_delta, max_delta)
-        } else {
-            delta_lagrange
         };
 
         solver_data.total_motor_lagrange += delta_lagrange;
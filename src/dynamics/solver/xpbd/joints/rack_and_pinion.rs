@@ -0,0 +1,174 @@
+use super::{CoupledCoordinate, gear::delta_angle};
+use crate::{
+    dynamics::solver::{
+        solver_body::{SolverBody, SolverBodyInertia},
+        xpbd::*,
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the XPBD constraint solver for a [`RackAndPinionJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct RackAndPinionJointSolverData {
+    pub(super) pinion_coordinate: CoupledCoordinate,
+    pub(super) rack_coordinate: CoupledCoordinate,
+    /// The value of `translation - ratio * angle` when the joint was first prepared,
+    /// or when the ratio or the frames of the coupled joints were last changed.
+    pub(super) reference: Option<f32>,
+    /// The ratio that the [`reference`](Self::reference) was measured with.
+    pub(super) reference_ratio: f32,
+    pub(super) total_lagrange: f32,
+}
+
+impl XpbdConstraintSolverData for RackAndPinionJointSolverData {
+    fn clear_lagrange_multipliers(&mut self) {
+        self.total_lagrange = 0.0;
+    }
+
+    fn total_position_lagrange(&self) -> Vector {
+        // The force applied to the rack.
+        self.rack_coordinate.sign * self.total_lagrange * self.rack_coordinate.axis
+    }
+}
+
+impl XpbdConstraint<2> for RackAndPinionJoint {
+    type SolverData = RackAndPinionJointSolverData;
+
+    fn prepare(
+        &mut self,
+        _bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut RackAndPinionJointSolverData,
+    ) {
+        // The joint coordinates are measured by `update_rack_and_pinion_joint_coordinates`.
+        // Measure a new reference if the ratio or the frames of the coupled joints have changed.
+        if solver_data.reference_ratio != self.ratio
+            || solver_data.pinion_coordinate.frame_changed
+            || solver_data.rack_coordinate.frame_changed
+        {
+            solver_data.reference = None;
+            solver_data.reference_ratio = self.ratio;
+        }
+
+        if !solver_data.pinion_coordinate.is_valid || !solver_data.rack_coordinate.is_valid {
+            return;
+        }
+
+        // Use the initial configuration as the reference that the rack and pinion are kept at.
+        let offset =
+            solver_data.rack_coordinate.value - self.ratio * solver_data.pinion_coordinate.value;
+        solver_data.reference.get_or_insert(offset);
+    }
+
+    fn solve(
+        &mut self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut RackAndPinionJointSolverData,
+        dt: f32,
+    ) {
+        let Some(reference) = solver_data.reference else {
+            return;
+        };
+        if !solver_data.pinion_coordinate.is_valid || !solver_data.rack_coordinate.is_valid {
+            return;
+        }
+
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_mass2 = inertia2.effective_inv_mass();
+
+        let pinion = &solver_data.pinion_coordinate;
+        let rack = &solver_data.rack_coordinate;
+
+        // Compute the current pinion angle and rack translation.
+        let angle = pinion.value + pinion.sign * delta_angle(body1, pinion.axis);
+        let translation = rack.value + rack.sign * body2.delta_position.dot(rack.axis);
+
+        // C = translation - ratio * angle - reference
+        let c = translation - self.ratio * angle - reference;
+
+        // Compute the generalized inverse masses. The gradient for the pinion is scaled by the ratio.
+        #[cfg(feature = "2d")]
+        let w1 = self.ratio * self.ratio * inv_angular_inertia1;
+        #[cfg(feature = "3d")]
+        let w1 = self.ratio
+            * self.ratio
+            * AngularConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_angular_inertia1,
+                pinion.axis,
+            );
+        let w2 = inv_mass2.max_element();
+
+        let delta_lagrange = compute_lagrange_update(
+            solver_data.total_lagrange,
+            c,
+            &[w1, w2],
+            self.compliance,
+            dt,
+        );
+
+        if delta_lagrange.abs() <= f32::EPSILON {
+            return;
+        }
+
+        solver_data.total_lagrange += delta_lagrange;
+
+        // Rotate the pinion and translate the rack along the constraint gradients.
+        let impulse1 = -self.ratio * pinion.sign * delta_lagrange;
+        let impulse2 = rack.sign * delta_lagrange * rack.axis;
+
+        #[cfg(feature = "2d")]
+        {
+            let delta_angle = Self::get_delta_rot(inv_angular_inertia1, impulse1);
+            body1.delta_rotation = body1.delta_rotation.add_angle_fast(delta_angle);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let delta_quat = Self::get_delta_rot(inv_angular_inertia1, impulse1 * pinion.axis);
+            body1.delta_rotation = delta_quat * body1.delta_rotation;
+        }
+
+        body2.delta_position += impulse2 * inv_mass2;
+    }
+}
+
+/// Measures the pinion angle and rack translation of the joints referenced by each [`RackAndPinionJoint`].
+pub(crate) fn update_rack_and_pinion_joint_coordinates(
+    mut rack_and_pinion_joints: Query<
+        (&RackAndPinionJoint, &mut RackAndPinionJointSolverData),
+        (Without<RigidBody>, Without<JointDisabled>),
+    >,
+    revolute_joints: Query<&RevoluteJoint>,
+    prismatic_joints: Query<&PrismaticJoint>,
+    bodies: Query<(&Position, &Rotation), Without<RigidBodyDisabled>>,
+) {
+    for (joint, mut solver_data) in &mut rack_and_pinion_joints {
+        solver_data.pinion_coordinate.is_valid = false;
+        solver_data.rack_coordinate.is_valid = false;
+
+        if let Ok(pinion_joint) = revolute_joints.get(joint.pinion_joint)
+            && let Ok(joint_bodies) = bodies.get_many([pinion_joint.body1, pinion_joint.body2])
+        {
+            solver_data
+                .pinion_coordinate
+                .measure_revolute(pinion_joint, joint.body1, joint_bodies);
+        }
+
+        if let Ok(rack_joint) = prismatic_joints.get(joint.rack_joint)
+            && let Ok(joint_bodies) = bodies.get_many([rack_joint.body1, rack_joint.body2])
+        {
+            solver_data
+                .rack_coordinate
+                .measure_prismatic(rack_joint, joint.body2, joint_bodies);
+        }
+    }
+}
+
+impl AngularConstraint for RackAndPinionJoint {}
@@ -1,11 +1,8 @@
-use super::PointConstraintShared;
+use super::{PointConstraintShared, compute_motor_lagrange};
 use crate::{
-    dynamics::{
-        joints::{AngularMotor, MotorModel},
-        solver::{
-            solver_body::{SolverBody, SolverBodyInertia},
-            xpbd::*,
-        },
+    dynamics::solver::{
+        solver_body::{SolverBody, SolverBodyInertia},
+        xpbd::*,
     },
     prelude::*,
 };
@@ -301,9 +298,14 @@ impl RevoluteJoint {
         let raw_error = motor.target_position - current_angle;
         let position_error = (raw_error + PI).rem_euclid(TAU) - PI;
 
-        let Some(delta_lagrange) =
-            Self::compute_angular_motor_lagrange(velocity_error, position_error, w_sum, motor, dt)
-        else {
+        let Some(delta_lagrange) = compute_motor_lagrange(
+            velocity_error,
+            position_error,
+            w_sum,
+            motor.motor_model,
+            motor.max_torque,
+            dt,
+        ) else {
             return;
         };
 
@@ -335,55 +337,6 @@ impl RevoluteJoint {
             a1,
         );
     }
-
-    /// Computes the Lagrange multiplier delta for an angular motor.
-    ///
-    /// Returns `None` if the correction is negligible.
-    fn compute_angular_motor_lagrange(
-        velocity_error: f32,
-        position_error: f32,
-        w_sum: f32,
-        motor: &AngularMotor,
-        dt: f32,
-    ) -> Option<f32> {
-        let target_velocity_change = match motor.motor_model {
-            MotorModel::SpringDamper {
-                frequency,
-                damping_ratio,
-            } => {
-                // Implicit Euler formulation for stable spring-damper behavior.
-                let omega = TAU * frequency;
-                let omega_sq = omega * omega;
-                let two_zeta_omega = 2.0 * damping_ratio * omega;
-                let inv_denominator = 1.0 / (1.0 + two_zeta_omega * dt + omega_sq * dt * dt);
-                (omega_sq * position_error + two_zeta_omega * velocity_error) * dt * inv_denominator
-            }
-            MotorModel::AccelerationBased { stiffness, damping } => {
-                damping * velocity_error + stiffness * position_error * dt
-            }
-            MotorModel::ForceBased { stiffness, damping } => {
-                // Velocity change = (stiffness * pos_error + damping * vel_error) * inv_inertia
-                (stiffness * position_error + damping * velocity_error) * w_sum
-            }
-        };
-
-        let correction = target_velocity_change * dt;
-        if correction.abs() <= f32::EPSILON {
-            return None;
-        }
-
-        let delta_lagrange = correction / w_sum;
-
-        // Clamp to limit instantaneous torque per substep.
-        let delta_lagrange = if motor.max_torque < f32::MAX && motor.max_torque > 0.0 {
-            let max_delta = motor.max_torque * dt * dt;
-            delta_lagrange.clamp(-max_delta, max_delta)
-        } else {
-            delta_lagrange
-        };
-
-        Some(delta_lagrange)
-    }
 }
 
 impl PositionConstraint for RevoluteJoint {}
@@ -0,0 +1,159 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+use core::f32::consts::{PI, TAU};
+
+/// The angle or translation of a [`RevoluteJoint`] or [`PrismaticJoint`] that is coupled
+/// to another joint by a [`GearJoint`] or [`RackAndPinionJoint`].
+///
+/// The coordinate is measured before the substepping loop, and the motion of the coupled body
+/// along or about the [`axis`](Self::axis) is added to it while solving.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub(crate) struct CoupledCoordinate {
+    /// The world-space axis along or about which the coordinate is measured.
+    ///
+    /// This is unused for angles in 2D.
+    pub(crate) axis: Vector,
+    /// `1.0` if the coupled body is the second body of the referenced joint, and `-1.0` if it is the first body.
+    pub(crate) sign: f32,
+    /// The value of the coordinate at the start of the time step.
+    ///
+    /// Angles are unwrapped, so they keep increasing past full revolutions.
+    pub(crate) value: f32,
+    /// The last measured angle in the `[-PI, PI]` range, used for unwrapping.
+    pub(crate) wrapped_angle: Option<f32>,
+    /// Whether the coordinate was measured successfully for this time step.
+    pub(crate) is_valid: bool,
+    /// The local frame of the referenced joint when the coordinate was last measured.
+    pub(crate) frame: Option<CoupledFrame>,
+    /// Whether the [`frame`](Self::frame) of the referenced joint changed during the last measurement,
+    /// for example because its anchors or axis were modified.
+    pub(crate) frame_changed: bool,
+}
+
+/// The local anchors, bases, and axis of a joint referenced by a [`CoupledCoordinate`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, PartialEq)]
+pub(crate) struct CoupledFrame {
+    local_anchors: [Vector; 2],
+    local_bases: [Rot; 2],
+    axis: Vector,
+}
+
+impl CoupledCoordinate {
+    /// Measures the angle of the given [`RevoluteJoint`] for the coupled `body`.
+    ///
+    /// `bodies` contains the positions and rotations of the bodies attached to the joint.
+    pub(crate) fn measure_revolute(
+        &mut self,
+        joint: &RevoluteJoint,
+        body: Entity,
+        bodies: [(&Position, &Rotation); 2],
+    ) {
+        self.is_valid = false;
+
+        let Some(sign) = Self::body_sign(joint.body1, joint.body2, body) else {
+            return;
+        };
+        let (Some(local_basis1), Some(local_basis2)) = (joint.local_basis1(), joint.local_basis2())
+        else {
+            return;
+        };
+
+        self.set_frame(CoupledFrame {
+            local_anchors: [
+                joint.local_anchor1().unwrap_or_default(),
+                joint.local_anchor2().unwrap_or_default(),
+            ],
+            local_bases: [local_basis1, local_basis2],
+            #[cfg(feature = "2d")]
+            axis: Vector::ZERO,
+            #[cfg(feature = "3d")]
+            axis: joint.hinge_axis,
+        });
+
+        let [(_, rot1), (_, rot2)] = bodies;
+
+        #[cfg(feature = "2d")]
+        let angle = (Rot::from(*rot1) * local_basis1).angle_to(Rot::from(*rot2) * local_basis2);
+        #[cfg(feature = "3d")]
+        let angle = {
+            let a1 = Rot::from(*rot1) * local_basis1 * joint.hinge_axis;
+            let ortho = joint.hinge_axis.any_orthonormal_vector();
+            let b1 = Rot::from(*rot1) * local_basis1 * ortho;
+            let b2 = Rot::from(*rot2) * local_basis2 * ortho;
+            self.axis = a1;
+            b1.cross(b2).dot(a1).atan2(b1.dot(b2))
+        };
+
+        // Unwrap the angle so that it is continuous across full revolutions.
+        self.value = match self.wrapped_angle {
+            Some(previous) => self.value + ((angle - previous + PI).rem_euclid(TAU) - PI),
+            None => angle,
+        };
+        self.wrapped_angle = Some(angle);
+        self.sign = sign;
+        self.is_valid = true;
+    }
+
+    /// Measures the translation of the given [`PrismaticJoint`] for the coupled `body`.
+    ///
+    /// `bodies` contains the positions and rotations of the bodies attached to the joint.
+    pub(crate) fn measure_prismatic(
+        &mut self,
+        joint: &PrismaticJoint,
+        body: Entity,
+        bodies: [(&Position, &Rotation); 2],
+    ) {
+        self.is_valid = false;
+
+        let Some(sign) = Self::body_sign(joint.body1, joint.body2, body) else {
+            return;
+        };
+        let (Some(local_anchor1), Some(local_anchor2), Some(local_basis1)) = (
+            joint.local_anchor1(),
+            joint.local_anchor2(),
+            joint.local_basis1(),
+        ) else {
+            return;
+        };
+
+        self.set_frame(CoupledFrame {
+            local_anchors: [local_anchor1, local_anchor2],
+            local_bases: [local_basis1, joint.local_basis2().unwrap_or(Rot::IDENTITY)],
+            axis: joint.slider_axis,
+        });
+
+        let [(pos1, rot1), (pos2, rot2)] = bodies;
+
+        let axis = Rot::from(*rot1) * local_basis1 * joint.slider_axis;
+        let separation = (pos2.0 - pos1.0).f32() + *rot2 * local_anchor2 - *rot1 * local_anchor1;
+
+        self.axis = axis;
+        self.value = separation.dot(axis);
+        self.sign = sign;
+        self.is_valid = true;
+    }
+
+    /// Stores the local frame of the referenced joint, and records whether it changed.
+    fn set_frame(&mut self, frame: CoupledFrame) {
+        self.frame_changed = self.frame != Some(frame);
+        self.frame = Some(frame);
+    }
+
+    /// Returns `1.0` if `body` is `body2`, `-1.0` if it is `body1`, and `None` otherwise.
+    fn body_sign(body1: Entity, body2: Entity, body: Entity) -> Option<f32> {
+        if body == body2 {
+            Some(1.0)
+        } else if body == body1 {
+            Some(-1.0)
+        } else {
+            None
+        }
+    }
+}
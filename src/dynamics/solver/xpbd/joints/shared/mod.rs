@@ -1,5 +1,8 @@
+mod coupled_coordinate;
 mod fixed_angle_constraint;
 mod point_constraint;
 
+pub(crate) use crate::dynamics::joints::compute_motor_lagrange;
+pub(crate) use coupled_coordinate::CoupledCoordinate;
 pub use fixed_angle_constraint::FixedAngleConstraintShared;
 pub use point_constraint::PointConstraintShared;
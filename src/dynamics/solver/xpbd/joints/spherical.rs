@@ -1,4 +1,4 @@
-use super::PointConstraintShared;
+use super::{PointConstraintShared, compute_motor_lagrange};
 use crate::{
     dynamics::solver::{
         solver_body::{SolverBody, SolverBodyInertia},
@@ -21,8 +21,15 @@ pub struct SphericalJointSolverData {
     pub(super) swing_axis2: Vec3,
     pub(super) twist_axis1: Vec3,
     pub(super) twist_axis2: Vec3,
+    pub(super) basis1: Quat,
+    pub(super) basis2: Quat,
     pub(super) total_swing_lagrange: Vec3,
     pub(super) total_twist_lagrange: Vec3,
+    /// Accumulated motor Lagrange multiplier for this frame.
+    pub(super) total_motor_lagrange: Vec3,
+    /// Motor Lagrange multiplier from the previous frame, used for warm starting.
+    /// This is zeroed after being applied in the first substep.
+    pub(super) warm_start_motor_lagrange: Vec3,
 }
 
 impl XpbdConstraintSolverData for SphericalJointSolverData {
@@ -30,6 +37,9 @@ impl XpbdConstraintSolverData for SphericalJointSolverData {
         self.point_constraint.clear_lagrange_multipliers();
         self.total_swing_lagrange = Vec3::ZERO;
         self.total_twist_lagrange = Vec3::ZERO;
+        // Save motor lagrange for warm starting before clearing.
+        self.warm_start_motor_lagrange = self.total_motor_lagrange;
+        self.total_motor_lagrange = Vec3::ZERO;
     }
 
     fn total_position_lagrange(&self) -> Vec3 {
@@ -37,7 +47,11 @@ impl XpbdConstraintSolverData for SphericalJointSolverData {
     }
 
     fn total_rotation_lagrange(&self) -> Vec3 {
-        self.total_swing_lagrange + self.total_twist_lagrange
+        self.total_swing_lagrange + self.total_twist_lagrange + self.total_motor_lagrange
+    }
+
+    fn total_motor_lagrange(&self) -> f32 {
+        self.total_motor_lagrange.length()
     }
 }
 
@@ -81,6 +95,10 @@ impl XpbdConstraint<2> for SphericalJoint {
         solver_data.swing_axis2 = rot2_mat * (local_basis2 * swing_axis);
         solver_data.twist_axis1 = rot1_mat * (local_basis1 * self.twist_axis);
         solver_data.twist_axis2 = rot2_mat * (local_basis2 * self.twist_axis);
+
+        // Prepare the world-space joint frame bases for the motor.
+        solver_data.basis1 = body1.rotation.0 * local_basis1;
+        solver_data.basis2 = body2.rotation.0 * local_basis2;
     }
 
     fn solve(
@@ -101,12 +119,129 @@ impl XpbdConstraint<2> for SphericalJoint {
             dt,
         );
 
+        // Solve the motor before limits to give limits higher priority.
+        self.apply_motor(body1, body2, inertia1, inertia2, solver_data, dt);
+
         // Apply swing limits
         self.apply_swing_limits(body1, body2, inertia1, inertia2, solver_data, dt);
 
         // Apply twist limits
         self.apply_twist_limits(body1, body2, inertia1, inertia2, solver_data, dt);
     }
+
+    fn warm_start_motors(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut SphericalJointSolverData,
+        _dt: f32,
+        warm_start_coefficient: f32,
+    ) {
+        if !self.motor.enabled {
+            return;
+        }
+
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        let impulse = warm_start_coefficient * solver_data.warm_start_motor_lagrange;
+
+        body1.angular_velocity -= inv_angular_inertia1 * impulse;
+        body2.angular_velocity += inv_angular_inertia2 * impulse;
+
+        solver_data.warm_start_motor_lagrange = Vec3::ZERO;
+    }
+}
+
+impl SphericalJoint {
+    /// Applies motor torque to drive the relative orientation of the bodies
+    /// towards the target rotation and/or angular velocity.
+    fn apply_motor(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut SphericalJointSolverData,
+        dt: f32,
+    ) {
+        let motor = &self.motor;
+
+        if !motor.enabled {
+            return;
+        }
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        // The current world-space joint frames.
+        let basis1 = body1.delta_rotation * solver_data.basis1;
+        let basis2 = body2.delta_rotation * solver_data.basis2;
+
+        // The rotation that would take the second joint frame to its target orientation,
+        // following the shortest path.
+        let mut error_rotation = basis1 * motor.target_rotation * basis2.inverse();
+        if error_rotation.w < 0.0 {
+            error_rotation = -error_rotation;
+        }
+        let position_error = error_rotation.to_scaled_axis();
+
+        let target_velocity = basis1 * motor.target_velocity;
+        let velocity_error = target_velocity - (body2.angular_velocity - body1.angular_velocity);
+
+        // Drive each axis of the first joint frame independently,
+        // and clamp the total torque at the end.
+        let mut delta_lagrange = Vec3::ZERO;
+
+        for axis in [basis1 * Vec3::X, basis1 * Vec3::Y, basis1 * Vec3::Z] {
+            let w_sum = AngularConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_angular_inertia1,
+                axis,
+            ) + AngularConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_angular_inertia2,
+                axis,
+            );
+
+            if w_sum <= f32::EPSILON {
+                continue;
+            }
+
+            if let Some(axis_lagrange) = compute_motor_lagrange(
+                velocity_error.dot(axis),
+                position_error.dot(axis),
+                w_sum,
+                motor.motor_model,
+                f32::MAX,
+                dt,
+            ) {
+                delta_lagrange += axis_lagrange * axis;
+            }
+        }
+
+        if motor.max_torque < f32::MAX && motor.max_torque > 0.0 {
+            delta_lagrange = delta_lagrange.clamp_length_max(motor.max_torque * dt * dt);
+        }
+
+        if delta_lagrange == Vec3::ZERO {
+            return;
+        }
+
+        solver_data.total_motor_lagrange += delta_lagrange;
+
+        // Positive delta_lagrange increases body2's angular velocity relative to body1.
+        self.apply_angular_impulse(
+            body1,
+            body2,
+            inv_angular_inertia1,
+            inv_angular_inertia2,
+            -delta_lagrange,
+        );
+    }
 }
 
 impl SphericalJoint {
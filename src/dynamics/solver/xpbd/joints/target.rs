@@ -0,0 +1,231 @@
+use super::compute_motor_lagrange;
+use crate::{
+    dynamics::{
+        joints::{EntityConstraint, joint_graph::JointGraph},
+        solver::{
+            solver_body::{SolverBodies, SolverBody, SolverBodyIndex, SolverBodyInertia},
+            xpbd::*,
+        },
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+/// Constraint data required by the XPBD constraint solver for a [`TargetJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct TargetJointSolverData {
+    pub(super) world_r: Vector,
+    /// The offset from the target to the center of mass of the body.
+    pub(super) target_offset: Vector,
+    pub(super) total_position_lagrange: Vector,
+}
+
+impl XpbdConstraintSolverData for TargetJointSolverData {
+    fn clear_lagrange_multipliers(&mut self) {
+        self.total_position_lagrange = Vector::ZERO;
+    }
+
+    fn total_position_lagrange(&self) -> Vector {
+        self.total_position_lagrange
+    }
+}
+
+impl XpbdConstraint<1> for TargetJoint {
+    type SolverData = TargetJointSolverData;
+
+    fn prepare(
+        &mut self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 1],
+        solver_data: &mut TargetJointSolverData,
+    ) {
+        let [body] = bodies;
+
+        let JointAnchor::Local(local_anchor) = self.anchor else {
+            return;
+        };
+
+        solver_data.world_r = body.rotation * (local_anchor - body.center_of_mass.0);
+        solver_data.target_offset =
+            (body.position.0 - self.target).f32() + body.rotation * body.center_of_mass.0;
+    }
+
+    fn solve(
+        &mut self,
+        bodies: [&mut SolverBody; 1],
+        inertias: [&SolverBodyInertia; 1],
+        solver_data: &mut TargetJointSolverData,
+        dt: f32,
+    ) {
+        let [body] = bodies;
+        let [inertia] = inertias;
+
+        let inv_mass = inertia.effective_inv_mass();
+        let inv_angular_inertia = inertia.effective_inv_angular_inertia();
+
+        let world_r = body.delta_rotation * solver_data.world_r;
+
+        // The offset from the target to the anchor, and the velocity of the anchor.
+        let separation = solver_data.target_offset + body.delta_position + world_r;
+        let velocity = body.velocity_at_point(world_r);
+
+        // Drive each axis towards the target separately, and limit the total force afterwards.
+        let mut impulse = Vector::ZERO;
+
+        for axis in [
+            Vector::X,
+            Vector::Y,
+            #[cfg(feature = "3d")]
+            Vector::Z,
+        ] {
+            let w = PositionConstraint::compute_generalized_inverse_mass(
+                self,
+                inv_mass.dot(axis),
+                inv_angular_inertia,
+                world_r,
+                axis,
+            );
+
+            if w <= f32::EPSILON {
+                continue;
+            }
+
+            if let Some(delta_lagrange) = compute_motor_lagrange(
+                -velocity.dot(axis),
+                -separation.dot(axis),
+                w,
+                self.motor_model,
+                f32::MAX,
+                dt,
+            ) {
+                impulse += delta_lagrange * axis;
+            }
+        }
+
+        // Clamp to limit the instantaneous force per substep.
+        if self.max_force < f32::MAX && self.max_force > 0.0 {
+            impulse = impulse.clamp_length_max(self.max_force * dt * dt);
+        }
+
+        if impulse == Vector::ZERO {
+            return;
+        }
+
+        solver_data.total_position_lagrange += impulse;
+
+        body.delta_position += impulse * inv_mass;
+
+        #[cfg(feature = "2d")]
+        {
+            let delta_angle = Self::get_delta_rot(inv_angular_inertia, world_r, impulse);
+            body.delta_rotation = body.delta_rotation.add_angle_fast(delta_angle);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let delta_quat = Self::get_delta_rot(inv_angular_inertia, world_r, impulse);
+            body.delta_rotation = delta_quat * body.delta_rotation;
+        }
+    }
+}
+
+impl PositionConstraint for TargetJoint {}
+
+/// Prepares the [`TargetJoint`]s for solving.
+///
+/// Target joints only constrain a single body, so they are prepared separately from the other joints.
+pub(crate) fn prepare_xpbd_target_joints(
+    bodies: Query<RigidBodyQueryReadOnly, Without<RigidBodyDisabled>>,
+    mut joints: Query<
+        (&mut TargetJoint, &mut TargetJointSolverData),
+        (Without<RigidBody>, Without<JointDisabled>),
+    >,
+) {
+    for (mut joint, mut solver_data) in &mut joints {
+        // Clear the Lagrange multipliers.
+        solver_data.clear_lagrange_multipliers();
+
+        if let Ok(body) = bodies.get(joint.body) {
+            joint.prepare([&body], &mut solver_data);
+        }
+    }
+}
+
+/// Solves the [`TargetJoint`]s.
+pub(crate) fn solve_xpbd_target_joints(
+    mut solver_bodies: ResMut<SolverBodies>,
+    index_query: Query<&SolverBodyIndex, Without<RigidBodyDisabled>>,
+    mut joints: Query<
+        (&mut TargetJoint, &mut TargetJointSolverData),
+        (Without<RigidBody>, Without<JointDisabled>),
+    >,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (mut joint, mut solver_data) in &mut joints {
+        let Ok(&index) = index_query.get(joint.body) else {
+            continue;
+        };
+        let Some(inertia) = solver_bodies.get_inertia(index).cloned() else {
+            continue;
+        };
+        let Some(body) = solver_bodies.get_mut(index) else {
+            continue;
+        };
+
+        joint.solve([body], [&inertia], &mut solver_data, delta_secs);
+    }
+}
+
+/// Writes back the forces applied by the [`TargetJoint`]s to their [`JointForces`].
+pub(crate) fn writeback_target_joint_forces(
+    mut joints: Query<(&TargetJointSolverData, &mut JointForces)>,
+    time: Res<Time>,
+    substep_count: Res<SubstepCount>,
+) {
+    let delta_secs = time.delta_secs();
+
+    // See `writeback_joint_forces` for the derivation.
+    let rhs = (delta_secs * delta_secs).recip_or_zero() * substep_count.0 as f32;
+
+    for (solver_data, mut forces) in &mut joints {
+        forces.set_force(solver_data.total_position_lagrange() * rhs);
+    }
+}
+
+/// Makes the XPBD solver project the velocity of the body of a newly added [`TargetJoint`].
+pub(crate) fn on_add_target_joint(
+    trigger: On<Add, TargetJoint>,
+    joints: Query<&TargetJoint>,
+    mut commands: Commands,
+) {
+    if let Ok(joint) = joints.get(trigger.entity) {
+        commands
+            .entity(joint.body)
+            .try_insert(XpbdVelocityProjection);
+    }
+}
+
+/// Stops projecting XPBD velocities for the body of a removed [`TargetJoint`]
+/// if it is no longer constrained by any other joint.
+pub(crate) fn on_remove_target_joint(
+    trigger: On<Remove, TargetJoint>,
+    joints: Query<(Entity, &TargetJoint)>,
+    joint_graph: Res<JointGraph>,
+    mut commands: Commands,
+) {
+    let Ok((_, removed_joint)) = joints.get(trigger.entity) else {
+        return;
+    };
+    let body = removed_joint.body;
+
+    let has_other_target_joint = joints
+        .iter()
+        .any(|(entity, joint)| entity != trigger.entity && joint.entities() == [body]);
+
+    if !has_other_target_joint && joint_graph.joints_of(body).next().is_none() {
+        commands.entity(body).try_remove::<XpbdVelocityProjection>();
+    }
+}
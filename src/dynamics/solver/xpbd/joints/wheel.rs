@@ -0,0 +1,595 @@
+use super::compute_motor_lagrange;
+use crate::{
+    dynamics::solver::{
+        solver_body::{SolverBody, SolverBodyInertia},
+        xpbd::*,
+    },
+    prelude::*,
+};
+use bevy::prelude::*;
+
+use core::f32::consts::{PI, TAU};
+
+/// Constraint data required by the XPBD constraint solver for a [`WheelJoint`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct WheelJointSolverData {
+    pub(super) world_r1: Vector,
+    pub(super) world_r2: Vector,
+    pub(super) center_difference: Vector,
+    pub(super) slider_axis1: Vector,
+    #[cfg(feature = "2d")]
+    pub(super) rotation_difference: f32,
+    #[cfg(feature = "3d")]
+    pub(super) spin_axis1: Vector,
+    #[cfg(feature = "3d")]
+    pub(super) spin_axis2: Vector,
+    #[cfg(feature = "3d")]
+    pub(super) reference_axis1: Vector,
+    #[cfg(feature = "3d")]
+    pub(super) reference_axis2: Vector,
+    pub(super) total_position_lagrange: Vector,
+    pub(super) total_rotation_lagrange: AngularVector,
+    /// Accumulated drive motor Lagrange multiplier for this frame.
+    pub(super) total_motor_lagrange: AngularVector,
+    /// Drive motor Lagrange multiplier from the previous frame, used for warm starting.
+    /// This is zeroed after being applied in the first substep.
+    pub(super) warm_start_motor_lagrange: AngularVector,
+    /// Accumulated suspension Lagrange multiplier for this frame.
+    pub(super) total_suspension_lagrange: f32,
+    /// Suspension Lagrange multiplier from the previous frame, used for warm starting.
+    /// This is zeroed after being applied in the first substep.
+    pub(super) warm_start_suspension_lagrange: f32,
+}
+
+impl XpbdConstraintSolverData for WheelJointSolverData {
+    fn clear_lagrange_multipliers(&mut self) {
+        self.total_position_lagrange = Vector::ZERO;
+        self.total_rotation_lagrange = AngularVector::default();
+        // Save motor and suspension lagrange for warm starting before clearing.
+        self.warm_start_motor_lagrange = self.total_motor_lagrange;
+        self.total_motor_lagrange = AngularVector::default();
+        self.warm_start_suspension_lagrange = self.total_suspension_lagrange;
+        self.total_suspension_lagrange = 0.0;
+    }
+
+    fn total_motor_lagrange(&self) -> f32 {
+        #[cfg(feature = "2d")]
+        {
+            self.total_motor_lagrange
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.total_motor_lagrange.length()
+        }
+    }
+
+    fn total_position_lagrange(&self) -> Vector {
+        self.total_position_lagrange
+    }
+
+    fn total_rotation_lagrange(&self) -> AngularVector {
+        self.total_rotation_lagrange + self.total_motor_lagrange
+    }
+}
+
+impl XpbdConstraint<2> for WheelJoint {
+    type SolverData = WheelJointSolverData;
+
+    fn prepare(
+        &mut self,
+        bodies: [&RigidBodyQueryReadOnlyItem; 2],
+        solver_data: &mut WheelJointSolverData,
+    ) {
+        let [body1, body2] = bodies;
+
+        let Some(local_anchor1) = self.local_anchor1() else {
+            return;
+        };
+        let Some(local_anchor2) = self.local_anchor2() else {
+            return;
+        };
+        let Some(local_basis1) = self.local_basis1() else {
+            return;
+        };
+        let Some(local_basis2) = self.local_basis2() else {
+            return;
+        };
+
+        let rot1 = Rot::from(*body1.rotation);
+        let rot2 = Rot::from(*body2.rotation);
+
+        // Prepare the suspension.
+        solver_data.world_r1 = body1.rotation * (local_anchor1 - body1.center_of_mass.0);
+        solver_data.world_r2 = body2.rotation * (local_anchor2 - body2.center_of_mass.0);
+        solver_data.center_difference = (body2.position.0 - body1.position.0).f32()
+            + (body2.rotation * body2.center_of_mass.0 - body1.rotation * body1.center_of_mass.0);
+        solver_data.slider_axis1 = rot1 * local_basis1 * self.slider_axis;
+
+        // Prepare the base rotation difference.
+        #[cfg(feature = "2d")]
+        {
+            solver_data.rotation_difference = (rot1 * local_basis1).angle_to(rot2 * local_basis2);
+        }
+        #[cfg(feature = "3d")]
+        {
+            // Prepare the base spin axes and the reference axes perpendicular to them.
+            let reference_axis = self.spin_axis.any_orthonormal_vector();
+            solver_data.spin_axis1 = rot1 * local_basis1 * self.spin_axis;
+            solver_data.spin_axis2 = rot2 * local_basis2 * self.spin_axis;
+            solver_data.reference_axis1 = rot1 * local_basis1 * reference_axis;
+            solver_data.reference_axis2 = rot2 * local_basis2 * reference_axis;
+        }
+    }
+
+    fn solve(
+        &mut self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut WheelJointSolverData,
+        dt: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        // Keep the spin axis of the wheel aligned with the chassis, and apply steering.
+        #[cfg(feature = "3d")]
+        self.align_spin_axis(body1, body2, inertia1, inertia2, solver_data, dt);
+
+        // Solve motors before limits to give limits higher priority.
+        self.apply_motor(body1, body2, inertia1, inertia2, solver_data, dt);
+        self.apply_suspension(body1, body2, inertia1, inertia2, solver_data, dt);
+
+        // Constrain the relative positions of the bodies, only allowing translation along the slider axis.
+        self.constrain_positions(body1, body2, inertia1, inertia2, solver_data, dt);
+    }
+
+    fn warm_start_motors(
+        &self,
+        bodies: [&mut SolverBody; 2],
+        inertias: [&SolverBodyInertia; 2],
+        solver_data: &mut WheelJointSolverData,
+        _dt: f32,
+        warm_start_coefficient: f32,
+    ) {
+        let [body1, body2] = bodies;
+        let [inertia1, inertia2] = inertias;
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        if self.motor.enabled {
+            let impulse = warm_start_coefficient * solver_data.warm_start_motor_lagrange;
+
+            body1.angular_velocity -= inv_angular_inertia1 * impulse;
+            body2.angular_velocity += inv_angular_inertia2 * impulse;
+        }
+
+        if self.suspension.enabled {
+            let inv_mass1 = inertia1.effective_inv_mass();
+            let inv_mass2 = inertia2.effective_inv_mass();
+
+            let axis = body1.delta_rotation * solver_data.slider_axis1;
+            let world_r1 = body1.delta_rotation * solver_data.world_r1;
+            let world_r2 = body2.delta_rotation * solver_data.world_r2;
+
+            let impulse =
+                warm_start_coefficient * solver_data.warm_start_suspension_lagrange * axis;
+
+            body1.linear_velocity -= impulse * inv_mass1;
+            body2.linear_velocity += impulse * inv_mass2;
+            body1.angular_velocity -= inv_angular_inertia1 * cross(world_r1, impulse);
+            body2.angular_velocity += inv_angular_inertia2 * cross(world_r2, impulse);
+        }
+
+        solver_data.warm_start_motor_lagrange = AngularVector::default();
+        solver_data.warm_start_suspension_lagrange = 0.0;
+    }
+}
+
+impl WheelJoint {
+    /// Aligns the spin axis of the wheel with the chassis.
+    ///
+    /// If a steering limit is set, the wheel is allowed to rotate about the slider axis
+    /// within the limit, and the steering motor is applied.
+    #[cfg(feature = "3d")]
+    fn align_spin_axis(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut WheelJointSolverData,
+        dt: f32,
+    ) {
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        let a1 = body1.delta_rotation * solver_data.spin_axis1;
+        let a2 = body2.delta_rotation * solver_data.spin_axis2;
+
+        let Some(steering_limit) = self.steering_limit else {
+            // Steering is locked, so the spin axes must be fully aligned.
+            solver_data.total_rotation_lagrange += self.align_orientation(
+                body1,
+                body2,
+                inv_angular_inertia1,
+                inv_angular_inertia2,
+                a1.cross(a2),
+                0.0,
+                self.angle_compliance,
+                dt,
+            );
+            return;
+        };
+
+        // Keep the spin axis of the wheel perpendicular to the slider axis.
+        let s1 = body1.delta_rotation * solver_data.slider_axis1;
+        let projected = (a2 - s1 * a2.dot(s1)).normalize_or_zero();
+        if projected != Vector::ZERO {
+            solver_data.total_rotation_lagrange += self.align_orientation(
+                body1,
+                body2,
+                inv_angular_inertia1,
+                inv_angular_inertia2,
+                projected.cross(a2),
+                0.0,
+                self.angle_compliance,
+                dt,
+            );
+        }
+
+        // Drive the steering angle.
+        let motor = &self.steering_motor;
+        if motor.enabled {
+            let s1 = body1.delta_rotation * solver_data.slider_axis1;
+            let a1 = body1.delta_rotation * solver_data.spin_axis1;
+            let a2 = body2.delta_rotation * solver_data.spin_axis2;
+
+            let current_angle = a1.cross(a2).dot(s1).atan2(a1.dot(a2));
+            let relative_angular_velocity =
+                (body2.angular_velocity - body1.angular_velocity).dot(s1);
+
+            let w_sum =
+                AngularConstraint::compute_generalized_inverse_mass(self, inv_angular_inertia1, s1)
+                    + AngularConstraint::compute_generalized_inverse_mass(
+                        self,
+                        inv_angular_inertia2,
+                        s1,
+                    );
+
+            if w_sum > f32::EPSILON {
+                let velocity_error = motor.target_velocity - relative_angular_velocity;
+                let raw_error = motor.target_position - current_angle;
+                let position_error = (raw_error + PI).rem_euclid(TAU) - PI;
+
+                if let Some(delta_lagrange) = compute_motor_lagrange(
+                    velocity_error,
+                    position_error,
+                    w_sum,
+                    motor.motor_model,
+                    motor.max_torque,
+                    dt,
+                ) {
+                    solver_data.total_rotation_lagrange += delta_lagrange * s1;
+                    self.apply_angular_lagrange_update(
+                        body1,
+                        body2,
+                        inv_angular_inertia1,
+                        inv_angular_inertia2,
+                        delta_lagrange,
+                        s1,
+                    );
+                }
+            }
+        }
+
+        // Apply the steering limits.
+        let s1 = body1.delta_rotation * solver_data.slider_axis1;
+        let a1 = body1.delta_rotation * solver_data.spin_axis1;
+        let a2 = body2.delta_rotation * solver_data.spin_axis2;
+
+        if let Some(correction) = steering_limit.compute_correction(s1, a1, a2, PI) {
+            solver_data.total_rotation_lagrange += self.align_orientation(
+                body1,
+                body2,
+                inv_angular_inertia1,
+                inv_angular_inertia2,
+                correction,
+                0.0,
+                self.limit_compliance,
+                dt,
+            );
+        }
+    }
+
+    /// Applies motor forces to drive the rotation of the wheel towards the target velocity and/or position.
+    fn apply_motor(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut WheelJointSolverData,
+        dt: f32,
+    ) {
+        let motor = &self.motor;
+
+        if !motor.enabled {
+            return;
+        }
+
+        let inv_angular_inertia1 = inertia1.effective_inv_angular_inertia();
+        let inv_angular_inertia2 = inertia2.effective_inv_angular_inertia();
+
+        #[cfg(feature = "2d")]
+        let current_angle =
+            solver_data.rotation_difference + body1.delta_rotation.angle_to(body2.delta_rotation);
+        #[cfg(feature = "3d")]
+        let a2 = body2.delta_rotation * solver_data.spin_axis2;
+        #[cfg(feature = "3d")]
+        let current_angle = {
+            let b1 = body1.delta_rotation * solver_data.reference_axis1;
+            let b2 = body2.delta_rotation * solver_data.reference_axis2;
+            let sin_angle = b1.cross(b2).dot(a2);
+            let cos_angle = b1.dot(b2);
+            sin_angle.atan2(cos_angle)
+        };
+
+        #[cfg(feature = "2d")]
+        let relative_angular_velocity = body2.angular_velocity - body1.angular_velocity;
+        #[cfg(feature = "3d")]
+        let relative_angular_velocity = (body2.angular_velocity - body1.angular_velocity).dot(a2);
+
+        #[cfg(feature = "2d")]
+        let w_sum = inv_angular_inertia1 + inv_angular_inertia2;
+        #[cfg(feature = "3d")]
+        let w_sum =
+            AngularConstraint::compute_generalized_inverse_mass(self, inv_angular_inertia1, a2)
+                + AngularConstraint::compute_generalized_inverse_mass(
+                    self,
+                    inv_angular_inertia2,
+                    a2,
+                );
+
+        if w_sum <= f32::EPSILON {
+            return;
+        }
+
+        let velocity_error = motor.target_velocity - relative_angular_velocity;
+
+        // Wrap position error to [-PI, PI] for shortest path rotation.
+        let raw_error = motor.target_position - current_angle;
+        let position_error = (raw_error + PI).rem_euclid(TAU) - PI;
+
+        let Some(delta_lagrange) = compute_motor_lagrange(
+            velocity_error,
+            position_error,
+            w_sum,
+            motor.motor_model,
+            motor.max_torque,
+            dt,
+        ) else {
+            return;
+        };
+
+        // Positive delta_lagrange increases body2's angular velocity relative to body1.
+        #[cfg(feature = "2d")]
+        {
+            solver_data.total_motor_lagrange += delta_lagrange;
+            self.apply_angular_lagrange_update(
+                body1,
+                body2,
+                inv_angular_inertia1,
+                inv_angular_inertia2,
+                delta_lagrange,
+            );
+        }
+        #[cfg(feature = "3d")]
+        {
+            solver_data.total_motor_lagrange += delta_lagrange * a2;
+            self.apply_angular_lagrange_update(
+                body1,
+                body2,
+                inv_angular_inertia1,
+                inv_angular_inertia2,
+                delta_lagrange,
+                a2,
+            );
+        }
+    }
+
+    /// Applies the suspension force along the slider axis.
+    fn apply_suspension(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut WheelJointSolverData,
+        dt: f32,
+    ) {
+        let suspension = &self.suspension;
+
+        if !suspension.enabled {
+            return;
+        }
+
+        let axis1 = body1.delta_rotation * solver_data.slider_axis1;
+        let world_r1 = body1.delta_rotation * solver_data.world_r1;
+        let world_r2 = body2.delta_rotation * solver_data.world_r2;
+
+        let separation = (body2.delta_position - body1.delta_position)
+            + (world_r2 - world_r1)
+            + solver_data.center_difference;
+        let current_position = separation.dot(axis1);
+        let current_velocity = (body2.linear_velocity - body1.linear_velocity).dot(axis1);
+
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(
+            self,
+            inertia1.effective_inv_mass().max_element(),
+            inertia1.effective_inv_angular_inertia(),
+            world_r1,
+            axis1,
+        );
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(
+            self,
+            inertia2.effective_inv_mass().max_element(),
+            inertia2.effective_inv_angular_inertia(),
+            world_r2,
+            axis1,
+        );
+
+        let w_sum = w1 + w2;
+        if w_sum <= f32::EPSILON {
+            return;
+        }
+
+        let velocity_error = suspension.target_velocity - current_velocity;
+        let position_error = suspension.target_position - current_position;
+
+        let Some(delta_lagrange) = compute_motor_lagrange(
+            velocity_error,
+            position_error,
+            w_sum,
+            suspension.motor_model,
+            suspension.max_force,
+            dt,
+        ) else {
+            return;
+        };
+
+        solver_data.total_suspension_lagrange += delta_lagrange;
+
+        let impulse = delta_lagrange * axis1;
+        solver_data.total_position_lagrange += impulse;
+
+        // Negate impulse: apply_positional_impulse convention is opposite to motor direction.
+        self.apply_positional_impulse(
+            body1, body2, inertia1, inertia2, -impulse, world_r1, world_r2,
+        );
+    }
+
+    /// Constrains the relative positions of the bodies, only allowing translation along the slider axis
+    /// within the suspension limits.
+    fn constrain_positions(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &mut WheelJointSolverData,
+        dt: f32,
+    ) {
+        let axis1 = body1.delta_rotation * solver_data.slider_axis1;
+        let zero_distance_limit = DistanceLimit::ZERO;
+
+        let separation = |body1: &SolverBody, body2: &SolverBody| {
+            (body2.delta_position - body1.delta_position)
+                + (body2.delta_rotation * solver_data.world_r2
+                    - body1.delta_rotation * solver_data.world_r1)
+                + solver_data.center_difference
+        };
+
+        // Remove any translation perpendicular to the slider axis.
+        let mut delta_x = Vector::ZERO;
+        #[cfg(feature = "2d")]
+        {
+            let axis2 = Vec2::new(axis1.y, -axis1.x);
+            delta_x +=
+                zero_distance_limit.compute_correction_along_axis(separation(body1, body2), axis2);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let axis2 = axis1.any_orthogonal_vector();
+            let axis3 = axis1.cross(axis2);
+            let separation = separation(body1, body2);
+            delta_x += zero_distance_limit.compute_correction_along_axis(separation, axis2);
+            delta_x += zero_distance_limit.compute_correction_along_axis(separation, axis3);
+        }
+        solver_data.total_position_lagrange += self.apply_correction(
+            body1,
+            body2,
+            inertia1,
+            inertia2,
+            solver_data,
+            delta_x,
+            self.align_compliance,
+            dt,
+        );
+
+        // Limit the suspension travel.
+        if let Some(limits) = self.suspension_limits {
+            let delta_x = limits.compute_correction_along_axis(separation(body1, body2), axis1);
+            solver_data.total_position_lagrange += self.apply_correction(
+                body1,
+                body2,
+                inertia1,
+                inertia2,
+                solver_data,
+                delta_x,
+                self.limit_compliance,
+                dt,
+            );
+        }
+    }
+
+    /// Applies a positional correction `delta_x` at the joint anchors.
+    ///
+    /// Returns the applied impulse.
+    fn apply_correction(
+        &self,
+        body1: &mut SolverBody,
+        body2: &mut SolverBody,
+        inertia1: &SolverBodyInertia,
+        inertia2: &SolverBodyInertia,
+        solver_data: &WheelJointSolverData,
+        delta_x: Vector,
+        compliance: f32,
+        dt: f32,
+    ) -> Vector {
+        let magnitude = delta_x.length();
+
+        if magnitude <= f32::EPSILON {
+            return Vector::ZERO;
+        }
+
+        let dir = delta_x / magnitude;
+
+        let world_r1 = body1.delta_rotation * solver_data.world_r1;
+        let world_r2 = body2.delta_rotation * solver_data.world_r2;
+
+        // Compute generalized inverse masses
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(
+            self,
+            inertia1.effective_inv_mass().max_element(),
+            inertia1.effective_inv_angular_inertia(),
+            world_r1,
+            dir,
+        );
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(
+            self,
+            inertia2.effective_inv_mass().max_element(),
+            inertia2.effective_inv_angular_inertia(),
+            world_r2,
+            dir,
+        );
+
+        // Compute Lagrange multiplier update
+        let delta_lagrange = compute_lagrange_update(0.0, magnitude, &[w1, w2], compliance, dt);
+        let impulse = delta_lagrange * dir;
+
+        // Apply positional correction to align the positions of the bodies
+        self.apply_positional_impulse(
+            body1, body2, inertia1, inertia2, impulse, world_r1, world_r2,
+        );
+
+        impulse
+    }
+}
+
+impl PositionConstraint for WheelJoint {}
+
+impl AngularConstraint for WheelJoint {}
@@ -32,6 +32,11 @@
 //!     - [`DistanceJoint`]
 #![cfg_attr(feature = "3d", doc = "    - [`SphericalJoint`]")]
 //!     - [`PrismaticJoint`]
+//!     - [`WheelJoint`]
+//!     - [`GenericJoint`]
+//!     - [`GearJoint`]
+//!     - [`RackAndPinionJoint`]
+//!     - [`PulleyJoint`]
 //!
 //! Avian's [`ContactConstraint`](dynamics::solver::contact::ContactConstraint)
 //! is impulse-based instead.
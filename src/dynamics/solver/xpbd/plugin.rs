@@ -1,13 +1,11 @@
-use core::cmp::Ordering;
-
 use super::joints::*;
 use crate::{
     dynamics::{
         joints::EntityConstraint,
         solver::{
-            SolverConfig,
+            JointSolver, SolverConfig,
             schedule::SubstepSolverSystems,
-            solver_body::{SolverBodies, SolverBody, SolverBodyIndex, SolverBodyInertia},
+            solver_body::{SolverBodies, SolverBodyIndex, for_each_joint_body_pair},
             xpbd::{XpbdConstraint, XpbdConstraintSolverData},
         },
     },
@@ -28,6 +26,17 @@ impl Plugin for XpbdSolverPlugin {
         app.register_required_components::<SphericalJoint, SphericalJointSolverData>();
         app.register_required_components::<PrismaticJoint, PrismaticJointSolverData>();
         app.register_required_components::<DistanceJoint, DistanceJointSolverData>();
+        app.register_required_components::<WheelJoint, WheelJointSolverData>();
+        app.register_required_components::<GenericJoint, GenericJointSolverData>();
+        app.register_required_components::<GearJoint, GearJointSolverData>();
+        app.register_required_components::<RackAndPinionJoint, RackAndPinionJointSolverData>();
+        app.register_required_components::<PulleyJoint, PulleyJointSolverData>();
+        app.register_required_components::<TargetJoint, TargetJointSolverData>();
+
+        // Target joints only constrain a single body, so they are not in the joint graph,
+        // and velocity projection for their bodies must be managed separately.
+        app.add_observer(on_add_target_joint);
+        app.add_observer(on_remove_target_joint);
 
         // Configure scheduling.
         app.configure_sets(
@@ -46,12 +55,20 @@ impl Plugin for XpbdSolverPlugin {
         app.add_systems(
             PhysicsSchedule,
             (
-                prepare_xpbd_joint::<FixedJoint>,
-                prepare_xpbd_joint::<RevoluteJoint>,
+                update_gear_joint_coordinates,
+                update_rack_and_pinion_joint_coordinates,
+                prepare_xpbd_joint::<FixedJoint>.run_if(uses_xpbd_joints),
+                prepare_xpbd_joint::<RevoluteJoint>.run_if(uses_xpbd_joints),
                 #[cfg(feature = "3d")]
-                prepare_xpbd_joint::<SphericalJoint>,
-                prepare_xpbd_joint::<PrismaticJoint>,
-                prepare_xpbd_joint::<DistanceJoint>,
+                prepare_xpbd_joint::<SphericalJoint>.run_if(uses_xpbd_joints),
+                prepare_xpbd_joint::<PrismaticJoint>.run_if(uses_xpbd_joints),
+                prepare_xpbd_joint::<DistanceJoint>.run_if(uses_xpbd_joints),
+                prepare_xpbd_joint::<WheelJoint>,
+                prepare_xpbd_joint::<GenericJoint>,
+                prepare_xpbd_joint::<GearJoint>,
+                prepare_xpbd_joint::<RackAndPinionJoint>,
+                prepare_xpbd_joint::<PulleyJoint>,
+                prepare_xpbd_target_joints,
             )
                 .chain()
                 .in_set(SolverSystems::PrepareJoints),
@@ -64,8 +81,12 @@ impl Plugin for XpbdSolverPlugin {
         app.add_systems(
             SubstepSchedule,
             (
-                warm_start_xpbd_motors::<RevoluteJoint>,
-                warm_start_xpbd_motors::<PrismaticJoint>,
+                warm_start_xpbd_motors::<RevoluteJoint>.run_if(uses_xpbd_joints),
+                #[cfg(feature = "3d")]
+                warm_start_xpbd_motors::<SphericalJoint>.run_if(uses_xpbd_joints),
+                warm_start_xpbd_motors::<PrismaticJoint>.run_if(uses_xpbd_joints),
+                warm_start_xpbd_motors::<WheelJoint>,
+                warm_start_xpbd_motors::<GenericJoint>,
             )
                 .chain()
                 .ambiguous_with_all()
@@ -77,12 +98,18 @@ impl Plugin for XpbdSolverPlugin {
             SubstepSchedule,
             (
                 store_pre_solve_deltas,
-                solve_xpbd_joint::<FixedJoint>,
-                solve_xpbd_joint::<RevoluteJoint>,
+                solve_xpbd_joint::<FixedJoint>.run_if(uses_xpbd_joints),
+                solve_xpbd_joint::<RevoluteJoint>.run_if(uses_xpbd_joints),
                 #[cfg(feature = "3d")]
-                solve_xpbd_joint::<SphericalJoint>,
-                solve_xpbd_joint::<PrismaticJoint>,
-                solve_xpbd_joint::<DistanceJoint>,
+                solve_xpbd_joint::<SphericalJoint>.run_if(uses_xpbd_joints),
+                solve_xpbd_joint::<PrismaticJoint>.run_if(uses_xpbd_joints),
+                solve_xpbd_joint::<DistanceJoint>.run_if(uses_xpbd_joints),
+                solve_xpbd_joint::<WheelJoint>,
+                solve_xpbd_joint::<GenericJoint>,
+                solve_xpbd_joint::<GearJoint>,
+                solve_xpbd_joint::<RackAndPinionJoint>,
+                solve_xpbd_joint::<PulleyJoint>,
+                solve_xpbd_target_joints,
             )
                 .chain()
                 .in_set(XpbdSolverSystems::SolveConstraints),
@@ -100,12 +127,18 @@ impl Plugin for XpbdSolverPlugin {
         app.add_systems(
             PhysicsSchedule,
             (
-                writeback_joint_forces::<FixedJoint>,
-                writeback_joint_forces::<RevoluteJoint>,
+                writeback_joint_forces::<FixedJoint>.run_if(uses_xpbd_joints),
+                writeback_joint_forces::<RevoluteJoint>.run_if(uses_xpbd_joints),
                 #[cfg(feature = "3d")]
-                writeback_joint_forces::<SphericalJoint>,
-                writeback_joint_forces::<PrismaticJoint>,
-                writeback_joint_forces::<DistanceJoint>,
+                writeback_joint_forces::<SphericalJoint>.run_if(uses_xpbd_joints),
+                writeback_joint_forces::<PrismaticJoint>.run_if(uses_xpbd_joints),
+                writeback_joint_forces::<DistanceJoint>.run_if(uses_xpbd_joints),
+                writeback_joint_forces::<WheelJoint>,
+                writeback_joint_forces::<GenericJoint>,
+                writeback_joint_forces::<GearJoint>,
+                writeback_joint_forces::<RackAndPinionJoint>,
+                writeback_joint_forces::<PulleyJoint>,
+                writeback_target_joint_forces,
             )
                 .chain()
                 .in_set(SolverSystems::Finalize),
@@ -113,6 +146,11 @@ impl Plugin for XpbdSolverPlugin {
     }
 }
 
+/// Returns `true` if the joint types supported by both joint solvers should be solved using XPBD.
+fn uses_xpbd_joints(solver_config: Res<SolverConfig>) -> bool {
+    solver_config.joint_solver == JointSolver::Xpbd
+}
+
 /// System sets for the XPBD constraint solver in the [`SubstepSchedule`].
 #[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum XpbdSolverSystems {
@@ -173,57 +211,14 @@ pub fn solve_xpbd_joint<
 {
     let delta_secs = time.delta_secs();
 
-    let access = solver_bodies.access();
-
-    let mut dummy_body1 = SolverBody::default();
-    let mut dummy_body2 = SolverBody::default();
-
-    for (mut joint, mut solver_data) in &mut joints {
-        let [entity1, entity2] = joint.entities();
-
-        let index1 = index_query
-            .get(entity1)
-            .copied()
-            .unwrap_or(SolverBodyIndex::INVALID);
-        let index2 = index_query
-            .get(entity2)
-            .copied()
-            .unwrap_or(SolverBodyIndex::INVALID);
-
-        if index1 == index2 {
-            continue;
-        }
-
-        let (mut body1, mut inertia1) = (&mut dummy_body1, &SolverBodyInertia::DUMMY);
-        let (mut body2, mut inertia2) = (&mut dummy_body2, &SolverBodyInertia::DUMMY);
-
-        // Get the solver bodies for the two jointed bodies.
-        //
-        // SAFETY: The two jointed bodies are distinct, and joints are processed serially here.
-        let (b1, b2) = unsafe { access.get_pair_unchecked_mut(index1, index2) };
-        if let Some((body, inertia)) = b1 {
-            body1 = body;
-            inertia1 = inertia;
-        }
-        if let Some((body, inertia)) = b2 {
-            body2 = body;
-            inertia2 = inertia;
-        }
-
-        // If a body has a higher dominance, it is treated as a static or kinematic body.
-        match (inertia1.dominance() - inertia2.dominance()).cmp(&0) {
-            Ordering::Greater => inertia1 = &SolverBodyInertia::DUMMY,
-            Ordering::Less => inertia2 = &SolverBodyInertia::DUMMY,
-            _ => {}
-        }
-
-        joint.solve(
-            [body1, body2],
-            [inertia1, inertia2],
-            &mut solver_data,
-            delta_secs,
-        );
-    }
+    for_each_joint_body_pair(
+        &mut solver_bodies,
+        &index_query,
+        &mut joints,
+        |mut joint, mut solver_data, bodies, inertias| {
+            joint.solve(bodies, inertias, &mut solver_data, delta_secs);
+        },
+    );
 }
 
 /// Warm starts the motor constraints for joints of a given type.
@@ -243,56 +238,20 @@ pub fn warm_start_xpbd_motors<
 {
     let delta_secs = time.delta_secs();
 
-    let access = solver_bodies.access();
-
-    let mut dummy_body1 = SolverBody::default();
-    let mut dummy_body2 = SolverBody::default();
-
-    for (joint, mut solver_data) in &mut joints {
-        let [entity1, entity2] = joint.entities();
-
-        let index1 = index_query
-            .get(entity1)
-            .copied()
-            .unwrap_or(SolverBodyIndex::INVALID);
-        let index2 = index_query
-            .get(entity2)
-            .copied()
-            .unwrap_or(SolverBodyIndex::INVALID);
-
-        if index1 == index2 {
-            continue;
-        }
-
-        let (mut body1, mut inertia1) = (&mut dummy_body1, &SolverBodyInertia::DUMMY);
-        let (mut body2, mut inertia2) = (&mut dummy_body2, &SolverBodyInertia::DUMMY);
-
-        // SAFETY: The two jointed bodies are distinct, and joints are processed serially here.
-        let (b1, b2) = unsafe { access.get_pair_unchecked_mut(index1, index2) };
-        if let Some((body, inertia)) = b1 {
-            body1 = body;
-            inertia1 = inertia;
-        }
-        if let Some((body, inertia)) = b2 {
-            body2 = body;
-            inertia2 = inertia;
-        }
-
-        // If a body has a higher dominance, it is treated as a static or kinematic body.
-        match (inertia1.dominance() - inertia2.dominance()).cmp(&0) {
-            Ordering::Greater => inertia1 = &SolverBodyInertia::DUMMY,
-            Ordering::Less => inertia2 = &SolverBodyInertia::DUMMY,
-            _ => {}
-        }
-
-        joint.warm_start_motors(
-            [body1, body2],
-            [inertia1, inertia2],
-            &mut solver_data,
-            delta_secs,
-            solver_config.warm_start_coefficient,
-        );
-    }
+    for_each_joint_body_pair(
+        &mut solver_bodies,
+        &index_query,
+        &mut joints,
+        |joint, mut solver_data, bodies, inertias| {
+            joint.warm_start_motors(
+                bodies,
+                inertias,
+                &mut solver_data,
+                delta_secs,
+                solver_config.warm_start_coefficient,
+            );
+        },
+    );
 }
 
 /// Stores the delta position and rotation of each body before XPBD constraints are solved.
@@ -55,10 +55,21 @@
 //! | `parry-f32`            | Enables the `f32` version of the Parry collision detection library. Also enables the `default-collider` feature.                                   | Yes             |
 //! | `parry-f64`            | Enables the `f64` version of the Parry collision detection library. Also enables the `default-collider` feature.                                   | No              |
 //! | `xpbd_joints`          | Enables support for [XPBD joints](dynamics::solver::xpbd).                            .                                                            | Yes             |
+//! | `layers-64`            | Increases the maximum number of [collision layers](CollisionLayers) from 32 to 64.                                                                 | No              |
+//! | `layers-128`           | Increases the maximum number of [collision layers](CollisionLayers) from 32 to 128.                                                                | No              |
+//! | `collision-matrix-asset` | Enables loading a [`CollisionMatrix`] from a RON asset using the `CollisionMatrixAssetPlugin`. Also enables the `serialize` feature.             | No              |
 #![cfg_attr(
     feature = "3d",
     doc = "| `collider-from-mesh`   | Allows you to create [`Collider`]s from `Mesh`es.                                                                                                  | Yes             |"
 )]
+#![cfg_attr(
+    feature = "2d",
+    doc = "| `collider-from-image`  | Allows you to create [`Collider`]s from the alpha of `Image`s, like the images of sprites.                                                         | No              |"
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "| `persistent-collider-cache` | Enables persisting colliders created from meshes to disk with the `PersistentColliderCache` resource.                                         | No              |"
+)]
 //! | `bevy_scene`           | Enables [`ColliderConstructorHierarchy`] to wait until a [`Scene`] has loaded before processing it.                                                 | Yes             |
 //! | `bevy_picking`         | Enables physics picking support for [`bevy_picking`] using the [`PhysicsPickingPlugin`]. The plugin must be added separately.                       | Yes             |
 //! | `bevy_diagnostic`      | Enables writing [physics diagnostics] to the [`DiagnosticsStore`] with the [`PhysicsDiagnosticsPlugin`]. The plugin must be added separately.       | No              |
@@ -174,14 +185,33 @@
 //!     - [Prismatic joint](PrismaticJoint)
 //!     - [Revolute joint](RevoluteJoint)
 #![cfg_attr(feature = "3d", doc = "    - [Spherical joint](SphericalJoint)")]
+//!     - [Wheel joint](WheelJoint)
+//!     - [Generic joint](GenericJoint)
+//!     - [Gear joint](GearJoint)
+//!     - [Rack-and-pinion joint](RackAndPinionJoint)
+//!     - [Pulley joint](PulleyJoint)
+//!     - [Target joint](TargetJoint)
 //! - [Temporarily disabling a joint](JointDisabled)
+//! - [Breaking joints](JointBreakThreshold)
+//! - [Joint projection](dynamics::joint_projection) for stretch-free chains of bodies
+//! - [Force fields](dynamics::force_field) and gravity override volumes
+#![cfg_attr(
+    feature = "default-collider",
+    doc = "- [Buoyancy](dynamics::fluid) in fluid volumes"
+)]
+#![cfg_attr(
+    feature = "default-collider",
+    doc = "- [Aerodynamic drag and lift](dynamics::aerodynamics) based on collider shapes"
+)]
+#![cfg_attr(
+    all(feature = "3d", feature = "default-collider"),
+    doc = "- [Ragdolls](dynamics::ragdoll) from skeletons"
+)]
 #![cfg_attr(
     feature = "xpbd_joints",
     doc = "- [Custom XPBD constraints](dynamics::solver::xpbd#constraints) (advanced)"
 )]
 //!
-//! Joint motors and articulations are not supported yet, but they will be implemented in a future release.
-//!
 //! ## Spatial Queries
 //!
 //! - [Spatial query types](spatial_query)
@@ -399,9 +429,10 @@
 //!
 //! ## Is there a character controller?
 //!
-//! Avian does not have a built-in character controller yet. However, it has a [`MoveAndSlide`]
-//! system parameter with utilities for implementing your own kinematic character controllers.
-//! See its documentation for more information.
+//! Avian has a basic kinematic [`CharacterController`] that handles grounding, slopes, stairs,
+//! and moving platforms. It is built on top of the [`MoveAndSlide`] system parameter,
+//! which provides utilities for implementing your own kinematic character controllers.
+//! See their documentation for more information.
 //!
 //! There are also some third party character controllers such as [`bevy_ahoy`](https://github.com/janhohenheim/bevy_ahoy)
 //! (kinematic) and [`bevy_tnua`](https://github.com/idanarye/bevy-tnua) (dynamic) that work with Avian.
@@ -567,7 +598,8 @@ pub mod prelude {
     pub use crate::diagnostics::ui::{PhysicsDiagnosticsUiPlugin, PhysicsDiagnosticsUiSettings};
     #[cfg(feature = "bevy_picking")]
     pub use crate::picking::{
-        PhysicsPickable, PhysicsPickingFilter, PhysicsPickingPlugin, PhysicsPickingSettings,
+        PhysicsDraggable, PhysicsPickable, PhysicsPickingFilter, PhysicsPickingPlugin,
+        PhysicsPickingSettings,
     };
     #[expect(deprecated)]
     pub use crate::{
@@ -626,6 +658,10 @@ use prelude::*;
     all(feature = "collider-from-mesh", feature = "default-collider"),
     doc = "| [`ColliderCachePlugin`]           | Caches colliders created from meshes. Requires `collider-from-mesh` and `default-collider` features.                                                       |"
 )]
+#[cfg_attr(
+    all(feature = "collider-from-image", feature = "default-collider"),
+    doc = "| [`ColliderCachePlugin`]           | Caches colliders created from images. Requires `collider-from-image` and `default-collider` features.                                                      |"
+)]
 /// | [`ColliderTreePlugin`]            | Manages [`ColliderTrees`] for broad phase collision detection and spatial queries.                                                                         |
 /// | [`BroadPhaseCorePlugin`]          | The core [broad phase] plugin that sets up the required resources, system sets, and diagnostics.                                                           |
 /// | [`BvhBroadPhasePlugin`]           | A [broad phase] plugin that uses a [Bounding Volume Hierarchy (BVH)][BVH] to efficiently find pairs of colliders with overlapping AABBs.                   |
@@ -645,6 +681,26 @@ use prelude::*;
 /// | --------------------------------- | ---------------------------------------------------------------------------------------------------------------------------------------------------------- |
 /// | [`PhysicsPickingPlugin`]          | Enables a physics picking backend for [`bevy_picking`](bevy::picking) (only with `bevy_picking` feature enabled).                                          |
 /// | [`PhysicsDebugPlugin`]            | Renders physics objects and events like [AABBs](ColliderAabb) and contacts for debugging purposes (only with `debug-plugin` feature enabled).              |
+/// | [`ForceFieldPlugin`]              | Applies [force fields](ForceField) and [gravity fields](GravityField) to rigid bodies inside them.                                                         |
+#[cfg_attr(
+    feature = "default-collider",
+    doc = "| [`FluidPlugin`]                   | Applies buoyancy and drag to rigid bodies submerged in [fluid volumes](FluidVolume). Requires the `default-collider` feature.                              |"
+)]
+#[cfg_attr(
+    feature = "default-collider",
+    doc = "| [`AerodynamicsPlugin`]            | Applies shape-based drag and lift to colliders with [`Aerodynamics`]. Requires the `default-collider` feature.                                             |"
+)]
+#[cfg_attr(
+    all(feature = "3d", feature = "default-collider"),
+    doc = "| [`RagdollPlugin`]                 | Constructs [ragdolls](dynamics::ragdoll) from skeletons and blends them with animation. Requires the `default-collider` feature.                            |"
+)]
+#[cfg_attr(
+    all(
+        feature = "default-collider",
+        any(feature = "parry-f32", feature = "parry-f64")
+    ),
+    doc = "| [`CharacterControllerPlugin`]     | Moves kinematic [character controllers](CharacterController) with [`MoveAndSlide`]. Requires the `default-collider` feature.                               |"
+)]
 /// | [`PhysicsDiagnosticsPlugin`]      | Writes [physics diagnostics](diagnostics) to the [`DiagnosticsStore`] (only with `bevy_diagnostic` feature enabled).                                       |
 /// | [`PhysicsDiagnosticsUiPlugin`]    | Displays [physics diagnostics](diagnostics) with a debug UI overlay (only with `diagnostic_ui` feature enabled).                                           |
 ///
@@ -789,7 +845,10 @@ impl PluginGroup for PhysicsPlugins {
             .add(ColliderHierarchyPlugin)
             .add(ColliderTransformPlugin::new(self.schedule));
 
-        #[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
+        #[cfg(all(
+            any(feature = "collider-from-mesh", feature = "collider-from-image"),
+            feature = "default-collider"
+        ))]
         let builder = builder.add(ColliderCachePlugin);
 
         #[cfg(all(
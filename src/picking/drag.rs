@@ -0,0 +1,218 @@
+//! Dragging [rigid bodies](RigidBody) with pointers using [`TargetJoint`]s.
+//!
+//! See [`PhysicsDraggable`].
+
+use crate::prelude::*;
+use bevy::{
+    picking::{
+        backend::ray::{RayId, RayMap},
+        pointer::PointerId,
+    },
+    prelude::*,
+};
+
+/// A component that allows a [`RigidBody`] to be dragged with pointers using the [`PhysicsPickingPlugin`].
+///
+/// When a drag starts on one of the [colliders](Collider) of the body, a [`TargetJoint`] is spawned
+/// that pulls the picked point towards the pointer. The joint is despawned when the drag ends.
+///
+/// Unlike moving the body directly, this lets the body interact with collisions and joints
+/// while it is being dragged, so stacks and other structures are not disturbed unnaturally.
+#[cfg_attr(
+    feature = "2d",
+    doc = "\nIn 2D, the pointer is projected onto the [`z_plane`](PhysicsPickingSettings::z_plane) used for picking."
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "\nIn 3D, the pointer is projected onto a plane that faces the camera and goes through the picked point."
+)]
+///
+/// # Example
+///
+/// ```
+#[cfg_attr(feature = "2d", doc = "# use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "# use avian3d::prelude::*;")]
+/// # use bevy::prelude::*;
+/// #
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     RigidBody::Dynamic,
+#[cfg_attr(feature = "2d", doc = "    Collider::circle(0.5),")]
+#[cfg_attr(feature = "3d", doc = "    Collider::sphere(0.5),")]
+///     // Drag the body with a stiff spring that cannot apply more than 1000 N.
+///     PhysicsDraggable::default()
+///         .with_motor_model(MotorModel::SpringDamper {
+///             frequency: 10.0,
+///             damping_ratio: 1.0,
+///         })
+///         .with_max_force(1000.0),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct PhysicsDraggable {
+    /// The model used for pulling the body towards the pointer.
+    ///
+    /// Default: [`MotorModel::DEFAULT`]
+    pub motor_model: MotorModel,
+    /// The maximum force that can be applied to drag the body (N).
+    ///
+    /// A good value is often some multiple of the weight of the body.
+    ///
+    /// Default: `f32::MAX`
+    pub max_force: f32,
+}
+
+impl Default for PhysicsDraggable {
+    fn default() -> Self {
+        Self {
+            motor_model: MotorModel::DEFAULT,
+            max_force: f32::MAX,
+        }
+    }
+}
+
+impl PhysicsDraggable {
+    /// Sets the model used for pulling the body towards the pointer.
+    #[inline]
+    pub const fn with_motor_model(mut self, motor_model: MotorModel) -> Self {
+        self.motor_model = motor_model;
+        self
+    }
+
+    /// Sets the maximum force that can be applied to drag the body (N).
+    #[inline]
+    pub const fn with_max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force;
+        self
+    }
+}
+
+/// A component for a [`TargetJoint`] spawned for dragging a [`PhysicsDraggable`] body.
+#[derive(Component, Clone, Copy, Debug)]
+struct PointerDragJoint {
+    /// The pointer that is dragging the body.
+    pointer: PointerId,
+    /// The camera that the pointer ray is cast from.
+    camera: Entity,
+    /// A point on the plane that the pointer is projected onto.
+    plane_origin: Vec3,
+    /// The plane that the pointer is projected onto.
+    plane: InfinitePlane3d,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(start_drag)
+        .add_observer(update_drag)
+        .add_observer(end_drag);
+}
+
+/// Spawns a [`TargetJoint`] when a drag starts on a [`PhysicsDraggable`] body.
+fn start_drag(
+    event: On<Pointer<DragStart>>,
+    colliders: Query<&ColliderOf>,
+    draggables: Query<&PhysicsDraggable>,
+    #[cfg(feature = "2d")] settings: Res<PhysicsPickingSettings>,
+    #[cfg(feature = "3d")] ray_map: Res<RayMap>,
+    mut commands: Commands,
+) {
+    // Only handle the event once for the picked entity, not for each of its ancestors.
+    if event.entity != event.original_event_target() {
+        return;
+    }
+
+    let hit = &event.event.hit;
+    let Some(point) = hit.position else {
+        return;
+    };
+    let Ok(&ColliderOf { body }) = colliders.get(event.entity) else {
+        return;
+    };
+    let Ok(draggable) = draggables.get(body) else {
+        return;
+    };
+
+    // In 2D, project the pointer onto the Z plane used for picking.
+    #[cfg(feature = "2d")]
+    let (plane_origin, plane_normal) = (Vec3::new(0.0, 0.0, settings.z_plane), Dir3::Z);
+
+    // In 3D, project the pointer onto a plane that faces the camera and goes through the picked point.
+    #[cfg(feature = "3d")]
+    let (plane_origin, plane_normal) = (
+        point,
+        ray_map
+            .map
+            .get(&RayId::new(hit.camera, event.pointer_id))
+            .map_or(Dir3::Z, |ray| -ray.direction),
+    );
+
+    #[cfg(feature = "2d")]
+    let target = point.xy().real();
+    #[cfg(feature = "3d")]
+    let target = point.real();
+
+    commands.spawn((
+        TargetJoint::new(body, target)
+            .with_global_anchor(target)
+            .with_motor_model(draggable.motor_model)
+            .with_max_force(draggable.max_force),
+        PointerDragJoint {
+            pointer: event.pointer_id,
+            camera: hit.camera,
+            plane_origin,
+            plane: InfinitePlane3d::new(plane_normal),
+        },
+    ));
+}
+
+/// Moves the targets of the [`TargetJoint`]s of dragged bodies to follow the pointer.
+fn update_drag(
+    event: On<Pointer<Drag>>,
+    mut joints: Query<(&mut TargetJoint, &PointerDragJoint)>,
+    ray_map: Res<RayMap>,
+) {
+    if event.entity != event.original_event_target() {
+        return;
+    }
+
+    for (mut joint, drag) in &mut joints {
+        if drag.pointer != event.pointer_id {
+            continue;
+        }
+
+        let Some(ray) = ray_map.map.get(&RayId::new(drag.camera, drag.pointer)) else {
+            continue;
+        };
+        let Some(distance) = ray.intersect_plane(drag.plane_origin, drag.plane) else {
+            continue;
+        };
+        let point = ray.get_point(distance);
+
+        #[cfg(feature = "2d")]
+        {
+            joint.target = point.xy().real();
+        }
+        #[cfg(feature = "3d")]
+        {
+            joint.target = point.real();
+        }
+    }
+}
+
+/// Despawns the [`TargetJoint`]s of dragged bodies when the drag ends.
+fn end_drag(
+    event: On<Pointer<DragEnd>>,
+    joints: Query<(Entity, &PointerDragJoint)>,
+    mut commands: Commands,
+) {
+    if event.entity != event.original_event_target() {
+        return;
+    }
+
+    for (entity, drag) in &joints {
+        if drag.pointer == event.pointer_id {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
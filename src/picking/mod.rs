@@ -8,6 +8,10 @@
 //! to `true` and add a [`PhysicsPickable`] component to the desired camera and target entities.
 //!
 //! Cameras can further filter which entities are pickable with the [`PhysicsPickingFilter`] component.
+//!
+//! Rigid bodies with the [`PhysicsDraggable`] component can be dragged with pointers.
+//! Dragging uses a [`TargetJoint`] to pull the body towards the pointer, so the body
+//! still responds to collisions and other joints while it is being dragged.
 #![cfg_attr(
     feature = "3d",
     doc = "
@@ -15,6 +19,10 @@
 Note that in 3D, only the closest intersection will be reported."
 )]
 
+mod drag;
+
+pub use drag::PhysicsDraggable;
+
 use crate::{
     diagnostics::{PhysicsDiagnostics, impl_diagnostic_paths},
     prelude::*,
@@ -64,6 +72,8 @@ impl Plugin for PhysicsPickingPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PhysicsPickingSettings>()
             .add_systems(PreUpdate, update_hits.in_set(PickingSystems::Backend));
+
+        app.add_plugins(drag::plugin);
     }
 
     fn finish(&self, app: &mut App) {
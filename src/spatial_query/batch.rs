@@ -0,0 +1,199 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// The minimum number of queries in a batch for it to be processed in parallel.
+const MIN_PARALLEL_LEN: usize = 16;
+
+/// A [ray cast](spatial_query#raycasting) performed as a part of a batch with [`SpatialQuery::cast_rays`].
+///
+/// The fields correspond to the arguments of [`SpatialQuery::cast_ray`].
+#[derive(Clone, Copy, Debug)]
+pub struct RayCastQuery<'a> {
+    /// Where the ray is cast from.
+    pub origin: RVector,
+    /// What direction the ray is cast in.
+    pub direction: Dir,
+    /// The maximum distance the ray can travel.
+    pub max_distance: f32,
+    /// If true *and* the ray origin is inside of a collider, the hit point will be the ray origin itself.
+    /// Otherwise, the collider will be treated as hollow, and the hit point will be at its boundary.
+    pub solid: bool,
+    /// A [`SpatialQueryFilter`] that determines which entities are included in the cast.
+    pub filter: &'a SpatialQueryFilter,
+}
+
+/// A [shape cast](spatial_query#shapecasting) performed as a part of a batch with [`SpatialQuery::cast_shapes`].
+///
+/// The fields correspond to the arguments of [`SpatialQuery::cast_shape`].
+#[derive(Clone, Copy, Debug)]
+pub struct ShapeCastQuery<'a> {
+    /// The shape being cast represented as a [`Collider`].
+    pub shape: &'a Collider,
+    /// Where the shape is cast from.
+    pub origin: RVector,
+    /// The rotation of the shape being cast.
+    pub shape_rotation: Rotation,
+    /// What direction the shape is cast in.
+    pub direction: Dir,
+    /// A [`ShapeCastConfig`] that determines the behavior of the cast.
+    pub config: &'a ShapeCastConfig,
+    /// A [`SpatialQueryFilter`] that determines which entities are included in the cast.
+    pub filter: &'a SpatialQueryFilter,
+}
+
+/// A [point projection](spatial_query#point-projection) performed as a part of a batch
+/// with [`SpatialQuery::project_points`].
+///
+/// The fields correspond to the arguments of [`SpatialQuery::project_point`].
+#[derive(Clone, Copy, Debug)]
+pub struct PointProjectionQuery<'a> {
+    /// The point that should be projected.
+    pub point: RVector,
+    /// If true and the point is inside of a collider, the projection will be at the point.
+    /// Otherwise, the collider will be treated as hollow, and the projection will be at the collider's boundary.
+    pub solid: bool,
+    /// A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    pub filter: &'a SpatialQueryFilter,
+}
+
+impl SpatialQuery<'_, '_> {
+    /// Casts a batch of [rays](spatial_query#raycasting) and computes the closest [hit](RayHitData)
+    /// with a collider for each of them.
+    ///
+    /// The `results` are cleared, and the result of each query is written at the same index as the query.
+    /// The buffer can be reused across calls to avoid allocating memory.
+    ///
+    /// If the `parallel` feature is enabled, large batches are processed in parallel
+    /// using the [`ComputeTaskPool`](bevy::tasks::ComputeTaskPool).
+    ///
+    /// # Arguments
+    ///
+    /// - `queries`: The [`RayCastQuery`] for each ray.
+    /// - `results`: A buffer for the closest hit of each ray, or `None` if the ray didn't hit anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "2d")]
+    /// # use avian2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use avian3d::{math::RVec3, prelude::*};
+    /// use bevy::prelude::*;
+    ///
+    /// # #[cfg(feature = "3d")]
+    /// fn cast_vision_rays(
+    ///     spatial_query: SpatialQuery,
+    ///     mut hits: Local<Vec<Option<RayHitData>>>,
+    /// ) {
+    ///     let filter = SpatialQueryFilter::default();
+    ///
+    ///     // Cast 100 rays in a fan around the origin
+    ///     let queries: Vec<RayCastQuery> = (0..100)
+    ///         .map(|i| RayCastQuery {
+    ///             origin: RVec3::ZERO,
+    ///             direction: Dir3::new(Vec3::new(1.0, 0.0, i as f32 * 0.01)).unwrap(),
+    ///             max_distance: 100.0,
+    ///             solid: true,
+    ///             filter: &filter,
+    ///         })
+    ///         .collect();
+    ///
+    ///     spatial_query.cast_rays(&queries, &mut hits);
+    ///
+    ///     // Print the hits
+    ///     for hit in hits.iter().flatten() {
+    ///         println!("Hit: {:?}", hit);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Related Methods
+    ///
+    /// - [`SpatialQuery::cast_ray`]
+    /// - [`SpatialQuery::cast_shapes`]
+    /// - [`SpatialQuery::project_points`]
+    pub fn cast_rays(&self, queries: &[RayCastQuery], results: &mut Vec<Option<RayHitData>>) {
+        results.clear();
+        results.resize(queries.len(), None);
+
+        crate::utils::par_for_each(results, MIN_PARALLEL_LEN, |index, result| {
+            let query = &queries[index];
+            *result = self.cast_ray(
+                query.origin,
+                query.direction,
+                query.max_distance,
+                query.solid,
+                query.filter,
+            );
+        });
+    }
+
+    /// Casts a batch of [shapes](spatial_query#shapecasting) and computes the closest [hit](ShapeHitData)
+    /// with a collider for each of them.
+    ///
+    /// The `results` are cleared, and the result of each query is written at the same index as the query.
+    /// The buffer can be reused across calls to avoid allocating memory.
+    ///
+    /// If the `parallel` feature is enabled, large batches are processed in parallel
+    /// using the [`ComputeTaskPool`](bevy::tasks::ComputeTaskPool).
+    ///
+    /// # Arguments
+    ///
+    /// - `queries`: The [`ShapeCastQuery`] for each shape.
+    /// - `results`: A buffer for the closest hit of each shape, or `None` if the shape didn't hit anything.
+    ///
+    /// # Related Methods
+    ///
+    /// - [`SpatialQuery::cast_shape`]
+    /// - [`SpatialQuery::cast_rays`]
+    /// - [`SpatialQuery::project_points`]
+    pub fn cast_shapes(&self, queries: &[ShapeCastQuery], results: &mut Vec<Option<ShapeHitData>>) {
+        results.clear();
+        results.resize(queries.len(), None);
+
+        crate::utils::par_for_each(results, MIN_PARALLEL_LEN, |index, result| {
+            let query = &queries[index];
+            *result = self.cast_shape(
+                query.shape,
+                query.origin,
+                query.shape_rotation,
+                query.direction,
+                query.config,
+                query.filter,
+            );
+        });
+    }
+
+    /// Finds the [projection](spatial_query#point-projection) of each point in a batch
+    /// on the closest [collider](Collider).
+    ///
+    /// The `results` are cleared, and the result of each query is written at the same index as the query.
+    /// The buffer can be reused across calls to avoid allocating memory.
+    ///
+    /// If the `parallel` feature is enabled, large batches are processed in parallel
+    /// using the [`ComputeTaskPool`](bevy::tasks::ComputeTaskPool).
+    ///
+    /// # Arguments
+    ///
+    /// - `queries`: The [`PointProjectionQuery`] for each point.
+    /// - `results`: A buffer for the projection of each point, or `None` if no collider was found.
+    ///
+    /// # Related Methods
+    ///
+    /// - [`SpatialQuery::project_point`]
+    /// - [`SpatialQuery::cast_rays`]
+    /// - [`SpatialQuery::cast_shapes`]
+    pub fn project_points(
+        &self,
+        queries: &[PointProjectionQuery],
+        results: &mut Vec<Option<PointProjection>>,
+    ) {
+        results.clear();
+        results.resize(queries.len(), None);
+
+        crate::utils::par_for_each(results, MIN_PARALLEL_LEN, |index, result| {
+            let query = &queries[index];
+            *result = self.project_point(query.point, query.solid, query.filter);
+        });
+    }
+}
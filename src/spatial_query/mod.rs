@@ -149,7 +149,17 @@
 //! See the documentation of the components and methods for more information.
 //!
 //! To specify which colliders should be considered in the query, use a [spatial query filter](`SpatialQueryFilter`).
+//!
+//! # Batched queries
+//!
+//! Ray casts, shape casts, and point projections can also be performed in batches using the
+//! [`cast_rays`](SpatialQuery::cast_rays), [`cast_shapes`](SpatialQuery::cast_shapes), and
+//! [`project_points`](SpatialQuery::project_points) methods. Each query in a batch has its own
+//! [`SpatialQueryFilter`], and the results are written into a buffer provided by the caller.
+//! If the `parallel` feature is enabled, large batches are processed in parallel.
 
+#[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+mod batch;
 mod query_filter;
 mod ray_caster;
 #[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
@@ -157,9 +167,14 @@ mod shape_caster;
 #[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
 mod system_param;
 
+#[cfg(all(test, any(feature = "parry-f32", feature = "parry-f64")))]
+mod tests;
+
 mod diagnostics;
 pub use diagnostics::SpatialQueryDiagnostics;
 
+#[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+pub use batch::*;
 pub use query_filter::*;
 pub use ray_caster::*;
 #[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
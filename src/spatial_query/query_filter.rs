@@ -4,6 +4,9 @@ use crate::prelude::*;
 
 /// Rules that determine which colliders are taken into account in [spatial queries](crate::spatial_query).
 ///
+/// [`SpatialQuery`] also takes the [`CollisionMatrix`] resource into account,
+/// treating the query as if it belonged to the layers in [`memberships`](Self::memberships).
+///
 /// # Example
 ///
 /// ```
@@ -35,6 +38,12 @@ use crate::prelude::*;
 pub struct SpatialQueryFilter {
     /// Specifies which [collision layers](CollisionLayers) will be included in the [spatial query](crate::spatial_query).
     pub mask: LayerMask,
+    /// The [collision layers](CollisionLayers) that the [spatial query](crate::spatial_query) belongs to.
+    ///
+    /// These are only used for checking which layers can interact according to the [`CollisionMatrix`] resource.
+    /// By default, the query belongs to all layers, so only colliders in layers
+    /// that cannot interact with any layer are excluded by the matrix.
+    pub memberships: LayerMask,
     /// Entities that will not be included in [spatial queries](crate::spatial_query).
     pub excluded_entities: EntityHashSet,
 }
@@ -50,6 +59,7 @@ impl SpatialQueryFilter {
     /// and has no excluded entities.
     pub const DEFAULT: Self = Self {
         mask: LayerMask::ALL,
+        memberships: LayerMask::ALL,
         excluded_entities: EntityHashSet::new(),
     };
 
@@ -65,6 +75,21 @@ impl SpatialQueryFilter {
         }
     }
 
+    /// Creates a new [`SpatialQueryFilter`] that includes the [collision layers] that colliders
+    /// with the given `memberships` can interact with according to the [`CollisionMatrix`].
+    ///
+    /// This can be used to make a [spatial query] behave like a collider in the given layers.
+    ///
+    /// [collision layers]: CollisionLayers
+    /// [spatial query]: crate::spatial_query
+    pub fn from_collision_matrix(
+        collision_matrix: &CollisionMatrix,
+        memberships: impl Into<LayerMask>,
+    ) -> Self {
+        let memberships = memberships.into();
+        Self::from_mask(collision_matrix.filters(memberships)).with_memberships(memberships)
+    }
+
     /// Creates a new [`SpatialQueryFilter`] with the given entities excluded from the [spatial query].
     ///
     /// [spatial query]: crate::spatial_query
@@ -85,6 +110,13 @@ impl SpatialQueryFilter {
         self
     }
 
+    /// Sets the [collision layers](CollisionLayers) that the [spatial query](crate::spatial_query) belongs to.
+    /// They are used for checking which layers can interact according to the [`CollisionMatrix`] resource.
+    pub fn with_memberships(mut self, memberships: impl Into<LayerMask>) -> Self {
+        self.memberships = memberships.into();
+        self
+    }
+
     /// Excludes the given entities from the [spatial query](crate::spatial_query).
     pub fn with_excluded_entities(mut self, entities: impl IntoIterator<Item = Entity>) -> Self {
         self.excluded_entities = EntityHashSet::from_iter(entities);
@@ -99,4 +131,21 @@ impl SpatialQueryFilter {
             && CollisionLayers::new(LayerMask::ALL, self.mask)
                 .interacts_with(CollisionLayers::new(layers.memberships, LayerMask::ALL))
     }
+
+    /// Tests if an entity should be included in [spatial queries] based on the filter configuration
+    /// and the given [`CollisionMatrix`].
+    ///
+    /// [spatial queries]: crate::spatial_query
+    pub fn test_with_collision_matrix(
+        &self,
+        entity: Entity,
+        layers: CollisionLayers,
+        collision_matrix: &CollisionMatrix,
+    ) -> bool {
+        !self.excluded_entities.contains(&entity)
+            && collision_matrix.layers_interact(
+                CollisionLayers::new(self.memberships, self.mask),
+                CollisionLayers::new(layers.memberships, LayerMask::ALL),
+            )
+    }
 }
@@ -11,6 +11,8 @@ use parry::query::ShapeCastOptions;
 /// - [Shapecasting](spatial_query#shapecasting): [`cast_shape`](SpatialQuery::cast_shape), [`cast_shape_predicate`](SpatialQuery::cast_shape_predicate),
 ///   [`shape_hits`](SpatialQuery::shape_hits), [`shape_hits_callback`](SpatialQuery::shape_hits_callback)
 /// - [Point projection](spatial_query#point-projection): [`project_point`](SpatialQuery::project_point) and [`project_point_predicate`](SpatialQuery::project_point_predicate)
+/// - [Batched queries](spatial_query#batched-queries): [`cast_rays`](SpatialQuery::cast_rays), [`cast_shapes`](SpatialQuery::cast_shapes),
+///   and [`project_points`](SpatialQuery::project_points)
 /// - [Intersection tests](spatial_query#intersection-tests)
 ///     - Point intersections: [`point_intersections`](SpatialQuery::point_intersections),
 ///       [`point_intersections_callback`](SpatialQuery::point_intersections_callback)
@@ -61,9 +63,27 @@ pub struct SpatialQuery<'w, 's> {
     colliders: Query<'w, 's, (&'static Position, &'static Rotation, &'static Collider)>,
     aabbs: Query<'w, 's, &'static ColliderAabb>,
     collider_trees: Res<'w, ColliderTrees>,
+    collision_matrix: Option<Res<'w, CollisionMatrix>>,
 }
 
 impl SpatialQuery<'_, '_> {
+    /// Tests if a collider should be included in a query based on the given [`SpatialQueryFilter`]
+    /// and the [`CollisionMatrix`], if it exists.
+    #[inline]
+    pub(crate) fn test_filter(
+        &self,
+        filter: &SpatialQueryFilter,
+        entity: Entity,
+        layers: CollisionLayers,
+    ) -> bool {
+        match &self.collision_matrix {
+            Some(collision_matrix) => {
+                filter.test_with_collision_matrix(entity, layers, collision_matrix)
+            }
+            None => filter.test(entity, layers),
+        }
+    }
+
     /// Casts a [ray](spatial_query#raycasting) and computes the closest [hit](RayHitData) with a collider.
     /// If there are no hits, `None` is returned.
     ///
@@ -189,7 +209,9 @@ impl SpatialQuery<'_, '_> {
         self.collider_trees.iter_trees().for_each(|tree| {
             tree.ray_traverse_closest(ray, max_distance, |proxy_id| {
                 let proxy = tree.get_proxy(proxy_id).unwrap();
-                if !filter.test(proxy.collider, proxy.layers) || !predicate(proxy.collider) {
+                if !self.test_filter(filter, proxy.collider, proxy.layers)
+                    || !predicate(proxy.collider)
+                {
                     return f32::MAX;
                 }
 
@@ -366,7 +388,7 @@ impl SpatialQuery<'_, '_> {
             tree.ray_traverse_all(ray, max_distance, |proxy_id| {
                 let proxy = tree.get_proxy(proxy_id).unwrap();
 
-                if !filter.test(proxy.collider, proxy.layers) {
+                if !self.test_filter(filter, proxy.collider, proxy.layers) {
                     return true;
                 }
 
@@ -546,7 +568,9 @@ impl SpatialQuery<'_, '_> {
                 |proxy_id| {
                     let proxy = tree.get_proxy(proxy_id).unwrap();
 
-                    if !filter.test(proxy.collider, proxy.layers) || !predicate(proxy.collider) {
+                    if !self.test_filter(filter, proxy.collider, proxy.layers)
+                        || !predicate(proxy.collider)
+                    {
                         return f32::MAX;
                     }
 
@@ -762,7 +786,7 @@ impl SpatialQuery<'_, '_> {
                 |proxy_id| {
                     let proxy = tree.get_proxy(proxy_id).unwrap();
 
-                    if !filter.test(proxy.collider, proxy.layers) {
+                    if !self.test_filter(filter, proxy.collider, proxy.layers) {
                         return true;
                     }
 
@@ -906,7 +930,9 @@ impl SpatialQuery<'_, '_> {
         self.collider_trees.iter_trees().for_each(|tree| {
             tree.squared_distance_traverse_closest(point, f32::INFINITY, |proxy_id| {
                 let proxy = tree.get_proxy(proxy_id).unwrap();
-                if !filter.test(proxy.collider, proxy.layers) || !predicate(proxy.collider) {
+                if !self.test_filter(filter, proxy.collider, proxy.layers)
+                    || !predicate(proxy.collider)
+                {
                     return f32::INFINITY;
                 }
 
@@ -1026,7 +1052,7 @@ impl SpatialQuery<'_, '_> {
             tree.point_traverse(point.f32(), |proxy_id| {
                 let proxy = tree.get_proxy(proxy_id).unwrap();
 
-                if !filter.test(proxy.collider, proxy.layers) {
+                if !self.test_filter(filter, proxy.collider, proxy.layers) {
                     return true;
                 }
 
@@ -1256,7 +1282,7 @@ impl SpatialQuery<'_, '_> {
         self.collider_trees.iter_trees().for_each(|tree| {
             tree.aabb_traverse(aabb, |proxy_id| {
                 let proxy = tree.get_proxy(proxy_id).unwrap();
-                if !filter.test(proxy.collider, proxy.layers) {
+                if !self.test_filter(filter, proxy.collider, proxy.layers) {
                     return true;
                 }
 
@@ -0,0 +1,104 @@
+use bevy::{ecs::system::SystemState, prelude::*};
+
+use crate::{math::Real, prelude::*, tests::utils::create_app};
+
+/// Tests that batched ray casts, shape casts, and point projections return the same results
+/// as the corresponding single queries, each with its own filter.
+#[test]
+fn batched_spatial_queries_match_single_queries() {
+    let mut app = create_app();
+    app.finish();
+
+    #[cfg(feature = "2d")]
+    let box_collider = Collider::rectangle(1.0, 1.0);
+    #[cfg(feature = "3d")]
+    let box_collider = Collider::cuboid(1.0, 1.0, 1.0);
+
+    // A row of boxes to the right of the origin.
+    let boxes: Vec<Entity> = (1..=3)
+        .map(|i| {
+            app.world_mut()
+                .spawn((
+                    RigidBody::Static,
+                    box_collider.clone(),
+                    Transform::from_xyz(2.0 * i as f32, 0.0, 0.0),
+                ))
+                .id()
+        })
+        .collect();
+
+    // Run the physics schedule to insert the colliders into the collider trees.
+    for _ in 0..2 {
+        app.update();
+    }
+
+    let mut state = SystemState::<SpatialQuery>::new(app.world_mut());
+    let spatial_query = state.get(app.world()).unwrap();
+
+    // Every other query ignores the closest box.
+    let filters = [
+        SpatialQueryFilter::default(),
+        SpatialQueryFilter::from_excluded_entities([boxes[0]]),
+    ];
+    let shape_cast_config = ShapeCastConfig::from_max_distance(100.0);
+    let query_count = 64;
+
+    let ray_queries: Vec<RayCastQuery> = (0..query_count)
+        .map(|i| RayCastQuery {
+            origin: RVector::Y * (i as Real * 0.005),
+            direction: Dir::X,
+            max_distance: 100.0,
+            solid: true,
+            filter: &filters[i % 2],
+        })
+        .collect();
+    let shape_queries: Vec<ShapeCastQuery> = ray_queries
+        .iter()
+        .map(|query| ShapeCastQuery {
+            shape: &box_collider,
+            origin: query.origin,
+            shape_rotation: Rotation::IDENTITY,
+            direction: query.direction,
+            config: &shape_cast_config,
+            filter: query.filter,
+        })
+        .collect();
+    let point_queries: Vec<PointProjectionQuery> = ray_queries
+        .iter()
+        .map(|query| PointProjectionQuery {
+            point: query.origin,
+            solid: true,
+            filter: query.filter,
+        })
+        .collect();
+
+    // Start with a buffer that has stale results.
+    let mut ray_hits = vec![None; 3];
+    let mut shape_hits = Vec::new();
+    let mut projections = Vec::new();
+    spatial_query.cast_rays(&ray_queries, &mut ray_hits);
+    spatial_query.cast_shapes(&shape_queries, &mut shape_hits);
+    spatial_query.project_points(&point_queries, &mut projections);
+
+    assert_eq!(ray_hits.len(), query_count);
+    assert_eq!(shape_hits.len(), query_count);
+    assert_eq!(projections.len(), query_count);
+
+    for (i, query) in ray_queries.iter().enumerate() {
+        let expected_entity = boxes[i % 2];
+        let hit = ray_hits[i].unwrap();
+        assert_eq!(hit.entity, expected_entity);
+        assert_eq!(
+            Some(hit),
+            spatial_query.cast_ray(
+                query.origin,
+                query.direction,
+                query.max_distance,
+                query.solid,
+                query.filter,
+            )
+        );
+        assert_eq!(shape_hits[i].unwrap().entity, expected_entity);
+        assert_eq!(projections[i].as_ref().unwrap().entity, expected_entity);
+    }
+}
@@ -15,6 +15,8 @@ use core::time::Duration;
 
 #[cfg(all(feature = "2d", feature = "enhanced-determinism"))]
 mod determinism_2d;
+#[cfg(any(feature = "parry-f32", feature = "parry-f64"))]
+pub(crate) mod utils;
 
 fn create_app() -> App {
     let mut app = App::new();
@@ -26,6 +28,8 @@ fn create_app() -> App {
         bevy::asset::AssetPlugin::default(),
         #[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
         bevy::mesh::MeshPlugin,
+        #[cfg(all(feature = "collider-from-image", feature = "default-collider"))]
+        bevy::image::ImagePlugin::default(),
         #[cfg(feature = "bevy_scene")]
         bevy::scene::ScenePlugin,
     ))
@@ -200,6 +204,23 @@ fn no_ambiguity_errors() {
         bevy::scene::ScenePlugin,
         #[cfg(all(feature = "collider-from-mesh", feature = "default-collider"))]
         bevy::mesh::MeshPlugin,
+        #[cfg(all(feature = "collider-from-image", feature = "default-collider"))]
+        bevy::image::ImagePlugin::default(),
+    ))
+    // Also check the plugins that are not included in `PhysicsPlugins`.
+    .add_plugins((
+        ForceFieldPlugin,
+        #[cfg(feature = "default-collider")]
+        FluidPlugin,
+        #[cfg(feature = "default-collider")]
+        AerodynamicsPlugin,
+        #[cfg(all(feature = "3d", feature = "default-collider"))]
+        RagdollPlugin::new(DeterministicSchedule),
+        #[cfg(all(
+            feature = "default-collider",
+            any(feature = "parry-f32", feature = "parry-f64")
+        ))]
+        CharacterControllerPlugin::new(DeterministicSchedule),
     ))
     .edit_schedule(DeterministicSchedule, |s| {
         s.set_build_settings(ScheduleBuildSettings {
@@ -0,0 +1,38 @@
+//! Shared helpers for the unit tests of individual modules.
+
+use core::time::Duration;
+
+use bevy::{mesh::MeshPlugin, prelude::*, time::TimeUpdateStrategy};
+
+use crate::prelude::*;
+
+/// The fixed timestep used by apps created with [`create_app`].
+pub(crate) const TIMESTEP: f32 = 1.0 / 64.0;
+
+/// Creates an app with the [`PhysicsPlugins`] and a gravity of 9.81 m/s².
+///
+/// Each [`App::update`] runs exactly one physics step of [`TIMESTEP`] seconds.
+pub(crate) fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        PhysicsPlugins::default(),
+        TransformPlugin,
+        #[cfg(feature = "bevy_scene")]
+        AssetPlugin::default(),
+        #[cfg(feature = "bevy_scene")]
+        bevy::scene::ScenePlugin,
+        MeshPlugin,
+    ));
+
+    app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+    app.insert_resource(Time::<Fixed>::from_duration(Duration::from_secs_f32(
+        TIMESTEP,
+    )));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        TIMESTEP,
+    )));
+
+    app
+}